@@ -6,6 +6,7 @@ use log::info;
 use ruoyi_framework::cache::{init_global_cache_async, is_global_cache_initialized};
 use ruoyi_framework::db::DbManager;
 use ruoyi_framework::web::service::captcha::InMemoryCaptchaService;
+use ruoyi_system::repository::change_log_repository::ChangeLogRepositoryImpl;
 use ruoyi_system::repository::config_repository::ConfigRepositoryImpl;
 use ruoyi_system::repository::dept_repository::DeptRepositoryImpl;
 use ruoyi_system::repository::dict_data_repository::DictDataRepositoryImpl;
@@ -16,13 +17,18 @@ use ruoyi_system::repository::notice_repository::NoticeRepositoryImpl;
 use ruoyi_system::repository::oper_log_repository::OperLogRepositoryImpl;
 use ruoyi_system::repository::post_repository::PostRepositoryImpl;
 use ruoyi_system::repository::role_repository::RoleRepositoryImpl;
+use ruoyi_system::repository::tenant_package_repository::TenantPackageRepositoryImpl;
+use ruoyi_system::repository::tenant_repository::TenantRepositoryImpl;
 use ruoyi_system::repository::user_repository::UserRepositoryImpl;
 use ruoyi_system::service::{
-    config_service, dept_service, dict_data_service, dict_type_service, login_info_service,
-    menu_service, notice_service, oper_log_service, post_service, role_service, user_service,
+    change_log_service, config_service, dept_service, dict_data_service, dict_type_service,
+    login_info_service, menu_service, notice_service, oper_log_service, post_service, role_service,
+    tenant_package_service, tenant_service, user_service,
 };
 use std::sync::Arc;
+use utoipa::OpenApi;
 
+use ruoyi_framework::config::server::ApiDocSettings;
 use ruoyi_framework::config::AppConfig;
 use ruoyi_framework::web::middleware::{
     auth::Authentication, cors::default_cors, error::ErrorHandling, logger::RequestLogger,
@@ -32,6 +38,17 @@ use ruoyi_framework::web::middleware::{
 // 导入自定义中间件
 // 导入路由处理函数
 use ruoyi_framework::web::controller::common::{captcha_image, health_check};
+use ruoyi_framework::web::openapi::{api_docs_paths, configure_api_docs, SecurityAddon};
+use ruoyi_framework::web::CommonApi;
+
+/// 接口文档，除标注了 `security(())` 的公开接口外都需要令牌认证
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "若依管理系统", description = "若依管理系统 Rust 版接口文档"),
+    modifiers(&SecurityAddon),
+    security(("bearer_auth" = []))
+)]
+struct ApiDoc;
 
 /// 配置应用程序
 pub fn configure_app(
@@ -51,10 +68,14 @@ pub fn configure_app(
 > {
     // 创建应用
     let performance_threshold_ms = 500;
+    let api_doc_settings = config.server.api_docs.clone();
     let config_data = web::Data::new(config);
     // 创建验证码服务实现
     let captcha_service = web::Data::new(InMemoryCaptchaService::new(captcha_cache));
-    let user_repository = Arc::new(UserRepositoryImpl::new(db_manager.get_connection()));
+    let user_repository = Arc::new(
+        UserRepositoryImpl::new(db_manager.get_connection())
+            .with_read_router(db_manager.read_router()),
+    );
     let role_repository = Arc::new(RoleRepositoryImpl::new(db_manager.get_connection()));
     let menu_repository = Arc::new(MenuRepositoryImpl::new(db_manager.get_connection()));
     let dept_repository = Arc::new(DeptRepositoryImpl::new(db_manager.get_connection()));
@@ -63,16 +84,43 @@ pub fn configure_app(
     let dict_data_repository = Arc::new(DictDataRepositoryImpl::new(db_manager.get_connection()));
     let post_repository = Arc::new(PostRepositoryImpl::new(db_manager.get_connection()));
     let notice_repository = Arc::new(NoticeRepositoryImpl::new(db_manager.get_connection()));
+    let tenant_repository = Arc::new(TenantRepositoryImpl::new(db_manager.get_connection()));
+    let tenant_package_repository = Arc::new(TenantPackageRepositoryImpl::new(
+        db_manager.get_connection(),
+    ));
 
-    let oper_log_repository = Arc::new(OperLogRepositoryImpl::new(db_manager.get_connection()));
-    let login_info_repository = Arc::new(LoginInfoRepositoryImpl::new(db_manager.get_connection()));
+    // 日志和用户列表的读请求较多，列表查询走只读副本
+    let oper_log_repository = Arc::new(
+        OperLogRepositoryImpl::new(db_manager.get_connection())
+            .with_read_router(db_manager.read_router()),
+    );
+    let login_info_repository = Arc::new(
+        LoginInfoRepositoryImpl::new(db_manager.get_connection())
+            .with_read_router(db_manager.read_router()),
+    );
+    let change_log_repository = Arc::new(ChangeLogRepositoryImpl::new(db_manager.get_connection()));
 
     let user_service = web::Data::new(user_service::UserServiceImpl::new(
-        user_repository,
+        db_manager.get_connection(),
+        user_repository.clone(),
         role_repository.clone(),
+        post_repository.clone(),
         menu_repository.clone(),
         dept_repository.clone(),
     ));
+    let tenant_service = web::Data::new(tenant_service::TenantServiceImpl::new(
+        db_manager.get_connection(),
+        tenant_repository.clone(),
+        tenant_package_repository.clone(),
+        dept_repository.clone(),
+        role_repository.clone(),
+        user_repository,
+    ));
+    let tenant_package_service =
+        web::Data::new(tenant_package_service::TenantPackageServiceImpl::new(
+            tenant_package_repository,
+            tenant_repository,
+        ));
     let role_service = web::Data::new(role_service::RoleServiceImpl::new(role_repository));
     let menu_service = web::Data::new(menu_service::MenuServiceImpl::new(menu_repository));
     let dept_service = web::Data::new(dept_service::DeptServiceImpl::new(dept_repository));
@@ -92,6 +140,9 @@ pub fn configure_app(
     let login_info_service = web::Data::new(login_info_service::LoginInfoServiceImpl::new(
         login_info_repository,
     ));
+    let change_log_service = web::Data::new(change_log_service::ChangeLogServiceImpl::new(
+        change_log_repository,
+    ));
 
     // 初始化代码生成服务
     let gen_controller = ruoyi_generator::init(db_manager.get_connection());
@@ -125,13 +176,18 @@ pub fn configure_app(
         .app_data(notice_service)
         .app_data(oper_log_service)
         .app_data(login_info_service)
+        .app_data(change_log_service)
+        .app_data(tenant_service)
+        .app_data(tenant_package_service)
         .app_data(gen_controller)
         // 基础健康检查路由
         .service(health_check)
         // 验证码路由
         .service(captcha_image)
         // API 路由分组
-        .configure(register_routes);
+        .configure(register_routes)
+        // 接口文档
+        .configure(|cfg| configure_api_docs(cfg, &api_doc_settings, api_doc()));
 
     info!("应用程序配置完成，已注册所有中间件和路由");
     app
@@ -143,9 +199,18 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
     cfg.configure(ruoyi_generator::register_routes);
 }
 
+/// 合并各模块的接口文档
+pub fn api_doc() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(CommonApi::openapi());
+    openapi.merge(ruoyi_system::openapi());
+    openapi.merge(ruoyi_generator::openapi());
+    openapi
+}
+
 /// 获取不需要认证的路径列表
-pub fn get_exclude_paths() -> Vec<String> {
-    vec![
+pub fn get_exclude_paths(api_docs: &ApiDocSettings) -> Vec<String> {
+    let mut paths = vec![
         "/login".to_string(),              // 登录接口
         "/logout".to_string(), // 登出接口（实际上登出需要认证，但若依的前端可能依赖这个行为）
         "/captchaImage".to_string(), // 验证码接口
@@ -157,7 +222,10 @@ pub fn get_exclude_paths() -> Vec<String> {
         "/static".to_string(),
         // 系统模块公共API（需要认证的API应当通过权限控制访问）
         "/api/system/menu/user-menu-tree".to_string(),
-    ]
+    ];
+    // 启用时接口文档和 Swagger UI 不需要认证
+    paths.extend(api_docs_paths(api_docs));
+    paths
 }
 
 pub async fn init_global_cache(config: Arc<AppConfig>) {
@@ -179,3 +247,34 @@ pub async fn init_global_cache(config: Arc<AppConfig>) {
         println!("全局缓存已经初始化");
     }
 }
+
+/// 启动时预热缓存，将所有参数和字典数据加载到缓存中
+pub async fn warm_up_cache(db_manager: Arc<DbManager>) {
+    use config_service::ConfigService;
+    use dict_data_service::DictDataService;
+    use dict_type_service::DictTypeService;
+
+    let config_service = config_service::ConfigServiceImpl::new(Arc::new(
+        ConfigRepositoryImpl::new(db_manager.get_connection()),
+    ));
+    if let Err(e) = config_service.load_config_cache().await {
+        log::warn!("预热参数缓存失败: {}", e);
+    }
+
+    let dict_type_service = dict_type_service::DictTypeServiceImpl::new(Arc::new(
+        DictTypeRepositoryImpl::new(db_manager.get_connection()),
+    ));
+    let dict_data_service = dict_data_service::DictDataServiceImpl::new(Arc::new(
+        DictDataRepositoryImpl::new(db_manager.get_connection()),
+    ));
+    match dict_type_service.get_all_dict_types().await {
+        Ok(dict_types) => {
+            let dict_types: Vec<String> =
+                dict_types.into_iter().filter_map(|t| t.dict_type).collect();
+            if let Err(e) = dict_data_service.load_dict_cache(&dict_types).await {
+                log::warn!("预热字典缓存失败: {}", e);
+            }
+        }
+        Err(e) => log::warn!("预热字典缓存失败: {}", e),
+    }
+}
@@ -0,0 +1,21 @@
+// ruoyi-admin/src/encrypt.rs
+//! 字段加密：`ruoyi-admin encrypt` 命令
+
+use ruoyi_framework::db::encryption::field_cipher;
+use ruoyi_framework::db::DbManager;
+use std::io::{Error, Result};
+
+/// 用当前密钥重新加密各模块的加密字段并重建盲索引
+///
+/// 轮换加密密钥、更换盲索引密钥或首次启用加密后执行，已是最新状态的记录不会被修改
+pub async fn run_command(db_manager: &DbManager) -> Result<()> {
+    if !field_cipher().is_enabled() {
+        println!("未配置当前加密密钥，只重建盲索引");
+    }
+    let db = db_manager.get_connection();
+    let updated = ruoyi_system::rotate_encryption(db.as_ref())
+        .await
+        .map_err(|e| Error::other(e.to_string()))?;
+    println!("重新加密完成，共更新 {} 条记录", updated);
+    Ok(())
+}
@@ -1,11 +1,15 @@
 //! 若依管理系统的 Rust 实现版本的主程序
 mod app;
+mod encrypt;
+mod migrate;
 
 use actix_web::HttpServer;
-use app::init_global_cache;
+use app::{init_global_cache, warm_up_cache};
 use dashmap::DashMap;
 use log::info;
-use ruoyi_framework::config::{db::DbSettings, load_config};
+use ruoyi_framework::cache::shutdown_global_cache;
+use ruoyi_framework::config::load_config;
+use ruoyi_framework::db::encryption::{field_cipher, init_field_cipher};
 use ruoyi_framework::db::DbManager;
 use ruoyi_framework::logger::init_logger_with_db;
 use std::sync::Arc;
@@ -17,6 +21,15 @@ async fn main() -> std::io::Result<()> {
 
     println!("若依管理系统启动中...");
 
+    // 初始化字段加密密钥，配置错误时拒绝启动
+    if let Err(e) = init_field_cipher(&app_config.database.encryption) {
+        println!("字段加密配置错误: {}", e);
+        return Err(std::io::Error::other(e.to_string()));
+    }
+    if !field_cipher().is_enabled() {
+        println!("未配置字段加密密钥，敏感字段将以明文存储");
+    }
+
     let jwt_secret = app_config.jwt.secret.clone();
 
     // 获取服务器配置
@@ -25,10 +38,10 @@ async fn main() -> std::io::Result<()> {
     let server_url = format!("{}:{}", host, port);
 
     // 获取排除认证的路径
-    let exclude_paths = app::get_exclude_paths();
+    let exclude_paths = app::get_exclude_paths(&app_config.server.api_docs);
 
     // 获取数据库连接
-    let db_settings = DbSettings::from_url(&app_config.database.url);
+    let db_settings = (*app_config.database).clone();
     let db_manager = match DbManager::new(db_settings).await {
         Ok(manager) => {
             println!("数据库连接初始化成功");
@@ -43,17 +56,35 @@ async fn main() -> std::io::Result<()> {
         }
     };
 
+    // 执行迁移命令后退出：ruoyi-admin migrate [up|status|baseline]
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("migrate") {
+        return migrate::run_command(&db_manager, &args[1..]).await;
+    }
+
+    // 重新加密敏感字段后退出：ruoyi-admin encrypt
+    if args.first().map(String::as_str) == Some("encrypt") {
+        return encrypt::run_command(&db_manager).await;
+    }
+
+    // 数据库版本与程序不一致时拒绝启动
+    if let Err(e) = migrate::migrate_on_startup(&db_manager).await {
+        println!("数据库迁移失败: {}", e);
+        return Err(e);
+    }
+
     // 初始化日志系统
     init_logger_with_db(db_manager.get_connection()).expect("初始化日志系统失败");
     init_global_cache(app_config.clone()).await;
+    warm_up_cache(db_manager.clone()).await;
 
     let captcha_cache = Arc::new(DashMap::new());
 
     info!("服务器地址: http://{}", server_url);
     info!("排除认证的路径: {:?}", exclude_paths);
 
-    // 启动 HTTP 服务器
-    HttpServer::new(move || {
+    // 启动 HTTP 服务器，收到停止信号后等待请求处理完毕再返回
+    let result = HttpServer::new(move || {
         // 配置应用
         app::configure_app(
             app_config.clone(),
@@ -66,5 +97,10 @@ async fn main() -> std::io::Result<()> {
     .bind(server_url)?
     .workers(4) // 设置工作线程数
     .run()
-    .await
+    .await;
+
+    // 停机时保存本地缓存快照
+    shutdown_global_cache().await;
+
+    result
 }
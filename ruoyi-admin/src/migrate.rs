@@ -0,0 +1,84 @@
+// ruoyi-admin/src/migrate.rs
+//! 数据库迁移：启动时自动迁移和校验，以及 `ruoyi-admin migrate` 命令
+
+use ruoyi_framework::db::migration::{Migration, Migrator};
+use ruoyi_framework::db::DbManager;
+use std::io::{Error, Result};
+
+/// 所有模块的迁移，按模块依赖顺序排列
+pub fn migrations() -> Vec<Migration> {
+    let mut migrations = ruoyi_system::migrations();
+    migrations.extend(ruoyi_generator::migrations());
+    migrations.extend(ruoyi_cms::migrations());
+    migrations
+}
+
+/// 启动时按配置执行迁移，校验数据库版本与程序一致，并补齐历史数据缺失的盲索引
+pub async fn migrate_on_startup(db_manager: &DbManager) -> Result<()> {
+    let settings = db_manager.get_config().migration.clone();
+    let db = db_manager.get_connection();
+    let migrator = Migrator::new(db.clone(), migrations());
+
+    if settings.auto_migrate {
+        for status in migrator.up(settings.seed).await.map_err(other)? {
+            println!("数据库迁移完成: {}", status);
+        }
+    }
+    migrator.verify().await.map_err(other)?;
+
+    // 盲索引为空的记录无法按手机号、邮箱查询，唯一性校验会漏掉，补齐前不能对外服务
+    let updated = ruoyi_system::backfill_encryption_indexes(db.as_ref())
+        .await
+        .map_err(other)?;
+    if updated > 0 {
+        println!("已补齐 {} 条记录的盲索引", updated);
+    }
+    Ok(())
+}
+
+/// 执行迁移命令
+///
+/// - `migrate` / `migrate up`：执行所有未执行的迁移（包括初始数据）
+/// - `migrate status`：查看迁移状态
+/// - `migrate baseline [版本]`：将不超过指定版本（默认1）的迁移标记为已执行，
+///   用于接管之前通过 database.sql 初始化的数据库
+pub async fn run_command(db_manager: &DbManager, args: &[String]) -> Result<()> {
+    let migrator = Migrator::new(db_manager.get_connection(), migrations());
+
+    match args.first().map(String::as_str).unwrap_or("up") {
+        "up" => {
+            let executed = migrator.up(true).await.map_err(other)?;
+            for status in &executed {
+                println!("数据库迁移完成: {}", status);
+            }
+            println!("共执行 {} 个迁移", executed.len());
+        }
+        "status" => {
+            for status in migrator.status().await.map_err(other)? {
+                println!("{}", status);
+            }
+        }
+        "baseline" => {
+            let version = match args.get(1) {
+                Some(version) => version
+                    .parse()
+                    .map_err(|_| other(format!("无效的版本号: {}", version)))?,
+                None => 1,
+            };
+            for status in migrator.baseline(version).await.map_err(other)? {
+                println!("标记为已执行: {}", status);
+            }
+        }
+        command => {
+            return Err(other(format!(
+                "未知的迁移命令: {}，可用命令: up、status、baseline [版本]",
+                command
+            )))
+        }
+    }
+    Ok(())
+}
+
+fn other(e: impl ToString) -> Error {
+    Error::other(e.to_string())
+}
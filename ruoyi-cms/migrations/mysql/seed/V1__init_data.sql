@@ -1,50 +1,7 @@
-/*
- Navicat Premium Data Transfer
+-- 内容管理模块初始数据
 
- Source Server         : 本机
- Source Server Type    : MySQL
- Source Server Version : 50717
- Source Host           : localhost:3306
- Source Schema         : ruoyi-vue-blog
-
- Target Server Type    : MySQL
- Target Server Version : 50717
- File Encoding         : 65001
-
- Date: 27/09/2022 11:00:43
-*/
-
-SET NAMES utf8mb4;
-SET FOREIGN_KEY_CHECKS = 0;
-
--- ----------------------------
--- Table structure for cms_blog
 -- ----------------------------
-DROP TABLE IF EXISTS `cms_blog`;
-CREATE TABLE `cms_blog`  (
-  `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'ID',
-  `title` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NOT NULL COMMENT '标题',
-  `type` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NOT NULL COMMENT '类型',
-  `content_type` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NOT NULL COMMENT '文本编辑器类型',
-  `content` longblob COMMENT '内容',
-  `content_markdown` longblob NULL COMMENT 'Markdown格式内容',
-  `Top` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '0' COMMENT '置顶（0否 1是）',
-  `views` int(11) DEFAULT 0 COMMENT '阅读',
-  `status` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '0' COMMENT '状态（0暂存 1发布）',
-  `blog_pic_type` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NULL DEFAULT '0' COMMENT '首页图片类型（0地址 1上传）',
-  `blog_pic` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NULL DEFAULT '' COMMENT '首页图片（ 1上传）',
-  `blog_pic_link` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NULL DEFAULT '' COMMENT '首页图片（ 0地址）',
-  `blog_desc` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '简介',
-  `blog_files` text CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci COMMENT '附件列表',
-  `create_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '创建者',
-  `create_time` datetime(0) DEFAULT NULL COMMENT '创建时间',
-  `update_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '更新者',
-  `update_time` datetime(0) DEFAULT NULL COMMENT '更新时间',
-  PRIMARY KEY (`id`) USING BTREE
-) ENGINE = InnoDB AUTO_INCREMENT = 23 CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = '博客信息表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Records of cms_blog
+-- 博客信息表数据
 -- ----------------------------
 INSERT INTO `cms_blog` VALUES (7, '阿萨', '1', '1', 0x3C683120636C6173733D22716C2D616C69676E2D63656E746572223EE6B58BE8AF953C2F68313E3C703E312EE58F91E7949FE79A84E59CB0E696B9E998BFE890A8E79A843C2F703E3C703E322EE5958AE6898BE58AA8E998803C2F703E3C703EE998BFE890A8E4BA8BE5AE9EE4B88A3C2F703E, NULL, '1', 77, '1', '0', '', '', '置顶文章', '[]', 'admin', '2022-01-02 12:25:26', 'admin', '2023-06-06 15:16:01');
 INSERT INTO `cms_blog` VALUES (10, '2222', '1', '1', NULL, NULL, '0', 0, '0', '0', '', '', NULL, NULL, 'admin', '2022-01-03 02:59:22', '', NULL);
@@ -57,17 +14,7 @@ INSERT INTO `cms_blog` VALUES (19, '是VSD v阿萨DVD v大是大非v阿斯蒂芬
 INSERT INTO `cms_blog` VALUES (22, '测试', '1', '2', 0x3C64697620646174612D696E6C696E652D636F64652D7468656D653D227265642220646174612D636F64652D626C6F636B2D7468656D653D22746F6D6F72726F772D6E69676874223E3C646976207374796C653D22746578742D616C69676E3A63656E7465723B2220646174612D6C696E65733D22332220646174612D7369676E3D2264343763313532663664333237633265336139343963353362626332383030382220636C6173733D226368657272792D746578742D616C69676E206368657272792D746578742D616C69676E5F5F63656E746572206368657272792D686967686C696768742D6C696E65223E3C64697620636C6173733D226368657272792D70616E656C2D2D7469746C65223E3C2F6469763E3C64697620636C6173733D226368657272792D70616E656C2D2D626F6479223E3C703E3C696D6720616C743D222331343170782023313431707822206865696768743D223134317078222077696474683D22313431707822207372633D2268747470733A2F2F6F7363696D672E6F736368696E612E6E65742F6F73636E65742F75702D34336533393431363534666133303534633936383462663533643162316433353661312E706E67223E3C2F703E3C2F6469763E3C2F6469763E3C64697620646174612D6C696E65733D22312220646174612D747970653D226469762220646174612D7369676E3D22313538386337623033363034313162316465336165313066346166366161346131223E3C6831207374796C653D226D617267696E3A2033307078203020333070783B20666F6E742D7765696768743A20626F6C643B2220616C69676E3D2263656E746572223E52756F59692076312E312E303C2F68313E3C2F6469763E3C64697620646174612D6C696E65733D22322220646174612D747970653D226469762220646174612D7369676E3D22656366303634376461346434303833313632343666636530346335393862643632223E3C683420616C69676E3D2263656E746572223EE59FBAE4BA8E556E69417070E5BC80E58F91E79A84E8BDBBE9878FE7BAA7E7A7BBE58AA8E7ABAFE6A186E69EB63C2F68343E3C2F6469763E3C7020646174612D6C696E65733D22332220646174612D747970653D2262722220646174612D7369676E3D22627233223E266E6273703B3C2F703E3C683220646174612D6C696E65733D22312220646174612D7369676E3D226639643365626164383231333333323338363763653464336431656566613364222069643D22254535254239254233254535253846254230254537254145253830254534254242253842223E3C6120687265663D22232545352542392542332545352538462542302545372541452538302545342542422538422220636C6173733D22616E63686F72223E3C2F613EE5B9B3E58FB0E7AE80E4BB8B3C2F68323E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C7020646174612D6C696E65733D22312220646174612D747970653D22702220646174612D7369676E3D22396533316262313564306133306561656234303262636531653837376665383131223E52756F59692041707020E7A7BBE58AA8E8A7A3E586B3E696B9E6A188EFBC8CE98787E794A8756E69617070E6A186E69EB6EFBC8CE4B880E4BBBDE4BBA3E7A081E5A49AE7BB88E7ABAFE98082E9858DEFBC8CE5908CE697B6E694AFE68C81415050E38081E5B08FE7A88BE5BA8FE380814835EFBC81E5AE9EE78EB0E4BA86E4B88E3C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D567565223E52756F59692D5675653C2F613EE380813C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D436C6F7564223E52756F59692D436C6F75643C2F613EE5AE8CE7BE8EE5AFB9E68EA5E79A84E7A7BBE58AA8E8A7A3E586B3E696B9E6A188EFBC81E79BAEE5898DE5B7B2E7BB8FE5AE9EE78EB0E799BBE5BD95E38081E68891E79A84E38081E5B7A5E4BD9CE58FB0E38081E7BC96E8BE91E8B584E69699E38081E5A4B4E5838FE4BFAEE694B9E38081E5AF86E7A081E4BFAEE694B9E38081E5B8B8E8A781E997AEE9A298E38081E585B3E4BA8EE68891E4BBACE7AD89E59FBAE7A180E58A9FE883BDE380823C2F703E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C756C20636C6173733D226368657272792D6C6973745F5F7371756172652220646174612D7369676E3D2264613731396231646330313231393032323464663934303661636337386437612220646174612D6C696E65733D2239223E3C6C693E3C703EE9858DE5A597E5908EE7ABAFE4BBA3E7A081E4BB93E5BA93E59CB0E59D803C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D567565223E52756F59692D5675653C2F613E20E68896203C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F6769746875622E636F6D2F79616E677A6F6E677A6875616E2F52756F59692D436C6F7564223E52756F59692D436C6F75643C2F613E20E78988E69CACE380823C62723E3C2F703E3C2F6C693E3C6C693E3C703EE5BA94E794A8E6A186E69EB6E59FBAE4BA8E3C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F756E696170702E64636C6F75642E6E65742E636E2F223E756E696170703C2F613EEFBC8CE694AFE68C81E5B08FE7A88BE5BA8FE380814835E38081416E64726F6964E5928C494F53E380823C62723E3C2F703E3C2F6C693E3C6C693E3C703EE5898DE7ABAFE7BB84E4BBB6E98787E794A83C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F6769746875622E636F6D2F64636C6F7564696F2F756E692D7569223E756E692D75693C2F613EEFBC8CE585A8E7ABAFE585BCE5AEB9E79A84E9AB98E680A7E883BD5549E6A186E69EB6E380823C62723E3C2F703E3C2F6C693E3C6C693E3C703EE998BFE9878CE4BA91E68A98E689A3E59CBAEFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F616C792E72756F79692E766970223EE782B9E68891E8BF9BE585A53C2F613EEFBC8CE885BEE8AEAFE4BA91E7A792E69D80E59CBAEFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F7478792E72756F79692E766970223EE782B9E68891E8BF9BE585A53C2F613E266E6273703B266E6273703B3C62723E3C2F703E3C2F6C693E3C6C693E3C703EE998BFE9878CE4BA91E4BC98E683A0E588B8EFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F7777772E616C6979756E2E636F6D2F6D696E69736974652F676F6F64733F75736572436F64653D62726B6938696F6626616D703B73686172655F736F757263653D636F70795F6C696E6B223EE782B9E68891E9A286E58F963C2F613EEFBC8CE885BEE8AEAFE4BA91E4BC98E683A0E588B8EFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F636C6F75642E74656E63656E742E636F6D2F72656469726563742E7068703F72656469726563743D3130323526616D703B6370735F6B65793D313938633864663265643235393135373138373137336263376634663332666426616D703B66726F6D3D636F6E736F6C65223EE782B9E68891E9A286E58F963C2F613E266E6273703B266E6273703B3C2F703E3C2F6C693E3C2F756C3E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C683220646174612D6C696E65733D22312220646174612D7369676E3D223938663130646332643662336364663934323139313231623333373131643766222069643D22254536253841253830254536253943254146254536253936253837254536254131254133223E3C6120687265663D22232545362538412538302545362539432541462545362539362538372545362541312541332220636C6173733D22616E63686F72223E3C2F613EE68A80E69CAFE69687E6A1A33C2F68323E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C756C20636C6173733D226368657272792D6C6973745F5F64656661756C742220646174612D7369676E3D2231333533326164333732306363366436356339306636623562646563303233652220646174612D6C696E65733D2239223E3C6C693E3C703EE5AE98E7BD91E7BD91E7AB99EFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F72756F79692E766970223E687474703A2F2F72756F79692E7669703C2F613E3C62723E3C2F703E3C2F6C693E3C6C693E3C703EE69687E6A1A3E59CB0E59D80EFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F646F632E72756F79692E766970223E687474703A2F2F646F632E72756F79692E7669703C2F613E3C62723E3C2F703E3C2F6C693E3C6C693E3C703E4835E9A1B5E4BD93E9AA8CEFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F68352E72756F79692E766970223E687474703A2F2F68352E72756F79692E7669703C2F613E3C62723E3C2F703E3C2F6C693E3C6C693E3C703E5151E4BAA4E6B581E7BEA4EFBC9A20E291A03133333731333738303C62723E3C2F703E3C2F6C693E3C6C693E3C703EE5B08FE7A88BE5BA8FE4BD93E9AA8C3C2F703E3C2F6C693E3C2F756C3E3C7020646174612D6C696E65733D22322220646174612D747970653D22702220646174612D7369676E3D22363231343562356533303637353039623834333461343161643535613261653632223E3C696D6720616C743D22E5B08FE7A88BE5BA8FE6BC94E7A4BA22207372633D2268747470733A2F2F6F7363696D672E6F736368696E612E6E65742F6F73636E65742F75702D32366337366463393062393261636462643961633863643532353266303763386164392E6A7067223E3C2F703E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C2F6469763E, 0x3A3A3A2063656E74657220200A215B233134317078202331343170785D2868747470733A2F2F6F7363696D672E6F736368696E612E6E65742F6F73636E65742F75702D34336533393431363534666133303534633936383462663533643162316433353661312E706E67290A3A3A3A0A3C683120616C69676E3D2263656E74657222207374796C653D226D617267696E3A2033307078203020333070783B20666F6E742D7765696768743A20626F6C643B223E52756F59692076312E312E303C2F68313E0A0A3C683420616C69676E3D2263656E746572223EE59FBAE4BA8E556E69417070E5BC80E58F91E79A84E8BDBBE9878FE7BAA7E7A7BBE58AA8E7ABAFE6A186E69EB63C2F68343E0A0A0A0A232320E5B9B3E58FB0E7AE80E4BB8B0A0A0A52756F59692041707020E7A7BBE58AA8E8A7A3E586B3E696B9E6A188EFBC8CE98787E794A8756E69617070E6A186E69EB6EFBC8CE4B880E4BBBDE4BBA3E7A081E5A49AE7BB88E7ABAFE98082E9858DEFBC8CE5908CE697B6E694AFE68C81415050E38081E5B08FE7A88BE5BA8FE380814835EFBC81E5AE9EE78EB0E4BA86E4B88E5B52756F59692D5675655D2868747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D56756529E380815B52756F59692D436C6F75645D2868747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D436C6F756429E5AE8CE7BE8EE5AFB9E68EA5E79A84E7A7BBE58AA8E8A7A3E586B3E696B9E6A188EFBC81E79BAEE5898DE5B7B2E7BB8FE5AE9EE78EB0E799BBE5BD95E38081E68891E79A84E38081E5B7A5E4BD9CE58FB0E38081E7BC96E8BE91E8B584E69699E38081E5A4B4E5838FE4BFAEE694B9E38081E5AF86E7A081E4BFAEE694B9E38081E5B8B8E8A781E997AEE9A298E38081E585B3E4BA8EE68891E4BBACE7AD89E59FBAE7A180E58A9FE883BDE380820A0A0A2A20E9858DE5A597E5908EE7ABAFE4BBA3E7A081E4BB93E5BA93E59CB0E59D805B52756F59692D5675655D2868747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D5675652920E68896205B52756F59692D436C6F75645D2868747470733A2F2F6769746875622E636F6D2F79616E677A6F6E677A6875616E2F52756F59692D436C6F75642920E78988E69CACE380820A0A2A20E5BA94E794A8E6A186E69EB6E59FBAE4BA8E5B756E696170705D2868747470733A2F2F756E696170702E64636C6F75642E6E65742E636E2F29EFBC8CE694AFE68C81E5B08FE7A88BE5BA8FE380814835E38081416E64726F6964E5928C494F53E380820A0A2A20E5898DE7ABAFE7BB84E4BBB6E98787E794A85B756E692D75695D2868747470733A2F2F6769746875622E636F6D2F64636C6F7564696F2F756E692D756929EFBC8CE585A8E7ABAFE585BCE5AEB9E79A84E9AB98E680A7E883BD5549E6A186E69EB6E380820A0A2A20E998BFE9878CE4BA91E68A98E689A3E59CBAEFBC9A5BE782B9E68891E8BF9BE585A55D28687474703A2F2F616C792E72756F79692E76697029EFBC8CE885BEE8AEAFE4BA91E7A792E69D80E59CBAEFBC9A5BE782B9E68891E8BF9BE585A55D28687474703A2F2F7478792E72756F79692E76697029266E6273703B266E6273703B0A0A2A20E998BFE9878CE4BA91E4BC98E683A0E588B8EFBC9A5BE782B9E68891E9A286E58F965D2868747470733A2F2F7777772E616C6979756E2E636F6D2F6D696E69736974652F676F6F64733F75736572436F64653D62726B6938696F662673686172655F736F757263653D636F70795F6C696E6B29EFBC8CE885BEE8AEAFE4BA91E4BC98E683A0E588B8EFBC9A5BE782B9E68891E9A286E58F965D2868747470733A2F2F636C6F75642E74656E63656E742E636F6D2F72656469726563742E7068703F72656469726563743D31303235266370735F6B65793D31393863386466326564323539313537313837313733626337663466333266642666726F6D3D636F6E736F6C6529266E6273703B266E6273703B0A0A0A232320E68A80E69CAFE69687E6A1A30A0A0A2D20E5AE98E7BD91E7BD91E7AB99EFBC9A5B687474703A2F2F72756F79692E7669705D28687474703A2F2F72756F79692E766970290A0A2D20E69687E6A1A3E59CB0E59D80EFBC9A5B687474703A2F2F646F632E72756F79692E7669705D28687474703A2F2F646F632E72756F79692E766970290A0A2D204835E9A1B5E4BD93E9AA8CEFBC9A5B687474703A2F2F68352E72756F79692E7669705D28687474703A2F2F68352E72756F79692E766970290A0A2D205151E4BAA4E6B581E7BEA4EFBC9A20E291A03133333731333738300A0A2D20E5B08FE7A88BE5BA8FE4BD93E9AA8C0A0A215BE5B08FE7A88BE5BA8FE6BC94E7A4BA5D2868747470733A2F2F6F7363696D672E6F736368696E612E6E65742F6F73636E65742F75702D32366337366463393062393261636462643961633863643532353266303763386164392E6A7067290A0A0A, '0', 98, '1', '0', '', '', '萨达VS奥迪发奥迪发奥迪发答复答复答复奥迪奥迪发奥迪发奥迪发答复奥迪发奥迪发奥迪发奥迪发答复答复答复', NULL, 'admin', '2022-06-13 09:13:50', 'admin', '2023-06-06 15:12:24');
 
 -- ----------------------------
--- Table structure for cms_blog_file
--- ----------------------------
-DROP TABLE IF EXISTS `cms_blog_file`;
-CREATE TABLE `cms_blog_file`  (
-  `file_id` bigint(20) NOT NULL COMMENT '文件ID',
-  `blog_id` bigint(20) NOT NULL COMMENT 'blogID',
-  PRIMARY KEY (`file_id`, `blog_id`) USING BTREE
-) ENGINE = InnoDB CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = 'blog文件表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Records of cms_blog_file
+-- blog文件表数据
 -- ----------------------------
 INSERT INTO `cms_blog_file` VALUES (33, 17);
 INSERT INTO `cms_blog_file` VALUES (34, 17);
@@ -75,17 +22,7 @@ INSERT INTO `cms_blog_file` VALUES (36, 17);
 INSERT INTO `cms_blog_file` VALUES (37, 17);
 
 -- ----------------------------
--- Table structure for cms_blog_tag
--- ----------------------------
-DROP TABLE IF EXISTS `cms_blog_tag`;
-CREATE TABLE `cms_blog_tag`  (
-  `tag_id` bigint(20) NOT NULL COMMENT '标签ID',
-  `blog_id` bigint(20) NOT NULL COMMENT 'blogID',
-  PRIMARY KEY (`tag_id`, `blog_id`) USING BTREE
-) ENGINE = InnoDB CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = 'blog标签关联表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Records of cms_blog_tag
+-- blog标签关联表数据
 -- ----------------------------
 INSERT INTO `cms_blog_tag` VALUES (20, 7);
 INSERT INTO `cms_blog_tag` VALUES (20, 22);
@@ -102,17 +39,7 @@ INSERT INTO `cms_blog_tag` VALUES (26, 19);
 INSERT INTO `cms_blog_tag` VALUES (26, 22);
 
 -- ----------------------------
--- Table structure for cms_blog_type
--- ----------------------------
-DROP TABLE IF EXISTS `cms_blog_type`;
-CREATE TABLE `cms_blog_type`  (
-  `type_id` bigint(20) NOT NULL COMMENT '类型ID',
-  `blog_id` bigint(20) NOT NULL COMMENT 'blogID',
-  PRIMARY KEY (`type_id`, `blog_id`) USING BTREE
-) ENGINE = InnoDB CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = 'blog分类关联表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Records of cms_blog_type
+-- blog分类关联表数据
 -- ----------------------------
 INSERT INTO `cms_blog_type` VALUES (10, 7);
 INSERT INTO `cms_blog_type` VALUES (10, 22);
@@ -126,28 +53,7 @@ INSERT INTO `cms_blog_type` VALUES (15, 22);
 INSERT INTO `cms_blog_type` VALUES (16, 22);
 
 -- ----------------------------
--- Table structure for cms_comment
--- ----------------------------
-DROP TABLE IF EXISTS `cms_comment`;
-CREATE TABLE `cms_comment`  (
-  `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'ID',
-  `parent_id` bigint(20) DEFAULT NULL COMMENT '父评论id',
-  `main_id` bigint(20) DEFAULT NULL COMMENT '主评论id(第一级评论)',
-  `like_num` int(11) DEFAULT NULL COMMENT '点赞数量',
-  `content` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT NULL COMMENT '内容',
-  `type` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT NULL COMMENT '评论类型：对人评论，对项目评论，对资源评论',
-  `blog_id` bigint(20) DEFAULT NULL COMMENT '被评论者id，可以是人、项目、资源',
-  `del_flag` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '0' COMMENT '删除标志（0代表存在 1代表删除）',
-  `user_id` bigint(20) DEFAULT NULL COMMENT '评论者id',
-  `create_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '创建者',
-  `create_time` datetime(0) DEFAULT NULL COMMENT '创建时间',
-  `update_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '更新者',
-  `update_time` datetime(0) DEFAULT NULL COMMENT '更新时间',
-  PRIMARY KEY (`id`) USING BTREE
-) ENGINE = InnoDB AUTO_INCREMENT = 6 CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = '评论表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Records of cms_comment
+-- 评论表数据
 -- ----------------------------
 INSERT INTO `cms_comment` VALUES (1, NULL, NULL, 13, 'v地方', '0', 19, '0', 1, 'admin', '2022-01-28 17:43:08', '', NULL);
 INSERT INTO `cms_comment` VALUES (2, 1, 1, NULL, '奥迪发', '1', 19, '0', 1, 'admin', '2022-01-28 17:43:47', '', NULL);
@@ -156,42 +62,7 @@ INSERT INTO `cms_comment` VALUES (4, 2, 1, NULL, '测试', '1', 19, '0', NULL, '
 INSERT INTO `cms_comment` VALUES (5, NULL, NULL, NULL, '哈哈哈', '0', 19, '0', NULL, '匿名用户', '2022-06-16 15:45:17', '', NULL);
 
 -- ----------------------------
--- Table structure for cms_comment_like
--- ----------------------------
-DROP TABLE IF EXISTS `cms_comment_like`;
-CREATE TABLE `cms_comment_like`  (
-  `comment_id` bigint(20) NOT NULL COMMENT '点赞评论ID',
-  `user_id` bigint(20) NOT NULL COMMENT '用户ID',
-  `create_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '创建者',
-  `create_time` datetime(0) DEFAULT NULL COMMENT '创建时间',
-  `update_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '更新者',
-  `update_time` datetime(0) DEFAULT NULL COMMENT '更新时间',
-  PRIMARY KEY (`comment_id`, `user_id`) USING BTREE
-) ENGINE = InnoDB CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = '留言点赞表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Table structure for cms_message
--- ----------------------------
-DROP TABLE IF EXISTS `cms_message`;
-CREATE TABLE `cms_message`  (
-  `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'ID',
-  `parent_id` bigint(20) DEFAULT NULL COMMENT '父留言id',
-  `main_id` bigint(20) DEFAULT NULL COMMENT '主留言id(第一级留言)',
-  `like_num` int(11) DEFAULT NULL COMMENT '点赞数量',
-  `content` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT NULL COMMENT '内容',
-  `type` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT NULL COMMENT '留言类型：对人评论，对项目评论，对资源评论（0代表留言 1代表回复）',
-  `blog_id` bigint(20) DEFAULT NULL COMMENT '被留言者id，可以是人、项目、资源',
-  `del_flag` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '0' COMMENT '删除标志（0代表存在 1代表删除）',
-  `user_id` bigint(20) DEFAULT NULL COMMENT '留言者id',
-  `create_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '创建者',
-  `create_time` datetime(0) DEFAULT NULL COMMENT '创建时间',
-  `update_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '更新者',
-  `update_time` datetime(0) DEFAULT NULL COMMENT '更新时间',
-  PRIMARY KEY (`id`) USING BTREE
-) ENGINE = InnoDB AUTO_INCREMENT = 55 CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = '留言表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Records of cms_message
+-- 留言表数据
 -- ----------------------------
 INSERT INTO `cms_message` VALUES (1, NULL, NULL, 22, '你好', '0', NULL, '0', 1, 'admin', '2022-01-15 15:24:46', '', NULL);
 INSERT INTO `cms_message` VALUES (2, NULL, NULL, 10, 'hello!!  123', '0', NULL, '0', 1, 'admin', '2022-01-15 15:25:26', '', NULL);
@@ -214,35 +85,7 @@ INSERT INTO `cms_message` VALUES (53, 26, 6, NULL, '问什么删评论', '1', NU
 INSERT INTO `cms_message` VALUES (54, 53, 6, NULL, 'why?', '1', NULL, '0', 2, '若依', '2022-01-21 10:15:47', '', NULL);
 
 -- ----------------------------
--- Table structure for cms_message_like
--- ----------------------------
-DROP TABLE IF EXISTS `cms_message_like`;
-CREATE TABLE `cms_message_like`  (
-  `message_id` bigint(20) NOT NULL COMMENT '点赞留言ID',
-  `user_id` bigint(20) NOT NULL COMMENT '用户ID',
-  `create_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '创建者',
-  `create_time` datetime(0) DEFAULT NULL COMMENT '创建时间',
-  `update_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '更新者',
-  `update_time` datetime(0) DEFAULT NULL COMMENT '更新时间',
-  PRIMARY KEY (`message_id`, `user_id`) USING BTREE
-) ENGINE = InnoDB CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = '留言点赞表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Table structure for cms_tag
--- ----------------------------
-DROP TABLE IF EXISTS `cms_tag`;
-CREATE TABLE `cms_tag`  (
-  `tag_id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT '标签ID',
-  `tag_name` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NOT NULL COMMENT '标签名称',
-  `create_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '创建者',
-  `create_time` datetime(0) DEFAULT NULL COMMENT '创建时间',
-  `update_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '更新者',
-  `update_time` datetime(0) DEFAULT NULL COMMENT '更新时间',
-  PRIMARY KEY (`tag_id`) USING BTREE
-) ENGINE = InnoDB AUTO_INCREMENT = 27 CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = '标签信息表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Records of cms_tag
+-- 标签信息表数据
 -- ----------------------------
 INSERT INTO `cms_tag` VALUES (20, '啊实打实', 'admin', '2022-01-03 22:50:05', '', NULL);
 INSERT INTO `cms_tag` VALUES (21, '打', 'admin', '2022-01-03 22:50:23', '', NULL);
@@ -253,24 +96,7 @@ INSERT INTO `cms_tag` VALUES (25, '愤愤愤', 'admin', '2022-01-03 23:30:38', '
 INSERT INTO `cms_tag` VALUES (26, '若依', 'admin', '2022-01-04 14:48:26', '', NULL);
 
 -- ----------------------------
--- Table structure for cms_type
--- ----------------------------
-DROP TABLE IF EXISTS `cms_type`;
-CREATE TABLE `cms_type`  (
-  `type_id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT '分类ID',
-  `type_name` varchar(50) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NOT NULL COMMENT '分类名称',
-  `type_pic` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NULL DEFAULT '' COMMENT '分类图像（ 1上传）',
-  `type_pic_type` char(1) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NULL DEFAULT '0' COMMENT '分类图像类型（0地址 1上传）',
-  `type_pic_link` varchar(100) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci NULL DEFAULT '' COMMENT '分类图像（ 0地址）',
-  `create_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '创建者',
-  `create_time` datetime(0) DEFAULT NULL COMMENT '创建时间',
-  `update_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '更新者',
-  `update_time` datetime(0) DEFAULT NULL COMMENT '更新时间',
-  PRIMARY KEY (`type_id`) USING BTREE
-) ENGINE = InnoDB AUTO_INCREMENT = 17 CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = '分类信息表' ROW_FORMAT = Dynamic;
-
--- ----------------------------
--- Records of cms_type
+-- 分类信息表数据
 -- ----------------------------
 INSERT INTO `cms_type` VALUES (10, '阿萨的', '', '0', '', 'admin', '2022-01-03 23:31:10', '', NULL);
 INSERT INTO `cms_type` VALUES (11, '人然', '', '0', '', 'admin', '2022-01-03 23:31:20', '', NULL);
@@ -278,22 +104,3 @@ INSERT INTO `cms_type` VALUES (13, '啊啊', '', '0', '', 'admin', '2022-01-03 2
 INSERT INTO `cms_type` VALUES (14, '若依', '', '0', '', 'admin', '2022-01-04 14:31:23', '', NULL);
 INSERT INTO `cms_type` VALUES (15, '33', '', '0', '', 'admin', '2022-01-03 21:35:44', '', NULL);
 INSERT INTO `cms_type` VALUES (16, 'java', '', '0', '', 'admin', '2022-06-10 16:08:50', '', NULL);
-
--- ----------------------------
--- Table structure for cms_file_info
--- ----------------------------
-DROP TABLE IF EXISTS `cms_file_info`;
-CREATE TABLE `cms_file_info`  (
-  `file_id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT '文件主键id',
-  `file_origin_name` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci NOT NULL COMMENT '文件名称',
-  `file_suffix` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT NULL COMMENT '文件类型，例如txt',
-  `file_size_info` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT NULL COMMENT '文件大小',
-  `file_object_name` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT NULL COMMENT '存储文件名称',
-  `file_path` varchar(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci DEFAULT NULL COMMENT '存储路径',
-  `del_flag` char(1) CHARACTER SET utf8 COLLATE utf8_general_ci NOT NULL DEFAULT 'N' COMMENT '是否删除：Y-被删除，N-未删除',
-  `create_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '创建者',
-  `create_time` datetime(0) DEFAULT NULL COMMENT '创建时间',
-  `update_by` varchar(64) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci DEFAULT '' COMMENT '更新者',
-  `update_time` datetime(0) DEFAULT NULL COMMENT '更新时间',
-  PRIMARY KEY (`file_id`) USING BTREE
-) ENGINE = InnoDB AUTO_INCREMENT = 39 CHARACTER SET = utf8mb4 COLLATE = utf8mb4_general_ci COMMENT = '文件信息表' ROW_FORMAT = Dynamic;
\ No newline at end of file
@@ -0,0 +1,265 @@
+-- 内容管理模块表结构
+
+-- ----------------------------
+-- 博客信息表
+-- ----------------------------
+create table cms_blog (
+  id                   bigint generated by default as identity (start with 23),
+  title                varchar(50) not null,
+  type                 char(1) not null,
+  content_type         char(1) not null,
+  content              bytea,
+  content_markdown     bytea,
+  top                  char(1) default '0',
+  views                integer default 0,
+  status               char(1) default '0',
+  blog_pic_type        char(1) default '0',
+  blog_pic             varchar(100) default '',
+  blog_pic_link        varchar(100) default '',
+  blog_desc            varchar(100) default '',
+  blog_files           text,
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (id)
+);
+comment on table cms_blog is '博客信息表';
+comment on column cms_blog.id is 'ID';
+comment on column cms_blog.title is '标题';
+comment on column cms_blog.type is '类型';
+comment on column cms_blog.content_type is '文本编辑器类型';
+comment on column cms_blog.content is '内容';
+comment on column cms_blog.content_markdown is 'Markdown格式内容';
+comment on column cms_blog.top is '置顶（0否 1是）';
+comment on column cms_blog.views is '阅读';
+comment on column cms_blog.status is '状态（0暂存 1发布）';
+comment on column cms_blog.blog_pic_type is '首页图片类型（0地址 1上传）';
+comment on column cms_blog.blog_pic is '首页图片（ 1上传）';
+comment on column cms_blog.blog_pic_link is '首页图片（ 0地址）';
+comment on column cms_blog.blog_desc is '简介';
+comment on column cms_blog.blog_files is '附件列表';
+comment on column cms_blog.create_by is '创建者';
+comment on column cms_blog.create_time is '创建时间';
+comment on column cms_blog.update_by is '更新者';
+comment on column cms_blog.update_time is '更新时间';
+
+-- ----------------------------
+-- blog文件表
+-- ----------------------------
+create table cms_blog_file (
+  file_id              bigint not null,
+  blog_id              bigint not null,
+  primary key (file_id, blog_id)
+);
+comment on table cms_blog_file is 'blog文件表';
+comment on column cms_blog_file.file_id is '文件ID';
+comment on column cms_blog_file.blog_id is 'blogID';
+
+-- ----------------------------
+-- blog标签关联表
+-- ----------------------------
+create table cms_blog_tag (
+  tag_id               bigint not null,
+  blog_id              bigint not null,
+  primary key (tag_id, blog_id)
+);
+comment on table cms_blog_tag is 'blog标签关联表';
+comment on column cms_blog_tag.tag_id is '标签ID';
+comment on column cms_blog_tag.blog_id is 'blogID';
+
+-- ----------------------------
+-- blog分类关联表
+-- ----------------------------
+create table cms_blog_type (
+  type_id              bigint not null,
+  blog_id              bigint not null,
+  primary key (type_id, blog_id)
+);
+comment on table cms_blog_type is 'blog分类关联表';
+comment on column cms_blog_type.type_id is '类型ID';
+comment on column cms_blog_type.blog_id is 'blogID';
+
+-- ----------------------------
+-- 评论表
+-- ----------------------------
+create table cms_comment (
+  id                   bigint generated by default as identity (start with 6),
+  parent_id            bigint,
+  main_id              bigint,
+  like_num             integer,
+  content              varchar(255),
+  type                 char(1),
+  blog_id              bigint,
+  del_flag             char(1) default '0',
+  user_id              bigint,
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (id)
+);
+comment on table cms_comment is '评论表';
+comment on column cms_comment.id is 'ID';
+comment on column cms_comment.parent_id is '父评论id';
+comment on column cms_comment.main_id is '主评论id(第一级评论)';
+comment on column cms_comment.like_num is '点赞数量';
+comment on column cms_comment.content is '内容';
+comment on column cms_comment.type is '评论类型：对人评论，对项目评论，对资源评论';
+comment on column cms_comment.blog_id is '被评论者id，可以是人、项目、资源';
+comment on column cms_comment.del_flag is '删除标志（0代表存在 1代表删除）';
+comment on column cms_comment.user_id is '评论者id';
+comment on column cms_comment.create_by is '创建者';
+comment on column cms_comment.create_time is '创建时间';
+comment on column cms_comment.update_by is '更新者';
+comment on column cms_comment.update_time is '更新时间';
+
+-- ----------------------------
+-- 留言点赞表
+-- ----------------------------
+create table cms_comment_like (
+  comment_id           bigint not null,
+  user_id              bigint not null,
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (comment_id, user_id)
+);
+comment on table cms_comment_like is '留言点赞表';
+comment on column cms_comment_like.comment_id is '点赞评论ID';
+comment on column cms_comment_like.user_id is '用户ID';
+comment on column cms_comment_like.create_by is '创建者';
+comment on column cms_comment_like.create_time is '创建时间';
+comment on column cms_comment_like.update_by is '更新者';
+comment on column cms_comment_like.update_time is '更新时间';
+
+-- ----------------------------
+-- 留言表
+-- ----------------------------
+create table cms_message (
+  id                   bigint generated by default as identity (start with 55),
+  parent_id            bigint,
+  main_id              bigint,
+  like_num             integer,
+  content              varchar(255),
+  type                 char(1),
+  blog_id              bigint,
+  del_flag             char(1) default '0',
+  user_id              bigint,
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (id)
+);
+comment on table cms_message is '留言表';
+comment on column cms_message.id is 'ID';
+comment on column cms_message.parent_id is '父留言id';
+comment on column cms_message.main_id is '主留言id(第一级留言)';
+comment on column cms_message.like_num is '点赞数量';
+comment on column cms_message.content is '内容';
+comment on column cms_message.type is '留言类型：对人评论，对项目评论，对资源评论（0代表留言 1代表回复）';
+comment on column cms_message.blog_id is '被留言者id，可以是人、项目、资源';
+comment on column cms_message.del_flag is '删除标志（0代表存在 1代表删除）';
+comment on column cms_message.user_id is '留言者id';
+comment on column cms_message.create_by is '创建者';
+comment on column cms_message.create_time is '创建时间';
+comment on column cms_message.update_by is '更新者';
+comment on column cms_message.update_time is '更新时间';
+
+-- ----------------------------
+-- 留言点赞表
+-- ----------------------------
+create table cms_message_like (
+  message_id           bigint not null,
+  user_id              bigint not null,
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (message_id, user_id)
+);
+comment on table cms_message_like is '留言点赞表';
+comment on column cms_message_like.message_id is '点赞留言ID';
+comment on column cms_message_like.user_id is '用户ID';
+comment on column cms_message_like.create_by is '创建者';
+comment on column cms_message_like.create_time is '创建时间';
+comment on column cms_message_like.update_by is '更新者';
+comment on column cms_message_like.update_time is '更新时间';
+
+-- ----------------------------
+-- 标签信息表
+-- ----------------------------
+create table cms_tag (
+  tag_id               bigint generated by default as identity (start with 27),
+  tag_name             varchar(50) not null,
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (tag_id)
+);
+comment on table cms_tag is '标签信息表';
+comment on column cms_tag.tag_id is '标签ID';
+comment on column cms_tag.tag_name is '标签名称';
+comment on column cms_tag.create_by is '创建者';
+comment on column cms_tag.create_time is '创建时间';
+comment on column cms_tag.update_by is '更新者';
+comment on column cms_tag.update_time is '更新时间';
+
+-- ----------------------------
+-- 分类信息表
+-- ----------------------------
+create table cms_type (
+  type_id              bigint generated by default as identity (start with 17),
+  type_name            varchar(50) not null,
+  type_pic             varchar(100) default '',
+  type_pic_type        char(1) default '0',
+  type_pic_link        varchar(100) default '',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (type_id)
+);
+comment on table cms_type is '分类信息表';
+comment on column cms_type.type_id is '分类ID';
+comment on column cms_type.type_name is '分类名称';
+comment on column cms_type.type_pic is '分类图像（ 1上传）';
+comment on column cms_type.type_pic_type is '分类图像类型（0地址 1上传）';
+comment on column cms_type.type_pic_link is '分类图像（ 0地址）';
+comment on column cms_type.create_by is '创建者';
+comment on column cms_type.create_time is '创建时间';
+comment on column cms_type.update_by is '更新者';
+comment on column cms_type.update_time is '更新时间';
+
+-- ----------------------------
+-- 文件信息表
+-- ----------------------------
+create table cms_file_info (
+  file_id              bigint generated by default as identity (start with 39),
+  file_origin_name     varchar(255) not null,
+  file_suffix          varchar(255),
+  file_size_info       varchar(255),
+  file_object_name     varchar(255),
+  file_path            varchar(255),
+  del_flag             char(1) not null default 'N',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (file_id)
+);
+comment on table cms_file_info is '文件信息表';
+comment on column cms_file_info.file_id is '文件主键id';
+comment on column cms_file_info.file_origin_name is '文件名称';
+comment on column cms_file_info.file_suffix is '文件类型，例如txt';
+comment on column cms_file_info.file_size_info is '文件大小';
+comment on column cms_file_info.file_object_name is '存储文件名称';
+comment on column cms_file_info.file_path is '存储路径';
+comment on column cms_file_info.del_flag is '是否删除：Y-被删除，N-未删除';
+comment on column cms_file_info.create_by is '创建者';
+comment on column cms_file_info.create_time is '创建时间';
+comment on column cms_file_info.update_by is '更新者';
+comment on column cms_file_info.update_time is '更新时间';
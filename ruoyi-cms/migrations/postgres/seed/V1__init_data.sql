@@ -0,0 +1,118 @@
+-- 内容管理模块初始数据
+
+-- ----------------------------
+-- 博客信息表数据
+-- ----------------------------
+insert into cms_blog values(7, '阿萨', '1', '1', '\x3C683120636C6173733D22716C2D616C69676E2D63656E746572223EE6B58BE8AF953C2F68313E3C703E312EE58F91E7949FE79A84E59CB0E696B9E998BFE890A8E79A843C2F703E3C703E322EE5958AE6898BE58AA8E998803C2F703E3C703EE998BFE890A8E4BA8BE5AE9EE4B88A3C2F703E'::bytea, NULL, '1', 77, '1', '0', '', '', '置顶文章', '[]', 'admin', '2022-01-02 12:25:26', 'admin', '2023-06-06 15:16:01');
+insert into cms_blog values(10, '2222', '1', '1', NULL, NULL, '0', 0, '0', '0', '', '', NULL, NULL, 'admin', '2022-01-03 02:59:22', '', NULL);
+insert into cms_blog values(13, '强强强强', '1', '1', NULL, NULL, '0', 4, '1', '0', '', '', NULL, NULL, 'admin', '2022-01-03 21:37:57', 'admin', '2023-06-06 15:12:09');
+insert into cms_blog values(14, '若依', '1', '1', '\x3C703E3C62723E3C2F703E'::bytea, NULL, '0', 9, '1', '0', '', '', NULL, NULL, '若依', '2022-01-04 14:31:31', 'admin', '2023-06-06 15:12:11');
+insert into cms_blog values(16, '阿斯顿v奥迪的vvv从vvc从v从 啊高富帅是否公司费格式发给非钢是法国四个非钢', '1', '1', '\x3C68323EE6ADA4E4BA8BE998BFE890A8E79A843C2F68323E3C68333EE5958AE6898BE58AA8E998803C2F68333E3C703E3C656D3EE5958A3C2F656D3E3C656D207374796C653D22636F6C6F723A20726762283233302C20302C2030293B223EE58F91E7949F3C2F656D3E3C656D3EE79A843C2F656D3E3C2F703E3C703E3C733EE5A5A5E8BFAAE58F913C2F733E3C2F703E3C703E3C7370616E207374796C653D226261636B67726F756E642D636F6C6F723A20726762283235352C203235352C2030293B223EE698AFE590A63C2F7370616E3E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C683220636C6173733D22716C2D616C69676E2D63656E746572223E3C7374726F6E673EE5A5A5E8BFAA3C2F7374726F6E673E3C2F68323E3C703E3C753EE5958AE6898BE58AA8E998803C2F753E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C703E3C62723E3C2F703E3C626C6F636B71756F74653E7661722061203D20313B3C2F626C6F636B71756F74653E3C626C6F636B71756F74653E666F7220286C65742069203D20303B206920266C743B31303B20692B2B297B3C2F626C6F636B71756F74653E3C626C6F636B71756F74653E7D3C2F626C6F636B71756F74653E3C70726520636C6173733D22716C2D73796E74617822207370656C6C636865636B3D2266616C7365223E7661722061203D20313B0A666F7220286C65742069203D20303B206920266C743B31303B20692B2B297B0A7D0A3C2F7072653E3C703E3132333C2F703E3C703E3C62723E3C2F703E3C703E3C6120687265663D22687474703A2F2F7777772E62616964752E636F6D222072656C3D226E6F6F70656E6572206E6F726566657272657222207461726765743D225F626C616E6B223EE799BEE5BAA63C2F613E3C2F703E'::bytea, NULL, '0', 148, '1', '0', '', '', NULL, NULL, 'admin', '2022-01-05 09:44:53', 'admin', '2023-06-06 15:12:15');
+insert into cms_blog values(17, '你好!!!!', '2', '1', '\x3C703EE5928CE982A3E8B7AFE4B88AE79C8BE588B0E4BDA0E7ACACE4B889E696B93C2F703E3C703EE5A4A7E5B888E582853C2F703E3C703EE5958AE6898BE58AA8E998803C2F703E3C703EE5958AE6898BE58AA8E998803C2F703E3C703EE69292E697A63C2F703E'::bytea, NULL, '0', 0, '1', '0', '', '', NULL, NULL, 'admin', '2022-01-12 16:06:28', 'admin', '2022-06-23 16:16:54');
+insert into cms_blog values(18, '啊调查啊', '2', '1', '\x3C703EE5958AE5958A3C7374726F6E673EE5958AE588863C2F7374726F6E673E3C2F703E3C703E3C62723E3C2F703E3C6F6C3E3C6C693EE5A5A5E8BFAAE58F913C2F6C693E3C6C693EE5AE89E68A9A3C2F6C693E3C2F6F6C3E3C703E3C7370616E207374796C653D226261636B67726F756E642D636F6C6F723A20726762283235352C203235352C20313032293B223EE5A5A5E8BFAAE58F913C2F7370616E3E3C2F703E3C703E3C7370616E207374796C653D226261636B67726F756E642D636F6C6F723A20726762283235352C203235352C20313032293B223EEFBBBF3C2F7370616E3E3C2F703E'::bytea, NULL, '0', 0, '1', '0', '', '', NULL, NULL, 'admin', '2022-01-12 16:55:46', 'admin', '2022-02-10 17:00:10');
+insert into cms_blog values(19, '是VSD v阿萨DVD v大是大非v阿斯蒂芬v地方', '1', '1', '\x3C703E31323334353535E79A84E9A38EE6A0BC3C2F703E'::bytea, NULL, '0', 206, '1', '0', '', '', NULL, NULL, 'admin', '2022-01-12 17:40:54', 'admin', '2023-06-06 15:12:28');
+insert into cms_blog values(22, '测试', '1', '2', '\x3C64697620646174612D696E6C696E652D636F64652D7468656D653D227265642220646174612D636F64652D626C6F636B2D7468656D653D22746F6D6F72726F772D6E69676874223E3C646976207374796C653D22746578742D616C69676E3A63656E7465723B2220646174612D6C696E65733D22332220646174612D7369676E3D2264343763313532663664333237633265336139343963353362626332383030382220636C6173733D226368657272792D746578742D616C69676E206368657272792D746578742D616C69676E5F5F63656E746572206368657272792D686967686C696768742D6C696E65223E3C64697620636C6173733D226368657272792D70616E656C2D2D7469746C65223E3C2F6469763E3C64697620636C6173733D226368657272792D70616E656C2D2D626F6479223E3C703E3C696D6720616C743D222331343170782023313431707822206865696768743D223134317078222077696474683D22313431707822207372633D2268747470733A2F2F6F7363696D672E6F736368696E612E6E65742F6F73636E65742F75702D34336533393431363534666133303534633936383462663533643162316433353661312E706E67223E3C2F703E3C2F6469763E3C2F6469763E3C64697620646174612D6C696E65733D22312220646174612D747970653D226469762220646174612D7369676E3D22313538386337623033363034313162316465336165313066346166366161346131223E3C6831207374796C653D226D617267696E3A2033307078203020333070783B20666F6E742D7765696768743A20626F6C643B2220616C69676E3D2263656E746572223E52756F59692076312E312E303C2F68313E3C2F6469763E3C64697620646174612D6C696E65733D22322220646174612D747970653D226469762220646174612D7369676E3D22656366303634376461346434303833313632343666636530346335393862643632223E3C683420616C69676E3D2263656E746572223EE59FBAE4BA8E556E69417070E5BC80E58F91E79A84E8BDBBE9878FE7BAA7E7A7BBE58AA8E7ABAFE6A186E69EB63C2F68343E3C2F6469763E3C7020646174612D6C696E65733D22332220646174612D747970653D2262722220646174612D7369676E3D22627233223E266E6273703B3C2F703E3C683220646174612D6C696E65733D22312220646174612D7369676E3D226639643365626164383231333333323338363763653464336431656566613364222069643D22254535254239254233254535253846254230254537254145253830254534254242253842223E3C6120687265663D22232545352542392542332545352538462542302545372541452538302545342542422538422220636C6173733D22616E63686F72223E3C2F613EE5B9B3E58FB0E7AE80E4BB8B3C2F68323E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C7020646174612D6C696E65733D22312220646174612D747970653D22702220646174612D7369676E3D22396533316262313564306133306561656234303262636531653837376665383131223E52756F59692041707020E7A7BBE58AA8E8A7A3E586B3E696B9E6A188EFBC8CE98787E794A8756E69617070E6A186E69EB6EFBC8CE4B880E4BBBDE4BBA3E7A081E5A49AE7BB88E7ABAFE98082E9858DEFBC8CE5908CE697B6E694AFE68C81415050E38081E5B08FE7A88BE5BA8FE380814835EFBC81E5AE9EE78EB0E4BA86E4B88E3C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D567565223E52756F59692D5675653C2F613EE380813C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D436C6F7564223E52756F59692D436C6F75643C2F613EE5AE8CE7BE8EE5AFB9E68EA5E79A84E7A7BBE58AA8E8A7A3E586B3E696B9E6A188EFBC81E79BAEE5898DE5B7B2E7BB8FE5AE9EE78EB0E799BBE5BD95E38081E68891E79A84E38081E5B7A5E4BD9CE58FB0E38081E7BC96E8BE91E8B584E69699E38081E5A4B4E5838FE4BFAEE694B9E38081E5AF86E7A081E4BFAEE694B9E38081E5B8B8E8A781E997AEE9A298E38081E585B3E4BA8EE68891E4BBACE7AD89E59FBAE7A180E58A9FE883BDE380823C2F703E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C756C20636C6173733D226368657272792D6C6973745F5F7371756172652220646174612D7369676E3D2264613731396231646330313231393032323464663934303661636337386437612220646174612D6C696E65733D2239223E3C6C693E3C703EE9858DE5A597E5908EE7ABAFE4BBA3E7A081E4BB93E5BA93E59CB0E59D803C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D567565223E52756F59692D5675653C2F613E20E68896203C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F6769746875622E636F6D2F79616E677A6F6E677A6875616E2F52756F59692D436C6F7564223E52756F59692D436C6F75643C2F613E20E78988E69CACE380823C62723E3C2F703E3C2F6C693E3C6C693E3C703EE5BA94E794A8E6A186E69EB6E59FBAE4BA8E3C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F756E696170702E64636C6F75642E6E65742E636E2F223E756E696170703C2F613EEFBC8CE694AFE68C81E5B08FE7A88BE5BA8FE380814835E38081416E64726F6964E5928C494F53E380823C62723E3C2F703E3C2F6C693E3C6C693E3C703EE5898DE7ABAFE7BB84E4BBB6E98787E794A83C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F6769746875622E636F6D2F64636C6F7564696F2F756E692D7569223E756E692D75693C2F613EEFBC8CE585A8E7ABAFE585BCE5AEB9E79A84E9AB98E680A7E883BD5549E6A186E69EB6E380823C62723E3C2F703E3C2F6C693E3C6C693E3C703EE998BFE9878CE4BA91E68A98E689A3E59CBAEFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F616C792E72756F79692E766970223EE782B9E68891E8BF9BE585A53C2F613EEFBC8CE885BEE8AEAFE4BA91E7A792E69D80E59CBAEFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F7478792E72756F79692E766970223EE782B9E68891E8BF9BE585A53C2F613E266E6273703B266E6273703B3C62723E3C2F703E3C2F6C693E3C6C693E3C703EE998BFE9878CE4BA91E4BC98E683A0E588B8EFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F7777772E616C6979756E2E636F6D2F6D696E69736974652F676F6F64733F75736572436F64653D62726B6938696F6626616D703B73686172655F736F757263653D636F70795F6C696E6B223EE782B9E68891E9A286E58F963C2F613EEFBC8CE885BEE8AEAFE4BA91E4BC98E683A0E588B8EFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D2268747470733A2F2F636C6F75642E74656E63656E742E636F6D2F72656469726563742E7068703F72656469726563743D3130323526616D703B6370735F6B65793D313938633864663265643235393135373138373137336263376634663332666426616D703B66726F6D3D636F6E736F6C65223EE782B9E68891E9A286E58F963C2F613E266E6273703B266E6273703B3C2F703E3C2F6C693E3C2F756C3E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C683220646174612D6C696E65733D22312220646174612D7369676E3D223938663130646332643662336364663934323139313231623333373131643766222069643D22254536253841253830254536253943254146254536253936253837254536254131254133223E3C6120687265663D22232545362538412538302545362539432541462545362539362538372545362541312541332220636C6173733D22616E63686F72223E3C2F613EE68A80E69CAFE69687E6A1A33C2F68323E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C756C20636C6173733D226368657272792D6C6973745F5F64656661756C742220646174612D7369676E3D2231333533326164333732306363366436356339306636623562646563303233652220646174612D6C696E65733D2239223E3C6C693E3C703EE5AE98E7BD91E7BD91E7AB99EFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F72756F79692E766970223E687474703A2F2F72756F79692E7669703C2F613E3C62723E3C2F703E3C2F6C693E3C6C693E3C703EE69687E6A1A3E59CB0E59D80EFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F646F632E72756F79692E766970223E687474703A2F2F646F632E72756F79692E7669703C2F613E3C62723E3C2F703E3C2F6C693E3C6C693E3C703E4835E9A1B5E4BD93E9AA8CEFBC9A3C612072656C3D226E6F666F6C6C6F772220687265663D22687474703A2F2F68352E72756F79692E766970223E687474703A2F2F68352E72756F79692E7669703C2F613E3C62723E3C2F703E3C2F6C693E3C6C693E3C703E5151E4BAA4E6B581E7BEA4EFBC9A20E291A03133333731333738303C62723E3C2F703E3C2F6C693E3C6C693E3C703EE5B08FE7A88BE5BA8FE4BD93E9AA8C3C2F703E3C2F6C693E3C2F756C3E3C7020646174612D6C696E65733D22322220646174612D747970653D22702220646174612D7369676E3D22363231343562356533303637353039623834333461343161643535613261653632223E3C696D6720616C743D22E5B08FE7A88BE5BA8FE6BC94E7A4BA22207372633D2268747470733A2F2F6F7363696D672E6F736368696E612E6E65742F6F73636E65742F75702D32366337366463393062393261636462643961633863643532353266303763386164392E6A7067223E3C2F703E3C7020646174612D6C696E65733D22322220646174612D747970653D2262722220646174612D7369676E3D22627232223E266E6273703B3C2F703E3C2F6469763E'::bytea, '\x3A3A3A2063656E74657220200A215B233134317078202331343170785D2868747470733A2F2F6F7363696D672E6F736368696E612E6E65742F6F73636E65742F75702D34336533393431363534666133303534633936383462663533643162316433353661312E706E67290A3A3A3A0A3C683120616C69676E3D2263656E74657222207374796C653D226D617267696E3A2033307078203020333070783B20666F6E742D7765696768743A20626F6C643B223E52756F59692076312E312E303C2F68313E0A0A3C683420616C69676E3D2263656E746572223EE59FBAE4BA8E556E69417070E5BC80E58F91E79A84E8BDBBE9878FE7BAA7E7A7BBE58AA8E7ABAFE6A186E69EB63C2F68343E0A0A0A0A232320E5B9B3E58FB0E7AE80E4BB8B0A0A0A52756F59692041707020E7A7BBE58AA8E8A7A3E586B3E696B9E6A188EFBC8CE98787E794A8756E69617070E6A186E69EB6EFBC8CE4B880E4BBBDE4BBA3E7A081E5A49AE7BB88E7ABAFE98082E9858DEFBC8CE5908CE697B6E694AFE68C81415050E38081E5B08FE7A88BE5BA8FE380814835EFBC81E5AE9EE78EB0E4BA86E4B88E5B52756F59692D5675655D2868747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D56756529E380815B52756F59692D436C6F75645D2868747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D436C6F756429E5AE8CE7BE8EE5AFB9E68EA5E79A84E7A7BBE58AA8E8A7A3E586B3E696B9E6A188EFBC81E79BAEE5898DE5B7B2E7BB8FE5AE9EE78EB0E799BBE5BD95E38081E68891E79A84E38081E5B7A5E4BD9CE58FB0E38081E7BC96E8BE91E8B584E69699E38081E5A4B4E5838FE4BFAEE694B9E38081E5AF86E7A081E4BFAEE694B9E38081E5B8B8E8A781E997AEE9A298E38081E585B3E4BA8EE68891E4BBACE7AD89E59FBAE7A180E58A9FE883BDE380820A0A0A2A20E9858DE5A597E5908EE7ABAFE4BBA3E7A081E4BB93E5BA93E59CB0E59D805B52756F59692D5675655D2868747470733A2F2F67697465652E636F6D2F795F70726F6A6563742F52756F59692D5675652920E68896205B52756F59692D436C6F75645D2868747470733A2F2F6769746875622E636F6D2F79616E677A6F6E677A6875616E2F52756F59692D436C6F75642920E78988E69CACE380820A0A2A20E5BA94E794A8E6A186E69EB6E59FBAE4BA8E5B756E696170705D2868747470733A2F2F756E696170702E64636C6F75642E6E65742E636E2F29EFBC8CE694AFE68C81E5B08FE7A88BE5BA8FE380814835E38081416E64726F6964E5928C494F53E380820A0A2A20E5898DE7ABAFE7BB84E4BBB6E98787E794A85B756E692D75695D2868747470733A2F2F6769746875622E636F6D2F64636C6F7564696F2F756E692D756929EFBC8CE585A8E7ABAFE585BCE5AEB9E79A84E9AB98E680A7E883BD5549E6A186E69EB6E380820A0A2A20E998BFE9878CE4BA91E68A98E689A3E59CBAEFBC9A5BE782B9E68891E8BF9BE585A55D28687474703A2F2F616C792E72756F79692E76697029EFBC8CE885BEE8AEAFE4BA91E7A792E69D80E59CBAEFBC9A5BE782B9E68891E8BF9BE585A55D28687474703A2F2F7478792E72756F79692E76697029266E6273703B266E6273703B0A0A2A20E998BFE9878CE4BA91E4BC98E683A0E588B8EFBC9A5BE782B9E68891E9A286E58F965D2868747470733A2F2F7777772E616C6979756E2E636F6D2F6D696E69736974652F676F6F64733F75736572436F64653D62726B6938696F662673686172655F736F757263653D636F70795F6C696E6B29EFBC8CE885BEE8AEAFE4BA91E4BC98E683A0E588B8EFBC9A5BE782B9E68891E9A286E58F965D2868747470733A2F2F636C6F75642E74656E63656E742E636F6D2F72656469726563742E7068703F72656469726563743D31303235266370735F6B65793D31393863386466326564323539313537313837313733626337663466333266642666726F6D3D636F6E736F6C6529266E6273703B266E6273703B0A0A0A232320E68A80E69CAFE69687E6A1A30A0A0A2D20E5AE98E7BD91E7BD91E7AB99EFBC9A5B687474703A2F2F72756F79692E7669705D28687474703A2F2F72756F79692E766970290A0A2D20E69687E6A1A3E59CB0E59D80EFBC9A5B687474703A2F2F646F632E72756F79692E7669705D28687474703A2F2F646F632E72756F79692E766970290A0A2D204835E9A1B5E4BD93E9AA8CEFBC9A5B687474703A2F2F68352E72756F79692E7669705D28687474703A2F2F68352E72756F79692E766970290A0A2D205151E4BAA4E6B581E7BEA4EFBC9A20E291A03133333731333738300A0A2D20E5B08FE7A88BE5BA8FE4BD93E9AA8C0A0A215BE5B08FE7A88BE5BA8FE6BC94E7A4BA5D2868747470733A2F2F6F7363696D672E6F736368696E612E6E65742F6F73636E65742F75702D32366337366463393062393261636462643961633863643532353266303763386164392E6A7067290A0A0A'::bytea, '0', 98, '1', '0', '', '', '萨达VS奥迪发奥迪发奥迪发答复答复答复奥迪奥迪发奥迪发奥迪发答复奥迪发奥迪发奥迪发奥迪发答复答复答复', NULL, 'admin', '2022-06-13 09:13:50', 'admin', '2023-06-06 15:12:24');
+
+-- ----------------------------
+-- blog文件表数据
+-- ----------------------------
+insert into cms_blog_file values(33, 17);
+insert into cms_blog_file values(34, 17);
+insert into cms_blog_file values(36, 17);
+insert into cms_blog_file values(37, 17);
+
+-- ----------------------------
+-- blog标签关联表数据
+-- ----------------------------
+insert into cms_blog_tag values(20, 7);
+insert into cms_blog_tag values(20, 22);
+insert into cms_blog_tag values(21, 16);
+insert into cms_blog_tag values(21, 22);
+insert into cms_blog_tag values(22, 22);
+insert into cms_blog_tag values(23, 22);
+insert into cms_blog_tag values(24, 7);
+insert into cms_blog_tag values(24, 22);
+insert into cms_blog_tag values(25, 7);
+insert into cms_blog_tag values(25, 16);
+insert into cms_blog_tag values(25, 22);
+insert into cms_blog_tag values(26, 19);
+insert into cms_blog_tag values(26, 22);
+
+-- ----------------------------
+-- blog分类关联表数据
+-- ----------------------------
+insert into cms_blog_type values(10, 7);
+insert into cms_blog_type values(10, 22);
+insert into cms_blog_type values(11, 16);
+insert into cms_blog_type values(11, 22);
+insert into cms_blog_type values(13, 7);
+insert into cms_blog_type values(13, 22);
+insert into cms_blog_type values(14, 19);
+insert into cms_blog_type values(14, 22);
+insert into cms_blog_type values(15, 22);
+insert into cms_blog_type values(16, 22);
+
+-- ----------------------------
+-- 评论表数据
+-- ----------------------------
+insert into cms_comment values(1, NULL, NULL, 13, 'v地方', '0', 19, '0', 1, 'admin', '2022-01-28 17:43:08', '', NULL);
+insert into cms_comment values(2, 1, 1, NULL, '奥迪发', '1', 19, '0', 1, 'admin', '2022-01-28 17:43:47', '', NULL);
+insert into cms_comment values(3, NULL, NULL, 0, '啊吖吖', '0', 16, '0', 1, 'admin', '2022-01-28 18:09:23', '', NULL);
+insert into cms_comment values(4, 2, 1, NULL, '测试', '1', 19, '0', NULL, '匿名用户', '2022-06-16 15:44:28', '', NULL);
+insert into cms_comment values(5, NULL, NULL, NULL, '哈哈哈', '0', 19, '0', NULL, '匿名用户', '2022-06-16 15:45:17', '', NULL);
+
+-- ----------------------------
+-- 留言表数据
+-- ----------------------------
+insert into cms_message values(1, NULL, NULL, 22, '你好', '0', NULL, '0', 1, 'admin', '2022-01-15 15:24:46', '', NULL);
+insert into cms_message values(2, NULL, NULL, 10, 'hello!!  123', '0', NULL, '0', 1, 'admin', '2022-01-15 15:25:26', '', NULL);
+insert into cms_message values(3, 1, 1, 2, '我很好!', '1', NULL, '0', NULL, '匿名用户', '2022-01-15 15:25:51', '', NULL);
+insert into cms_message values(4, 1, 1, 3, '大家好', '1', NULL, '0', 1, 'admin', '2022-01-15 15:26:23', '', NULL);
+insert into cms_message values(5, 3, 1, 3, '好', '1', NULL, '0', 1, 'admin', '2022-01-15 15:26:39', '', NULL);
+insert into cms_message values(6, NULL, NULL, 2, '测试', '0', NULL, '0', NULL, '匿名用户', '2022-01-16 18:17:27', '', NULL);
+insert into cms_message values(17, 6, 6, NULL, '额', '1', NULL, '0', 1, 'admin', '2022-01-16 20:42:39', '', NULL);
+insert into cms_message values(23, 17, 6, NULL, '哈哈', '1', NULL, '0', NULL, '匿名用户', '2022-01-19 01:31:19', '', NULL);
+insert into cms_message values(24, 5, 1, NULL, '1', '1', NULL, '0', NULL, '匿名用户', '2022-01-19 01:33:03', '', NULL);
+insert into cms_message values(26, 23, 6, NULL, '啊', '1', NULL, '1', 1, 'admin', '2022-01-19 17:17:43', '', NULL);
+insert into cms_message values(27, NULL, NULL, 0, '阿斯顿v阿萨v发到付vDVD发v地方vv阿飞阿斯顿v阿萨v阿萨v阿斯顿v阿斯顿v沙发VS发VS的VS调查', '0', NULL, '0', 1, 'admin', '2022-01-19 17:30:29', '', NULL);
+insert into cms_message values(28, NULL, NULL, 0, '阿萨的顶顶顶顶顶顶顶顶顶', '0', NULL, '0', 1, 'admin', '2022-01-19 17:30:51', '', NULL);
+insert into cms_message values(48, NULL, NULL, 1, 'good!
+', '0', NULL, '0', 2, '若依', '2022-01-20 10:33:14', '', NULL);
+insert into cms_message values(49, 1, 1, NULL, '若依', '1', NULL, '0', 2, '若依', '2022-01-20 10:33:32', '', NULL);
+insert into cms_message values(50, 2, 2, NULL, '你好
+', '1', NULL, '0', 2, '若依', '2022-01-20 10:33:53', '', NULL);
+insert into cms_message values(51, 50, 2, NULL, 'yes', '1', NULL, '0', 1, 'admin', '2022-01-20 10:34:39', '', NULL);
+insert into cms_message values(52, 49, 1, NULL, '你好若依
+', '1', NULL, '0', 1, 'admin', '2022-01-20 17:20:24', '', NULL);
+insert into cms_message values(53, 26, 6, NULL, '问什么删评论', '1', NULL, '0', 1, 'admin', '2022-01-21 10:13:20', '', NULL);
+insert into cms_message values(54, 53, 6, NULL, 'why?', '1', NULL, '0', 2, '若依', '2022-01-21 10:15:47', '', NULL);
+
+-- ----------------------------
+-- 标签信息表数据
+-- ----------------------------
+insert into cms_tag values(20, '啊实打实', 'admin', '2022-01-03 22:50:05', '', NULL);
+insert into cms_tag values(21, '打', 'admin', '2022-01-03 22:50:23', '', NULL);
+insert into cms_tag values(22, '问问', 'admin', '2022-01-03 22:50:43', '', NULL);
+insert into cms_tag values(23, '阿斯弗', 'admin', '2022-01-03 23:30:28', '', NULL);
+insert into cms_tag values(24, '阿松大', 'admin', '2022-01-03 23:30:32', '', NULL);
+insert into cms_tag values(25, '愤愤愤', 'admin', '2022-01-03 23:30:38', '', NULL);
+insert into cms_tag values(26, '若依', 'admin', '2022-01-04 14:48:26', '', NULL);
+
+-- ----------------------------
+-- 分类信息表数据
+-- ----------------------------
+insert into cms_type values(10, '阿萨的', '', '0', '', 'admin', '2022-01-03 23:31:10', '', NULL);
+insert into cms_type values(11, '人然', '', '0', '', 'admin', '2022-01-03 23:31:20', '', NULL);
+insert into cms_type values(13, '啊啊', '', '0', '', 'admin', '2022-01-03 23:31:29', '', NULL);
+insert into cms_type values(14, '若依', '', '0', '', 'admin', '2022-01-04 14:31:23', '', NULL);
+insert into cms_type values(15, '33', '', '0', '', 'admin', '2022-01-03 21:35:44', '', NULL);
+insert into cms_type values(16, 'java', '', '0', '', 'admin', '2022-06-10 16:08:50', '', NULL);
+
+-- ----------------------------
+-- 自增序列从已有数据之后开始
+-- ----------------------------
+select setval(pg_get_serial_sequence('cms_blog', 'id'), greatest((select max(id) from cms_blog), 22));
+select setval(pg_get_serial_sequence('cms_comment', 'id'), greatest((select max(id) from cms_comment), 5));
+select setval(pg_get_serial_sequence('cms_message', 'id'), greatest((select max(id) from cms_message), 54));
+select setval(pg_get_serial_sequence('cms_tag', 'tag_id'), greatest((select max(tag_id) from cms_tag), 26));
+select setval(pg_get_serial_sequence('cms_type', 'type_id'), greatest((select max(type_id) from cms_type), 16));
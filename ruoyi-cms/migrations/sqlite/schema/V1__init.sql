@@ -0,0 +1,166 @@
+-- 内容管理模块表结构
+
+-- ----------------------------
+-- 博客信息表
+-- ----------------------------
+create table cms_blog (  -- 博客信息表
+  id                   integer primary key autoincrement,  -- ID
+  title                varchar(50) not null,  -- 标题
+  type                 char(1) not null,  -- 类型
+  content_type         char(1) not null,  -- 文本编辑器类型
+  content              blob,  -- 内容
+  content_markdown     blob,  -- Markdown格式内容
+  Top                  char(1) default '0',  -- 置顶（0否 1是）
+  views                int default 0,  -- 阅读
+  status               char(1) default '0',  -- 状态（0暂存 1发布）
+  blog_pic_type        char(1) default '0',  -- 首页图片类型（0地址 1上传）
+  blog_pic             varchar(100) default '',  -- 首页图片（ 1上传）
+  blog_pic_link        varchar(100) default '',  -- 首页图片（ 0地址）
+  blog_desc            varchar(100) default '',  -- 简介
+  blog_files           text,  -- 附件列表
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime  -- 更新时间
+);
+insert into sqlite_sequence (name, seq) values ('cms_blog', 22);
+
+-- ----------------------------
+-- blog文件表
+-- ----------------------------
+create table cms_blog_file (  -- blog文件表
+  file_id              bigint not null,  -- 文件ID
+  blog_id              bigint not null,  -- blogID
+  primary key (file_id, blog_id)
+);
+
+-- ----------------------------
+-- blog标签关联表
+-- ----------------------------
+create table cms_blog_tag (  -- blog标签关联表
+  tag_id               bigint not null,  -- 标签ID
+  blog_id              bigint not null,  -- blogID
+  primary key (tag_id, blog_id)
+);
+
+-- ----------------------------
+-- blog分类关联表
+-- ----------------------------
+create table cms_blog_type (  -- blog分类关联表
+  type_id              bigint not null,  -- 类型ID
+  blog_id              bigint not null,  -- blogID
+  primary key (type_id, blog_id)
+);
+
+-- ----------------------------
+-- 评论表
+-- ----------------------------
+create table cms_comment (  -- 评论表
+  id                   integer primary key autoincrement,  -- ID
+  parent_id            bigint,  -- 父评论id
+  main_id              bigint,  -- 主评论id(第一级评论)
+  like_num             int,  -- 点赞数量
+  content              varchar(255),  -- 内容
+  type                 char(1),  -- 评论类型：对人评论，对项目评论，对资源评论
+  blog_id              bigint,  -- 被评论者id，可以是人、项目、资源
+  del_flag             char(1) default '0',  -- 删除标志（0代表存在 1代表删除）
+  user_id              bigint,  -- 评论者id
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime  -- 更新时间
+);
+insert into sqlite_sequence (name, seq) values ('cms_comment', 5);
+
+-- ----------------------------
+-- 留言点赞表
+-- ----------------------------
+create table cms_comment_like (  -- 留言点赞表
+  comment_id           bigint not null,  -- 点赞评论ID
+  user_id              bigint not null,  -- 用户ID
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  primary key (comment_id, user_id)
+);
+
+-- ----------------------------
+-- 留言表
+-- ----------------------------
+create table cms_message (  -- 留言表
+  id                   integer primary key autoincrement,  -- ID
+  parent_id            bigint,  -- 父留言id
+  main_id              bigint,  -- 主留言id(第一级留言)
+  like_num             int,  -- 点赞数量
+  content              varchar(255),  -- 内容
+  type                 char(1),  -- 留言类型：对人评论，对项目评论，对资源评论（0代表留言 1代表回复）
+  blog_id              bigint,  -- 被留言者id，可以是人、项目、资源
+  del_flag             char(1) default '0',  -- 删除标志（0代表存在 1代表删除）
+  user_id              bigint,  -- 留言者id
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime  -- 更新时间
+);
+insert into sqlite_sequence (name, seq) values ('cms_message', 54);
+
+-- ----------------------------
+-- 留言点赞表
+-- ----------------------------
+create table cms_message_like (  -- 留言点赞表
+  message_id           bigint not null,  -- 点赞留言ID
+  user_id              bigint not null,  -- 用户ID
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  primary key (message_id, user_id)
+);
+
+-- ----------------------------
+-- 标签信息表
+-- ----------------------------
+create table cms_tag (  -- 标签信息表
+  tag_id               integer primary key autoincrement,  -- 标签ID
+  tag_name             varchar(50) not null,  -- 标签名称
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime  -- 更新时间
+);
+insert into sqlite_sequence (name, seq) values ('cms_tag', 26);
+
+-- ----------------------------
+-- 分类信息表
+-- ----------------------------
+create table cms_type (  -- 分类信息表
+  type_id              integer primary key autoincrement,  -- 分类ID
+  type_name            varchar(50) not null,  -- 分类名称
+  type_pic             varchar(100) default '',  -- 分类图像（ 1上传）
+  type_pic_type        char(1) default '0',  -- 分类图像类型（0地址 1上传）
+  type_pic_link        varchar(100) default '',  -- 分类图像（ 0地址）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime  -- 更新时间
+);
+insert into sqlite_sequence (name, seq) values ('cms_type', 16);
+
+-- ----------------------------
+-- 文件信息表
+-- ----------------------------
+create table cms_file_info (  -- 文件信息表
+  file_id              integer primary key autoincrement,  -- 文件主键id
+  file_origin_name     varchar(255) not null,  -- 文件名称
+  file_suffix          varchar(255),  -- 文件类型，例如txt
+  file_size_info       varchar(255),  -- 文件大小
+  file_object_name     varchar(255),  -- 存储文件名称
+  file_path            varchar(255),  -- 存储路径
+  del_flag             char(1) not null default 'N',  -- 是否删除：Y-被删除，N-未删除
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime  -- 更新时间
+);
+insert into sqlite_sequence (name, seq) values ('cms_file_info', 38);
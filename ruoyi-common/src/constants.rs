@@ -59,6 +59,8 @@ pub mod cache {
     pub const RATE_LIMIT_PREFIX: &str = "rate_limit:";
     /// 密码错误次数
     pub const PWD_ERR_CNT_PREFIX: &str = "pwd_err_cnt:";
+    /// 扫描缓存键时最多返回的数量
+    pub const MAX_SCAN_KEYS: usize = 10000;
 }
 
 /// 字典类型常量
@@ -96,3 +98,17 @@ pub mod permission {
     /// 管理员角色唯一标识
     pub const ADMIN_ROLE_KEY: &str = "admin";
 }
+
+/// 租户常量
+pub mod tenant {
+    /// 默认租户ID，平台管理员所在的租户，升级前的数据都属于该租户
+    pub const DEFAULT_TENANT_ID: i64 = 1;
+    /// 未登录的请求（如登录）指定租户的请求头
+    pub const TENANT_HEADER: &str = "tenant-id";
+}
+
+/// 分页常量
+pub mod page {
+    /// 每页最大数量
+    pub const MAX_PAGE_SIZE: u64 = 500;
+}
@@ -12,7 +12,11 @@ use thiserror::Error;
 pub enum Error {
     /// 数据库错误
     #[error("数据库错误: {0}")]
-    Database(#[from] sea_orm::DbErr),
+    Database(sea_orm::DbErr),
+
+    /// 并发修改冲突错误
+    #[error("数据冲突: {0}")]
+    Conflict(String),
 
     /// 未授权错误
     #[error("未授权: {0}")]
@@ -81,6 +85,7 @@ impl ResponseError for Error {
             Error::Forbidden(_) => StatusCode::FORBIDDEN,
             Error::NotFound(_) => StatusCode::NOT_FOUND,
             Error::Validation(_) => StatusCode::BAD_REQUEST,
+            Error::Conflict(_) => StatusCode::CONFLICT,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -96,9 +101,60 @@ impl ResponseError for Error {
     }
 }
 
+/// 乐观锁冲突时返回给用户的错误信息
+pub const OPTIMISTIC_LOCK_CONFLICT: &str = "数据已被其他用户修改，请刷新后重试";
+
+/// 乐观锁冲突的识别标记，与错误信息分开，修改或翻译错误信息不影响冲突的识别
+const OPTIMISTIC_LOCK_CONFLICT_MARKER: &str = "ruoyi::optimistic_lock_conflict";
+
+/// 乐观锁冲突时仓库返回的数据库错误，转换为 [`Error::Conflict`]
+///
+/// 其他更新零行的情况（记录不存在、已删除、不属于当前租户等）仍是 [`sea_orm::DbErr::RecordNotUpdated`]
+/// 或 [`sea_orm::DbErr::RecordNotFound`]，转换为 [`Error::Database`]
+pub fn optimistic_lock_conflict() -> sea_orm::DbErr {
+    sea_orm::DbErr::Custom(OPTIMISTIC_LOCK_CONFLICT_MARKER.to_string())
+}
+
+/// 是否为 [`optimistic_lock_conflict`] 返回的乐观锁冲突
+pub fn is_optimistic_lock_conflict(error: &sea_orm::DbErr) -> bool {
+    matches!(error, sea_orm::DbErr::Custom(marker) if marker == OPTIMISTIC_LOCK_CONFLICT_MARKER)
+}
+
+impl From<sea_orm::DbErr> for Error {
+    fn from(error: sea_orm::DbErr) -> Self {
+        if is_optimistic_lock_conflict(&error) {
+            Error::Conflict(OPTIMISTIC_LOCK_CONFLICT.to_string())
+        } else {
+            Error::Database(error)
+        }
+    }
+}
 
 impl From<argon2::password_hash::Error> for Error {
     fn from(error: argon2::password_hash::Error) -> Self {
         Error::PasswordError(error.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_db_err() {
+        assert!(matches!(
+            Error::from(optimistic_lock_conflict()),
+            Error::Conflict(msg) if msg == OPTIMISTIC_LOCK_CONFLICT
+        ));
+        // 只按标记识别，内容与错误信息相同的其他自定义错误不是冲突
+        assert!(matches!(
+            Error::from(sea_orm::DbErr::Custom(OPTIMISTIC_LOCK_CONFLICT.to_string())),
+            Error::Database(_)
+        ));
+        // 其他原因更新零行不是冲突
+        assert!(matches!(
+            Error::from(sea_orm::DbErr::RecordNotUpdated),
+            Error::Database(sea_orm::DbErr::RecordNotUpdated)
+        ));
+    }
+}
@@ -20,6 +20,13 @@ pub struct Claims {
     pub user_name: String,
     /// 会话ID
     pub token_id: String,
+    /// 租户ID，升级前签发的令牌属于默认租户
+    #[serde(default = "default_tenant_id")]
+    pub tenant_id: i64,
+}
+
+fn default_tenant_id() -> i64 {
+    crate::constants::tenant::DEFAULT_TENANT_ID
 }
 
 /// 生成 JWT 令牌
@@ -27,6 +34,7 @@ pub fn generate_token(
     token_id: &str,
     user_id: i64,
     user_name: &str,
+    tenant_id: i64,
     secret: &str,
     expires_in: i64,
 ) -> Result<String, jsonwebtoken::errors::Error> {
@@ -40,6 +48,7 @@ pub fn generate_token(
         user_id,
         user_name: user_name.to_string(),
         token_id: token_id.to_string(),
+        tenant_id,
     };
 
     encode(
@@ -2,10 +2,11 @@
 //! VO (Value Object) 对象定义
 
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 /// 通用响应结构
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct R<T> {
     /// 状态码
     pub code: i32,
@@ -91,10 +92,14 @@ impl<T> R<T> {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// 列表响应结构
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RList<T> {
+    /// 状态码
     pub code: i32,
+    /// 消息
     pub msg: String,
+    /// 列表数据
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub data: Vec<T>,
 }
@@ -109,10 +114,14 @@ impl<T> RList<T> {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// 数据响应结构，数据放在 data 字段中
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RData<T> {
+    /// 状态码
     pub code: i32,
+    /// 消息
     pub msg: String,
+    /// 数据
     pub data: T,
 }
 
@@ -126,15 +135,47 @@ impl<T> RData<T> {
     }
 }
 
+/// 无数据，与 [`R`] 组合描述只返回状态码和消息的响应
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct NoData {}
+
+/// 分页列表数据，与 [`R`] 组合后响应为 `{code, msg, rows, total}`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TableDataInfo<T> {
+    /// 当前页数据
+    pub rows: Vec<T>,
+    /// 总记录数
+    pub total: u64,
+}
+
+/// 游标分页列表数据，与 [`R`] 组合后响应为 `{code, msg, rows, nextCursor}`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorData<T> {
+    /// 当前页数据
+    pub rows: Vec<T>,
+    /// 下一页的游标，没有更多数据时为空
+    pub next_cursor: Option<i64>,
+}
+
 /// 分页查询参数
-#[derive(Debug, Serialize, Deserialize, Clone)]
+///
+/// 排序支持多个字段，以逗号分隔，如 `orderByColumn=status,createTime&isAsc=asc,desc`，
+/// 字段名使用驼峰或下划线均可，每页数量不超过 [`MAX_PAGE_SIZE`](super::constants::page::MAX_PAGE_SIZE)
+#[derive(Debug, Serialize, Deserialize, Clone, IntoParams)]
 #[serde(rename_all = "camelCase")]
 pub struct PageParam {
     /// 当前页码
-    #[serde(default = "default_page_num")]
+    #[serde(
+        default = "default_page_num",
+        deserialize_with = "deserialize_page_num"
+    )]
     pub page_num: u64,
     /// 每页数量
-    #[serde(default = "default_page_size")]
+    #[serde(
+        default = "default_page_size",
+        deserialize_with = "deserialize_page_size"
+    )]
     pub page_size: u64,
     /// 排序字段
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -142,6 +183,72 @@ pub struct PageParam {
     /// 排序方向 (ASC/DESC)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_asc: Option<String>,
+    /// 游标分页：上一页最后一条记录的主键，为空时从第一条开始
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<i64>,
+}
+
+impl Default for PageParam {
+    fn default() -> Self {
+        PageParam {
+            page_num: default_page_num(),
+            page_size: default_page_size(),
+            order_by_column: None,
+            is_asc: None,
+            cursor: None,
+        }
+    }
+}
+
+impl PageParam {
+    /// 每页数量，限制在 1 到 MAX_PAGE_SIZE 之间
+    pub fn limit(&self) -> u64 {
+        self.page_size
+            .clamp(1, super::constants::page::MAX_PAGE_SIZE)
+    }
+
+    /// 从0开始的页码
+    pub fn page_index(&self) -> u64 {
+        self.page_num.max(1) - 1
+    }
+
+    /// 排序字段及方向（true为升序），字段名已转为下划线格式
+    ///
+    /// 排序方向与字段按位置对应，方向少于字段时沿用最后一个方向，未指定时为升序
+    pub fn order_by(&self) -> Vec<(String, bool)> {
+        let Some(columns) = &self.order_by_column else {
+            return Vec::new();
+        };
+        let directions: Vec<bool> = self
+            .is_asc
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|direction| !direction.is_empty())
+            .map(|direction| {
+                !matches!(
+                    direction.to_ascii_lowercase().as_str(),
+                    "desc" | "descending"
+                )
+            })
+            .collect();
+
+        columns
+            .split(',')
+            .map(str::trim)
+            .filter(|column| !column.is_empty())
+            .enumerate()
+            .map(|(i, column)| {
+                let asc = directions
+                    .get(i)
+                    .or(directions.last())
+                    .copied()
+                    .unwrap_or(true);
+                (super::utils::string::to_snake_case(column), asc)
+            })
+            .collect()
+    }
 }
 
 /// 默认每页数量
@@ -154,6 +261,16 @@ fn default_page_num() -> u64 {
     1
 }
 
+/// 页码最小为1
+fn deserialize_page_num<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    Ok(u64::deserialize(deserializer)?.max(1))
+}
+
+/// 每页数量限制在 1 到 MAX_PAGE_SIZE 之间
+fn deserialize_page_size<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    Ok(u64::deserialize(deserializer)?.clamp(1, super::constants::page::MAX_PAGE_SIZE))
+}
+
 /// 分页信息
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PageInfo<T> {
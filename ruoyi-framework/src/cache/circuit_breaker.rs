@@ -0,0 +1,268 @@
+//! 熔断器模块
+//!
+//! Redis连续失败达到阈值后熔断，熔断期间命令立即失败而不再等待超时；
+//! 熔断时间过后进入半开状态，放行一个探测请求，成功则恢复，失败则重新熔断。
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 熔断器配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// 是否启用熔断器
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 连续失败多少次后熔断
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: u32,
+    /// 熔断持续时间（毫秒），之后放行探测请求
+    #[serde(default = "default_reset_timeout")]
+    pub reset_timeout: u64,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_failure_threshold() -> u32 {
+    5
+}
+
+fn default_reset_timeout() -> u64 {
+    10000
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            failure_threshold: 5,
+            reset_timeout: 10000,
+        }
+    }
+}
+
+/// 熔断器状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CircuitState {
+    /// 正常放行
+    Closed,
+    /// 已熔断，拒绝请求
+    Open,
+    /// 熔断时间已过，放行一个探测请求
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// 半开状态下正在执行的探测请求的开始时间，探测请求被取消时超过熔断时间后允许重新探测
+    probing: Option<Instant>,
+}
+
+impl Inner {
+    fn is_probing(&self, reset_timeout: Duration) -> bool {
+        self.probing.is_some_and(|at| at.elapsed() < reset_timeout)
+    }
+}
+
+/// 熔断器
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    name: String,
+    config: CircuitBreakerConfig,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    /// 创建熔断器，name用于日志
+    pub fn new(name: &str, config: CircuitBreakerConfig) -> Self {
+        Self {
+            name: name.to_string(),
+            config,
+            inner: Mutex::new(Inner {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                probing: None,
+            }),
+        }
+    }
+
+    fn reset_timeout(&self) -> Duration {
+        Duration::from_millis(self.config.reset_timeout)
+    }
+
+    /// 当前状态，熔断时间已过时返回半开
+    pub fn state(&self) -> CircuitState {
+        let inner = self.inner.lock().unwrap();
+        match inner.state {
+            CircuitState::Open
+                if inner
+                    .opened_at
+                    .is_some_and(|at| at.elapsed() >= self.reset_timeout()) =>
+            {
+                CircuitState::HalfOpen
+            }
+            state => state,
+        }
+    }
+
+    /// 是否可以放行请求，不改变状态
+    pub fn is_available(&self) -> bool {
+        if !self.config.enabled {
+            return true;
+        }
+        match self.state() {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => !self.inner.lock().unwrap().is_probing(self.reset_timeout()),
+        }
+    }
+
+    /// 申请执行一次请求，半开状态下只放行一个探测请求
+    pub fn try_acquire(&self) -> bool {
+        if !self.config.enabled {
+            return true;
+        }
+        let reset_timeout = self.reset_timeout();
+        let mut inner = self.inner.lock().unwrap();
+        match inner.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => {
+                if inner
+                    .opened_at
+                    .is_some_and(|at| at.elapsed() >= reset_timeout)
+                {
+                    inner.state = CircuitState::HalfOpen;
+                    inner.probing = Some(Instant::now());
+                    info!("{}：熔断时间已过，放行探测请求", self.name);
+                    true
+                } else {
+                    false
+                }
+            }
+            CircuitState::HalfOpen => {
+                if inner.is_probing(reset_timeout) {
+                    false
+                } else {
+                    inner.probing = Some(Instant::now());
+                    true
+                }
+            }
+        }
+    }
+
+    /// 记录一次成功，半开状态下恢复正常
+    pub fn record_success(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.state != CircuitState::Closed {
+            info!("{}：探测请求成功，熔断器已恢复", self.name);
+        }
+        inner.state = CircuitState::Closed;
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+        inner.probing = None;
+    }
+
+    /// 记录一次失败，连续失败达到阈值或探测失败时熔断
+    pub fn record_failure(&self) {
+        if !self.config.enabled {
+            return;
+        }
+        let mut inner = self.inner.lock().unwrap();
+        inner.consecutive_failures += 1;
+        let trip = match inner.state {
+            CircuitState::Closed => inner.consecutive_failures >= self.config.failure_threshold,
+            CircuitState::HalfOpen => true,
+            CircuitState::Open => false,
+        };
+        if trip {
+            warn!(
+                "{}：连续失败{}次，熔断{}毫秒",
+                self.name, inner.consecutive_failures, self.config.reset_timeout
+            );
+            inner.state = CircuitState::Open;
+            inner.opened_at = Some(Instant::now());
+        }
+        inner.probing = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(failure_threshold: u32, reset_timeout: u64) -> CircuitBreaker {
+        CircuitBreaker::new(
+            "test",
+            CircuitBreakerConfig {
+                enabled: true,
+                failure_threshold,
+                reset_timeout,
+            },
+        )
+    }
+
+    #[test]
+    fn test_trip_after_consecutive_failures() {
+        let breaker = breaker(3, 10000);
+        breaker.record_failure();
+        breaker.record_failure();
+        // 中间的成功会清零失败次数
+        breaker.record_success();
+        breaker.record_failure();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.try_acquire());
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.is_available());
+        assert!(!breaker.try_acquire());
+    }
+
+    #[test]
+    fn test_half_open_probe() {
+        let breaker = breaker(1, 50);
+        breaker.record_failure();
+        assert!(!breaker.try_acquire());
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.is_available());
+        assert!(breaker.try_acquire(), "应放行一个探测请求");
+        assert!(!breaker.try_acquire(), "探测期间不放行其他请求");
+
+        // 探测失败重新熔断
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(breaker.try_acquire());
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.try_acquire());
+    }
+
+    #[test]
+    fn test_disabled() {
+        let breaker = CircuitBreaker::new(
+            "test",
+            CircuitBreakerConfig {
+                enabled: false,
+                ..Default::default()
+            },
+        );
+        for _ in 0..10 {
+            breaker.record_failure();
+        }
+        assert!(breaker.try_acquire());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
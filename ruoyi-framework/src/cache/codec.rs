@@ -0,0 +1,316 @@
+//! 缓存值编解码模块
+//!
+//! 支持JSON、MessagePack和bincode三种序列化格式，以及超过阈值后使用deflate压缩。
+//! 非JSON格式或压缩后的值以两个字节的格式标记开头：
+//! 1. 第一个字节固定为 `0x00`，合法的JSON文本不会以该字节开头
+//! 2. 第二个字节的低4位为序列化格式，高4位为压缩算法
+//!
+//! 未压缩的JSON不加标记，与旧数据、Redis的INCR/DECR以及其他客户端保持兼容。
+//! 读取时按标记解码，因此切换格式期间新旧数据可以混合存在。
+
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::io::{Read, Write};
+
+use super::{CacheError, CacheResult};
+
+/// 格式标记的第一个字节
+const MARKER: u8 = 0x00;
+/// 格式标记的长度
+const HEADER_LEN: usize = 2;
+/// 未压缩
+const COMPRESSION_NONE: u8 = 0x00;
+/// deflate压缩
+const COMPRESSION_DEFLATE: u8 = 0x10;
+/// 压缩算法所在的位
+const COMPRESSION_MASK: u8 = 0xF0;
+/// 序列化格式所在的位
+const FORMAT_MASK: u8 = 0x0F;
+
+/// 序列化格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CodecFormat {
+    /// JSON，可读性好，兼容旧数据
+    #[default]
+    Json,
+    /// MessagePack，保留字段名，体积比JSON小
+    #[serde(alias = "messagepack")]
+    Msgpack,
+    /// bincode，体积最小、速度最快，但不支持 `serde_json::Value`、`#[serde(flatten)]`
+    /// 和 `#[serde(untagged)]` 等需要自描述格式的类型
+    Bincode,
+}
+
+impl CodecFormat {
+    fn id(self) -> u8 {
+        match self {
+            CodecFormat::Json => 0x01,
+            CodecFormat::Msgpack => 0x02,
+            CodecFormat::Bincode => 0x03,
+        }
+    }
+
+    fn from_id(id: u8) -> CacheResult<Self> {
+        match id {
+            0x01 => Ok(CodecFormat::Json),
+            0x02 => Ok(CodecFormat::Msgpack),
+            0x03 => Ok(CodecFormat::Bincode),
+            _ => Err(CacheError::Deserialization(format!(
+                "未知的缓存值格式: {:#04x}",
+                id
+            ))),
+        }
+    }
+}
+
+/// 按键前缀覆盖的编码规则
+#[derive(Debug, Clone, Deserialize)]
+pub struct CodecRule {
+    /// 键前缀，如 `sys_dict:`
+    pub prefix: String,
+    /// 序列化格式
+    pub format: CodecFormat,
+    /// 压缩阈值（字节），不设置时使用全局配置
+    #[serde(default)]
+    pub compress_threshold: Option<usize>,
+}
+
+/// 编码配置
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CodecConfig {
+    /// 默认序列化格式
+    #[serde(default)]
+    pub format: CodecFormat,
+    /// 序列化后超过该字节数的值使用deflate压缩，0表示不压缩
+    #[serde(default)]
+    pub compress_threshold: usize,
+    /// 按键前缀覆盖的规则，匹配最长的前缀
+    #[serde(default)]
+    pub rules: Vec<CodecRule>,
+}
+
+/// 缓存值编解码器
+#[derive(Debug, Clone, Default)]
+pub struct ValueCodec {
+    config: CodecConfig,
+}
+
+impl ValueCodec {
+    /// 根据配置创建编解码器
+    pub fn new(config: CodecConfig) -> Self {
+        Self { config }
+    }
+
+    /// 获取键使用的序列化格式和压缩阈值
+    fn rule_for(&self, key: &str) -> (CodecFormat, usize) {
+        self.config
+            .rules
+            .iter()
+            .filter(|rule| key.starts_with(&rule.prefix))
+            .max_by_key(|rule| rule.prefix.len())
+            .map(|rule| {
+                (
+                    rule.format,
+                    rule.compress_threshold
+                        .unwrap_or(self.config.compress_threshold),
+                )
+            })
+            .unwrap_or((self.config.format, self.config.compress_threshold))
+    }
+
+    /// 按键对应的规则编码值
+    pub fn encode<T: Serialize + ?Sized>(&self, key: &str, value: &T) -> CacheResult<Vec<u8>> {
+        let (format, threshold) = self.rule_for(key);
+        let data = match format {
+            CodecFormat::Json => serde_json::to_vec(value)?,
+            CodecFormat::Msgpack => rmp_serde::to_vec_named(value)
+                .map_err(|e| CacheError::Serialization(e.to_string()))?,
+            CodecFormat::Bincode => {
+                bincode::serialize(value).map_err(|e| CacheError::Serialization(e.to_string()))?
+            }
+        };
+
+        let compress = threshold > 0 && data.len() > threshold;
+        if format == CodecFormat::Json && !compress {
+            return Ok(data);
+        }
+
+        let mut encoded = Vec::with_capacity(HEADER_LEN + data.len());
+        if compress {
+            encoded.extend_from_slice(&[MARKER, format.id() | COMPRESSION_DEFLATE]);
+            let mut encoder = DeflateEncoder::new(encoded, Compression::fast());
+            encoder
+                .write_all(&data)
+                .map_err(|e| CacheError::Serialization(e.to_string()))?;
+            encoder
+                .finish()
+                .map_err(|e| CacheError::Serialization(e.to_string()))
+        } else {
+            encoded.extend_from_slice(&[MARKER, format.id() | COMPRESSION_NONE]);
+            encoded.extend_from_slice(&data);
+            Ok(encoded)
+        }
+    }
+
+    /// 解码值，格式由值自身的标记决定，与当前配置无关
+    pub fn decode<T: DeserializeOwned>(&self, data: &[u8]) -> CacheResult<T> {
+        if data.first() != Some(&MARKER) {
+            return Ok(serde_json::from_slice(data)?);
+        }
+        let flags = *data
+            .get(1)
+            .ok_or_else(|| CacheError::Deserialization("缓存值格式标记不完整".to_string()))?;
+        let format = CodecFormat::from_id(flags & FORMAT_MASK)?;
+        let payload = &data[HEADER_LEN..];
+
+        let decompressed;
+        let payload = match flags & COMPRESSION_MASK {
+            COMPRESSION_NONE => payload,
+            COMPRESSION_DEFLATE => {
+                let mut buf = Vec::with_capacity(payload.len() * 4);
+                DeflateDecoder::new(payload)
+                    .read_to_end(&mut buf)
+                    .map_err(|e| CacheError::Deserialization(e.to_string()))?;
+                decompressed = buf;
+                &decompressed
+            }
+            other => {
+                return Err(CacheError::Deserialization(format!(
+                    "未知的缓存值压缩算法: {:#04x}",
+                    other
+                )))
+            }
+        };
+
+        match format {
+            CodecFormat::Json => Ok(serde_json::from_slice(payload)?),
+            CodecFormat::Msgpack => rmp_serde::from_slice(payload)
+                .map_err(|e| CacheError::Deserialization(e.to_string())),
+            CodecFormat::Bincode => bincode::deserialize(payload)
+                .map_err(|e| CacheError::Deserialization(e.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Dict {
+        label: String,
+        value: Option<String>,
+        sort: i32,
+    }
+
+    fn dicts(n: usize) -> Vec<Dict> {
+        (0..n)
+            .map(|i| Dict {
+                label: format!("标签{}", i),
+                value: Some(i.to_string()),
+                sort: i as i32,
+            })
+            .collect()
+    }
+
+    fn codec(format: CodecFormat, compress_threshold: usize) -> ValueCodec {
+        ValueCodec::new(CodecConfig {
+            format,
+            compress_threshold,
+            rules: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let value = dicts(100);
+        for format in [
+            CodecFormat::Json,
+            CodecFormat::Msgpack,
+            CodecFormat::Bincode,
+        ] {
+            for threshold in [0, 64] {
+                let codec = codec(format, threshold);
+                let data = codec.encode("sys_dict:test", &value).unwrap();
+                let decoded: Vec<Dict> = codec.decode(&data).unwrap();
+                assert_eq!(decoded, value, "{:?} 阈值 {}", format, threshold);
+            }
+        }
+    }
+
+    #[test]
+    fn test_plain_json_is_unmarked() {
+        let codec = codec(CodecFormat::Json, 1024);
+        assert_eq!(codec.encode("counter", &42).unwrap(), b"42");
+        assert_eq!(codec.encode("name", "value").unwrap(), b"\"value\"");
+    }
+
+    #[test]
+    fn test_compression() {
+        let value = dicts(200);
+        let plain = codec(CodecFormat::Json, 0).encode("k", &value).unwrap();
+        let compressed = codec(CodecFormat::Json, 1024).encode("k", &value).unwrap();
+        assert_eq!(&compressed[..2], &[MARKER, 0x01 | COMPRESSION_DEFLATE]);
+        assert!(compressed.len() < plain.len() / 2);
+
+        // 低于阈值的值不压缩
+        let small = codec(CodecFormat::Msgpack, 1024).encode("k", &1).unwrap();
+        assert_eq!(&small[..2], &[MARKER, 0x02]);
+    }
+
+    #[test]
+    fn test_mixed_formats() {
+        // 切换格式后仍能读取旧格式写入的数据
+        let value = dicts(10);
+        let json = codec(CodecFormat::Json, 0).encode("k", &value).unwrap();
+        let msgpack = codec(CodecFormat::Msgpack, 0).encode("k", &value).unwrap();
+        let bincode = codec(CodecFormat::Bincode, 16).encode("k", &value).unwrap();
+
+        let reader = codec(CodecFormat::Bincode, 0);
+        for data in [json, msgpack, bincode] {
+            let decoded: Vec<Dict> = reader.decode(&data).unwrap();
+            assert_eq!(decoded, value);
+        }
+    }
+
+    #[test]
+    fn test_prefix_rules() {
+        let codec = ValueCodec::new(CodecConfig {
+            format: CodecFormat::Json,
+            compress_threshold: 0,
+            rules: vec![
+                CodecRule {
+                    prefix: "sys_".to_string(),
+                    format: CodecFormat::Msgpack,
+                    compress_threshold: None,
+                },
+                CodecRule {
+                    prefix: "sys_dict:".to_string(),
+                    format: CodecFormat::Bincode,
+                    compress_threshold: Some(8),
+                },
+            ],
+        });
+        assert_eq!(codec.rule_for("login_tokens:a"), (CodecFormat::Json, 0));
+        assert_eq!(codec.rule_for("sys_config:a"), (CodecFormat::Msgpack, 0));
+        assert_eq!(codec.rule_for("sys_dict:a"), (CodecFormat::Bincode, 8));
+
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), 1);
+        let data = codec.encode("sys_config:map", &map).unwrap();
+        assert_eq!(&data[..2], &[MARKER, 0x02]);
+        assert_eq!(codec.decode::<HashMap<String, i32>>(&data).unwrap(), map);
+    }
+
+    #[test]
+    fn test_invalid_marker() {
+        let codec = ValueCodec::default();
+        assert!(codec.decode::<i32>(&[MARKER]).is_err());
+        assert!(codec.decode::<i32>(&[MARKER, 0x0F, 1]).is_err());
+        assert!(codec.decode::<i32>(&[MARKER, 0x71, 1]).is_err());
+    }
+}
@@ -23,6 +23,7 @@ pub async fn global_cache_example() -> CacheResult<()> {
             max_capacity: 10000,
             default_ttl: 3600,
             cleanup_interval: 60,
+            ..Default::default()
         };
 
         // 创建Redis配置
@@ -9,7 +9,7 @@ use std::sync::Arc;
 use tokio::sync::OnceCell;
 
 use crate::cache::{
-    CacheAdapter, CacheBase, CacheError, CacheManager, CacheResult,
+    CacheAdapter, CacheBase, CacheError, CacheManager, CacheResult, LocalCache,
     LocalCacheManager, MultiLevelCache, RedisCacheManager,
 };
 use crate::config::cache::{CacheSettings, CacheType};
@@ -22,6 +22,8 @@ static INITIALIZING: AtomicBool = AtomicBool::new(false);
 lazy_static! {
     // 使用tokio的OnceCell代替手动管理缓存实例
     static ref GLOBAL_CACHE: OnceCell<Arc<dyn CacheBase>> = OnceCell::new();
+    // 启用了快照的本地缓存，停机时保存快照
+    static ref SNAPSHOT_CACHE: OnceCell<LocalCache> = OnceCell::new();
 }
 
 /// 异步初始化全局缓存管理器
@@ -117,6 +119,9 @@ async fn init_cache_by_type(settings: Arc<CacheSettings>) -> CacheResult<Arc<dyn
 
             let local_cache_manager = LocalCacheManager::new(settings.local.clone());
             let cache = local_cache_manager.get_cache().await?;
+            if settings.local.snapshot.enabled {
+                restore_snapshot(&cache).await;
+            }
             let adapter = CacheAdapter::new(cache);
             Ok(Arc::new(adapter))
         }
@@ -137,6 +142,9 @@ async fn init_cache_by_type(settings: Arc<CacheSettings>) -> CacheResult<Arc<dyn
         }
         CacheType::Multi => {
             info!("正在初始化多级缓存...");
+            if settings.local.snapshot.enabled {
+                warn!("多级缓存的数据以Redis为准，本地缓存快照配置已忽略");
+            }
 
             match MultiLevelCache::new(settings.clone()).await {
                 Ok(cache) => {
@@ -152,6 +160,29 @@ async fn init_cache_by_type(settings: Arc<CacheSettings>) -> CacheResult<Arc<dyn
     }
 }
 
+/// 加载本地缓存快照并启动定时保存，加载失败时以空缓存启动
+async fn restore_snapshot(cache: &LocalCache) {
+    let path = &cache.get_config().snapshot.path;
+    match cache.load_snapshot().await {
+        Ok(count) => info!("已从快照 {} 恢复 {} 个本地缓存键", path, count),
+        Err(e) => warn!("加载本地缓存快照 {} 失败，以空缓存启动: {}", path, e),
+    }
+    cache.spawn_snapshot_task();
+    let _ = SNAPSHOT_CACHE.set(cache.clone());
+}
+
+/// 关闭全局缓存
+///
+/// 应在服务停止后调用，启用了快照的本地缓存会在此时保存快照。
+pub async fn shutdown_global_cache() {
+    if let Some(cache) = SNAPSHOT_CACHE.get() {
+        match cache.save_snapshot().await {
+            Ok(count) => info!("已保存 {} 个本地缓存键到快照", count),
+            Err(e) => error!("保存本地缓存快照失败: {}", e),
+        }
+    }
+}
+
 /// 初始化全局缓存管理器
 ///
 /// 只需调用一次，后续调用将被忽略。
@@ -250,6 +281,7 @@ mod tests {
             max_capacity: 1000,
             default_ttl: 3600,
             cleanup_interval: 60,
+            ..Default::default()
         };
 
         let settings = CacheSettings {
@@ -413,6 +445,7 @@ mod tests {
             max_capacity: 1000,
             default_ttl: 3600,
             cleanup_interval: 60,
+            ..Default::default()
         };
 
         let redis_config = crate::cache::RedisConfig {
@@ -3,15 +3,27 @@
 //! 使用 moka-rs 实现的线程安全、高性能的本地缓存
 
 use async_trait::async_trait;
+use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
+use log::warn;
 use moka::future::Cache as MokaCache;
+use moka::notification::RemovalCause;
+use moka::Expiry;
 use ruoyi_common::utils::string::{regex_from_pattern, regex_match};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::sync::Arc;
-use std::time::Duration;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
-use super::{Cache, CacheError, CacheManager, CacheResult};
+use super::snapshot::{
+    from_unix_millis, read_snapshot, to_unix_millis, write_snapshot, SnapshotEntry, SnapshotValue,
+};
+use super::{
+    Cache, CacheError, CacheKeyType, CacheManager, CacheResult, CacheStats, CodecConfig,
+    NamespaceStats, SnapshotConfig, ValueCodec,
+};
 
 /// 本地缓存配置
 #[derive(Debug, Clone, Deserialize)]
@@ -28,6 +40,12 @@ pub struct LocalCacheConfig {
     /// 缓存项过期后自动清理的时间间隔（秒）
     #[serde(default = "default_cleanup_interval")]
     pub cleanup_interval: u64,
+    /// 值的编码方式
+    #[serde(default)]
+    pub codec: CodecConfig,
+    /// 快照持久化
+    #[serde(default)]
+    pub snapshot: SnapshotConfig,
 }
 
 fn default_name() -> String {
@@ -53,17 +71,203 @@ impl Default for LocalCacheConfig {
             max_capacity: 10000,
             default_ttl: 3600,
             cleanup_interval: 60,
+            codec: CodecConfig::default(),
+            snapshot: SnapshotConfig::default(),
         }
     }
 }
 
+/// 字符串缓存项，记录值及其过期时间点
+#[derive(Debug)]
+struct LocalEntry {
+    data: Vec<u8>,
+    /// 过期时间点，None 表示永不过期
+    expires_at: Option<Instant>,
+}
+
+/// 按缓存项的过期时间点计算存活时长
+struct LocalExpiry;
+
+impl Expiry<String, Arc<LocalEntry>> for LocalExpiry {
+    fn expire_after_create(
+        &self,
+        _key: &String,
+        value: &Arc<LocalEntry>,
+        created_at: Instant,
+    ) -> Option<Duration> {
+        value
+            .expires_at
+            .map(|at| at.saturating_duration_since(created_at))
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &String,
+        value: &Arc<LocalEntry>,
+        updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        value
+            .expires_at
+            .map(|at| at.saturating_duration_since(updated_at))
+    }
+}
+
+/// 哈希表缓存项
+#[derive(Debug, Default)]
+struct HashEntry {
+    fields: DashMap<String, Vec<u8>>,
+    /// 过期时间点，None 表示永不过期
+    expires_at: Mutex<Option<Instant>>,
+}
+
+impl HashEntry {
+    fn expires_at(&self) -> Option<Instant> {
+        *self.expires_at.lock().unwrap()
+    }
+
+    fn set_expires_at(&self, expires_at: Option<Instant>) {
+        *self.expires_at.lock().unwrap() = expires_at;
+    }
+
+    fn is_expired(&self, now: Instant) -> bool {
+        matches!(self.expires_at(), Some(at) if at <= now)
+    }
+}
+
+/// 列表、集合和有序集合的数据
+#[derive(Debug)]
+enum Collection {
+    /// 列表
+    List(VecDeque<Vec<u8>>),
+    /// 集合
+    Set(HashSet<Vec<u8>>),
+    /// 有序集合，保存成员到分数的映射，读取时再排序
+    SortedSet(HashMap<Vec<u8>, f64>),
+}
+
+impl Collection {
+    fn new_list() -> Self {
+        Collection::List(VecDeque::new())
+    }
+
+    fn new_set() -> Self {
+        Collection::Set(HashSet::new())
+    }
+
+    fn new_sorted_set() -> Self {
+        Collection::SortedSet(HashMap::new())
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Collection::List(list) => list.is_empty(),
+            Collection::Set(set) => set.is_empty(),
+            Collection::SortedSet(members) => members.is_empty(),
+        }
+    }
+}
+
+/// 列表、集合和有序集合缓存项
+#[derive(Debug)]
+struct CollectionEntry {
+    data: Collection,
+    /// 过期时间点，None 表示永不过期
+    expires_at: Option<Instant>,
+}
+
+impl CollectionEntry {
+    fn is_expired(&self, now: Instant) -> bool {
+        matches!(self.expires_at, Some(at) if at <= now)
+    }
+}
+
+/// 进程内锁，记录持有者令牌和租期到期时间
+#[derive(Debug)]
+struct LockEntry {
+    token: String,
+    expires_at: Instant,
+}
+
+impl LockEntry {
+    fn is_held_by(&self, token: &str, now: Instant) -> bool {
+        self.token == token && self.expires_at > now
+    }
+}
+
+/// 与Redis一致，对存储其他类型数据的键执行操作时返回错误
+fn wrong_type(key: &str) -> CacheError {
+    CacheError::Other(format!("WRONGTYPE 键 {} 存储的数据类型与操作不符", key))
+}
+
+/// 按Redis的LRANGE规则换算列表区间，负数下标从尾部倒数，区间为空时返回None
+fn list_range(start: i64, stop: i64, len: usize) -> Option<(usize, usize)> {
+    let len = len as i64;
+    let start = if start < 0 {
+        (len + start).max(0)
+    } else {
+        start
+    };
+    let stop = if stop < 0 {
+        len + stop
+    } else {
+        stop.min(len - 1)
+    };
+    if start > stop || start >= len {
+        None
+    } else {
+        Some((start as usize, stop as usize))
+    }
+}
+
+/// 按分数从小到大排列有序集合成员，分数相同时按成员字节序排列，与Redis一致
+fn sorted_members(members: &HashMap<Vec<u8>, f64>) -> Vec<(&Vec<u8>, f64)> {
+    let mut sorted: Vec<(&Vec<u8>, f64)> = members.iter().map(|(m, s)| (m, *s)).collect();
+    sorted.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+    sorted
+}
+
+/// 有序集合的分数不能为NaN
+fn check_score(score: f64) -> CacheResult<f64> {
+    if score.is_nan() {
+        Err(CacheError::Other(
+            "有序集合的分数不是有效的数字".to_string(),
+        ))
+    } else {
+        Ok(score)
+    }
+}
+
+/// 将剩余存活时间换算为秒，与Redis的TTL命令保持一致（四舍五入）
+fn remaining_secs(expires_at: Option<Instant>) -> i64 {
+    match expires_at {
+        Some(at) => {
+            let remaining = at.saturating_duration_since(Instant::now());
+            ((remaining.as_millis() + 500) / 1000) as i64
+        }
+        None => -1,
+    }
+}
+
 /// 本地缓存实现
 #[derive(Clone)]
 pub struct LocalCache {
     /// 普通键值缓存
-    cache: Arc<MokaCache<String, Vec<u8>>>,
+    cache: Arc<MokaCache<String, Arc<LocalEntry>>>,
     /// 哈希表缓存 - 直接使用DashMap嵌套结构，避免每次访问时从moka获取并复制整个map
-    hash_cache: Arc<DashMap<String, Arc<DashMap<String, Vec<u8>>>>>,
+    hash_cache: Arc<DashMap<String, Arc<HashEntry>>>,
+    /// 列表、集合和有序集合缓存
+    collections: Arc<DashMap<String, CollectionEntry>>,
+    /// 进程内锁，与普通键分开保存
+    locks: Arc<DashMap<String, LockEntry>>,
+    /// 上次清理过期哈希表和集合类数据的时间
+    last_cleanup: Arc<Mutex<Instant>>,
+    /// 按命名空间统计的命中和淘汰情况
+    stats: Arc<CacheStats>,
+    /// 字符串和哈希表值的编解码器
+    codec: Arc<ValueCodec>,
+    /// 保证同一时间只有一个快照在写入
+    snapshot_lock: Arc<tokio::sync::Mutex<()>>,
     /// 配置
     config: Arc<LocalCacheConfig>,
 }
@@ -71,10 +275,18 @@ pub struct LocalCache {
 impl LocalCache {
     /// 创建新的本地缓存实例
     pub fn new(config: Arc<LocalCacheConfig>) -> Self {
-        // 普通缓存
+        let stats = Arc::new(CacheStats::new());
+
+        // 普通缓存，过期时间由每个缓存项单独决定，因容量或过期被移除时计入淘汰次数
+        let listener_stats = stats.clone();
         let cache = MokaCache::builder()
             .max_capacity(config.max_capacity)
-            .time_to_live(Duration::from_secs(config.default_ttl))
+            .expire_after(LocalExpiry)
+            .eviction_listener(move |key: Arc<String>, _, cause: RemovalCause| {
+                if cause.was_evicted() {
+                    listener_stats.record_eviction(&key);
+                }
+            })
             .build();
 
         // 哈希缓存 - 使用DashMap而不是MokaCache，以减少获取和设置操作的开销
@@ -83,6 +295,12 @@ impl LocalCache {
         Self {
             cache: Arc::new(cache),
             hash_cache: Arc::new(hash_cache),
+            collections: Arc::new(DashMap::new()),
+            locks: Arc::new(DashMap::new()),
+            last_cleanup: Arc::new(Mutex::new(Instant::now())),
+            stats,
+            codec: Arc::new(ValueCodec::new(config.codec.clone())),
+            snapshot_lock: Arc::new(tokio::sync::Mutex::new(())),
             config,
         }
     }
@@ -91,23 +309,259 @@ impl LocalCache {
         self.config.clone()
     }
 
-    /// 获取指定键的哈希表，如果不存在则创建
-    fn get_or_create_hash(&self, key: &str) -> Arc<DashMap<String, Vec<u8>>> {
-        if let Some(hash) = self.hash_cache.get(key) {
-            hash.value().clone()
-        } else {
-            let hash = Arc::new(DashMap::new());
-            self.hash_cache.insert(key.to_string(), hash.clone());
-            hash
+    /// 写入字符串缓存项，同一个键上的哈希表和集合类数据会被覆盖
+    async fn insert_entry(&self, key: &str, data: Vec<u8>, expires_at: Option<Instant>) {
+        self.hash_cache.remove(key);
+        self.collections.remove(key);
+        self.cache
+            .insert(key.to_string(), Arc::new(LocalEntry { data, expires_at }))
+            .await;
+    }
+
+    /// 获取指定键未过期的哈希表，已过期的哈希表会被顺带删除
+    fn get_hash(&self, key: &str) -> Option<Arc<HashEntry>> {
+        let hash = self.hash_cache.get(key)?.value().clone();
+        if hash.is_expired(Instant::now()) {
+            if self
+                .hash_cache
+                .remove_if(key, |_, h| h.is_expired(Instant::now()))
+                .is_some()
+            {
+                self.stats.record_eviction(key);
+            }
+            return None;
+        }
+        Some(hash)
+    }
+
+    /// 获取指定键的哈希表，如果不存在或已过期则创建
+    async fn get_or_create_hash(&self, key: &str) -> Arc<HashEntry> {
+        self.cleanup_expired_entries();
+        if let Some(hash) = self.get_hash(key) {
+            return hash;
+        }
+        self.cache.invalidate(key).await;
+        self.collections.remove(key);
+        self.hash_cache
+            .entry(key.to_string())
+            .or_default()
+            .value()
+            .clone()
+    }
+
+    /// 删除指定键上已过期的集合类数据
+    fn remove_expired_collection(&self, key: &str) {
+        if self
+            .collections
+            .remove_if(key, |_, entry| entry.is_expired(Instant::now()))
+            .is_some()
+        {
+            self.stats.record_eviction(key);
+        }
+    }
+
+    /// 判断键上是否存储了字符串或哈希表
+    fn holds_other_type(&self, key: &str) -> bool {
+        self.cache.contains_key(key) || self.get_hash(key).is_some()
+    }
+
+    /// 读取指定键的列表、集合或有序集合，键不存在时返回None
+    fn read_collection<R>(
+        &self,
+        key: &str,
+        f: impl FnOnce(&Collection) -> CacheResult<R>,
+    ) -> CacheResult<Option<R>> {
+        self.remove_expired_collection(key);
+        match self.collections.get(key) {
+            Some(entry) => f(&entry.data).map(Some),
+            None if self.holds_other_type(key) => Err(wrong_type(key)),
+            None => Ok(None),
+        }
+    }
+
+    /// 修改指定键的列表、集合或有序集合，不存在时先创建，修改后为空则与Redis一致删除该键
+    fn write_collection<R>(
+        &self,
+        key: &str,
+        create: fn() -> Collection,
+        f: impl FnOnce(&mut Collection) -> CacheResult<R>,
+    ) -> CacheResult<R> {
+        self.cleanup_expired_entries();
+        self.remove_expired_collection(key);
+        if !self.collections.contains_key(key) && self.holds_other_type(key) {
+            return Err(wrong_type(key));
+        }
+
+        let mut entry =
+            self.collections
+                .entry(key.to_string())
+                .or_insert_with(|| CollectionEntry {
+                    data: create(),
+                    expires_at: None,
+                });
+        let result = f(&mut entry.data);
+        let is_empty = entry.data.is_empty();
+        drop(entry);
+        if is_empty {
+            self.collections
+                .remove_if(key, |_, entry| entry.data.is_empty());
+        }
+        result
+    }
+
+    /// 按配置的清理间隔删除过期的哈希表和集合类数据
+    fn cleanup_expired_entries(&self) {
+        let interval = Duration::from_secs(self.config.cleanup_interval);
+        {
+            let mut last_cleanup = self.last_cleanup.lock().unwrap();
+            if last_cleanup.elapsed() < interval {
+                return;
+            }
+            *last_cleanup = Instant::now();
+        }
+        self.purge_expired_entries();
+    }
+
+    /// 立即删除所有过期的哈希表和集合类数据，以及租期已到的锁
+    fn purge_expired_entries(&self) {
+        let now = Instant::now();
+        self.hash_cache.retain(|key, hash| {
+            let expired = hash.is_expired(now);
+            if expired {
+                self.stats.record_eviction(key);
+            }
+            !expired
+        });
+        self.collections.retain(|key, entry| {
+            let expired = entry.is_expired(now);
+            if expired {
+                self.stats.record_eviction(key);
+            }
+            !expired
+        });
+        self.locks.retain(|_, lock| lock.expires_at > now);
+    }
+
+    /// 统计未过期的键数量和其中设置了过期时间的键数量
+    fn count_keys(&self) -> (usize, usize) {
+        self.purge_expired_entries();
+        let (mut keys, mut expires) = (0, 0);
+        for (_, entry) in self.cache.iter() {
+            keys += 1;
+            if entry.expires_at.is_some() {
+                expires += 1;
+            }
+        }
+        keys += self.hash_cache.len() + self.collections.len();
+        expires += self
+            .hash_cache
+            .iter()
+            .filter(|item| item.value().expires_at().is_some())
+            .count();
+        expires += self
+            .collections
+            .iter()
+            .filter(|item| item.value().expires_at.is_some())
+            .count();
+        (keys, expires)
+    }
+
+    /// 将未过期的字符串和哈希表保存到配置的快照文件，返回保存的键数量
+    pub async fn save_snapshot(&self) -> CacheResult<usize> {
+        let _guard = self.snapshot_lock.lock().await;
+        let now = Instant::now();
+        let mut entries = Vec::new();
+        for (key, entry) in self.cache.iter() {
+            if matches!(entry.expires_at, Some(at) if at <= now) {
+                continue;
+            }
+            entries.push(SnapshotEntry {
+                key: key.to_string(),
+                value: SnapshotValue::String(entry.data.clone()),
+                expires_at: entry.expires_at.map(to_unix_millis),
+            });
+        }
+        for item in self.hash_cache.iter() {
+            let hash = item.value();
+            if hash.is_expired(now) {
+                continue;
+            }
+            let fields = hash
+                .fields
+                .iter()
+                .map(|field| (field.key().clone(), field.value().clone()))
+                .collect();
+            entries.push(SnapshotEntry {
+                key: item.key().clone(),
+                value: SnapshotValue::Hash(fields),
+                expires_at: hash.expires_at().map(to_unix_millis),
+            });
+        }
+
+        write_snapshot(Path::new(&self.config.snapshot.path), &entries).await?;
+        Ok(entries.len())
+    }
+
+    /// 从配置的快照文件加载字符串和哈希表，跳过已过期的键，返回加载的键数量
+    pub async fn load_snapshot(&self) -> CacheResult<usize> {
+        let entries = match read_snapshot(Path::new(&self.config.snapshot.path)).await? {
+            Some(entries) => entries,
+            None => return Ok(0),
+        };
+        let mut loaded = 0;
+        for entry in entries {
+            let expires_at = match entry.expires_at {
+                Some(millis) => match from_unix_millis(millis) {
+                    Some(at) => Some(at),
+                    None => continue,
+                },
+                None => None,
+            };
+            match entry.value {
+                SnapshotValue::String(data) => {
+                    self.insert_entry(&entry.key, data, expires_at).await;
+                }
+                SnapshotValue::Hash(fields) => {
+                    self.cache.invalidate(&entry.key).await;
+                    self.collections.remove(&entry.key);
+                    let hash = HashEntry {
+                        fields: fields.into_iter().collect(),
+                        expires_at: Mutex::new(expires_at),
+                    };
+                    self.hash_cache.insert(entry.key, Arc::new(hash));
+                }
+            }
+            loaded += 1;
+        }
+        Ok(loaded)
+    }
+
+    /// 按配置的间隔在后台定时保存快照，间隔为0时不启动
+    pub fn spawn_snapshot_task(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let interval = self.config.snapshot.interval;
+        if interval == 0 {
+            return None;
         }
+        let cache = self.clone();
+        Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+            // 第一次tick立即返回，跳过
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                if let Err(e) = cache.save_snapshot().await {
+                    warn!("保存本地缓存快照失败: {}", e);
+                }
+            }
+        }))
     }
 }
 
 #[async_trait]
 impl Cache for LocalCache {
     async fn set<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<()> {
-        let serialized = serde_json::to_vec(value)?;
-        self.cache.insert(key.to_string(), serialized).await;
+        let serialized = self.codec.encode(key, value)?;
+        self.insert_entry(key, serialized, None).await;
         Ok(())
     }
 
@@ -115,18 +569,19 @@ impl Cache for LocalCache {
         &self,
         key: &str,
         value: &T,
-        _ttl: Duration,
+        ttl: Duration,
     ) -> CacheResult<()> {
-        let serialized = serde_json::to_vec(value)?;
-        self.cache.insert(key.to_string(), serialized).await;
-        // 注意：moka在缓存项级别不支持单独设置TTL，这里我们使用的是全局配置
-        // 如果需要更精细的控制，需要使用更复杂的实现
+        let serialized = self.codec.encode(key, value)?;
+        self.insert_entry(key, serialized, Some(Instant::now() + ttl))
+            .await;
         Ok(())
     }
 
     async fn get<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>> {
-        if let Some(data) = self.cache.get(&key.to_string()).await {
-            let value = serde_json::from_slice(&data)?;
+        let entry = self.cache.get(key).await;
+        self.stats.record_lookup(key, entry.is_some());
+        if let Some(entry) = entry {
+            let value = self.codec.decode(&entry.data)?;
             Ok(Some(value))
         } else {
             Ok(None)
@@ -134,64 +589,141 @@ impl Cache for LocalCache {
     }
 
     async fn keys(&self, pattern: &str) -> CacheResult<Vec<String>> {
+        self.purge_expired_entries();
         let regex = regex_from_pattern(pattern);
-        let keys: Vec<String> = self
+        let mut keys: Vec<String> = self
             .cache
             .iter()
             .filter(|(k, _)| regex_match(k, &regex))
             .map(|(k, _)| k.clone().to_string())
             .collect();
+        keys.extend(
+            self.hash_cache
+                .iter()
+                .filter(|item| regex_match(item.key(), &regex))
+                .map(|item| item.key().clone()),
+        );
+        keys.extend(
+            self.collections
+                .iter()
+                .filter(|item| regex_match(item.key(), &regex))
+                .map(|item| item.key().clone()),
+        );
         Ok(keys)
     }
 
+    async fn scan(
+        &self,
+        cursor: u64,
+        pattern: &str,
+        count: usize,
+    ) -> CacheResult<(u64, Vec<String>)> {
+        // 本地缓存没有稳定的遍历顺序，按排序后的key列表偏移量作为游标
+        let mut keys = self.keys(pattern).await?;
+        keys.sort();
+        let start = (cursor as usize).min(keys.len());
+        let end = start.saturating_add(count.max(1)).min(keys.len());
+        let next = if end < keys.len() { end as u64 } else { 0 };
+        Ok((next, keys[start..end].to_vec()))
+    }
+
     async fn del(&self, key: &str) -> CacheResult<()> {
-        self.cache.invalidate(&key.to_string()).await;
-        // 同时删除相关的hash结构
-        self.hash_cache.remove(&key.to_string());
+        self.cache.invalidate(key).await;
+        // 同时删除相关的hash结构和集合类数据
+        self.hash_cache.remove(key);
+        self.collections.remove(key);
         Ok(())
     }
 
     async fn exists(&self, key: &str) -> CacheResult<bool> {
-        Ok(self.cache.get(&key.to_string()).await.is_some() || self.hash_cache.contains_key(key))
+        self.remove_expired_collection(key);
+        Ok(self.cache.get(key).await.is_some()
+            || self.get_hash(key).is_some()
+            || self.collections.contains_key(key))
     }
 
-    async fn expire(&self, key: &str, _ttl: Duration) -> CacheResult<()> {
-        // Moka不支持在缓存项级别设置TTL，这里只是简单地检查键是否存在
-        if self.cache.get(&key.to_string()).await.is_some() || self.hash_cache.contains_key(key) {
-            Ok(())
+    async fn expire(&self, key: &str, ttl: Duration) -> CacheResult<()> {
+        // 与Redis一致，过期时间为0时直接删除，键不存在时不做任何处理
+        if ttl.is_zero() {
+            return self.del(key).await;
+        }
+        let expires_at = Some(Instant::now() + ttl);
+        if let Some(entry) = self.cache.get(key).await {
+            self.cache
+                .insert(
+                    key.to_string(),
+                    Arc::new(LocalEntry {
+                        data: entry.data.clone(),
+                        expires_at,
+                    }),
+                )
+                .await;
+        } else if let Some(hash) = self.get_hash(key) {
+            hash.set_expires_at(expires_at);
         } else {
-            Err(CacheError::Other(format!("键 {} 不存在", key)))
+            self.remove_expired_collection(key);
+            if let Some(mut entry) = self.collections.get_mut(key) {
+                entry.expires_at = expires_at;
+            }
         }
+        Ok(())
+    }
+
+    async fn ttl(&self, key: &str) -> CacheResult<i64> {
+        if let Some(entry) = self.cache.get(key).await {
+            Ok(remaining_secs(entry.expires_at))
+        } else if let Some(hash) = self.get_hash(key) {
+            Ok(remaining_secs(hash.expires_at()))
+        } else {
+            self.remove_expired_collection(key);
+            match self.collections.get(key) {
+                Some(entry) => Ok(remaining_secs(entry.expires_at)),
+                None => Ok(-2),
+            }
+        }
+    }
+
+    async fn key_type(&self, key: &str) -> CacheResult<CacheKeyType> {
+        if self.cache.contains_key(key) {
+            return Ok(CacheKeyType::String);
+        }
+        if self.get_hash(key).is_some() {
+            return Ok(CacheKeyType::Hash);
+        }
+        self.remove_expired_collection(key);
+        Ok(self
+            .collections
+            .get(key)
+            .map_or(CacheKeyType::None, |entry| match entry.data {
+                Collection::List(_) => CacheKeyType::List,
+                Collection::Set(_) => CacheKeyType::Set,
+                Collection::SortedSet(_) => CacheKeyType::ZSet,
+            }))
     }
 
     async fn incr(&self, key: &str) -> CacheResult<i64> {
-        let value = if let Some(data) = self.cache.get(&key.to_string()).await {
-            let current: i64 = String::from_utf8_lossy(&data)
-                .parse::<i64>()
-                .map_err(|e| CacheError::Deserialization(e.to_string()))?;
-            current + 1
+        // 与Redis一致，递增不改变键的剩余过期时间
+        let (value, expires_at) = if let Some(entry) = self.cache.get(key).await {
+            let current: i64 = self.codec.decode(&entry.data)?;
+            (current + 1, entry.expires_at)
         } else {
-            1
+            (1, None)
         };
 
-        self.cache
-            .insert(key.to_string(), value.to_string().into_bytes())
+        self.insert_entry(key, value.to_string().into_bytes(), expires_at)
             .await;
         Ok(value)
     }
 
     async fn decr(&self, key: &str) -> CacheResult<i64> {
-        let value = if let Some(data) = self.cache.get(&key.to_string()).await {
-            let current: i64 = String::from_utf8_lossy(&data)
-                .parse::<i64>()
-                .map_err(|e| CacheError::Deserialization(e.to_string()))?;
-            current - 1
+        let (value, expires_at) = if let Some(entry) = self.cache.get(key).await {
+            let current: i64 = self.codec.decode(&entry.data)?;
+            (current - 1, entry.expires_at)
         } else {
-            -1
+            (-1, None)
         };
 
-        self.cache
-            .insert(key.to_string(), value.to_string().into_bytes())
+        self.insert_entry(key, value.to_string().into_bytes(), expires_at)
             .await;
         Ok(value)
     }
@@ -204,13 +736,13 @@ impl Cache for LocalCache {
         field: &str,
         value: &T,
     ) -> CacheResult<()> {
-        let serialized = serde_json::to_vec(value)?;
+        let serialized = self.codec.encode(key, value)?;
 
         // 获取或创建哈希表
-        let hash_map = self.get_or_create_hash(key);
+        let hash = self.get_or_create_hash(key).await;
 
         // 直接插入字段值，无需再次插入moka缓存
-        hash_map.insert(field.to_string(), serialized);
+        hash.fields.insert(field.to_string(), serialized);
 
         Ok(())
     }
@@ -221,23 +753,25 @@ impl Cache for LocalCache {
         field: &str,
     ) -> CacheResult<Option<T>> {
         // 直接从hash_cache中查找，避免获取整个哈希表的开销
-        if let Some(hash_map) = self.hash_cache.get(key) {
-            if let Some(data) = hash_map.get(field) {
-                let value = serde_json::from_slice(data.value())?;
+        if let Some(hash) = self.get_hash(key) {
+            if let Some(data) = hash.fields.get(field) {
+                self.stats.record_lookup(key, true);
+                let value = self.codec.decode(data.value())?;
                 return Ok(Some(value));
             }
         }
 
+        self.stats.record_lookup(key, false);
         Ok(None)
     }
 
     async fn hdel(&self, key: &str, field: &str) -> CacheResult<()> {
-        if let Some(hash_map) = self.hash_cache.get(key) {
-            hash_map.remove(field);
+        if let Some(hash) = self.get_hash(key) {
+            hash.fields.remove(field);
 
             // 如果哈希表为空，删除整个哈希表
-            if hash_map.is_empty() {
-                self.hash_cache.remove(key);
+            if hash.fields.is_empty() {
+                self.hash_cache.remove_if(key, |_, h| h.fields.is_empty());
             }
         }
 
@@ -245,17 +779,17 @@ impl Cache for LocalCache {
     }
 
     async fn hexists(&self, key: &str, field: &str) -> CacheResult<bool> {
-        if let Some(hash_map) = self.hash_cache.get(key) {
-            return Ok(hash_map.contains_key(field));
+        if let Some(hash) = self.get_hash(key) {
+            return Ok(hash.fields.contains_key(field));
         }
 
         Ok(false)
     }
 
     async fn hkeys(&self, key: &str) -> CacheResult<Vec<String>> {
-        if let Some(hash_map) = self.hash_cache.get(key) {
-            let mut keys = Vec::with_capacity(hash_map.len());
-            for item in hash_map.iter() {
+        if let Some(hash) = self.get_hash(key) {
+            let mut keys = Vec::with_capacity(hash.fields.len());
+            for item in hash.fields.iter() {
                 keys.push(item.key().clone());
             }
             return Ok(keys);
@@ -265,19 +799,328 @@ impl Cache for LocalCache {
     }
 
     async fn hlen(&self, key: &str) -> CacheResult<usize> {
-        if let Some(hash_map) = self.hash_cache.get(key) {
-            return Ok(hash_map.len());
+        if let Some(hash) = self.get_hash(key) {
+            return Ok(hash.fields.len());
         }
 
         Ok(0)
     }
 
-    async fn info(&self, _key: Option<String>) -> CacheResult<String> {
-        Ok("".to_string())
+    async fn hgetall<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+    ) -> CacheResult<Vec<(String, T)>> {
+        let Some(hash) = self.get_hash(key) else {
+            return Ok(Vec::new());
+        };
+        let mut fields = Vec::with_capacity(hash.fields.len());
+        for item in hash.fields.iter() {
+            fields.push((item.key().clone(), self.codec.decode(item.value())?));
+        }
+        fields.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(fields)
+    }
+
+    async fn lpush<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<usize> {
+        let serialized = serde_json::to_vec(value)?;
+        self.write_collection(key, Collection::new_list, |data| match data {
+            Collection::List(list) => {
+                list.push_front(serialized);
+                Ok(list.len())
+            }
+            _ => Err(wrong_type(key)),
+        })
+    }
+
+    async fn rpush<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<usize> {
+        let serialized = serde_json::to_vec(value)?;
+        self.write_collection(key, Collection::new_list, |data| match data {
+            Collection::List(list) => {
+                list.push_back(serialized);
+                Ok(list.len())
+            }
+            _ => Err(wrong_type(key)),
+        })
+    }
+
+    async fn lpop<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>> {
+        let data = self.write_collection(key, Collection::new_list, |data| match data {
+            Collection::List(list) => Ok(list.pop_front()),
+            _ => Err(wrong_type(key)),
+        })?;
+        match data {
+            Some(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn rpop<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>> {
+        let data = self.write_collection(key, Collection::new_list, |data| match data {
+            Collection::List(list) => Ok(list.pop_back()),
+            _ => Err(wrong_type(key)),
+        })?;
+        match data {
+            Some(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn lrange<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> CacheResult<Vec<T>> {
+        let items = self
+            .read_collection(key, |data| match data {
+                Collection::List(list) => Ok(match list_range(start, stop, list.len()) {
+                    Some((start, stop)) => list.range(start..=stop).cloned().collect(),
+                    None => Vec::new(),
+                }),
+                _ => Err(wrong_type(key)),
+            })?
+            .unwrap_or_default();
+        items
+            .iter()
+            .map(|data| serde_json::from_slice(data).map_err(CacheError::from))
+            .collect()
+    }
+
+    async fn ltrim(&self, key: &str, start: i64, stop: i64) -> CacheResult<()> {
+        self.write_collection(key, Collection::new_list, |data| match data {
+            Collection::List(list) => {
+                match list_range(start, stop, list.len()) {
+                    Some((start, stop)) => {
+                        list.truncate(stop + 1);
+                        list.drain(..start);
+                    }
+                    None => list.clear(),
+                }
+                Ok(())
+            }
+            _ => Err(wrong_type(key)),
+        })
+    }
+
+    async fn llen(&self, key: &str) -> CacheResult<usize> {
+        Ok(self
+            .read_collection(key, |data| match data {
+                Collection::List(list) => Ok(list.len()),
+                _ => Err(wrong_type(key)),
+            })?
+            .unwrap_or(0))
+    }
+
+    async fn sadd<T: Serialize + Send + Sync>(&self, key: &str, member: &T) -> CacheResult<bool> {
+        let serialized = serde_json::to_vec(member)?;
+        self.write_collection(key, Collection::new_set, |data| match data {
+            Collection::Set(set) => Ok(set.insert(serialized)),
+            _ => Err(wrong_type(key)),
+        })
+    }
+
+    async fn srem<T: Serialize + Send + Sync>(&self, key: &str, member: &T) -> CacheResult<bool> {
+        let serialized = serde_json::to_vec(member)?;
+        self.write_collection(key, Collection::new_set, |data| match data {
+            Collection::Set(set) => Ok(set.remove(&serialized)),
+            _ => Err(wrong_type(key)),
+        })
+    }
+
+    async fn smembers<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Vec<T>> {
+        let members = self
+            .read_collection(key, |data| match data {
+                Collection::Set(set) => Ok(set.iter().cloned().collect::<Vec<_>>()),
+                _ => Err(wrong_type(key)),
+            })?
+            .unwrap_or_default();
+        members
+            .iter()
+            .map(|data| serde_json::from_slice(data).map_err(CacheError::from))
+            .collect()
+    }
+
+    async fn sismember<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+    ) -> CacheResult<bool> {
+        let serialized = serde_json::to_vec(member)?;
+        Ok(self
+            .read_collection(key, |data| match data {
+                Collection::Set(set) => Ok(set.contains(&serialized)),
+                _ => Err(wrong_type(key)),
+            })?
+            .unwrap_or(false))
+    }
+
+    async fn zadd<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+        score: f64,
+    ) -> CacheResult<bool> {
+        let serialized = serde_json::to_vec(member)?;
+        let score = check_score(score)?;
+        self.write_collection(key, Collection::new_sorted_set, |data| match data {
+            Collection::SortedSet(members) => Ok(members.insert(serialized, score).is_none()),
+            _ => Err(wrong_type(key)),
+        })
+    }
+
+    async fn zrange_by_score<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> CacheResult<Vec<(T, f64)>> {
+        let members = self
+            .read_collection(key, |data| match data {
+                Collection::SortedSet(members) => Ok(sorted_members(members)
+                    .into_iter()
+                    .filter(|(_, score)| *score >= min && *score <= max)
+                    .map(|(member, score)| (member.clone(), score))
+                    .collect::<Vec<_>>()),
+                _ => Err(wrong_type(key)),
+            })?
+            .unwrap_or_default();
+        members
+            .iter()
+            .map(|(data, score)| Ok((serde_json::from_slice(data)?, *score)))
+            .collect()
+    }
+
+    async fn zincr_by<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+        delta: f64,
+    ) -> CacheResult<f64> {
+        let serialized = serde_json::to_vec(member)?;
+        check_score(delta)?;
+        self.write_collection(key, Collection::new_sorted_set, |data| match data {
+            Collection::SortedSet(members) => {
+                let score = members.entry(serialized).or_insert(0.0);
+                let updated = check_score(*score + delta)?;
+                *score = updated;
+                Ok(updated)
+            }
+            _ => Err(wrong_type(key)),
+        })
+    }
+
+    async fn zrank<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+    ) -> CacheResult<Option<usize>> {
+        let serialized = serde_json::to_vec(member)?;
+        Ok(self
+            .read_collection(key, |data| match data {
+                Collection::SortedSet(members) => Ok(sorted_members(members)
+                    .iter()
+                    .position(|(m, _)| **m == serialized)),
+                _ => Err(wrong_type(key)),
+            })?
+            .flatten())
+    }
+
+    async fn try_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool> {
+        self.cleanup_expired_entries();
+        let now = Instant::now();
+        let expires_at = now + lease;
+        match self.locks.entry(key.to_string()) {
+            Entry::Occupied(mut entry) => {
+                // 租期已到的锁视为不存在
+                if entry.get().expires_at > now {
+                    return Ok(false);
+                }
+                entry.insert(LockEntry {
+                    token: token.to_string(),
+                    expires_at,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(LockEntry {
+                    token: token.to_string(),
+                    expires_at,
+                });
+            }
+        }
+        Ok(true)
+    }
+
+    async fn unlock(&self, key: &str, token: &str) -> CacheResult<bool> {
+        Ok(self
+            .locks
+            .remove_if(key, |_, entry| entry.is_held_by(token, Instant::now()))
+            .is_some())
+    }
+
+    async fn renew_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool> {
+        let now = Instant::now();
+        match self.locks.get_mut(key) {
+            Some(mut entry) if entry.is_held_by(token, now) => {
+                entry.expires_at = now + lease;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn info(&self, key: Option<String>) -> CacheResult<String> {
+        // 按Redis INFO命令的格式输出，便于监控页面统一解析
+        let (keys, expires) = self.count_keys();
+        let stats = self.stats.snapshot();
+        let hits: u64 = stats.iter().map(|s| s.hits).sum();
+        let misses: u64 = stats.iter().map(|s| s.misses).sum();
+        let evictions: u64 = stats.iter().map(|s| s.evictions).sum();
+        let sections = [
+            (
+                "server",
+                format!(
+                    "# Server\r\nredis_mode:local\r\ncache_name:{}\r\n",
+                    self.config.name
+                ),
+            ),
+            (
+                "memory",
+                format!("# Memory\r\nmax_capacity:{}\r\n", self.config.max_capacity),
+            ),
+            (
+                "stats",
+                format!(
+                    "# Stats\r\nkeyspace_hits:{}\r\nkeyspace_misses:{}\r\nevicted_keys:{}\r\n",
+                    hits, misses, evictions
+                ),
+            ),
+            (
+                "keyspace",
+                format!("# Keyspace\r\ndb0:keys={},expires={}\r\n", keys, expires),
+            ),
+        ];
+        Ok(sections
+            .iter()
+            .filter(|(name, _)| match &key {
+                Some(section) => section.eq_ignore_ascii_case(name),
+                None => true,
+            })
+            .map(|(_, section)| section.as_str())
+            .collect::<Vec<_>>()
+            .join("\r\n"))
     }
 
     async fn dbsize(&self) -> CacheResult<usize> {
-        Ok(0)
+        let (keys, _) = self.count_keys();
+        Ok(keys)
+    }
+
+    fn stats(&self) -> Vec<NamespaceStats> {
+        self.stats.snapshot()
+    }
+
+    fn record_load(&self, key: &str, elapsed: Duration) {
+        self.stats.record_load(key, elapsed);
     }
 }
 
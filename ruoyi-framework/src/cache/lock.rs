@@ -0,0 +1,329 @@
+//! 分布式锁模块
+//!
+//! 基于全局缓存实现跨实例的互斥锁：
+//! 1. Redis：使用 SET NX PX 加锁，释放和续期时通过Lua脚本校验持有者令牌
+//! 2. 本地缓存：使用等价的进程内锁，只能保证单个实例内互斥
+//!
+//! 锁在租期到期后自动失效，避免持有者崩溃后锁无法释放；执行时间可能超过租期的任务
+//! 可以开启自动续期。`LockGuard` 被丢弃时自动释放锁。
+
+use log::{debug, warn};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Instant};
+use uuid::Uuid;
+
+use super::{get_global_cache, CacheBase, CacheResult};
+
+/// 锁的键前缀
+pub const LOCK_PREFIX: &str = "lock:";
+
+/// 默认租期
+const DEFAULT_LEASE: Duration = Duration::from_secs(30);
+/// 默认等待时间
+const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+/// 默认重试间隔
+const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 分布式锁
+///
+/// ```ignore
+/// let lock = DistributedLock::global("refresh_config_cache")?
+///     .lease(Duration::from_secs(60))
+///     .wait_timeout(Duration::from_secs(5));
+/// if let Some(guard) = lock.acquire().await? {
+///     // 临界区
+///     guard.release().await?;
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DistributedLock {
+    cache: Arc<dyn CacheBase>,
+    key: String,
+    lease: Duration,
+    wait_timeout: Duration,
+    retry_interval: Duration,
+    auto_renew: bool,
+}
+
+impl DistributedLock {
+    /// 在指定缓存上创建锁，name为锁的名称，实际的键为 `lock:{name}`
+    pub fn new(cache: Arc<dyn CacheBase>, name: &str) -> Self {
+        Self {
+            cache,
+            key: format!("{}{}", LOCK_PREFIX, name),
+            lease: DEFAULT_LEASE,
+            wait_timeout: DEFAULT_WAIT_TIMEOUT,
+            retry_interval: DEFAULT_RETRY_INTERVAL,
+            auto_renew: false,
+        }
+    }
+
+    /// 在全局缓存上创建锁
+    pub fn global(name: &str) -> CacheResult<Self> {
+        Ok(Self::new(get_global_cache()?, name))
+    }
+
+    /// 设置租期，超过租期未释放或续期的锁自动失效
+    pub fn lease(mut self, lease: Duration) -> Self {
+        self.lease = lease;
+        self
+    }
+
+    /// 设置获取锁的最长等待时间
+    pub fn wait_timeout(mut self, wait_timeout: Duration) -> Self {
+        self.wait_timeout = wait_timeout;
+        self
+    }
+
+    /// 设置等待期间重试加锁的间隔
+    pub fn retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+
+    /// 设置是否在持有期间每隔三分之一租期自动续期
+    pub fn auto_renew(mut self, auto_renew: bool) -> Self {
+        self.auto_renew = auto_renew;
+        self
+    }
+
+    /// 锁的键
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 尝试加锁一次，锁被其他持有者占用时立即返回None
+    pub async fn try_acquire(&self) -> CacheResult<Option<LockGuard>> {
+        let token = Uuid::new_v4().to_string();
+        if !self.cache.try_lock(&self.key, &token, self.lease).await? {
+            return Ok(None);
+        }
+        debug!("获取锁成功: {}", self.key);
+        Ok(Some(LockGuard::new(self, token)))
+    }
+
+    /// 获取锁，在等待时间内按重试间隔反复尝试，超时返回None
+    pub async fn acquire(&self) -> CacheResult<Option<LockGuard>> {
+        let deadline = Instant::now() + self.wait_timeout;
+        loop {
+            if let Some(guard) = self.try_acquire().await? {
+                return Ok(Some(guard));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                debug!("获取锁超时: {}", self.key);
+                return Ok(None);
+            }
+            sleep(self.retry_interval.min(deadline - now)).await;
+        }
+    }
+}
+
+/// 已获取的锁，被丢弃时自动释放
+pub struct LockGuard {
+    cache: Arc<dyn CacheBase>,
+    key: String,
+    token: String,
+    lease: Duration,
+    released: bool,
+    renew_task: Option<JoinHandle<()>>,
+}
+
+impl LockGuard {
+    fn new(lock: &DistributedLock, token: String) -> Self {
+        let renew_task = lock.auto_renew.then(|| {
+            tokio::spawn(renew_loop(
+                lock.cache.clone(),
+                lock.key.clone(),
+                token.clone(),
+                lock.lease,
+            ))
+        });
+        Self {
+            cache: lock.cache.clone(),
+            key: lock.key.clone(),
+            token,
+            lease: lock.lease,
+            released: false,
+            renew_task,
+        }
+    }
+
+    /// 锁的键
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 持有者令牌
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// 将租期重置为获取锁时设置的租期，返回false表示锁已失效或被其他持有者获取
+    pub async fn renew(&self) -> CacheResult<bool> {
+        self.cache
+            .renew_lock(&self.key, &self.token, self.lease)
+            .await
+    }
+
+    /// 释放锁，返回false表示锁在释放前已失效
+    pub async fn release(mut self) -> CacheResult<bool> {
+        self.stop_renew();
+        self.released = true;
+        self.cache.unlock(&self.key, &self.token).await
+    }
+
+    fn stop_renew(&mut self) {
+        if let Some(task) = self.renew_task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        self.stop_renew();
+        if self.released {
+            return;
+        }
+        // Drop中无法等待异步操作，交给运行时在后台释放；没有运行时则等待租期到期
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                let cache = self.cache.clone();
+                let key = std::mem::take(&mut self.key);
+                let token = std::mem::take(&mut self.token);
+                handle.spawn(async move {
+                    if let Err(e) = cache.unlock(&key, &token).await {
+                        warn!("释放锁失败: {}, {}", key, e);
+                    }
+                });
+            }
+            Err(_) => warn!("没有可用的运行时，锁将在租期到期后失效: {}", self.key),
+        }
+    }
+}
+
+/// 每隔三分之一租期续期一次，锁失效后停止
+async fn renew_loop(cache: Arc<dyn CacheBase>, key: String, token: String, lease: Duration) {
+    let interval = (lease / 3).max(Duration::from_millis(1));
+    loop {
+        sleep(interval).await;
+        match cache.renew_lock(&key, &token, lease).await {
+            Ok(true) => {}
+            Ok(false) => {
+                warn!("锁已失效，停止续期: {}", key);
+                return;
+            }
+            Err(e) => warn!("锁续期失败: {}, {}", key, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::{CacheAdapter, LocalCache, LocalCacheConfig};
+
+    fn local_cache() -> Arc<dyn CacheBase> {
+        Arc::new(CacheAdapter::new(LocalCache::new(Arc::new(
+            LocalCacheConfig::default(),
+        ))))
+    }
+
+    #[tokio::test]
+    async fn test_acquire_and_release() {
+        let cache = local_cache();
+        let lock = DistributedLock::new(cache.clone(), "job").wait_timeout(Duration::ZERO);
+
+        let guard = lock.acquire().await.unwrap().expect("应获取到锁");
+        assert_eq!(guard.key(), "lock:job");
+        assert!(lock.try_acquire().await.unwrap().is_none());
+
+        assert!(guard.release().await.unwrap());
+        assert!(lock.try_acquire().await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_acquire_timeout() {
+        let cache = local_cache();
+        let lock = DistributedLock::new(cache, "job")
+            .wait_timeout(Duration::from_millis(200))
+            .retry_interval(Duration::from_millis(20));
+        let _guard = lock.try_acquire().await.unwrap().unwrap();
+
+        let start = Instant::now();
+        assert!(lock.acquire().await.unwrap().is_none());
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_release() {
+        let cache = local_cache();
+        let lock = DistributedLock::new(cache, "job").retry_interval(Duration::from_millis(10));
+        let guard = lock.try_acquire().await.unwrap().unwrap();
+
+        let waiter = {
+            let lock = lock.clone();
+            tokio::spawn(async move { lock.acquire().await.unwrap().is_some() })
+        };
+        sleep(Duration::from_millis(50)).await;
+        guard.release().await.unwrap();
+        assert!(waiter.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_drop_releases() {
+        let cache = local_cache();
+        let lock = DistributedLock::new(cache, "job");
+        {
+            let _guard = lock.try_acquire().await.unwrap().unwrap();
+        }
+        // 释放在后台任务中执行
+        sleep(Duration::from_millis(50)).await;
+        assert!(lock.try_acquire().await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_lease_expiry_and_renew() {
+        let cache = local_cache();
+        let lock = DistributedLock::new(cache.clone(), "job").lease(Duration::from_millis(150));
+
+        let guard = lock.try_acquire().await.unwrap().unwrap();
+        sleep(Duration::from_millis(100)).await;
+        assert!(guard.renew().await.unwrap());
+        sleep(Duration::from_millis(100)).await;
+        assert!(
+            lock.try_acquire().await.unwrap().is_none(),
+            "续期后锁仍然有效"
+        );
+
+        sleep(Duration::from_millis(100)).await;
+        let other = lock
+            .try_acquire()
+            .await
+            .unwrap()
+            .expect("租期到期后可以重新加锁");
+        // 过期的持有者不能续期或释放新持有者的锁
+        assert!(!guard.renew().await.unwrap());
+        assert!(!guard.release().await.unwrap());
+        assert!(other.release().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_auto_renew() {
+        let cache = local_cache();
+        let lock = DistributedLock::new(cache, "job")
+            .lease(Duration::from_millis(90))
+            .auto_renew(true);
+
+        let guard = lock.try_acquire().await.unwrap().unwrap();
+        sleep(Duration::from_millis(300)).await;
+        assert!(
+            lock.try_acquire().await.unwrap().is_none(),
+            "自动续期后锁仍然有效"
+        );
+        assert!(guard.release().await.unwrap());
+    }
+}
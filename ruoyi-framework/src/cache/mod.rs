@@ -2,13 +2,22 @@
 //!
 //! 提供统一的缓存访问接口，支持本地缓存和分布式缓存
 
+mod circuit_breaker;
+mod codec;
 mod error;
 mod examples;
 pub mod global_cache;
 mod local_cache;
+mod lock;
 mod multi_level_cache;
 mod redis_cache;
+mod sentinel;
+mod snapshot;
+mod stats;
+mod tests;
 
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+pub use codec::{CodecConfig, CodecFormat, CodecRule, ValueCodec};
 pub use error::{CacheError, CacheResult};
 pub use examples::{
     cache_admin_example, global_cache_example, hash_performance_test, local_cache_example,
@@ -17,14 +26,56 @@ pub use examples::{
 pub use global_cache::reset_global_cache;
 pub use global_cache::{
     get_global_cache, init_global_cache, init_global_cache_async, is_global_cache_initialized,
+    shutdown_global_cache,
 };
 pub use local_cache::{LocalCache, LocalCacheConfig, LocalCacheManager};
+pub use lock::{DistributedLock, LockGuard, LOCK_PREFIX};
 pub use multi_level_cache::{MultiLevelCache, MultiLevelCacheConfig, MultiLevelCacheManager};
 pub use redis_cache::{RedisCache, RedisCacheManager, RedisConfig, RedisConnectionType};
+pub use sentinel::RedisSentinel;
+pub use snapshot::SnapshotConfig;
+pub use stats::{CacheStats, NamespaceStats, DEFAULT_NAMESPACE};
 
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Serialize};
 use std::time::Duration;
+use utoipa::ToSchema;
+
+/// 每次增量扫描时建议Redis返回的key数量
+pub const SCAN_BATCH_SIZE: usize = 1000;
+
+/// 键存储的数据类型，与Redis的TYPE命令一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheKeyType {
+    /// 键不存在
+    None,
+    /// 字符串
+    String,
+    /// 哈希表
+    Hash,
+    /// 列表
+    List,
+    /// 集合
+    Set,
+    /// 有序集合
+    #[serde(rename = "zset")]
+    ZSet,
+}
+
+impl CacheKeyType {
+    /// 按Redis TYPE命令的返回值解析
+    pub fn from_redis(name: &str) -> Self {
+        match name {
+            "string" => CacheKeyType::String,
+            "hash" => CacheKeyType::Hash,
+            "list" => CacheKeyType::List,
+            "set" => CacheKeyType::Set,
+            "zset" => CacheKeyType::ZSet,
+            _ => CacheKeyType::None,
+        }
+    }
+}
 
 /// 对象安全的缓存接口，专用于字符串和基本类型操作
 #[async_trait]
@@ -38,15 +89,47 @@ pub trait CacheBase: Send + Sync + 'static {
     /// 获取字符串缓存
     async fn get_string(&self, key: &str) -> CacheResult<Option<String>>;
 
+    /// 以JSON值读取缓存，用于展示任意类型的值
+    async fn get_json(&self, key: &str) -> CacheResult<Option<serde_json::Value>>;
+
+    /// 以JSON值写入缓存
+    async fn set_json(&self, key: &str, value: &serde_json::Value) -> CacheResult<()>;
+
     /// 设置整数缓存
     async fn set_int(&self, key: &str, value: i64) -> CacheResult<()>;
 
     /// 获取整数缓存
     async fn get_int(&self, key: &str) -> CacheResult<Option<i64>>;
 
-    /// 获取缓存key集合 
+    /// 获取缓存key集合
     async fn keys(&self, pattern: &str) -> CacheResult<Vec<String>>;
 
+    /// 增量扫描缓存key，返回下一次扫描的游标和本次扫描到的key，游标为0表示扫描结束
+    async fn scan(
+        &self,
+        cursor: u64,
+        pattern: &str,
+        count: usize,
+    ) -> CacheResult<(u64, Vec<String>)>;
+
+    /// 按模式扫描全部缓存key，最多返回limit个，结果已排序去重
+    async fn scan_keys(&self, pattern: &str, limit: usize) -> CacheResult<Vec<String>> {
+        let mut cursor = 0;
+        let mut keys = Vec::new();
+        loop {
+            let (next, batch) = self.scan(cursor, pattern, SCAN_BATCH_SIZE).await?;
+            keys.extend(batch);
+            if next == 0 || keys.len() >= limit {
+                break;
+            }
+            cursor = next;
+        }
+        keys.sort();
+        keys.dedup();
+        keys.truncate(limit);
+        Ok(keys)
+    }
+
     /// 删除缓存
     async fn del(&self, key: &str) -> CacheResult<()>;
 
@@ -56,6 +139,12 @@ pub trait CacheBase: Send + Sync + 'static {
     /// 设置过期时间
     async fn expire(&self, key: &str, ttl: Duration) -> CacheResult<()>;
 
+    /// 获取剩余过期时间（秒），与Redis的TTL命令一致：-1 表示永不过期，-2 表示键不存在
+    async fn ttl(&self, key: &str) -> CacheResult<i64>;
+
+    /// 获取键存储的数据类型
+    async fn key_type(&self, key: &str) -> CacheResult<CacheKeyType>;
+
     /// 递增操作
     async fn incr(&self, key: &str) -> CacheResult<i64>;
 
@@ -86,11 +175,87 @@ pub trait CacheBase: Send + Sync + 'static {
     /// 获取哈希表中字段数量
     async fn hlen(&self, key: &str) -> CacheResult<usize>;
 
+    /// 以JSON值获取哈希表所有字段，按字段名排序
+    async fn hget_all_json(&self, key: &str) -> CacheResult<Vec<(String, serde_json::Value)>>;
+
+    /// 以JSON值设置哈希表字段
+    async fn hset_json(
+        &self,
+        key: &str,
+        field: &str,
+        value: &serde_json::Value,
+    ) -> CacheResult<()>;
+
+    /// 从列表头部插入字符串，返回插入后的列表长度
+    async fn lpush_string(&self, key: &str, value: &str) -> CacheResult<usize>;
+
+    /// 从列表尾部插入字符串，返回插入后的列表长度
+    async fn rpush_string(&self, key: &str, value: &str) -> CacheResult<usize>;
+
+    /// 弹出列表头部的字符串
+    async fn lpop_string(&self, key: &str) -> CacheResult<Option<String>>;
+
+    /// 弹出列表尾部的字符串
+    async fn rpop_string(&self, key: &str) -> CacheResult<Option<String>>;
+
+    /// 获取列表指定区间的字符串，下标规则与Redis的LRANGE一致，负数表示从尾部倒数
+    async fn lrange_string(&self, key: &str, start: i64, stop: i64) -> CacheResult<Vec<String>>;
+
+    /// 只保留列表指定区间内的元素
+    async fn ltrim(&self, key: &str, start: i64, stop: i64) -> CacheResult<()>;
+
+    /// 获取列表长度
+    async fn llen(&self, key: &str) -> CacheResult<usize>;
+
+    /// 向集合添加字符串，返回是否为新添加的成员
+    async fn sadd_string(&self, key: &str, member: &str) -> CacheResult<bool>;
+
+    /// 从集合删除字符串，返回成员是否存在
+    async fn srem_string(&self, key: &str, member: &str) -> CacheResult<bool>;
+
+    /// 获取集合所有成员
+    async fn smembers_string(&self, key: &str) -> CacheResult<Vec<String>>;
+
+    /// 判断字符串是否为集合成员
+    async fn sismember_string(&self, key: &str, member: &str) -> CacheResult<bool>;
+
+    /// 向有序集合添加成员或更新分数，返回是否为新添加的成员
+    async fn zadd_string(&self, key: &str, member: &str, score: f64) -> CacheResult<bool>;
+
+    /// 按分数区间获取有序集合成员及分数，按分数从小到大排列
+    async fn zrange_by_score_string(
+        &self,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> CacheResult<Vec<(String, f64)>>;
+
+    /// 增加有序集合成员的分数，返回增加后的分数
+    async fn zincr_by_string(&self, key: &str, member: &str, delta: f64) -> CacheResult<f64>;
+
+    /// 获取有序集合成员按分数从小到大的排名（从0开始）
+    async fn zrank_string(&self, key: &str, member: &str) -> CacheResult<Option<usize>>;
+
+    /// 尝试加锁，键不存在时写入持有者令牌并设置租期，返回是否加锁成功
+    async fn try_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool>;
+
+    /// 释放锁，仅当锁仍由该令牌持有时删除，返回是否释放成功
+    async fn unlock(&self, key: &str, token: &str) -> CacheResult<bool>;
+
+    /// 续期锁，仅当锁仍由该令牌持有时重置租期，返回是否续期成功
+    async fn renew_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool>;
+
     /// 获取Redis信息
     async fn info(&self, key: Option<String>) -> CacheResult<String>;
 
     /// 获取Redis数据库大小
     async fn dbsize(&self) -> CacheResult<usize>;
+
+    /// 获取按键前缀统计的命中、未命中、加载耗时和淘汰次数
+    fn stats(&self) -> Vec<NamespaceStats>;
+
+    /// 记录缓存未命中后从数据源加载数据的耗时
+    fn record_load(&self, key: &str, elapsed: Duration);
 }
 
 /// 通用缓存接口
@@ -110,9 +275,17 @@ pub trait Cache: Send + Sync + 'static {
     /// 获取缓存
     async fn get<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>>;
 
-    /// 获取缓存key集合 
+    /// 获取缓存key集合
     async fn keys(&self, pattern: &str) -> CacheResult<Vec<String>>;
 
+    /// 增量扫描缓存key，返回下一次扫描的游标和本次扫描到的key，游标为0表示扫描结束
+    async fn scan(
+        &self,
+        cursor: u64,
+        pattern: &str,
+        count: usize,
+    ) -> CacheResult<(u64, Vec<String>)>;
+
     /// 删除缓存
     async fn del(&self, key: &str) -> CacheResult<()>;
 
@@ -122,6 +295,12 @@ pub trait Cache: Send + Sync + 'static {
     /// 设置过期时间
     async fn expire(&self, key: &str, ttl: Duration) -> CacheResult<()>;
 
+    /// 获取剩余过期时间（秒），与Redis的TTL命令一致：-1 表示永不过期，-2 表示键不存在
+    async fn ttl(&self, key: &str) -> CacheResult<i64>;
+
+    /// 获取键存储的数据类型
+    async fn key_type(&self, key: &str) -> CacheResult<CacheKeyType>;
+
     /// 递增操作
     async fn incr(&self, key: &str) -> CacheResult<i64>;
 
@@ -155,11 +334,105 @@ pub trait Cache: Send + Sync + 'static {
     /// 获取哈希表中字段数量
     async fn hlen(&self, key: &str) -> CacheResult<usize>;
 
+    /// 获取哈希表所有字段及其值，按字段名排序
+    async fn hgetall<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+    ) -> CacheResult<Vec<(String, T)>>;
+
+    /// 从列表头部插入元素，返回插入后的列表长度
+    async fn lpush<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<usize>;
+
+    /// 从列表尾部插入元素，返回插入后的列表长度
+    async fn rpush<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<usize>;
+
+    /// 弹出列表头部的元素
+    async fn lpop<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>>;
+
+    /// 弹出列表尾部的元素
+    async fn rpop<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>>;
+
+    /// 获取列表指定区间的元素，下标规则与Redis的LRANGE一致，负数表示从尾部倒数
+    async fn lrange<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> CacheResult<Vec<T>>;
+
+    /// 只保留列表指定区间内的元素
+    async fn ltrim(&self, key: &str, start: i64, stop: i64) -> CacheResult<()>;
+
+    /// 获取列表长度
+    async fn llen(&self, key: &str) -> CacheResult<usize>;
+
+    /// 向集合添加成员，返回是否为新添加的成员
+    async fn sadd<T: Serialize + Send + Sync>(&self, key: &str, member: &T) -> CacheResult<bool>;
+
+    /// 从集合删除成员，返回成员是否存在
+    async fn srem<T: Serialize + Send + Sync>(&self, key: &str, member: &T) -> CacheResult<bool>;
+
+    /// 获取集合所有成员
+    async fn smembers<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Vec<T>>;
+
+    /// 判断是否为集合成员
+    async fn sismember<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+    ) -> CacheResult<bool>;
+
+    /// 向有序集合添加成员或更新分数，返回是否为新添加的成员
+    async fn zadd<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+        score: f64,
+    ) -> CacheResult<bool>;
+
+    /// 按分数区间获取有序集合成员及分数，按分数从小到大排列
+    async fn zrange_by_score<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> CacheResult<Vec<(T, f64)>>;
+
+    /// 增加有序集合成员的分数，返回增加后的分数
+    async fn zincr_by<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+        delta: f64,
+    ) -> CacheResult<f64>;
+
+    /// 获取有序集合成员按分数从小到大的排名（从0开始）
+    async fn zrank<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+    ) -> CacheResult<Option<usize>>;
+
+    /// 尝试加锁，键不存在时写入持有者令牌并设置租期，返回是否加锁成功
+    async fn try_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool>;
+
+    /// 释放锁，仅当锁仍由该令牌持有时删除，返回是否释放成功
+    async fn unlock(&self, key: &str, token: &str) -> CacheResult<bool>;
+
+    /// 续期锁，仅当锁仍由该令牌持有时重置租期，返回是否续期成功
+    async fn renew_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool>;
+
     /// 获取Redis信息
     async fn info(&self, key: Option<String>) -> CacheResult<String>;
 
     /// 获取Redis数据库大小
     async fn dbsize(&self) -> CacheResult<usize>;
+
+    /// 获取按键前缀统计的命中、未命中、加载耗时和淘汰次数
+    fn stats(&self) -> Vec<NamespaceStats>;
+
+    /// 记录缓存未命中后从数据源加载数据的耗时
+    fn record_load(&self, key: &str, elapsed: Duration);
 }
 
 /// 缓存管理器接口
@@ -198,6 +471,14 @@ impl<T: Cache> CacheBase for CacheAdapter<T> {
         self.inner.get(key).await
     }
 
+    async fn get_json(&self, key: &str) -> CacheResult<Option<serde_json::Value>> {
+        self.inner.get(key).await
+    }
+
+    async fn set_json(&self, key: &str, value: &serde_json::Value) -> CacheResult<()> {
+        self.inner.set(key, value).await
+    }
+
     async fn set_int(&self, key: &str, value: i64) -> CacheResult<()> {
         self.inner.set(key, &value).await
     }
@@ -210,6 +491,15 @@ impl<T: Cache> CacheBase for CacheAdapter<T> {
         self.inner.keys(pattern).await
     }
 
+    async fn scan(
+        &self,
+        cursor: u64,
+        pattern: &str,
+        count: usize,
+    ) -> CacheResult<(u64, Vec<String>)> {
+        self.inner.scan(cursor, pattern, count).await
+    }
+
     async fn del(&self, key: &str) -> CacheResult<()> {
         self.inner.del(key).await
     }
@@ -222,6 +512,14 @@ impl<T: Cache> CacheBase for CacheAdapter<T> {
         self.inner.expire(key, ttl).await
     }
 
+    async fn ttl(&self, key: &str) -> CacheResult<i64> {
+        self.inner.ttl(key).await
+    }
+
+    async fn key_type(&self, key: &str) -> CacheResult<CacheKeyType> {
+        self.inner.key_type(key).await
+    }
+
     async fn incr(&self, key: &str) -> CacheResult<i64> {
         self.inner.incr(key).await
     }
@@ -262,6 +560,96 @@ impl<T: Cache> CacheBase for CacheAdapter<T> {
         self.inner.hlen(key).await
     }
 
+    async fn hget_all_json(&self, key: &str) -> CacheResult<Vec<(String, serde_json::Value)>> {
+        self.inner.hgetall(key).await
+    }
+
+    async fn hset_json(
+        &self,
+        key: &str,
+        field: &str,
+        value: &serde_json::Value,
+    ) -> CacheResult<()> {
+        self.inner.hset(key, field, value).await
+    }
+
+    async fn lpush_string(&self, key: &str, value: &str) -> CacheResult<usize> {
+        self.inner.lpush(key, &value.to_string()).await
+    }
+
+    async fn rpush_string(&self, key: &str, value: &str) -> CacheResult<usize> {
+        self.inner.rpush(key, &value.to_string()).await
+    }
+
+    async fn lpop_string(&self, key: &str) -> CacheResult<Option<String>> {
+        self.inner.lpop(key).await
+    }
+
+    async fn rpop_string(&self, key: &str) -> CacheResult<Option<String>> {
+        self.inner.rpop(key).await
+    }
+
+    async fn lrange_string(&self, key: &str, start: i64, stop: i64) -> CacheResult<Vec<String>> {
+        self.inner.lrange(key, start, stop).await
+    }
+
+    async fn ltrim(&self, key: &str, start: i64, stop: i64) -> CacheResult<()> {
+        self.inner.ltrim(key, start, stop).await
+    }
+
+    async fn llen(&self, key: &str) -> CacheResult<usize> {
+        self.inner.llen(key).await
+    }
+
+    async fn sadd_string(&self, key: &str, member: &str) -> CacheResult<bool> {
+        self.inner.sadd(key, &member.to_string()).await
+    }
+
+    async fn srem_string(&self, key: &str, member: &str) -> CacheResult<bool> {
+        self.inner.srem(key, &member.to_string()).await
+    }
+
+    async fn smembers_string(&self, key: &str) -> CacheResult<Vec<String>> {
+        self.inner.smembers(key).await
+    }
+
+    async fn sismember_string(&self, key: &str, member: &str) -> CacheResult<bool> {
+        self.inner.sismember(key, &member.to_string()).await
+    }
+
+    async fn zadd_string(&self, key: &str, member: &str, score: f64) -> CacheResult<bool> {
+        self.inner.zadd(key, &member.to_string(), score).await
+    }
+
+    async fn zrange_by_score_string(
+        &self,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> CacheResult<Vec<(String, f64)>> {
+        self.inner.zrange_by_score(key, min, max).await
+    }
+
+    async fn zincr_by_string(&self, key: &str, member: &str, delta: f64) -> CacheResult<f64> {
+        self.inner.zincr_by(key, &member.to_string(), delta).await
+    }
+
+    async fn zrank_string(&self, key: &str, member: &str) -> CacheResult<Option<usize>> {
+        self.inner.zrank(key, &member.to_string()).await
+    }
+
+    async fn try_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool> {
+        self.inner.try_lock(key, token, lease).await
+    }
+
+    async fn unlock(&self, key: &str, token: &str) -> CacheResult<bool> {
+        self.inner.unlock(key, token).await
+    }
+
+    async fn renew_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool> {
+        self.inner.renew_lock(key, token, lease).await
+    }
+
     async fn info(&self, key: Option<String>) -> CacheResult<String> {
         self.inner.info(key).await
     }
@@ -269,4 +657,12 @@ impl<T: Cache> CacheBase for CacheAdapter<T> {
     async fn dbsize(&self) -> CacheResult<usize> {
         self.inner.dbsize().await
     }
+
+    fn stats(&self) -> Vec<NamespaceStats> {
+        self.inner.stats()
+    }
+
+    fn record_load(&self, key: &str, elapsed: Duration) {
+        self.inner.record_load(key, elapsed)
+    }
 }
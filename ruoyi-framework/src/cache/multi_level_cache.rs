@@ -6,19 +6,29 @@
 //! 2. 写操作：同时写入本地缓存和Redis缓存，确保一致性
 //! 3. 删除操作：同时在本地缓存和Redis缓存中删除
 //! 4. 过期操作：设置两级缓存的过期时间
+//! 5. 列表、集合和有序集合：本地缓存无法保存完整副本，以Redis为准，仅在降级模式下使用本地缓存
+//! 6. 分布式锁：只使用Redis，Redis不可用时返回错误，不降级到本地缓存，避免多个实例同时持有锁
+//!
+//! 启动时Redis不可用或运行中Redis熔断后，如果配置了fallback_to_local，除分布式锁外的操作降级到本地缓存；
+//! 启动时连接失败会在后台按熔断时间间隔重连，熔断后由熔断器放行探测请求，恢复后自动切回Redis。
+//! 降级期间的写入只保存在本地缓存中，不会同步到Redis。
 
 use async_trait::async_trait;
 use log::{debug, error, info, warn};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock, Weak};
 use std::time::Duration;
 
 use crate::config::cache::CacheSettings;
 
 use super::{
-    Cache, CacheManager, CacheResult, LocalCache, LocalCacheManager, RedisCache, RedisCacheManager,
+    Cache, CacheError, CacheKeyType, CacheManager, CacheResult, CacheStats, LocalCache,
+    LocalCacheManager, NamespaceStats, RedisCache, RedisCacheManager, RedisConfig,
 };
 
+/// 本地缓存扫描游标的标记位，用于区分本地缓存和Redis的游标
+const LOCAL_CURSOR_FLAG: u64 = 1 << 63;
+
 /// 多级缓存配置
 #[derive(Debug, Clone, Deserialize)]
 pub struct MultiLevelCacheConfig {
@@ -52,12 +62,12 @@ impl Default for MultiLevelCacheConfig {
 pub struct MultiLevelCache {
     /// 本地缓存
     local_cache: Arc<LocalCache>,
-    /// Redis缓存
-    redis_cache: Option<Arc<RedisCache>>,
+    /// Redis缓存，启动时连接失败则为None，由后台任务重连
+    redis_cache: Arc<RwLock<Option<Arc<RedisCache>>>>,
     /// 配置
     config: Arc<MultiLevelCacheConfig>,
-    /// 是否已降级到只读本地缓存模式
-    is_fallback_mode: bool,
+    /// 按命名空间统计的两级缓存整体命中情况
+    stats: Arc<CacheStats>,
 }
 
 impl MultiLevelCache {
@@ -71,15 +81,17 @@ impl MultiLevelCache {
         let redis_result = RedisCacheManager::new(config.redis.clone())
             .get_cache()
             .await;
-        let (redis_cache, is_fallback) = match redis_result {
+        let redis_cache = match redis_result {
             Ok(redis) => {
                 info!("多级缓存：已成功连接Redis服务器");
-                (Some(Arc::new(redis)), false)
+                Arc::new(RwLock::new(Some(Arc::new(redis))))
             }
             Err(e) => {
                 if config.multi.fallback_to_local {
                     warn!("多级缓存：无法连接Redis服务器，已降级为本地缓存模式: {}", e);
-                    (None, true)
+                    let redis_cache = Arc::new(RwLock::new(None));
+                    spawn_reconnect(Arc::downgrade(&redis_cache), config.redis.clone());
+                    redis_cache
                 } else {
                     error!("多级缓存：无法连接Redis服务器，且未配置降级策略: {}", e);
                     return Err(e);
@@ -91,13 +103,36 @@ impl MultiLevelCache {
             local_cache,
             redis_cache,
             config: config.multi.clone(),
-            is_fallback_mode: is_fallback,
+            stats: Arc::new(CacheStats::new()),
         })
     }
 
-    /// 检查是否处于降级模式
+    /// 检查是否处于降级模式，Redis未连接或熔断期间只使用本地缓存
     pub fn is_in_fallback_mode(&self) -> bool {
-        self.is_fallback_mode
+        self.redis().is_none()
+    }
+
+    /// 获取当前可用的Redis缓存
+    ///
+    /// 配置了fallback_to_local时，熔断期间返回None，所有操作改由本地缓存处理；
+    /// 否则仍返回Redis，由熔断器直接返回错误
+    fn redis(&self) -> Option<Arc<RedisCache>> {
+        let redis = self.redis_cache.read().unwrap().clone()?;
+        if self.config.fallback_to_local && !redis.is_available() {
+            return None;
+        }
+        Some(redis)
+    }
+
+    /// 获取分布式锁使用的Redis缓存
+    ///
+    /// 本地缓存只能保证进程内互斥，锁操作不降级：Redis未连接时返回连接错误，熔断期间由熔断器返回错误
+    fn lock_redis(&self) -> CacheResult<Arc<RedisCache>> {
+        self.redis_cache
+            .read()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| CacheError::Connection("Redis未连接，无法使用分布式锁".to_string()))
     }
 
     /// 获取本地缓存TTL
@@ -116,7 +151,7 @@ impl Cache for MultiLevelCache {
             .await?;
 
         // 如果Redis可用，也设置Redis缓存
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             match redis.set(key, value).await {
                 Ok(_) => debug!("多级缓存：键 {} 已成功写入Redis", key),
                 Err(e) => {
@@ -140,7 +175,7 @@ impl Cache for MultiLevelCache {
         self.local_cache.set_ex(key, value, local_ttl).await?;
 
         // 如果Redis可用，使用提供的TTL
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             match redis.set_ex(key, value, ttl).await {
                 Ok(_) => debug!("多级缓存：键 {} 已成功写入Redis，TTL: {:?}", key, ttl),
                 Err(e) => warn!("多级缓存：键 {} 写入Redis失败: {}", key, e),
@@ -158,6 +193,7 @@ impl Cache for MultiLevelCache {
         match self.local_cache.get::<T>(key).await {
             Ok(Some(value)) => {
                 debug!("多级缓存：键 {} 从本地缓存命中", key);
+                self.stats.record_lookup(key, true);
                 return Ok(Some(value));
             }
             Ok(None) => debug!("多级缓存：键 {} 在本地缓存中不存在", key),
@@ -165,10 +201,11 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果本地缓存未命中且Redis可用，尝试从Redis获取
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             match redis.get::<T>(key).await {
                 Ok(Some(value)) => {
                     debug!("多级缓存：键 {} 从Redis命中", key);
+                    self.stats.record_lookup(key, true);
                     // 不尝试回填本地缓存，因为T可能没有实现Serialize
                     // 在需要回填的场景，用户应该使用实现了Serialize的类型
                     return Ok(Some(value));
@@ -179,6 +216,7 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果两级缓存都未命中，则返回None
+        self.stats.record_lookup(key, false);
         Ok(None)
     }
 
@@ -187,13 +225,48 @@ impl Cache for MultiLevelCache {
         if !keys.is_empty() {
             return Ok(keys);
         }
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             let redis_keys = redis.keys(pattern).await?;
             keys.extend(redis_keys);
         }
         Ok(keys)
     }
 
+    async fn scan(
+        &self,
+        cursor: u64,
+        pattern: &str,
+        count: usize,
+    ) -> CacheResult<(u64, Vec<String>)> {
+        // 两个后端的游标含义不同，同一次遍历只使用开始时选择的后端：
+        // 本地缓存的游标带有标记位，Redis的游标在中途失败时返回错误，不交给本地缓存继续
+        if cursor & LOCAL_CURSOR_FLAG == 0 {
+            // Redis中保存了完整的数据，本地缓存只是其中的一部分
+            if let Some(redis) = self.redis() {
+                match redis.scan(cursor, pattern, count).await {
+                    Ok(result) => return Ok(result),
+                    Err(e) if cursor != 0 => return Err(e),
+                    Err(e) => warn!("多级缓存：在Redis中扫描键 {} 失败: {}", pattern, e),
+                }
+            } else if cursor != 0 {
+                return Err(CacheError::Connection(
+                    "Redis不可用，无法继续扫描，请从头开始".to_string(),
+                ));
+            }
+        }
+
+        let (next, keys) = self
+            .local_cache
+            .scan(cursor & !LOCAL_CURSOR_FLAG, pattern, count)
+            .await?;
+        let next = if next == 0 {
+            0
+        } else {
+            next | LOCAL_CURSOR_FLAG
+        };
+        Ok((next, keys))
+    }
+
     async fn del(&self, key: &str) -> CacheResult<()> {
         // 从本地缓存删除
         if let Err(e) = self.local_cache.del(key).await {
@@ -201,7 +274,7 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果Redis可用，也从Redis删除
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             if let Err(e) = redis.del(key).await {
                 warn!("多级缓存：从Redis删除键 {} 失败: {}", key, e);
             }
@@ -219,7 +292,7 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果本地不存在且Redis可用，检查Redis
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             match redis.exists(key).await {
                 Ok(true) => {
                     debug!("多级缓存：键 {} 在Redis中存在", key);
@@ -242,7 +315,7 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果Redis可用，使用提供的TTL
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             if let Err(e) = redis.expire(key, ttl).await {
                 warn!("多级缓存：设置Redis键 {} 的过期时间失败: {}", key, e);
             }
@@ -251,9 +324,33 @@ impl Cache for MultiLevelCache {
         Ok(())
     }
 
+    async fn ttl(&self, key: &str) -> CacheResult<i64> {
+        // Redis中的过期时间才是准确的，本地缓存的TTL会被local_ttl截断
+        if let Some(redis) = self.redis() {
+            match redis.ttl(key).await {
+                Ok(ttl) => return Ok(ttl),
+                Err(e) => warn!("多级缓存：获取Redis键 {} 的过期时间失败: {}", key, e),
+            }
+        }
+
+        self.local_cache.ttl(key).await
+    }
+
+    async fn key_type(&self, key: &str) -> CacheResult<CacheKeyType> {
+        // 本地缓存中的哈希表可能只有部分字段，以Redis为准
+        if let Some(redis) = self.redis() {
+            match redis.key_type(key).await {
+                Ok(key_type) => return Ok(key_type),
+                Err(e) => warn!("多级缓存：获取Redis键 {} 的类型失败: {}", key, e),
+            }
+        }
+
+        self.local_cache.key_type(key).await
+    }
+
     async fn incr(&self, key: &str) -> CacheResult<i64> {
         // 如果Redis可用，优先在Redis中递增（保证计数器准确性）
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             match redis.incr(key).await {
                 Ok(value) => {
                     // 更新本地缓存
@@ -279,7 +376,7 @@ impl Cache for MultiLevelCache {
 
     async fn decr(&self, key: &str) -> CacheResult<i64> {
         // 如果Redis可用，优先在Redis中递减
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             match redis.decr(key).await {
                 Ok(value) => {
                     // 更新本地缓存
@@ -315,7 +412,7 @@ impl Cache for MultiLevelCache {
         self.local_cache.hset(key, field, value).await?;
 
         // 如果Redis可用，也设置Redis缓存
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             if let Err(e) = redis.hset(key, field, value).await {
                 warn!(
                     "多级缓存：在Redis中设置哈希表键 {}::{} 失败: {}",
@@ -335,6 +432,7 @@ impl Cache for MultiLevelCache {
         match self.local_cache.hget::<T>(key, field).await {
             Ok(Some(value)) => {
                 debug!("多级缓存：哈希表键 {}::{} 从本地缓存命中", key, field);
+                self.stats.record_lookup(key, true);
                 return Ok(Some(value));
             }
             Ok(None) => debug!("多级缓存：哈希表键 {}::{} 在本地缓存中不存在", key, field),
@@ -345,10 +443,11 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果本地缓存未命中且Redis可用，尝试从Redis获取
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             match redis.hget::<T>(key, field).await {
                 Ok(Some(value)) => {
                     debug!("多级缓存：哈希表键 {}::{} 从Redis命中", key, field);
+                    self.stats.record_lookup(key, true);
                     // 不尝试回填本地缓存，因为T可能没有实现Serialize
                     return Ok(Some(value));
                 }
@@ -361,6 +460,7 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果两级缓存都未命中，则返回None
+        self.stats.record_lookup(key, false);
         Ok(None)
     }
 
@@ -374,7 +474,7 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果Redis可用，也从Redis删除
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             if let Err(e) = redis.hdel(key, field).await {
                 warn!(
                     "多级缓存：从Redis删除哈希表键 {}::{} 失败: {}",
@@ -398,7 +498,7 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果本地不存在且Redis可用，检查Redis
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             match redis.hexists(key, field).await {
                 Ok(true) => {
                     debug!("多级缓存：哈希表键 {}::{} 在Redis中存在", key, field);
@@ -432,8 +532,8 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果本地缓存失败或为空且Redis可用，尝试从Redis获取
-        if !local_success && self.redis_cache.is_some() {
-            if let Some(redis) = &self.redis_cache {
+        if !local_success {
+            if let Some(redis) = self.redis() {
                 match redis.hkeys(key).await {
                     Ok(keys) => {
                         result = keys;
@@ -463,8 +563,8 @@ impl Cache for MultiLevelCache {
         }
 
         // 如果本地缓存失败或为空且Redis可用，尝试从Redis获取
-        if !local_success && self.redis_cache.is_some() {
-            if let Some(redis) = &self.redis_cache {
+        if !local_success {
+            if let Some(redis) = self.redis() {
                 match redis.hlen(key).await {
                     Ok(len) => return Ok(len),
                     Err(e) => warn!("多级缓存：从Redis获取哈希表 {} 的长度失败: {}", key, e),
@@ -475,21 +575,205 @@ impl Cache for MultiLevelCache {
         Ok(local_len)
     }
 
+    async fn hgetall<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+    ) -> CacheResult<Vec<(String, T)>> {
+        // 本地缓存只保存读写过的字段，以Redis为准
+        if let Some(redis) = self.redis() {
+            match redis.hgetall(key).await {
+                Ok(fields) => return Ok(fields),
+                Err(e) => warn!("多级缓存：从Redis获取哈希表 {} 的所有字段失败: {}", key, e),
+            }
+        }
+
+        self.local_cache.hgetall(key).await
+    }
+
+    async fn lpush<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<usize> {
+        match self.redis() {
+            Some(redis) => redis.lpush(key, value).await,
+            None => self.local_cache.lpush(key, value).await,
+        }
+    }
+
+    async fn rpush<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<usize> {
+        match self.redis() {
+            Some(redis) => redis.rpush(key, value).await,
+            None => self.local_cache.rpush(key, value).await,
+        }
+    }
+
+    async fn lpop<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>> {
+        match self.redis() {
+            Some(redis) => redis.lpop(key).await,
+            None => self.local_cache.lpop(key).await,
+        }
+    }
+
+    async fn rpop<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>> {
+        match self.redis() {
+            Some(redis) => redis.rpop(key).await,
+            None => self.local_cache.rpop(key).await,
+        }
+    }
+
+    async fn lrange<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> CacheResult<Vec<T>> {
+        match self.redis() {
+            Some(redis) => redis.lrange(key, start, stop).await,
+            None => self.local_cache.lrange(key, start, stop).await,
+        }
+    }
+
+    async fn ltrim(&self, key: &str, start: i64, stop: i64) -> CacheResult<()> {
+        match self.redis() {
+            Some(redis) => redis.ltrim(key, start, stop).await,
+            None => self.local_cache.ltrim(key, start, stop).await,
+        }
+    }
+
+    async fn llen(&self, key: &str) -> CacheResult<usize> {
+        match self.redis() {
+            Some(redis) => redis.llen(key).await,
+            None => self.local_cache.llen(key).await,
+        }
+    }
+
+    async fn sadd<T: Serialize + Send + Sync>(&self, key: &str, member: &T) -> CacheResult<bool> {
+        match self.redis() {
+            Some(redis) => redis.sadd(key, member).await,
+            None => self.local_cache.sadd(key, member).await,
+        }
+    }
+
+    async fn srem<T: Serialize + Send + Sync>(&self, key: &str, member: &T) -> CacheResult<bool> {
+        match self.redis() {
+            Some(redis) => redis.srem(key, member).await,
+            None => self.local_cache.srem(key, member).await,
+        }
+    }
+
+    async fn smembers<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Vec<T>> {
+        match self.redis() {
+            Some(redis) => redis.smembers(key).await,
+            None => self.local_cache.smembers(key).await,
+        }
+    }
+
+    async fn sismember<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+    ) -> CacheResult<bool> {
+        match self.redis() {
+            Some(redis) => redis.sismember(key, member).await,
+            None => self.local_cache.sismember(key, member).await,
+        }
+    }
+
+    async fn zadd<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+        score: f64,
+    ) -> CacheResult<bool> {
+        match self.redis() {
+            Some(redis) => redis.zadd(key, member, score).await,
+            None => self.local_cache.zadd(key, member, score).await,
+        }
+    }
+
+    async fn zrange_by_score<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> CacheResult<Vec<(T, f64)>> {
+        match self.redis() {
+            Some(redis) => redis.zrange_by_score(key, min, max).await,
+            None => self.local_cache.zrange_by_score(key, min, max).await,
+        }
+    }
+
+    async fn zincr_by<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+        delta: f64,
+    ) -> CacheResult<f64> {
+        match self.redis() {
+            Some(redis) => redis.zincr_by(key, member, delta).await,
+            None => self.local_cache.zincr_by(key, member, delta).await,
+        }
+    }
+
+    async fn zrank<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+    ) -> CacheResult<Option<usize>> {
+        match self.redis() {
+            Some(redis) => redis.zrank(key, member).await,
+            None => self.local_cache.zrank(key, member).await,
+        }
+    }
+
+    async fn try_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool> {
+        self.lock_redis()?.try_lock(key, token, lease).await
+    }
+
+    async fn unlock(&self, key: &str, token: &str) -> CacheResult<bool> {
+        self.lock_redis()?.unlock(key, token).await
+    }
+
+    async fn renew_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool> {
+        self.lock_redis()?.renew_lock(key, token, lease).await
+    }
+
     async fn info(&self, key: Option<String>) -> CacheResult<String> {
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             redis.info(key).await
         } else {
-            Ok("".to_string())
+            self.local_cache.info(key).await
         }
     }
 
     async fn dbsize(&self) -> CacheResult<usize> {
-        if let Some(redis) = &self.redis_cache {
+        if let Some(redis) = self.redis() {
             redis.dbsize().await
         } else {
-            Ok(0)
+            self.local_cache.dbsize().await
         }
     }
+
+    fn stats(&self) -> Vec<NamespaceStats> {
+        // 命中和加载按两级缓存整体统计，淘汰只发生在本地缓存
+        let mut stats = self.stats.snapshot();
+        for local in self.local_cache.stats() {
+            if local.evictions == 0 {
+                continue;
+            }
+            match stats.iter_mut().find(|s| s.namespace == local.namespace) {
+                Some(item) => item.evictions = local.evictions,
+                None => stats.push(NamespaceStats {
+                    namespace: local.namespace,
+                    evictions: local.evictions,
+                    ..Default::default()
+                }),
+            }
+        }
+        stats.sort_by(|a, b| a.namespace.cmp(&b.namespace));
+        stats
+    }
+
+    fn record_load(&self, key: &str, elapsed: Duration) {
+        self.stats.record_load(key, elapsed);
+    }
 }
 /// 多级缓存管理器
 pub struct MultiLevelCacheManager {
@@ -521,3 +805,92 @@ impl CacheManager for MultiLevelCacheManager {
         }
     }
 }
+
+/// 启动时连接Redis失败后，在后台按熔断时间间隔重连，成功后切回Redis
+fn spawn_reconnect(redis_cache: Weak<RwLock<Option<Arc<RedisCache>>>>, config: Arc<RedisConfig>) {
+    let interval = Duration::from_millis(config.circuit_breaker.reset_timeout.max(1000));
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            // 多级缓存已被释放时停止重连
+            let Some(redis_cache) = redis_cache.upgrade() else {
+                return;
+            };
+            match RedisCacheManager::new(config.clone()).get_cache().await {
+                Ok(redis) => {
+                    *redis_cache.write().unwrap() = Some(Arc::new(redis));
+                    info!("多级缓存：已重新连接Redis服务器，退出降级模式");
+                    return;
+                }
+                Err(e) => debug!("多级缓存：重新连接Redis服务器失败: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::LocalCacheConfig;
+
+    /// 未连接Redis的多级缓存
+    fn local_only() -> MultiLevelCache {
+        MultiLevelCache {
+            local_cache: Arc::new(LocalCache::new(Arc::new(LocalCacheConfig::default()))),
+            redis_cache: Arc::new(RwLock::new(None)),
+            config: Arc::new(MultiLevelCacheConfig::default()),
+            stats: Arc::new(CacheStats::new()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lock_does_not_fall_back_to_local() {
+        let cache = local_only();
+        assert!(cache.is_in_fallback_mode());
+
+        // 普通操作降级到本地缓存
+        cache.set("k", &"v".to_string()).await.unwrap();
+        assert_eq!(
+            cache.get::<String>("k").await.unwrap().as_deref(),
+            Some("v")
+        );
+
+        // 分布式锁不降级
+        let lease = Duration::from_secs(10);
+        assert!(matches!(
+            cache.try_lock("lock", "t", lease).await,
+            Err(CacheError::Connection(_))
+        ));
+        assert!(cache.renew_lock("lock", "t", lease).await.is_err());
+        assert!(cache.unlock("lock", "t").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_scan_does_not_mix_cursors() {
+        let cache = local_only();
+        for i in 0..5 {
+            cache.set(&format!("scan:{}", i), &i).await.unwrap();
+        }
+
+        // 本地缓存的游标带有标记位，分批扫描能取到全部键
+        let mut cursor = 0;
+        let mut keys = Vec::new();
+        loop {
+            let (next, batch) = cache.scan(cursor, "scan:*", 2).await.unwrap();
+            keys.extend(batch);
+            if next == 0 {
+                break;
+            }
+            assert_ne!(next & LOCAL_CURSOR_FLAG, 0);
+            cursor = next;
+        }
+        keys.sort();
+        assert_eq!(keys.len(), 5);
+
+        // Redis返回的游标不能交给本地缓存继续扫描
+        assert!(matches!(
+            cache.scan(2, "scan:*", 2).await,
+            Err(CacheError::Connection(_))
+        ));
+    }
+}
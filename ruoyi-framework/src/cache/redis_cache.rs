@@ -1,19 +1,25 @@
 //! Redis缓存实现模块
 //!
-//! 支持单机Redis和Redis集群模式
+//! 支持单机Redis、Redis集群和Redis哨兵模式
 
 use async_trait::async_trait;
 use log::info;
 use redis::{
     aio::ConnectionManager, cluster::ClusterClient, cluster::ClusterConnection, AsyncCommands,
-    Client,
+    Client, ConnectionAddr, ConnectionInfo, FromRedisValue, IntoConnectionInfo,
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio::time::timeout;
 
-use super::{Cache, CacheError, CacheManager, CacheResult};
+use super::{
+    Cache, CacheError, CacheKeyType, CacheManager, CacheResult, CacheStats, CircuitBreaker,
+    CircuitBreakerConfig, CircuitState, CodecConfig, NamespaceStats, RedisSentinel, ValueCodec,
+    SCAN_BATCH_SIZE,
+};
 
 /// Redis配置
 #[derive(Debug, Clone, Deserialize)]
@@ -24,13 +30,22 @@ pub struct RedisConfig {
     /// 连接地址（单机模式）
     #[serde(default = "default_url")]
     pub url: Option<String>,
-    /// 主机地址（集群模式）
+    /// 主机地址（集群模式为集群节点，哨兵模式为哨兵节点）
     #[serde(default)]
     pub hosts: Option<Vec<String>>,
+    /// 主节点名称（哨兵模式）
+    #[serde(default)]
+    pub master_name: Option<String>,
+    /// 哨兵节点密码（哨兵模式）
+    #[serde(default)]
+    pub sentinel_password: Option<String>,
+    /// 用户名（Redis 6 ACL，集群模式和哨兵模式的主节点，单机模式在url中指定）
+    #[serde(default)]
+    pub username: Option<String>,
     /// 密码
     #[serde(default)]
     pub password: Option<String>,
-    /// 数据库索引（仅单机模式有效）
+    /// 数据库索引（单机模式和哨兵模式有效）
     #[serde(default = "default_db")]
     pub db: Option<i64>,
     /// 连接池最小空闲连接数
@@ -48,6 +63,12 @@ pub struct RedisConfig {
     /// 默认过期时间（秒）
     #[serde(default = "default_default_ttl")]
     pub default_ttl: u64,
+    /// 值的编码方式
+    #[serde(default)]
+    pub codec: CodecConfig,
+    /// 熔断器配置
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
 }
 
 fn default_url() -> Option<String> {
@@ -86,6 +107,8 @@ pub enum RedisConnectionType {
     Standalone,
     /// 集群模式
     Cluster,
+    /// 哨兵模式
+    Sentinel,
 }
 
 impl Default for RedisConnectionType {
@@ -100,6 +123,9 @@ impl Default for RedisConfig {
             connection_type: RedisConnectionType::Standalone,
             url: Some("redis://127.0.0.1:6379".to_string()),
             hosts: None,
+            master_name: None,
+            sentinel_password: None,
+            username: None,
             password: None,
             db: Some(0),
             min_idle: Some(5),
@@ -107,6 +133,8 @@ impl Default for RedisConfig {
             connect_timeout: Some(10000),
             command_timeout: Some(5000),
             default_ttl: 3600,
+            codec: CodecConfig::default(),
+            circuit_breaker: CircuitBreakerConfig::default(),
         }
     }
 }
@@ -120,11 +148,28 @@ pub struct RedisCache {
     standalone_client: Option<Arc<ConnectionManager>>,
     /// 集群客户端
     cluster_client: Option<Arc<ClusterClient>>,
+    /// 集群节点的连接信息，与集群客户端使用相同的认证和TLS配置，扫描时按主节点地址替换
+    cluster_node_info: Option<Arc<ConnectionInfo>>,
+    /// 集群扫描使用的主节点列表和节点连接，在游标循环中复用
+    cluster_scan: Arc<ClusterScanState>,
+    /// 哨兵客户端
+    sentinel: Option<Arc<RedisSentinel>>,
+    /// 按命名空间统计的命中情况，淘汰由Redis服务端完成，无法按前缀统计
+    stats: Arc<CacheStats>,
+    /// 字符串和哈希表值的编解码器
+    codec: Arc<ValueCodec>,
+    /// 熔断器，连续失败后暂停访问Redis
+    breaker: Arc<CircuitBreaker>,
 }
 
 impl RedisCache {
     /// 创建新的Redis缓存实例
     pub async fn new(config: Arc<RedisConfig>) -> CacheResult<Self> {
+        let codec = Arc::new(ValueCodec::new(config.codec.clone()));
+        let breaker = Arc::new(CircuitBreaker::new(
+            "Redis熔断器",
+            config.circuit_breaker.clone(),
+        ));
         match config.connection_type {
             RedisConnectionType::Standalone => {
                 let mut url = config.url.clone().ok_or_else(|| {
@@ -175,12 +220,25 @@ impl RedisCache {
                     log_url, config.db
                 );
                 let client = Client::open(url)?;
-                let manager = ConnectionManager::new(client).await?;
+                let manager = match config.connect_timeout {
+                    Some(ms) => timeout(Duration::from_millis(ms), ConnectionManager::new(client))
+                        .await
+                        .map_err(|_| {
+                            CacheError::Connection(format!("连接Redis超时({}毫秒)", ms))
+                        })??,
+                    None => ConnectionManager::new(client).await?,
+                };
 
                 Ok(Self {
                     config,
                     standalone_client: Some(Arc::new(manager)),
                     cluster_client: None,
+                    cluster_node_info: None,
+                    cluster_scan: Arc::default(),
+                    sentinel: None,
+                    stats: Arc::new(CacheStats::new()),
+                    codec,
+                    breaker,
                 })
             }
             RedisConnectionType::Cluster => {
@@ -191,9 +249,25 @@ impl RedisCache {
                 // 处理集群连接的认证
                 let mut cluster_options = redis::cluster::ClusterClientBuilder::new(hosts.clone());
 
+                // 节点连接信息取第一个节点的协议、用户名和密码，与集群客户端一致
+                let mut node_info = hosts
+                    .first()
+                    .ok_or_else(|| {
+                        CacheError::Configuration("Redis集群模式的hosts不能为空".to_string())
+                    })?
+                    .as_str()
+                    .into_connection_info()?;
+                node_info.redis.db = 0;
+
+                if let Some(username) = &config.username {
+                    cluster_options = cluster_options.username(username.clone());
+                    node_info.redis.username = Some(username.clone());
+                }
+
                 // 设置密码认证
                 if let Some(password) = &config.password {
                     cluster_options = cluster_options.password(password.clone());
+                    node_info.redis.password = Some(password.clone());
                     info!("Redis集群使用密码认证");
                 }
 
@@ -204,6 +278,34 @@ impl RedisCache {
                     config,
                     standalone_client: None,
                     cluster_client: Some(Arc::new(client)),
+                    cluster_node_info: Some(Arc::new(node_info)),
+                    cluster_scan: Arc::default(),
+                    sentinel: None,
+                    stats: Arc::new(CacheStats::new()),
+                    codec,
+                    breaker,
+                })
+            }
+            RedisConnectionType::Sentinel => {
+                let sentinel = RedisSentinel::new(&config)?;
+                info!(
+                    "初始化Redis哨兵连接, 主节点名称: {:?}, 哨兵数量: {}",
+                    config.master_name,
+                    config.hosts.as_ref().map(|h| h.len()).unwrap_or(0)
+                );
+                // 启动时即发现主节点并建立连接，与单机模式保持一致
+                sentinel.get_connection().await?;
+
+                Ok(Self {
+                    config,
+                    standalone_client: None,
+                    cluster_client: None,
+                    cluster_node_info: None,
+                    cluster_scan: Arc::default(),
+                    sentinel: Some(Arc::new(sentinel)),
+                    stats: Arc::new(CacheStats::new()),
+                    codec,
+                    breaker,
                 })
             }
         }
@@ -220,10 +322,26 @@ impl RedisCache {
         }
     }
 
+    /// 获取哨兵模式下的主节点连接
+    async fn get_sentinel_conn(&self) -> CacheResult<ConnectionManager> {
+        if let Some(sentinel) = &self.sentinel {
+            sentinel.get_connection().await
+        } else {
+            Err(CacheError::Connection(
+                "未初始化Redis哨兵客户端".to_string(),
+            ))
+        }
+    }
+
     /// 获取集群连接
     async fn get_cluster_conn(&self) -> CacheResult<ClusterConnection> {
         if let Some(client) = &self.cluster_client {
-            client.get_connection().map_err(|e| e.into())
+            // 集群连接使用同步接口，无法被异步超时打断，需要在套接字上设置读写超时
+            let conn = client.get_connection()?;
+            let command_timeout = self.command_timeout();
+            conn.set_read_timeout(command_timeout)?;
+            conn.set_write_timeout(command_timeout)?;
+            Ok(conn)
         } else {
             Err(CacheError::Connection(
                 "未初始化Redis集群客户端".to_string(),
@@ -231,27 +349,317 @@ impl RedisCache {
         }
     }
 
+    /// 获取集群中所有主节点的地址，按地址排序以保证多次扫描时顺序一致
+    async fn get_cluster_masters(&self) -> CacheResult<Vec<String>> {
+        let nodes: String = self
+            .execute(|mut conn| async move {
+                match conn {
+                    RedisConnection::Cluster(ref mut conn) => {
+                        redis::cmd("CLUSTER").arg("NODES").query(conn)
+                    }
+                    RedisConnection::Standalone(ref mut conn) => {
+                        redis::cmd("CLUSTER").arg("NODES").query_async(conn).await
+                    }
+                }
+            })
+            .await?;
+
+        // 每行格式: <id> <ip:port@cport> <flags> <master> ...
+        let mut masters: Vec<String> = nodes
+            .lines()
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                if parts.len() < 3 {
+                    return None;
+                }
+                let flags: Vec<&str> = parts[2].split(',').collect();
+                if flags.contains(&"master") && !flags.contains(&"fail") {
+                    parts[1].split('@').next().map(|addr| addr.to_string())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        masters.sort();
+        Ok(masters)
+    }
+
+    /// 获取集群主节点的连接，同一节点的连接在多次扫描间复用
+    async fn get_cluster_node_conn(&self, addr: &str) -> CacheResult<ConnectionManager> {
+        if let Some(conn) = self.cluster_scan.conns.read().await.get(addr) {
+            return Ok(conn.clone());
+        }
+
+        let template = self
+            .cluster_node_info
+            .as_ref()
+            .ok_or_else(|| CacheError::Connection("未初始化Redis集群客户端".to_string()))?;
+        let client = Client::open(node_connection_info(template, addr)?)?;
+        let conn = match self.config.connect_timeout {
+            Some(ms) => timeout(Duration::from_millis(ms), ConnectionManager::new(client))
+                .await
+                .map_err(|_| {
+                    CacheError::Connection(format!("连接Redis集群节点{}超时({}毫秒)", addr, ms))
+                })??,
+            None => ConnectionManager::new(client).await?,
+        };
+        self.cluster_scan
+            .conns
+            .write()
+            .await
+            .insert(addr.to_string(), conn.clone());
+        Ok(conn)
+    }
+
+    /// 在集群中扫描key，依次遍历每个主节点
+    ///
+    /// 游标的高16位记录当前扫描的主节点序号，低48位为该节点上的SCAN游标。
+    /// 主节点列表在游标为0（开始扫描）时获取，后续批次沿用，避免每批都执行CLUSTER NODES
+    async fn scan_cluster(
+        &self,
+        cursor: u64,
+        pattern: &str,
+        count: usize,
+    ) -> CacheResult<(u64, Vec<String>)> {
+        let masters = {
+            let masters = self.cluster_scan.masters.read().await;
+            if cursor != 0 && !masters.is_empty() {
+                Some(masters.clone())
+            } else {
+                None
+            }
+        };
+        let masters = match masters {
+            Some(masters) => masters,
+            None => {
+                let masters = self.get_cluster_masters().await?;
+                *self.cluster_scan.masters.write().await = masters.clone();
+                masters
+            }
+        };
+
+        let node_index = (cursor >> CLUSTER_CURSOR_SHIFT) as usize;
+        let node_cursor = cursor & CLUSTER_CURSOR_MASK;
+        let Some(addr) = masters.get(node_index) else {
+            return Ok((0, Vec::new()));
+        };
+
+        let mut conn = self.get_cluster_node_conn(addr).await?;
+        let (next, keys): (u64, Vec<String>) = scan_cmd(node_cursor, pattern, count)
+            .query_async(&mut conn)
+            .await?;
+
+        let next_cursor = if next != 0 {
+            ((node_index as u64) << CLUSTER_CURSOR_SHIFT) | next
+        } else if node_index + 1 < masters.len() {
+            ((node_index + 1) as u64) << CLUSTER_CURSOR_SHIFT
+        } else {
+            0
+        };
+        Ok((next_cursor, keys))
+    }
+
+    /// 命令超时时间，未配置或为0时不限制
+    fn command_timeout(&self) -> Option<Duration> {
+        self.config
+            .command_timeout
+            .filter(|ms| *ms > 0)
+            .map(Duration::from_millis)
+    }
+
+    /// 熔断器当前状态
+    pub fn circuit_state(&self) -> CircuitState {
+        self.breaker.state()
+    }
+
+    /// Redis当前是否可用，熔断期间返回false
+    pub fn is_available(&self) -> bool {
+        self.breaker.is_available()
+    }
+
     /// 执行Redis命令
+    ///
+    /// 命令受command_timeout限制，超时、连接失败等错误计入熔断器，熔断期间直接返回连接错误
     async fn execute<T, F, Fut>(&self, f: F) -> CacheResult<T>
     where
         F: FnOnce(RedisConnection) -> Fut,
         Fut: std::future::Future<Output = redis::RedisResult<T>>,
     {
-        let connection = match self.config.connection_type {
-            RedisConnectionType::Standalone => {
-                RedisConnection::Standalone(self.get_standalone_conn().await?)
+        if !self.breaker.try_acquire() {
+            return Err(CacheError::Connection(
+                "Redis熔断器已打开，暂停访问Redis".to_string(),
+            ));
+        }
+
+        let result = match self.command_timeout() {
+            Some(command_timeout) => {
+                match timeout(command_timeout, self.execute_command(f)).await {
+                    Ok(result) => result,
+                    Err(_) => {
+                        // 旧主节点网络分区时命令只会超时而不会返回错误，同样需要重新发现主节点
+                        self.invalidate_sentinel().await;
+                        Err(CacheError::Connection(format!(
+                            "Redis命令执行超时({}毫秒)",
+                            command_timeout.as_millis()
+                        )))
+                    }
+                }
             }
+            None => self.execute_command(f).await,
+        };
+
+        // 只有连接类错误说明Redis不可用，命令本身的错误（如WRONGTYPE）不计入熔断
+        match &result {
+            Err(CacheError::Connection(_)) => {
+                self.breaker.record_failure();
+                // 熔断后不再执行命令，丢弃主节点连接，熔断结束后的探测请求会重新向哨兵查询
+                if self.breaker.state() == CircuitState::Open {
+                    self.invalidate_sentinel().await;
+                }
+            }
+            _ => self.breaker.record_success(),
+        }
+        result
+    }
+
+    /// 哨兵模式下丢弃当前主节点连接，下次使用时重新向哨兵查询
+    async fn invalidate_sentinel(&self) {
+        if let Some(sentinel) = &self.sentinel {
+            sentinel.invalidate().await;
+        }
+    }
+
+    async fn execute_command<T, F, Fut>(&self, f: F) -> CacheResult<T>
+    where
+        F: FnOnce(RedisConnection) -> Fut,
+        Fut: std::future::Future<Output = redis::RedisResult<T>>,
+    {
+        let connection = match self.config.connection_type {
+            RedisConnectionType::Standalone => self
+                .get_standalone_conn()
+                .await
+                .map(RedisConnection::Standalone),
             RedisConnectionType::Cluster => {
                 // 注意：当前redis-rs的集群实现不支持异步接口
                 // 实际使用时可能需要使用单独的线程池处理集群命令
-                RedisConnection::Cluster(self.get_cluster_conn().await?)
+                self.get_cluster_conn().await.map(RedisConnection::Cluster)
             }
+            RedisConnectionType::Sentinel => self
+                .get_sentinel_conn()
+                .await
+                .map(RedisConnection::Standalone),
         };
+        let connection = connection.map_err(|e| match e {
+            CacheError::Connection(_) => e,
+            e => CacheError::Connection(e.to_string()),
+        })?;
+
+        match f(connection).await {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                // 主从切换后旧主节点不可用或变为只读，丢弃连接以便下次重新发现主节点
+                if let Some(sentinel) = &self.sentinel {
+                    if RedisSentinel::is_failover_error(&e) {
+                        sentinel.invalidate().await;
+                    }
+                }
+                if is_unavailable_error(&e) {
+                    Err(CacheError::Connection(e.to_string()))
+                } else {
+                    Err(e.into())
+                }
+            }
+        }
+    }
 
-        f(connection).await.map_err(|e| e.into())
+    /// 在单机或集群连接上执行同一条命令
+    async fn query<T: FromRedisValue>(&self, cmd: &redis::Cmd) -> CacheResult<T> {
+        self.execute(|mut conn| async move {
+            match conn {
+                RedisConnection::Standalone(ref mut conn) => cmd.query_async(conn).await,
+                RedisConnection::Cluster(ref mut conn) => cmd.query(conn),
+            }
+        })
+        .await
     }
 }
 
+/// 集群扫描游标中节点序号的偏移位数
+const CLUSTER_CURSOR_SHIFT: u32 = 48;
+/// 集群扫描游标中节点游标的掩码
+const CLUSTER_CURSOR_MASK: u64 = (1 << CLUSTER_CURSOR_SHIFT) - 1;
+
+/// 集群扫描状态
+#[derive(Default)]
+struct ClusterScanState {
+    /// 最近一次开始扫描时获取的主节点地址
+    masters: RwLock<Vec<String>>,
+    /// 主节点地址到连接的映射
+    conns: RwLock<HashMap<String, ConnectionManager>>,
+}
+
+/// 按节点地址（`ip:port`）生成连接信息，沿用模板中的TLS、用户名和密码
+///
+/// 用于集群主节点和哨兵发现的主节点，认证信息不经过URL拼接，密码中可以包含任意字符
+pub(super) fn node_connection_info(
+    template: &ConnectionInfo,
+    addr: &str,
+) -> CacheResult<ConnectionInfo> {
+    let (host, port) = addr
+        .rsplit_once(':')
+        .and_then(|(host, port)| Some((host.to_string(), port.parse().ok()?)))
+        .ok_or_else(|| CacheError::Connection(format!("无效的Redis节点地址: {}", addr)))?;
+    let addr = match template.addr {
+        ConnectionAddr::TcpTls { insecure, .. } => ConnectionAddr::TcpTls {
+            host,
+            port,
+            insecure,
+        },
+        _ => ConnectionAddr::Tcp(host, port),
+    };
+    Ok(ConnectionInfo {
+        addr,
+        redis: template.redis.clone(),
+    })
+}
+
+/// 校验持有者令牌后删除锁，保证只释放自己持有的锁
+const UNLOCK_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// 校验持有者令牌后重置锁的租期
+const RENEW_LOCK_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 0
+"#;
+
+/// 判断是否为Redis不可用导致的错误
+fn is_unavailable_error(e: &redis::RedisError) -> bool {
+    e.is_io_error() || e.is_timeout() || e.is_connection_refusal() || e.is_connection_dropped()
+}
+
+/// 将租期换算为毫秒，PX参数不能为0
+fn lease_millis(lease: Duration) -> u64 {
+    (lease.as_millis() as u64).max(1)
+}
+
+/// 构造SCAN命令
+fn scan_cmd(cursor: u64, pattern: &str, count: usize) -> redis::Cmd {
+    let mut cmd = redis::cmd("SCAN");
+    cmd.arg(cursor)
+        .arg("MATCH")
+        .arg(pattern)
+        .arg("COUNT")
+        .arg(count);
+    cmd
+}
+
 /// Redis连接枚举，用于统一单机和集群接口
 pub enum RedisConnection {
     Standalone(ConnectionManager),
@@ -261,7 +669,7 @@ pub enum RedisConnection {
 #[async_trait]
 impl Cache for RedisCache {
     async fn set<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<()> {
-        let serialized = serde_json::to_string(value)?;
+        let serialized = self.codec.encode(key, value)?;
 
         self.execute(|mut conn| async move {
             match conn {
@@ -280,7 +688,7 @@ impl Cache for RedisCache {
         value: &T,
         ttl: Duration,
     ) -> CacheResult<()> {
-        let serialized = serde_json::to_string(value)?;
+        let serialized = self.codec.encode(key, value)?;
         let seconds = ttl.as_secs() as usize;
 
         self.execute(|mut conn| async move {
@@ -299,7 +707,7 @@ impl Cache for RedisCache {
     }
 
     async fn get<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>> {
-        let result: Option<String> = self
+        let result: Option<Vec<u8>> = self
             .execute(|mut conn| async move {
                 match conn {
                     RedisConnection::Standalone(ref mut conn) => conn.get(key).await,
@@ -309,10 +717,11 @@ impl Cache for RedisCache {
                 }
             })
             .await?;
+        self.stats.record_lookup(key, result.is_some());
 
         match result {
             Some(data) => {
-                let value = serde_json::from_str(&data)?;
+                let value = self.codec.decode(&data)?;
                 Ok(Some(value))
             }
             None => Ok(None),
@@ -320,18 +729,44 @@ impl Cache for RedisCache {
     }
 
     async fn keys(&self, pattern: &str) -> CacheResult<Vec<String>> {
-        let result: Vec<String> = self
-            .execute(|mut conn| async move {
-                match conn {
-                    RedisConnection::Standalone(ref mut conn) => conn.keys(pattern).await,
-                    RedisConnection::Cluster(ref mut conn) => {
-                        redis::cmd("KEYS").arg(pattern).query(conn)
-                    }
-                }
-            })
-            .await?;
+        // 使用SCAN增量遍历，避免KEYS命令阻塞Redis
+        let mut cursor = 0;
+        let mut keys = Vec::new();
+        loop {
+            let (next, batch) = self.scan(cursor, pattern, SCAN_BATCH_SIZE).await?;
+            keys.extend(batch);
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+        keys.sort();
+        keys.dedup();
+        Ok(keys)
+    }
 
-        Ok(result)
+    async fn scan(
+        &self,
+        cursor: u64,
+        pattern: &str,
+        count: usize,
+    ) -> CacheResult<(u64, Vec<String>)> {
+        match self.config.connection_type {
+            RedisConnectionType::Standalone | RedisConnectionType::Sentinel => {
+                self.execute(|mut conn| async move {
+                    match conn {
+                        RedisConnection::Standalone(ref mut conn) => {
+                            scan_cmd(cursor, pattern, count).query_async(conn).await
+                        }
+                        RedisConnection::Cluster(ref mut conn) => {
+                            scan_cmd(cursor, pattern, count).query(conn)
+                        }
+                    }
+                })
+                .await
+            }
+            RedisConnectionType::Cluster => self.scan_cluster(cursor, pattern, count).await,
+        }
     }
 
     async fn del(&self, key: &str) -> CacheResult<()> {
@@ -378,6 +813,21 @@ impl Cache for RedisCache {
         .await
     }
 
+    async fn ttl(&self, key: &str) -> CacheResult<i64> {
+        self.execute(|mut conn| async move {
+            match conn {
+                RedisConnection::Standalone(ref mut conn) => conn.ttl(key).await,
+                RedisConnection::Cluster(ref mut conn) => redis::cmd("TTL").arg(key).query(conn),
+            }
+        })
+        .await
+    }
+
+    async fn key_type(&self, key: &str) -> CacheResult<CacheKeyType> {
+        let name: String = self.query(redis::cmd("TYPE").arg(key)).await?;
+        Ok(CacheKeyType::from_redis(&name))
+    }
+
     async fn incr(&self, key: &str) -> CacheResult<i64> {
         self.execute(|mut conn| async move {
             match conn {
@@ -406,7 +856,7 @@ impl Cache for RedisCache {
         field: &str,
         value: &T,
     ) -> CacheResult<()> {
-        let serialized = serde_json::to_string(value)?;
+        let serialized = self.codec.encode(key, value)?;
 
         self.execute(|mut conn| async move {
             match conn {
@@ -432,7 +882,7 @@ impl Cache for RedisCache {
         key: &str,
         field: &str,
     ) -> CacheResult<Option<T>> {
-        let result: Option<String> = self
+        let result: Option<Vec<u8>> = self
             .execute(|mut conn| async move {
                 match conn {
                     RedisConnection::Standalone(ref mut conn) => conn.hget(key, field).await,
@@ -442,10 +892,11 @@ impl Cache for RedisCache {
                 }
             })
             .await?;
+        self.stats.record_lookup(key, result.is_some());
 
         match result {
             Some(data) => {
-                let value = serde_json::from_str(&data)?;
+                let value = self.codec.decode(&data)?;
                 Ok(Some(value))
             }
             None => Ok(None),
@@ -500,6 +951,201 @@ impl Cache for RedisCache {
         .await
     }
 
+    async fn hgetall<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+    ) -> CacheResult<Vec<(String, T)>> {
+        let result: HashMap<String, Vec<u8>> = self.query(redis::cmd("HGETALL").arg(key)).await?;
+        let mut fields = result
+            .into_iter()
+            .map(|(field, data)| Ok((field, self.codec.decode(&data)?)))
+            .collect::<CacheResult<Vec<(String, T)>>>()?;
+        fields.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(fields)
+    }
+
+    async fn lpush<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<usize> {
+        let serialized = serde_json::to_string(value)?;
+        self.query(redis::cmd("LPUSH").arg(key).arg(serialized))
+            .await
+    }
+
+    async fn rpush<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> CacheResult<usize> {
+        let serialized = serde_json::to_string(value)?;
+        self.query(redis::cmd("RPUSH").arg(key).arg(serialized))
+            .await
+    }
+
+    async fn lpop<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>> {
+        let result: Option<String> = self.query(redis::cmd("LPOP").arg(key)).await?;
+        match result {
+            Some(data) => Ok(Some(serde_json::from_str(&data)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn rpop<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Option<T>> {
+        let result: Option<String> = self.query(redis::cmd("RPOP").arg(key)).await?;
+        match result {
+            Some(data) => Ok(Some(serde_json::from_str(&data)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn lrange<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> CacheResult<Vec<T>> {
+        let result: Vec<String> = self
+            .query(redis::cmd("LRANGE").arg(key).arg(start).arg(stop))
+            .await?;
+        result
+            .iter()
+            .map(|data| serde_json::from_str(data).map_err(CacheError::from))
+            .collect()
+    }
+
+    async fn ltrim(&self, key: &str, start: i64, stop: i64) -> CacheResult<()> {
+        self.query(redis::cmd("LTRIM").arg(key).arg(start).arg(stop))
+            .await
+    }
+
+    async fn llen(&self, key: &str) -> CacheResult<usize> {
+        self.query(redis::cmd("LLEN").arg(key)).await
+    }
+
+    async fn sadd<T: Serialize + Send + Sync>(&self, key: &str, member: &T) -> CacheResult<bool> {
+        let serialized = serde_json::to_string(member)?;
+        let added: usize = self
+            .query(redis::cmd("SADD").arg(key).arg(serialized))
+            .await?;
+        Ok(added > 0)
+    }
+
+    async fn srem<T: Serialize + Send + Sync>(&self, key: &str, member: &T) -> CacheResult<bool> {
+        let serialized = serde_json::to_string(member)?;
+        let removed: usize = self
+            .query(redis::cmd("SREM").arg(key).arg(serialized))
+            .await?;
+        Ok(removed > 0)
+    }
+
+    async fn smembers<T: DeserializeOwned + Send + Sync>(&self, key: &str) -> CacheResult<Vec<T>> {
+        let result: Vec<String> = self.query(redis::cmd("SMEMBERS").arg(key)).await?;
+        result
+            .iter()
+            .map(|data| serde_json::from_str(data).map_err(CacheError::from))
+            .collect()
+    }
+
+    async fn sismember<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+    ) -> CacheResult<bool> {
+        let serialized = serde_json::to_string(member)?;
+        self.query(redis::cmd("SISMEMBER").arg(key).arg(serialized))
+            .await
+    }
+
+    async fn zadd<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+        score: f64,
+    ) -> CacheResult<bool> {
+        let serialized = serde_json::to_string(member)?;
+        let added: usize = self
+            .query(redis::cmd("ZADD").arg(key).arg(score).arg(serialized))
+            .await?;
+        Ok(added > 0)
+    }
+
+    async fn zrange_by_score<T: DeserializeOwned + Send + Sync>(
+        &self,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> CacheResult<Vec<(T, f64)>> {
+        let result: Vec<(String, f64)> = self
+            .query(
+                redis::cmd("ZRANGEBYSCORE")
+                    .arg(key)
+                    .arg(min)
+                    .arg(max)
+                    .arg("WITHSCORES"),
+            )
+            .await?;
+        result
+            .iter()
+            .map(|(data, score)| Ok((serde_json::from_str(data)?, *score)))
+            .collect()
+    }
+
+    async fn zincr_by<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+        delta: f64,
+    ) -> CacheResult<f64> {
+        let serialized = serde_json::to_string(member)?;
+        self.query(redis::cmd("ZINCRBY").arg(key).arg(delta).arg(serialized))
+            .await
+    }
+
+    async fn zrank<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        member: &T,
+    ) -> CacheResult<Option<usize>> {
+        let serialized = serde_json::to_string(member)?;
+        self.query(redis::cmd("ZRANK").arg(key).arg(serialized))
+            .await
+    }
+
+    async fn try_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool> {
+        let reply: Option<String> = self
+            .query(
+                redis::cmd("SET")
+                    .arg(key)
+                    .arg(token)
+                    .arg("NX")
+                    .arg("PX")
+                    .arg(lease_millis(lease)),
+            )
+            .await?;
+        Ok(reply.is_some())
+    }
+
+    async fn unlock(&self, key: &str, token: &str) -> CacheResult<bool> {
+        let removed: i64 = self
+            .query(
+                redis::cmd("EVAL")
+                    .arg(UNLOCK_SCRIPT)
+                    .arg(1)
+                    .arg(key)
+                    .arg(token),
+            )
+            .await?;
+        Ok(removed > 0)
+    }
+
+    async fn renew_lock(&self, key: &str, token: &str, lease: Duration) -> CacheResult<bool> {
+        let renewed: i64 = self
+            .query(
+                redis::cmd("EVAL")
+                    .arg(RENEW_LOCK_SCRIPT)
+                    .arg(1)
+                    .arg(key)
+                    .arg(token)
+                    .arg(lease_millis(lease)),
+            )
+            .await?;
+        Ok(renewed > 0)
+    }
+
     async fn info(&self, key: Option<String>) -> CacheResult<String> {
         self.execute(|mut conn| async move {
             match conn {
@@ -527,6 +1173,14 @@ impl Cache for RedisCache {
         })
         .await
     }
+
+    fn stats(&self) -> Vec<NamespaceStats> {
+        self.stats.snapshot()
+    }
+
+    fn record_load(&self, key: &str, elapsed: Duration) {
+        self.stats.record_load(key, elapsed);
+    }
 }
 
 /// Redis缓存管理器
@@ -578,3 +1232,40 @@ impl CacheManager for RedisCacheManager {
         Ok(cache_guard.as_ref().unwrap().clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_connection_info() {
+        // 密码中的特殊字符、用户名和TLS设置都沿用集群配置，不经过URL拼接
+        let template = ConnectionInfo {
+            addr: ConnectionAddr::TcpTls {
+                host: "10.0.0.1".to_string(),
+                port: 7000,
+                insecure: false,
+            },
+            redis: redis::RedisConnectionInfo {
+                db: 0,
+                username: Some("admin".to_string()),
+                password: Some("p@ss:w/rd".to_string()),
+            },
+        };
+
+        let info = node_connection_info(&template, "10.0.0.2:7001").unwrap();
+        assert!(matches!(
+            info.addr,
+            ConnectionAddr::TcpTls { ref host, port: 7001, .. } if host == "10.0.0.2"
+        ));
+        assert_eq!(info.redis.username.as_deref(), Some("admin"));
+        assert_eq!(info.redis.password.as_deref(), Some("p@ss:w/rd"));
+
+        let plain = "redis://10.0.0.1:7000".into_connection_info().unwrap();
+        assert!(matches!(
+            node_connection_info(&plain, "::1:7002").unwrap().addr,
+            ConnectionAddr::Tcp(ref host, 7002) if host == "::1"
+        ));
+        assert!(node_connection_info(&plain, "10.0.0.2").is_err());
+    }
+}
@@ -0,0 +1,199 @@
+//! 本地缓存快照模块
+//!
+//! 将本地缓存中的字符串和哈希表连同过期时间保存到快照文件，重启后重新加载，
+//! 避免单机部署时重启导致登录令牌等数据丢失。
+//! 1. 过期时间以Unix毫秒时间戳保存，停机期间同样计入存活时间，加载时跳过已过期的数据
+//! 2. 值按缓存中的原始字节保存，与编码方式无关
+//! 3. 先写入临时文件再重命名，写入过程中进程退出不会损坏已有快照
+//! 4. 列表、集合、有序集合和锁不保存
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use super::{CacheError, CacheResult};
+
+/// 快照文件的魔数
+const MAGIC: &[u8; 4] = b"RYCS";
+/// 快照文件格式版本
+const VERSION: u8 = 1;
+/// 文件头长度
+const HEADER_LEN: usize = MAGIC.len() + 1;
+
+/// 本地缓存快照配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct SnapshotConfig {
+    /// 是否启用快照，启用后启动时加载快照，运行期间定时保存，停机时再保存一次
+    #[serde(default)]
+    pub enabled: bool,
+    /// 快照文件路径
+    #[serde(default = "default_path")]
+    pub path: String,
+    /// 定时保存的间隔（秒），0表示只在停机时保存
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+}
+
+fn default_path() -> String {
+    "data/local_cache.snapshot".to_string()
+}
+
+fn default_interval() -> u64 {
+    300
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_path(),
+            interval: default_interval(),
+        }
+    }
+}
+
+/// 快照中的值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) enum SnapshotValue {
+    /// 字符串
+    String(Vec<u8>),
+    /// 哈希表
+    Hash(Vec<(String, Vec<u8>)>),
+}
+
+/// 快照中的一个键
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SnapshotEntry {
+    pub key: String,
+    pub value: SnapshotValue,
+    /// 过期时间点的Unix毫秒时间戳，None 表示永不过期
+    pub expires_at: Option<u64>,
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// 将进程内的过期时间点换算为Unix毫秒时间戳
+pub(crate) fn to_unix_millis(expires_at: Instant) -> u64 {
+    let remaining = expires_at.saturating_duration_since(Instant::now());
+    now_millis() + remaining.as_millis() as u64
+}
+
+/// 将Unix毫秒时间戳换算为进程内的过期时间点，已过期时返回None
+pub(crate) fn from_unix_millis(expires_at: u64) -> Option<Instant> {
+    let remaining = expires_at.checked_sub(now_millis()).filter(|ms| *ms > 0)?;
+    Some(Instant::now() + Duration::from_millis(remaining))
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+/// 将快照写入文件，先写临时文件再重命名
+pub(crate) async fn write_snapshot(path: &Path, entries: &[SnapshotEntry]) -> CacheResult<()> {
+    let body = bincode::serialize(entries).map_err(|e| CacheError::Serialization(e.to_string()))?;
+    let mut data = Vec::with_capacity(HEADER_LEN + body.len());
+    data.extend_from_slice(MAGIC);
+    data.push(VERSION);
+    data.extend_from_slice(&body);
+
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| CacheError::Other(format!("创建快照目录失败: {}", e)))?;
+    }
+    let temp = temp_path(path);
+    tokio::fs::write(&temp, &data)
+        .await
+        .map_err(|e| CacheError::Other(format!("写入快照文件失败: {}", e)))?;
+    tokio::fs::rename(&temp, path)
+        .await
+        .map_err(|e| CacheError::Other(format!("替换快照文件失败: {}", e)))
+}
+
+/// 读取快照文件，文件不存在时返回None
+pub(crate) async fn read_snapshot(path: &Path) -> CacheResult<Option<Vec<SnapshotEntry>>> {
+    let data = match tokio::fs::read(path).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(CacheError::Other(format!("读取快照文件失败: {}", e))),
+    };
+    if data.len() < HEADER_LEN || &data[..MAGIC.len()] != MAGIC {
+        return Err(CacheError::Deserialization(
+            "快照文件格式不正确".to_string(),
+        ));
+    }
+    if data[MAGIC.len()] != VERSION {
+        return Err(CacheError::Deserialization(format!(
+            "不支持的快照文件版本: {}",
+            data[MAGIC.len()]
+        )));
+    }
+    bincode::deserialize(&data[HEADER_LEN..])
+        .map(Some)
+        .map_err(|e| CacheError::Deserialization(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("ruoyi_snapshot_{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn test_unix_millis_conversion() {
+        let expires_at = Instant::now() + Duration::from_secs(60);
+        let millis = to_unix_millis(expires_at);
+        let restored = from_unix_millis(millis).unwrap();
+        let diff = if restored > expires_at {
+            restored - expires_at
+        } else {
+            expires_at - restored
+        };
+        assert!(diff < Duration::from_millis(50));
+
+        assert!(from_unix_millis(now_millis() - 1000).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_write_and_read() {
+        let path = temp_file("round_trip.snapshot");
+        let entries = vec![
+            SnapshotEntry {
+                key: "login_tokens:a".to_string(),
+                value: SnapshotValue::String(b"\"token\"".to_vec()),
+                expires_at: Some(now_millis() + 60_000),
+            },
+            SnapshotEntry {
+                key: "hash".to_string(),
+                value: SnapshotValue::Hash(vec![("f".to_string(), vec![0, 0x12, 1])]),
+                expires_at: None,
+            },
+        ];
+        write_snapshot(&path, &entries).await.unwrap();
+        assert!(!temp_path(&path).exists(), "临时文件应已被重命名");
+        assert_eq!(read_snapshot(&path).await.unwrap(), Some(entries));
+
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(read_snapshot(&path).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_file() {
+        let path = temp_file("invalid.snapshot");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, b"not a snapshot").unwrap();
+        assert!(read_snapshot(&path).await.is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+}
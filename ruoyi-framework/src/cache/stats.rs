@@ -0,0 +1,187 @@
+//! 缓存统计模块
+//!
+//! 按键的前缀（如 `sys_dict:`、`login_tokens:`）统计命中、未命中、加载耗时和淘汰次数
+
+use dashmap::DashMap;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use utoipa::ToSchema;
+
+/// 没有前缀的键归入的命名空间
+pub const DEFAULT_NAMESPACE: &str = "default";
+
+/// 单个命名空间的计数器
+#[derive(Debug, Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    loads: AtomicU64,
+    load_time_micros: AtomicU64,
+    evictions: AtomicU64,
+}
+
+/// 命名空间统计快照
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceStats {
+    /// 命名空间（键前缀）
+    pub namespace: String,
+    /// 命中次数
+    pub hits: u64,
+    /// 未命中次数
+    pub misses: u64,
+    /// 命中率（0~1），没有访问时为0
+    pub hit_rate: f64,
+    /// 未命中后从数据源加载的次数
+    pub loads: u64,
+    /// 加载总耗时（毫秒）
+    pub total_load_millis: f64,
+    /// 平均加载耗时（毫秒）
+    pub avg_load_millis: f64,
+    /// 因容量不足或过期被淘汰的次数
+    pub evictions: u64,
+}
+
+/// 按命名空间统计的缓存计数器
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    namespaces: DashMap<String, Arc<Counters>>,
+}
+
+impl CacheStats {
+    /// 创建空的统计
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 获取键所属的命名空间，即第一个冒号及之前的部分
+    pub fn namespace_of(key: &str) -> &str {
+        match key.find(':') {
+            Some(index) => &key[..=index],
+            None => DEFAULT_NAMESPACE,
+        }
+    }
+
+    fn counters(&self, key: &str) -> Arc<Counters> {
+        let namespace = Self::namespace_of(key);
+        if let Some(counters) = self.namespaces.get(namespace) {
+            return counters.value().clone();
+        }
+        self.namespaces
+            .entry(namespace.to_string())
+            .or_default()
+            .value()
+            .clone()
+    }
+
+    /// 记录一次读取，hit为true表示命中
+    pub fn record_lookup(&self, key: &str, hit: bool) {
+        let counters = self.counters(key);
+        if hit {
+            counters.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            counters.misses.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// 记录一次未命中后从数据源加载的耗时
+    pub fn record_load(&self, key: &str, elapsed: Duration) {
+        let counters = self.counters(key);
+        counters.loads.fetch_add(1, Ordering::Relaxed);
+        counters
+            .load_time_micros
+            .fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// 记录一次淘汰
+    pub fn record_eviction(&self, key: &str) {
+        self.counters(key).evictions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 获取所有命名空间的统计快照，按命名空间排序
+    pub fn snapshot(&self) -> Vec<NamespaceStats> {
+        let mut stats: Vec<NamespaceStats> = self
+            .namespaces
+            .iter()
+            .map(|item| {
+                let counters = item.value();
+                let hits = counters.hits.load(Ordering::Relaxed);
+                let misses = counters.misses.load(Ordering::Relaxed);
+                let loads = counters.loads.load(Ordering::Relaxed);
+                let total_load_millis =
+                    counters.load_time_micros.load(Ordering::Relaxed) as f64 / 1000.0;
+                NamespaceStats {
+                    namespace: item.key().clone(),
+                    hits,
+                    misses,
+                    hit_rate: if hits + misses > 0 {
+                        hits as f64 / (hits + misses) as f64
+                    } else {
+                        0.0
+                    },
+                    loads,
+                    total_load_millis,
+                    avg_load_millis: if loads > 0 {
+                        total_load_millis / loads as f64
+                    } else {
+                        0.0
+                    },
+                    evictions: counters.evictions.load(Ordering::Relaxed),
+                }
+            })
+            .collect();
+        stats.sort_by(|a, b| a.namespace.cmp(&b.namespace));
+        stats
+    }
+
+    /// 清空所有统计
+    pub fn reset(&self) {
+        self.namespaces.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespace_of() {
+        assert_eq!(
+            CacheStats::namespace_of("sys_dict:sys_user_sex"),
+            "sys_dict:"
+        );
+        assert_eq!(
+            CacheStats::namespace_of("login_tokens:a:b"),
+            "login_tokens:"
+        );
+        assert_eq!(CacheStats::namespace_of("plain"), DEFAULT_NAMESPACE);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let stats = CacheStats::new();
+        stats.record_lookup("sys_dict:a", true);
+        stats.record_lookup("sys_dict:b", true);
+        stats.record_lookup("sys_dict:c", false);
+        stats.record_load("sys_dict:c", Duration::from_millis(4));
+        stats.record_load("sys_dict:d", Duration::from_millis(2));
+        stats.record_eviction("sys_config:x");
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].namespace, "sys_config:");
+        assert_eq!(snapshot[0].evictions, 1);
+
+        let dict = &snapshot[1];
+        assert_eq!(dict.hits, 2);
+        assert_eq!(dict.misses, 1);
+        assert!((dict.hit_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(dict.loads, 2);
+        assert!((dict.avg_load_millis - 3.0).abs() < 1e-9);
+
+        stats.reset();
+        assert!(stats.snapshot().is_empty());
+    }
+}
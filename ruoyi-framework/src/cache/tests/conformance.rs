@@ -0,0 +1,768 @@
+//! 缓存一致性测试
+//!
+//! 同一组用例分别在本地缓存和Redis缓存上运行，确保两种实现的行为一致。
+//! Redis用例需要运行Redis服务器，连接失败时跳过。
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+use crate::cache::{
+    Cache, CacheKeyType, CacheManager, CodecConfig, CodecFormat, CodecRule, LocalCache,
+    LocalCacheConfig, LocalCacheManager, MultiLevelCache, RedisCache, RedisCacheManager,
+    RedisConfig, RedisConnectionType,
+};
+use crate::config::cache::{CacheSettings, CacheType};
+
+async fn local_cache() -> LocalCache {
+    LocalCacheManager::new(Arc::new(LocalCacheConfig::default()))
+        .get_cache()
+        .await
+        .unwrap()
+}
+
+fn redis_config() -> RedisConfig {
+    RedisConfig {
+        connection_type: RedisConnectionType::Standalone,
+        url: Some("redis://127.0.0.1:6379".to_string()),
+        password: Some("123456".to_string()),
+        db: Some(0),
+        ..Default::default()
+    }
+}
+
+async fn redis_cache() -> Option<RedisCache> {
+    match RedisCacheManager::new(Arc::new(redis_config()))
+        .get_cache()
+        .await
+    {
+        Ok(cache) => Some(cache),
+        Err(e) => {
+            println!("Redis不可用，跳过测试: {}", e);
+            None
+        }
+    }
+}
+
+/// 默认使用MessagePack并压缩较大的值，`codec_bincode:` 前缀使用bincode
+fn codec_config() -> CodecConfig {
+    CodecConfig {
+        format: CodecFormat::Msgpack,
+        compress_threshold: 256,
+        rules: vec![CodecRule {
+            prefix: "codec_bincode:".to_string(),
+            format: CodecFormat::Bincode,
+            compress_threshold: None,
+        }],
+    }
+}
+
+async fn redis_cache_with_codec(codec: CodecConfig) -> Option<RedisCache> {
+    let config = RedisConfig {
+        codec,
+        ..redis_config()
+    };
+    RedisCacheManager::new(Arc::new(config))
+        .get_cache()
+        .await
+        .ok()
+}
+
+/// Redis不可用时多级缓存降级为本地缓存，两种情况都应满足同样的用例
+async fn multi_cache() -> MultiLevelCache {
+    let settings = CacheSettings {
+        enabled: true,
+        cache_type: CacheType::Multi,
+        local: Arc::new(LocalCacheConfig::default()),
+        redis: Arc::new(redis_config()),
+        multi: Arc::new(Default::default()),
+    };
+    MultiLevelCache::new(Arc::new(settings)).await.unwrap()
+}
+
+/// 字符串键的过期时间
+async fn check_string_ttl<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:string_ttl", prefix);
+    cache.del(&key).await.unwrap();
+    assert_eq!(cache.ttl(&key).await.unwrap(), -2, "不存在的键应返回-2");
+
+    cache.set(&key, &"value").await.unwrap();
+    assert_eq!(cache.ttl(&key).await.unwrap(), -1, "未设置过期时间应返回-1");
+
+    cache
+        .set_ex(&key, &"value", Duration::from_secs(100))
+        .await
+        .unwrap();
+    let ttl = cache.ttl(&key).await.unwrap();
+    assert!((99..=100).contains(&ttl), "剩余过期时间不正确: {}", ttl);
+
+    // 重新set会清除过期时间
+    cache.set(&key, &"value").await.unwrap();
+    assert_eq!(cache.ttl(&key).await.unwrap(), -1);
+
+    cache.expire(&key, Duration::from_secs(50)).await.unwrap();
+    let ttl = cache.ttl(&key).await.unwrap();
+    assert!(
+        (49..=50).contains(&ttl),
+        "expire后剩余过期时间不正确: {}",
+        ttl
+    );
+
+    cache.del(&key).await.unwrap();
+}
+
+/// 字符串键到期后自动删除
+async fn check_string_expiry<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:string_expiry", prefix);
+    cache
+        .set_ex(&key, &"value", Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(
+        cache.get::<String>(&key).await.unwrap(),
+        Some("value".to_string())
+    );
+
+    sleep(Duration::from_millis(1500)).await;
+    assert_eq!(cache.get::<String>(&key).await.unwrap(), None);
+    assert!(!cache.exists(&key).await.unwrap());
+    assert_eq!(cache.ttl(&key).await.unwrap(), -2);
+}
+
+/// 计数器递增不改变过期时间
+async fn check_incr_keeps_ttl<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:incr_ttl", prefix);
+    cache.del(&key).await.unwrap();
+    assert_eq!(cache.incr(&key).await.unwrap(), 1);
+    cache.expire(&key, Duration::from_secs(100)).await.unwrap();
+    assert_eq!(cache.incr(&key).await.unwrap(), 2);
+    assert_eq!(cache.decr(&key).await.unwrap(), 1);
+    let ttl = cache.ttl(&key).await.unwrap();
+    assert!(
+        (99..=100).contains(&ttl),
+        "递增后剩余过期时间不正确: {}",
+        ttl
+    );
+    cache.del(&key).await.unwrap();
+}
+
+/// 哈希表的过期时间
+async fn check_hash_expiry<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:hash_expiry", prefix);
+    cache.del(&key).await.unwrap();
+    cache.hset(&key, "field", &"value").await.unwrap();
+    assert_eq!(cache.ttl(&key).await.unwrap(), -1);
+
+    cache.expire(&key, Duration::from_secs(1)).await.unwrap();
+    let ttl = cache.ttl(&key).await.unwrap();
+    assert!((0..=1).contains(&ttl), "哈希表剩余过期时间不正确: {}", ttl);
+    assert!(cache.hexists(&key, "field").await.unwrap());
+
+    sleep(Duration::from_millis(1500)).await;
+    assert_eq!(cache.hget::<String>(&key, "field").await.unwrap(), None);
+    assert!(!cache.hexists(&key, "field").await.unwrap());
+    assert_eq!(cache.hlen(&key).await.unwrap(), 0);
+    assert!(cache.hkeys(&key).await.unwrap().is_empty());
+    assert!(!cache.exists(&key).await.unwrap());
+    assert_eq!(cache.ttl(&key).await.unwrap(), -2);
+
+    // 过期后重新写入的哈希表不再带有过期时间
+    cache.hset(&key, "field", &"value").await.unwrap();
+    assert_eq!(cache.ttl(&key).await.unwrap(), -1);
+    cache.del(&key).await.unwrap();
+}
+
+/// 过期时间为0时删除键
+async fn check_expire_zero<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:expire_zero", prefix);
+    cache.set(&key, &"value").await.unwrap();
+    cache.expire(&key, Duration::ZERO).await.unwrap();
+    assert!(!cache.exists(&key).await.unwrap());
+
+    // 对不存在的键设置过期时间不报错
+    cache.expire(&key, Duration::from_secs(10)).await.unwrap();
+    assert_eq!(cache.ttl(&key).await.unwrap(), -2);
+}
+
+/// 增量扫描能遍历全部匹配的键
+async fn check_scan<C: Cache>(cache: &C, prefix: &str) {
+    let pattern = format!("{}:scan:*", prefix);
+    for i in 0..25 {
+        cache
+            .set(&format!("{}:scan:{}", prefix, i), &i)
+            .await
+            .unwrap();
+    }
+    cache
+        .hset(&format!("{}:scan:hash", prefix), "field", &"value")
+        .await
+        .unwrap();
+    cache
+        .set(&format!("{}:other", prefix), &"value")
+        .await
+        .unwrap();
+
+    let mut cursor = 0;
+    let mut scanned = Vec::new();
+    loop {
+        let (next, keys) = cache.scan(cursor, &pattern, 10).await.unwrap();
+        scanned.extend(keys);
+        if next == 0 {
+            break;
+        }
+        cursor = next;
+    }
+    scanned.sort();
+    scanned.dedup();
+    assert_eq!(scanned.len(), 26, "扫描结果数量不正确: {:?}", scanned);
+    assert!(scanned
+        .iter()
+        .all(|k| k.starts_with(&format!("{}:scan:", prefix))));
+
+    let mut keys = cache.keys(&pattern).await.unwrap();
+    keys.sort();
+    assert_eq!(keys, scanned);
+
+    for key in scanned {
+        cache.del(&key).await.unwrap();
+    }
+    cache.del(&format!("{}:other", prefix)).await.unwrap();
+}
+
+/// 按键前缀统计命中和加载次数
+async fn check_stats<C: Cache>(cache: &C, prefix: &str) {
+    let namespace = format!("{}_stats:", prefix);
+    let key = format!("{}a", namespace);
+    cache.del(&key).await.unwrap();
+
+    assert_eq!(cache.get::<String>(&key).await.unwrap(), None);
+    cache.record_load(&key, Duration::from_millis(5));
+    cache.set(&key, &"value").await.unwrap();
+    assert!(cache.get::<String>(&key).await.unwrap().is_some());
+    assert!(cache.get::<String>(&key).await.unwrap().is_some());
+
+    let stats = cache.stats();
+    let item = stats
+        .iter()
+        .find(|s| s.namespace == namespace)
+        .expect("缺少命名空间统计");
+    assert_eq!(item.hits, 2);
+    assert_eq!(item.misses, 1);
+    assert_eq!(item.loads, 1);
+    assert!(item.avg_load_millis >= 5.0);
+
+    cache.del(&key).await.unwrap();
+}
+
+/// 使用非默认编码方式时字符串、哈希表和计数器的读写
+async fn check_codec<C: Cache>(cache: &C, prefix: &str) {
+    let rows: Vec<(String, Option<i64>)> =
+        (0..100).map(|i| (format!("用户{}", i), Some(i))).collect();
+    for namespace in ["codec_msgpack:", "codec_bincode:"] {
+        let key = format!("{}{}", namespace, prefix);
+        cache.del(&key).await.unwrap();
+
+        cache.set(&key, &rows).await.unwrap();
+        assert_eq!(
+            cache.get::<Vec<(String, Option<i64>)>>(&key).await.unwrap(),
+            Some(rows.clone())
+        );
+        cache
+            .set_ex(&key, &"短值", Duration::from_secs(100))
+            .await
+            .unwrap();
+        assert_eq!(
+            cache.get::<String>(&key).await.unwrap(),
+            Some("短值".to_string())
+        );
+        cache.del(&key).await.unwrap();
+
+        cache.hset(&key, "rows", &rows).await.unwrap();
+        assert_eq!(
+            cache
+                .hget::<Vec<(String, Option<i64>)>>(&key, "rows")
+                .await
+                .unwrap(),
+            Some(rows.clone())
+        );
+        cache.del(&key).await.unwrap();
+    }
+
+    // 计数器通过incr创建，始终以数字文本保存
+    let key = format!("codec_msgpack:{}:counter", prefix);
+    cache.del(&key).await.unwrap();
+    assert_eq!(cache.incr(&key).await.unwrap(), 1);
+    assert_eq!(cache.incr(&key).await.unwrap(), 2);
+    assert_eq!(cache.decr(&key).await.unwrap(), 1);
+    cache.del(&key).await.unwrap();
+}
+
+/// 列表的插入、弹出、区间读取和裁剪
+async fn check_list<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:list", prefix);
+    cache.del(&key).await.unwrap();
+
+    assert_eq!(cache.rpush(&key, &"b").await.unwrap(), 1);
+    assert_eq!(cache.rpush(&key, &"c").await.unwrap(), 2);
+    assert_eq!(cache.lpush(&key, &"a").await.unwrap(), 3);
+    assert_eq!(cache.rpush(&key, &"d").await.unwrap(), 4);
+    assert_eq!(cache.llen(&key).await.unwrap(), 4);
+
+    let all: Vec<String> = cache.lrange(&key, 0, -1).await.unwrap();
+    assert_eq!(all, vec!["a", "b", "c", "d"]);
+    let tail: Vec<String> = cache.lrange(&key, -2, 100).await.unwrap();
+    assert_eq!(tail, vec!["c", "d"]);
+    let empty: Vec<String> = cache.lrange(&key, 3, 1).await.unwrap();
+    assert!(empty.is_empty());
+
+    cache.ltrim(&key, 1, -1).await.unwrap();
+    let trimmed: Vec<String> = cache.lrange(&key, 0, -1).await.unwrap();
+    assert_eq!(trimmed, vec!["b", "c", "d"]);
+
+    assert_eq!(cache.lpop::<String>(&key).await.unwrap(), Some("b".into()));
+    assert_eq!(cache.rpop::<String>(&key).await.unwrap(), Some("d".into()));
+    assert_eq!(cache.rpop::<String>(&key).await.unwrap(), Some("c".into()));
+    assert_eq!(cache.rpop::<String>(&key).await.unwrap(), None);
+
+    // 与Redis一致，列表为空时键被删除
+    assert!(!cache.exists(&key).await.unwrap());
+    assert_eq!(cache.llen(&key).await.unwrap(), 0);
+}
+
+/// 集合的添加、删除和成员判断
+async fn check_set<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:set", prefix);
+    cache.del(&key).await.unwrap();
+
+    assert!(cache.sadd(&key, &1_i64).await.unwrap());
+    assert!(cache.sadd(&key, &2_i64).await.unwrap());
+    assert!(!cache.sadd(&key, &1_i64).await.unwrap());
+    assert!(cache.sismember(&key, &2_i64).await.unwrap());
+    assert!(!cache.sismember(&key, &3_i64).await.unwrap());
+
+    let mut members: Vec<i64> = cache.smembers(&key).await.unwrap();
+    members.sort();
+    assert_eq!(members, vec![1, 2]);
+
+    assert!(cache.srem(&key, &1_i64).await.unwrap());
+    assert!(!cache.srem(&key, &1_i64).await.unwrap());
+    assert!(cache.srem(&key, &2_i64).await.unwrap());
+    assert!(!cache.exists(&key).await.unwrap());
+    assert!(cache.smembers::<i64>(&key).await.unwrap().is_empty());
+}
+
+/// 有序集合的分数区间、累加和排名
+async fn check_sorted_set<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:zset", prefix);
+    cache.del(&key).await.unwrap();
+
+    assert!(cache.zadd(&key, &"post:1", 10.0).await.unwrap());
+    assert!(cache.zadd(&key, &"post:2", 5.0).await.unwrap());
+    assert!(cache.zadd(&key, &"post:3", 20.0).await.unwrap());
+    assert!(!cache.zadd(&key, &"post:2", 15.0).await.unwrap());
+
+    let range: Vec<(String, f64)> = cache.zrange_by_score(&key, 10.0, 15.0).await.unwrap();
+    assert_eq!(
+        range,
+        vec![("post:1".to_string(), 10.0), ("post:2".to_string(), 15.0)]
+    );
+    let all: Vec<(String, f64)> = cache
+        .zrange_by_score(&key, f64::NEG_INFINITY, f64::INFINITY)
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 3);
+
+    assert_eq!(cache.zincr_by(&key, &"post:1", 7.5).await.unwrap(), 17.5);
+    assert_eq!(cache.zincr_by(&key, &"post:4", 1.0).await.unwrap(), 1.0);
+    assert_eq!(cache.zrank(&key, &"post:4").await.unwrap(), Some(0));
+    assert_eq!(cache.zrank(&key, &"post:2").await.unwrap(), Some(1));
+    assert_eq!(cache.zrank(&key, &"post:1").await.unwrap(), Some(2));
+    assert_eq!(cache.zrank(&key, &"post:3").await.unwrap(), Some(3));
+    assert_eq!(cache.zrank(&key, &"post:5").await.unwrap(), None);
+
+    cache.expire(&key, Duration::from_secs(60)).await.unwrap();
+    let ttl = cache.ttl(&key).await.unwrap();
+    assert!(ttl > 0 && ttl <= 60, "ttl: {}", ttl);
+    let keys = cache.keys(&format!("{}:zset", prefix)).await.unwrap();
+    assert_eq!(keys, vec![key.clone()]);
+
+    cache.del(&key).await.unwrap();
+    assert_eq!(cache.zrank(&key, &"post:1").await.unwrap(), None);
+}
+
+/// 对存储其他类型数据的键执行集合类操作时返回错误
+async fn check_wrong_type<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:wrong_type", prefix);
+    cache.set(&key, &"value").await.unwrap();
+    assert!(cache.lpush(&key, &"a").await.is_err());
+    assert!(cache.sadd(&key, &"a").await.is_err());
+
+    cache.del(&key).await.unwrap();
+    cache.sadd(&key, &"a").await.unwrap();
+    assert!(cache.rpush(&key, &"a").await.is_err());
+    assert!(cache.zadd(&key, &"a", 1.0).await.is_err());
+    assert!(cache.lrange::<String>(&key, 0, -1).await.is_err());
+
+    // 写入字符串会覆盖集合
+    cache.set(&key, &"value").await.unwrap();
+    assert_eq!(
+        cache.get::<String>(&key).await.unwrap(),
+        Some("value".to_string())
+    );
+    cache.del(&key).await.unwrap();
+}
+
+/// 集合类数据的过期时间
+async fn check_collection_expiry<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:list_expiry", prefix);
+    cache.rpush(&key, &"a").await.unwrap();
+    cache.expire(&key, Duration::from_secs(1)).await.unwrap();
+    sleep(Duration::from_millis(1100)).await;
+    assert!(!cache.exists(&key).await.unwrap());
+    assert_eq!(cache.llen(&key).await.unwrap(), 0);
+    assert_eq!(cache.ttl(&key).await.unwrap(), -2);
+}
+
+/// 键类型与Redis的TYPE命令一致，哈希表可以一次读取全部字段
+async fn check_key_type<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:key_type", prefix);
+    cache.del(&key).await.unwrap();
+    assert_eq!(cache.key_type(&key).await.unwrap(), CacheKeyType::None);
+
+    cache.set(&key, &"value").await.unwrap();
+    assert_eq!(cache.key_type(&key).await.unwrap(), CacheKeyType::String);
+
+    cache.del(&key).await.unwrap();
+    cache.hset(&key, "b", &2).await.unwrap();
+    cache.hset(&key, "a", &1).await.unwrap();
+    assert_eq!(cache.key_type(&key).await.unwrap(), CacheKeyType::Hash);
+    assert_eq!(
+        cache.hgetall::<i32>(&key).await.unwrap(),
+        vec![("a".to_string(), 1), ("b".to_string(), 2)]
+    );
+
+    cache.del(&key).await.unwrap();
+    assert!(cache.hgetall::<i32>(&key).await.unwrap().is_empty());
+    cache.rpush(&key, &"a").await.unwrap();
+    assert_eq!(cache.key_type(&key).await.unwrap(), CacheKeyType::List);
+    cache.del(&key).await.unwrap();
+    cache.sadd(&key, &"a").await.unwrap();
+    assert_eq!(cache.key_type(&key).await.unwrap(), CacheKeyType::Set);
+    cache.del(&key).await.unwrap();
+    cache.zadd(&key, &"a", 1.0).await.unwrap();
+    assert_eq!(cache.key_type(&key).await.unwrap(), CacheKeyType::ZSet);
+    cache.del(&key).await.unwrap();
+}
+
+/// 锁的加锁、续期、释放和租期到期
+async fn check_lock<C: Cache>(cache: &C, prefix: &str) {
+    let key = format!("{}:lock", prefix);
+    let lease = Duration::from_millis(300);
+    cache.del(&key).await.unwrap();
+
+    assert!(cache.try_lock(&key, "owner", lease).await.unwrap());
+    assert!(
+        !cache.try_lock(&key, "other", lease).await.unwrap(),
+        "锁被占用时不能重复加锁"
+    );
+    assert!(
+        !cache.unlock(&key, "other").await.unwrap(),
+        "不能释放他人的锁"
+    );
+    assert!(!cache.renew_lock(&key, "other", lease).await.unwrap());
+
+    sleep(Duration::from_millis(200)).await;
+    assert!(cache.renew_lock(&key, "owner", lease).await.unwrap());
+    sleep(Duration::from_millis(200)).await;
+    assert!(
+        !cache.try_lock(&key, "other", lease).await.unwrap(),
+        "续期后锁仍然有效"
+    );
+
+    assert!(cache.unlock(&key, "owner").await.unwrap());
+    assert!(
+        !cache.unlock(&key, "owner").await.unwrap(),
+        "重复释放应返回false"
+    );
+
+    // 租期到期后其他持有者可以加锁，原持有者不能再释放
+    assert!(cache
+        .try_lock(&key, "owner", Duration::from_millis(100))
+        .await
+        .unwrap());
+    sleep(Duration::from_millis(200)).await;
+    assert!(cache.try_lock(&key, "other", lease).await.unwrap());
+    assert!(!cache.unlock(&key, "owner").await.unwrap());
+    assert!(cache.unlock(&key, "other").await.unwrap());
+}
+
+#[tokio::test]
+async fn local_string_ttl() {
+    check_string_ttl(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_string_expiry() {
+    check_string_expiry(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_incr_keeps_ttl() {
+    check_incr_keeps_ttl(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_hash_expiry() {
+    check_hash_expiry(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_expire_zero() {
+    check_expire_zero(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_scan() {
+    check_scan(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_stats() {
+    check_stats(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_hash_eviction_stats() {
+    let cache = local_cache().await;
+    cache
+        .hset("conformance_evict:hash", "field", &"value")
+        .await
+        .unwrap();
+    cache
+        .expire("conformance_evict:hash", Duration::from_secs(1))
+        .await
+        .unwrap();
+    sleep(Duration::from_millis(1100)).await;
+    assert_eq!(
+        cache
+            .hget::<String>("conformance_evict:hash", "field")
+            .await
+            .unwrap(),
+        None
+    );
+
+    let stats = cache.stats();
+    let item = stats
+        .iter()
+        .find(|s| s.namespace == "conformance_evict:")
+        .unwrap();
+    assert_eq!(item.evictions, 1);
+    assert_eq!(item.misses, 1);
+}
+
+#[tokio::test]
+async fn local_list() {
+    check_list(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_set() {
+    check_set(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_sorted_set() {
+    check_sorted_set(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_wrong_type() {
+    check_wrong_type(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_collection_expiry() {
+    check_collection_expiry(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_lock() {
+    check_lock(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_key_type() {
+    check_key_type(&local_cache().await, "conformance").await;
+}
+
+#[tokio::test]
+async fn local_codec() {
+    let config = LocalCacheConfig {
+        codec: codec_config(),
+        ..Default::default()
+    };
+    check_codec(&LocalCache::new(Arc::new(config)), "conformance").await;
+}
+
+#[tokio::test]
+async fn redis_string_ttl() {
+    if let Some(cache) = redis_cache().await {
+        check_string_ttl(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_string_expiry() {
+    if let Some(cache) = redis_cache().await {
+        check_string_expiry(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_incr_keeps_ttl() {
+    if let Some(cache) = redis_cache().await {
+        check_incr_keeps_ttl(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_hash_expiry() {
+    if let Some(cache) = redis_cache().await {
+        check_hash_expiry(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_expire_zero() {
+    if let Some(cache) = redis_cache().await {
+        check_expire_zero(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_scan() {
+    if let Some(cache) = redis_cache().await {
+        check_scan(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_stats() {
+    if let Some(cache) = redis_cache().await {
+        check_stats(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_list() {
+    if let Some(cache) = redis_cache().await {
+        check_list(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_set() {
+    if let Some(cache) = redis_cache().await {
+        check_set(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_sorted_set() {
+    if let Some(cache) = redis_cache().await {
+        check_sorted_set(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_wrong_type() {
+    if let Some(cache) = redis_cache().await {
+        check_wrong_type(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_collection_expiry() {
+    if let Some(cache) = redis_cache().await {
+        check_collection_expiry(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_key_type() {
+    if let Some(cache) = redis_cache().await {
+        check_key_type(&cache, "conformance").await;
+    }
+}
+
+#[tokio::test]
+async fn multi_list() {
+    check_list(&multi_cache().await, "conformance_multi").await;
+}
+
+#[tokio::test]
+async fn multi_set() {
+    check_set(&multi_cache().await, "conformance_multi").await;
+}
+
+#[tokio::test]
+async fn multi_sorted_set() {
+    check_sorted_set(&multi_cache().await, "conformance_multi").await;
+}
+
+#[tokio::test]
+async fn multi_key_type() {
+    check_key_type(&multi_cache().await, "conformance_multi").await;
+}
+
+#[tokio::test]
+async fn redis_lock() {
+    if let Some(cache) = redis_cache().await {
+        check_lock(&cache, "conformance").await;
+    }
+}
+
+/// 分布式锁只使用Redis，Redis不可用时返回错误而不是降级到本地缓存
+#[tokio::test]
+async fn multi_lock() {
+    let cache = multi_cache().await;
+    if cache.is_in_fallback_mode() {
+        assert!(cache
+            .try_lock("conformance_multi:lock", "owner", Duration::from_secs(1))
+            .await
+            .is_err());
+    } else {
+        check_lock(&cache, "conformance_multi").await;
+    }
+}
+
+#[tokio::test]
+async fn redis_codec() {
+    if let Some(cache) = redis_cache_with_codec(codec_config()).await {
+        check_codec(&cache, "conformance").await;
+    }
+}
+
+/// 切换编码方式后仍能读取旧格式写入的数据
+#[tokio::test]
+async fn redis_mixed_codec() {
+    let (Some(json), Some(msgpack)) = (
+        redis_cache().await,
+        redis_cache_with_codec(codec_config()).await,
+    ) else {
+        return;
+    };
+    let old_key = "codec_msgpack:conformance:old";
+    let new_key = "codec_msgpack:conformance:new";
+    let value = vec!["a".repeat(300), "b".to_string()];
+    json.set(old_key, &value).await.unwrap();
+    msgpack.set(new_key, &value).await.unwrap();
+
+    assert_eq!(
+        msgpack.get::<Vec<String>>(old_key).await.unwrap(),
+        Some(value.clone())
+    );
+    assert_eq!(json.get::<Vec<String>>(new_key).await.unwrap(), Some(value));
+    json.del(old_key).await.unwrap();
+    json.del(new_key).await.unwrap();
+}
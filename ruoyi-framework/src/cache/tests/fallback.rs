@@ -0,0 +1,169 @@
+//! Redis无响应时的超时、熔断和多级缓存降级测试
+//!
+//! 使用一个只接受连接、从不回复的TCP服务模拟卡住的Redis，不依赖真实的Redis服务器。
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::cache::{
+    Cache, CacheError, CircuitBreakerConfig, CircuitState, LocalCacheConfig, MultiLevelCache,
+    RedisCache, RedisConfig, RedisConnectionType,
+};
+use crate::config::cache::{CacheSettings, CacheType};
+
+/// 启动一个接受连接但从不回复的服务，返回其地址
+async fn stalled_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                return;
+            };
+            tokio::spawn(async move {
+                let mut buf = [0u8; 1024];
+                while let Ok(n) = socket.read(&mut buf).await {
+                    if n == 0 {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+    format!("redis://{}", addr)
+}
+
+/// 启动一个模拟的哨兵，对任何请求都回复主节点地址，返回其地址和收到的查询次数
+async fn fake_sentinel(master: &str) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (host, port) = master
+        .trim_start_matches("redis://")
+        .rsplit_once(':')
+        .unwrap();
+    let reply = format!(
+        "*2\r\n${}\r\n{}\r\n${}\r\n{}\r\n",
+        host.len(),
+        host,
+        port.len(),
+        port
+    );
+    let queries = Arc::new(AtomicUsize::new(0));
+    let counter = queries.clone();
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                return;
+            };
+            let reply = reply.clone();
+            let counter = counter.clone();
+            tokio::spawn(async move {
+                let mut buf = [0u8; 1024];
+                while let Ok(n) = socket.read(&mut buf).await {
+                    if n == 0 || socket.write_all(reply.as_bytes()).await.is_err() {
+                        return;
+                    }
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }
+            });
+        }
+    });
+    (addr.to_string(), queries)
+}
+
+fn stalled_config(url: String) -> RedisConfig {
+    RedisConfig {
+        connection_type: RedisConnectionType::Standalone,
+        url: Some(url),
+        password: None,
+        db: None,
+        command_timeout: Some(100),
+        circuit_breaker: CircuitBreakerConfig {
+            enabled: true,
+            failure_threshold: 2,
+            reset_timeout: 60000,
+        },
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_command_timeout_trips_breaker() {
+    let config = stalled_config(stalled_server().await);
+    let redis = RedisCache::new(Arc::new(config)).await.unwrap();
+
+    for _ in 0..2 {
+        let start = Instant::now();
+        let result = redis.get::<String>("fallback:key").await;
+        assert!(matches!(result, Err(CacheError::Connection(_))));
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "命令应在超时后返回"
+        );
+    }
+    assert_eq!(redis.circuit_state(), CircuitState::Open);
+    assert!(!redis.is_available());
+
+    // 熔断期间立即失败
+    let start = Instant::now();
+    assert!(redis.set("fallback:key", &"value").await.is_err());
+    assert!(start.elapsed() < Duration::from_millis(50));
+}
+
+/// 旧主节点网络分区时命令只会超时，超时后应重新向哨兵查询主节点
+#[tokio::test]
+async fn test_sentinel_rediscover_on_timeout() {
+    let (sentinel, queries) = fake_sentinel(&stalled_server().await).await;
+    let config = RedisConfig {
+        connection_type: RedisConnectionType::Sentinel,
+        master_name: Some("mymaster".to_string()),
+        hosts: Some(vec![sentinel]),
+        ..stalled_config(String::new())
+    };
+    let redis = RedisCache::new(Arc::new(config)).await.unwrap();
+    assert_eq!(queries.load(Ordering::SeqCst), 1);
+
+    assert!(redis.get::<String>("fallback:key").await.is_err());
+    assert!(redis.get::<String>("fallback:key").await.is_err());
+    assert_eq!(queries.load(Ordering::SeqCst), 2);
+    assert_eq!(redis.circuit_state(), CircuitState::Open);
+}
+
+#[tokio::test]
+async fn test_multi_level_fallback_when_open() {
+    let settings = CacheSettings {
+        enabled: true,
+        cache_type: CacheType::Multi,
+        local: Arc::new(LocalCacheConfig::default()),
+        redis: Arc::new(stalled_config(stalled_server().await)),
+        multi: Arc::new(Default::default()),
+    };
+    let cache = MultiLevelCache::new(Arc::new(settings)).await.unwrap();
+    assert!(!cache.is_in_fallback_mode());
+
+    // 写入Redis超时，本地缓存仍写入成功；连续失败后熔断
+    cache.set("fallback:a", &"value").await.unwrap();
+    cache.set("fallback:b", &"value").await.unwrap();
+    assert!(cache.is_in_fallback_mode());
+
+    // 熔断期间普通操作由本地缓存处理，分布式锁直接返回错误，都不再等待Redis
+    let start = Instant::now();
+    assert_eq!(
+        cache.get::<String>("fallback:a").await.unwrap(),
+        Some("value".to_string())
+    );
+    assert_eq!(cache.get::<String>("fallback:missing").await.unwrap(), None);
+    assert_eq!(cache.rpush("fallback:list", &1).await.unwrap(), 1);
+    assert_eq!(
+        cache.lrange::<i32>("fallback:list", 0, -1).await.unwrap(),
+        vec![1]
+    );
+    assert!(cache
+        .try_lock("fallback:lock", "owner", Duration::from_secs(1))
+        .await
+        .is_err());
+    assert!(start.elapsed() < Duration::from_millis(50));
+}
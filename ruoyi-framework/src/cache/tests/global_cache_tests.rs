@@ -30,6 +30,7 @@ async fn a_test_global_cache_local() {
         max_capacity: 1000,
         default_ttl: 3600,
         cleanup_interval: 60,
+        ..Default::default()
     };
 
     let settings = CacheSettings {
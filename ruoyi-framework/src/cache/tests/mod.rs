@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod conformance;
+#[cfg(test)]
+mod fallback;
+#[cfg(test)]
+mod snapshot;
@@ -0,0 +1,98 @@
+//! 本地缓存快照的保存和恢复测试
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::cache::{Cache, CodecConfig, CodecFormat, LocalCache, LocalCacheConfig, SnapshotConfig};
+
+fn snapshot_cache(name: &str) -> LocalCache {
+    let path = std::env::temp_dir()
+        .join(format!("ruoyi_cache_snapshot_{}", std::process::id()))
+        .join(format!("{}.snapshot", name));
+    let _ = std::fs::remove_file(&path);
+    LocalCache::new(Arc::new(LocalCacheConfig {
+        codec: CodecConfig {
+            format: CodecFormat::Msgpack,
+            ..Default::default()
+        },
+        snapshot: SnapshotConfig {
+            enabled: true,
+            path: path.to_string_lossy().into_owned(),
+            interval: 0,
+        },
+        ..Default::default()
+    }))
+}
+
+/// 模拟重启：使用相同配置创建一个新的空缓存
+fn restart(cache: &LocalCache) -> LocalCache {
+    LocalCache::new(cache.get_config())
+}
+
+#[tokio::test]
+async fn test_snapshot_round_trip() {
+    let cache = snapshot_cache("round_trip");
+    cache
+        .set_ex("login_tokens:a", &"user-a", Duration::from_secs(600))
+        .await
+        .unwrap();
+    cache.set("sys_config:k", &vec![1, 2, 3]).await.unwrap();
+    cache.hset("hash", "f1", &"v1").await.unwrap();
+    cache.hset("hash", "f2", &2).await.unwrap();
+    cache
+        .expire("hash", Duration::from_secs(300))
+        .await
+        .unwrap();
+    // 列表不保存
+    cache.rpush("list", &1).await.unwrap();
+
+    assert_eq!(cache.save_snapshot().await.unwrap(), 3);
+
+    let restored = restart(&cache);
+    assert_eq!(restored.load_snapshot().await.unwrap(), 3);
+    assert_eq!(
+        restored.get::<String>("login_tokens:a").await.unwrap(),
+        Some("user-a".to_string())
+    );
+    assert_eq!(
+        restored.get::<Vec<i32>>("sys_config:k").await.unwrap(),
+        Some(vec![1, 2, 3])
+    );
+    assert_eq!(
+        restored.hget::<String>("hash", "f1").await.unwrap(),
+        Some("v1".to_string())
+    );
+    assert_eq!(restored.hget::<i32>("hash", "f2").await.unwrap(), Some(2));
+    assert!(!restored.exists("list").await.unwrap());
+
+    // 剩余存活时间随快照保留
+    let ttl = restored.ttl("login_tokens:a").await.unwrap();
+    assert!((599..=600).contains(&ttl), "ttl = {}", ttl);
+    let ttl = restored.ttl("hash").await.unwrap();
+    assert!((299..=300).contains(&ttl), "ttl = {}", ttl);
+    assert_eq!(restored.ttl("sys_config:k").await.unwrap(), -1);
+}
+
+#[tokio::test]
+async fn test_snapshot_skips_expired() {
+    let cache = snapshot_cache("expired");
+    cache
+        .set_ex("short", &"v", Duration::from_millis(200))
+        .await
+        .unwrap();
+    cache.set("long", &"v").await.unwrap();
+    assert_eq!(cache.save_snapshot().await.unwrap(), 2);
+
+    // 停机期间过期的键不再恢复
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    let restored = restart(&cache);
+    assert_eq!(restored.load_snapshot().await.unwrap(), 1);
+    assert!(!restored.exists("short").await.unwrap());
+    assert!(restored.exists("long").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_missing_snapshot() {
+    let cache = snapshot_cache("missing");
+    assert_eq!(cache.load_snapshot().await.unwrap(), 0);
+}
@@ -2,6 +2,7 @@
 //! 数据库配置模块
 
 use serde::Deserialize;
+use std::collections::HashMap;
 
 /// 数据库配置详情
 #[derive(Debug, Deserialize, Clone)]
@@ -23,6 +24,69 @@ pub struct DbSettings {
     /// 空闲超时时间（秒）
     #[serde(default = "default_idle_timeout")]
     pub idle_timeout: u64,
+    /// 数据库迁移配置
+    #[serde(default)]
+    pub migration: MigrationSettings,
+    /// 只读副本配置
+    #[serde(default)]
+    pub replica: ReplicaSettings,
+    /// 字段加密配置
+    #[serde(default)]
+    pub encryption: EncryptionSettings,
+}
+
+/// 字段加密配置
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct EncryptionSettings {
+    /// 当前用于加密的密钥编号，为空时敏感字段以明文存储
+    #[serde(default)]
+    pub current_key: Option<String>,
+    /// 密钥编号到密钥（Base64编码的32字节）的映射，轮换密钥后旧密钥需保留到数据重新加密完成
+    #[serde(default)]
+    pub keys: HashMap<String, String>,
+    /// 盲索引密钥，用于加密字段的等值查询，启用加密时必须配置且不能使用公开的默认值
+    #[serde(default)]
+    pub blind_index_key: Option<String>,
+}
+
+/// 只读副本配置
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReplicaSettings {
+    /// 只读副本连接 URL，为空时读写都使用主库
+    #[serde(default)]
+    pub urls: Vec<String>,
+    /// 健康检查间隔（秒）
+    #[serde(default = "default_health_check_interval")]
+    pub health_check_interval: u64,
+}
+
+impl Default for ReplicaSettings {
+    fn default() -> Self {
+        Self {
+            urls: Vec::new(),
+            health_check_interval: 10,
+        }
+    }
+}
+
+/// 数据库迁移配置
+#[derive(Debug, Deserialize, Clone)]
+pub struct MigrationSettings {
+    /// 启动时自动执行未执行的迁移
+    #[serde(default = "default_true")]
+    pub auto_migrate: bool,
+    /// 自动迁移时是否同时写入初始数据（管理员、菜单、字典等）
+    #[serde(default = "default_true")]
+    pub seed: bool,
+}
+
+impl Default for MigrationSettings {
+    fn default() -> Self {
+        Self {
+            auto_migrate: true,
+            seed: true,
+        }
+    }
 }
 
 impl Default for DbSettings {
@@ -34,6 +98,9 @@ impl Default for DbSettings {
             connect_timeout: 10,
             max_lifetime: 1800,
             idle_timeout: 600,
+            migration: MigrationSettings::default(),
+            replica: ReplicaSettings::default(),
+            encryption: EncryptionSettings::default(),
         }
     }
 }
@@ -58,6 +125,14 @@ fn default_idle_timeout() -> u64 {
     600
 }
 
+fn default_health_check_interval() -> u64 {
+    10
+}
+
+fn default_true() -> bool {
+    true
+}
+
 impl DbSettings {
     pub fn from_url(url: &str) -> Self {
         Self {
@@ -65,4 +140,14 @@ impl DbSettings {
             ..Default::default()
         }
     }
+
+    /// 是否为SQLite内存数据库
+    pub fn is_sqlite_memory(&self) -> bool {
+        is_sqlite_memory_url(&self.url)
+    }
+}
+
+/// 连接URL是否为SQLite内存数据库
+pub fn is_sqlite_memory_url(url: &str) -> bool {
+    url.starts_with("sqlite:") && url.contains(":memory:")
 }
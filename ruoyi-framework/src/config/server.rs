@@ -28,6 +28,20 @@ pub struct ServerSettings {
     pub ssl_cert: Option<String>,
     /// SSL 密钥路径
     pub ssl_key: Option<String>,
+    /// 接口文档配置
+    #[serde(default)]
+    pub api_docs: ApiDocSettings,
+}
+
+/// 接口文档配置，默认关闭，需要在配置中显式启用
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ApiDocSettings {
+    /// 是否提供 OpenAPI 文档（/v3/api-docs）
+    #[serde(default)]
+    pub enabled: bool,
+    /// 是否提供 Swagger UI（/swagger-ui/），需要同时启用 OpenAPI 文档
+    #[serde(default)]
+    pub swagger_ui: bool,
 }
 
 fn default_host() -> String {
@@ -65,6 +79,7 @@ impl Default for ServerSettings {
             enable_https: false,
             ssl_cert: None,
             ssl_key: None,
+            api_docs: ApiDocSettings::default(),
         }
     }
 }
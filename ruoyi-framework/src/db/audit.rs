@@ -0,0 +1,153 @@
+// ruoyi-framework/src/db/audit.rs
+//! 审计字段模块，统一填充 create_by、create_time、update_by、update_time
+//!
+//! 实体在 `ActiveModelBehavior::before_save` 中调用 [`fill_audit_fields`]：
+//!
+//! ```ignore
+//! #[async_trait::async_trait]
+//! impl ActiveModelBehavior for ActiveModel {
+//!     async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+//!     where
+//!         C: ConnectionTrait,
+//!     {
+//!         fill_audit_fields(&mut self, insert);
+//!         Ok(self)
+//!     }
+//! }
+//! ```
+//!
+//! `before_save` 只在 `ActiveModel::insert/update/save` 时执行，`update_many` 等批量操作需要手动调用
+
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ActiveValue, EntityTrait, IdenStatic, Iterable, Value};
+
+use crate::web::context::current_user;
+
+/// 没有用户上下文时（定时任务、命令行、启动初始化等）记录的操作人
+pub const SYSTEM_OPERATOR: &str = "system";
+
+/// 当前操作人：请求中的登录用户，没有用户上下文时为 [`SYSTEM_OPERATOR`]
+pub fn current_operator() -> String {
+    current_user()
+        .map(|user| user.user_name)
+        .unwrap_or_else(|| SYSTEM_OPERATOR.to_string())
+}
+
+/// 填充审计字段，实体中不存在的字段会被忽略
+///
+/// 1. 新增时填充 create_by、create_time、update_by、update_time
+/// 2. 修改时填充 update_by、update_time，不改动创建信息
+/// 3. 代码中已显式设置（Set 且非空）的值保持不变
+pub fn fill_audit_fields<A: ActiveModelTrait>(active_model: &mut A, insert: bool) {
+    let operator = current_operator();
+    let now = Utc::now();
+
+    for column in <A::Entity as EntityTrait>::Column::iter() {
+        let value = match column.as_str() {
+            "create_by" if insert => Value::String(Some(Box::new(operator.clone()))),
+            "update_by" => Value::String(Some(Box::new(operator.clone()))),
+            "create_time" if insert => Value::ChronoDateTimeUtc(Some(Box::new(now))),
+            "update_time" => Value::ChronoDateTimeUtc(Some(Box::new(now))),
+            _ => continue,
+        };
+        if !is_explicitly_set(&active_model.get(column)) {
+            active_model.set(column, value);
+        }
+    }
+}
+
+/// 是否已显式设置为非空值
+fn is_explicitly_set(value: &ActiveValue<Value>) -> bool {
+    match value {
+        ActiveValue::Set(Value::String(Some(value))) => !value.is_empty(),
+        ActiveValue::Set(Value::ChronoDateTimeUtc(Some(_))) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web::context::{RequestContext, UserContext};
+    use sea_orm::ActiveValue::{NotSet, Set, Unchanged};
+
+    mod article {
+        use chrono::{DateTime, Utc};
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+        #[sea_orm(table_name = "sys_article")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i64,
+            pub title: Option<String>,
+            pub create_by: Option<String>,
+            pub create_time: Option<DateTime<Utc>>,
+            pub update_by: Option<String>,
+            pub update_time: Option<DateTime<Utc>>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn login(user_name: &str) -> RequestContext {
+        RequestContext::user(UserContext {
+            user_id: 1,
+            user_name: user_name.to_string(),
+            ip: "127.0.0.1".parse().unwrap(),
+            token_id: "token".to_string(),
+            tenant_id: 1,
+        })
+    }
+
+    #[test]
+    fn test_fill_on_insert_and_update() {
+        login("admin").sync_scope(|| {
+            let mut model = article::ActiveModel {
+                title: Set(Some("标题".to_string())),
+                create_by: Set(None),
+                ..Default::default()
+            };
+            fill_audit_fields(&mut model, true);
+            assert_eq!(model.create_by, Set(Some("admin".to_string())));
+            assert_eq!(model.update_by, Set(Some("admin".to_string())));
+            assert!(matches!(model.create_time, Set(Some(_))));
+            assert!(matches!(model.update_time, Set(Some(_))));
+        });
+
+        // 修改时只更新修改信息，旧的修改人被覆盖
+        login("ry").sync_scope(|| {
+            let created = chrono::Utc::now() - chrono::Duration::days(1);
+            let mut model = article::ActiveModel {
+                id: Unchanged(1),
+                create_by: Unchanged(Some("admin".to_string())),
+                create_time: Unchanged(Some(created)),
+                update_by: Unchanged(Some("admin".to_string())),
+                update_time: Unchanged(Some(created)),
+                ..Default::default()
+            };
+            fill_audit_fields(&mut model, false);
+            assert_eq!(model.create_by, Unchanged(Some("admin".to_string())));
+            assert_eq!(model.create_time, Unchanged(Some(created)));
+            assert_eq!(model.update_by, Set(Some("ry".to_string())));
+            assert!(matches!(model.update_time, Set(Some(time)) if time > created));
+        });
+    }
+
+    #[test]
+    fn test_explicit_value_and_fallback() {
+        // 没有用户上下文
+        let mut model = article::ActiveModel {
+            create_by: Set(Some("importer".to_string())),
+            update_by: Set(Some(String::new())),
+            ..Default::default()
+        };
+        fill_audit_fields(&mut model, true);
+        assert_eq!(model.create_by, Set(Some("importer".to_string())));
+        assert_eq!(model.update_by, Set(Some(SYSTEM_OPERATOR.to_string())));
+        assert_eq!(model.title, NotSet);
+    }
+}
@@ -0,0 +1,703 @@
+// ruoyi-framework/src/db/encryption.rs
+//! 字段加密模块，敏感字段（手机号、邮箱、身份证号等）在数据库中以密文存储
+//!
+//! 1. 实体字段声明为 [`Encrypted`] 类型，写入时使用 AES-256-GCM 加密，读取时自动解密
+//! 2. 密文格式为 `ENC:<密钥编号>:<Base64(随机数+密文)>`，不带前缀的历史明文原样读取
+//! 3. 同一明文每次加密的结果不同，等值查询需要使用盲索引列（HMAC-SHA256），
+//!    实体实现 [`EncryptedEntity`] 声明加密字段和盲索引列，并在 `before_save` 中调用 [`fill_blind_indexes`]
+//! 4. 轮换密钥时新增密钥并修改当前密钥编号，旧密钥继续用于解密，
+//!    再执行 [`rotate_encrypted_fields`] 用当前密钥重新加密并重建盲索引
+//! 5. 新增盲索引列后，启动时由 [`backfill_blind_indexes`] 为历史数据补齐盲索引
+//!
+//! ```ignore
+//! impl EncryptedEntity for Entity {
+//!     fn encrypted_fields() -> Vec<EncryptedField<Column>> {
+//!         vec![EncryptedField::new(Column::Phonenumber).with_blind_index(Column::PhonenumberIndex)]
+//!     }
+//! }
+//!
+//! // 按手机号查询
+//! UserEntity::find().filter(UserColumn::PhonenumberIndex.eq(blind_index(phone)))
+//! ```
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use lazy_static::lazy_static;
+use ruoyi_common::error::Error;
+use sea_orm::sea_query::{ArrayType, ColumnType, Expr, Nullable, ValueType, ValueTypeErr};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColIdx, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, IdenStatic, Iterable, PrimaryKeyToColumn, QueryFilter, QueryOrder, QueryResult,
+    QuerySelect, QueryTrait, TryGetError, TryGetable, Value,
+};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use utoipa::ToSchema;
+
+use crate::config::db::EncryptionSettings;
+
+/// 密文前缀
+const CIPHERTEXT_PREFIX: &str = "ENC:";
+
+/// AES-GCM 随机数长度
+const NONCE_LEN: usize = 12;
+
+/// 未启用加密且未配置盲索引密钥时使用的密钥，此时敏感字段本身以明文存储
+const PLAINTEXT_BLIND_INDEX_KEY: &str = "ruoyi_rust_blind_index_key_for_development";
+
+/// 公开的默认盲索引密钥，启用加密时不能使用
+const KNOWN_BLIND_INDEX_KEYS: &[&str] = &[PLAINTEXT_BLIND_INDEX_KEY];
+
+/// 重新加密时每批处理的记录数
+const ROTATE_BATCH_SIZE: u64 = 500;
+
+lazy_static! {
+    static ref FIELD_CIPHER: RwLock<Arc<FieldCipher>> =
+        RwLock::new(Arc::new(FieldCipher::default()));
+}
+
+/// 字段加解密器
+pub struct FieldCipher {
+    /// 当前用于加密的密钥编号，为None时不加密
+    current_key: Option<String>,
+    /// 密钥编号到密钥的映射
+    keys: HashMap<String, Aes256Gcm>,
+    /// 盲索引密钥
+    blind_index_key: Vec<u8>,
+}
+
+impl Default for FieldCipher {
+    fn default() -> Self {
+        Self::from_settings(&EncryptionSettings::default()).expect("默认字段加密配置无效")
+    }
+}
+
+impl FieldCipher {
+    /// 根据配置创建加解密器，密钥格式错误、当前密钥未配置，
+    /// 或启用加密时盲索引密钥缺失、使用默认值时返回错误
+    pub fn from_settings(settings: &EncryptionSettings) -> Result<Self, Error> {
+        let mut keys = HashMap::new();
+        for (key_id, key) in &settings.keys {
+            if key_id.is_empty() || key_id.contains(':') {
+                return Err(Error::Configuration(format!(
+                    "无效的加密密钥编号: {}",
+                    key_id
+                )));
+            }
+            let bytes = BASE64.decode(key.trim()).map_err(|e| {
+                Error::Configuration(format!("加密密钥{}不是有效的Base64: {}", key_id, e))
+            })?;
+            let cipher = Aes256Gcm::new_from_slice(&bytes)
+                .map_err(|_| Error::Configuration(format!("加密密钥{}长度必须为32字节", key_id)))?;
+            keys.insert(key_id.clone(), cipher);
+        }
+
+        let current_key = settings
+            .current_key
+            .as_ref()
+            .filter(|key_id| !key_id.is_empty())
+            .cloned();
+        if let Some(key_id) = &current_key {
+            if !keys.contains_key(key_id) {
+                return Err(Error::Configuration(format!(
+                    "未配置当前加密密钥: {}",
+                    key_id
+                )));
+            }
+        }
+
+        let blind_index_key = settings
+            .blind_index_key
+            .as_deref()
+            .filter(|key| !key.is_empty());
+        if current_key.is_some()
+            && blind_index_key.is_none_or(|key| KNOWN_BLIND_INDEX_KEYS.contains(&key))
+        {
+            return Err(Error::Configuration(
+                "启用字段加密时必须配置盲索引密钥 blind_index_key，且不能使用默认值".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            current_key,
+            keys,
+            blind_index_key: blind_index_key
+                .unwrap_or(PLAINTEXT_BLIND_INDEX_KEY)
+                .as_bytes()
+                .to_vec(),
+        })
+    }
+
+    /// 是否启用加密
+    pub fn is_enabled(&self) -> bool {
+        self.current_key.is_some()
+    }
+
+    /// 使用当前密钥加密，未启用加密或明文为空时原样返回
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        let Some(key_id) = &self.current_key else {
+            return plaintext.to_string();
+        };
+        if plaintext.is_empty() {
+            return String::new();
+        }
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self.keys[key_id]
+            .encrypt(&nonce, plaintext.as_bytes())
+            .expect("AES-GCM 加密失败");
+        let mut payload = nonce.to_vec();
+        payload.extend_from_slice(&ciphertext);
+        format!("{}{}:{}", CIPHERTEXT_PREFIX, key_id, BASE64.encode(payload))
+    }
+
+    /// 解密，按密文中的密钥编号选择密钥，不带密文前缀的值视为明文
+    pub fn decrypt(&self, stored: &str) -> Result<String, String> {
+        let Some(rest) = stored.strip_prefix(CIPHERTEXT_PREFIX) else {
+            return Ok(stored.to_string());
+        };
+        let (key_id, payload) = rest
+            .split_once(':')
+            .ok_or_else(|| "密文格式错误".to_string())?;
+        let cipher = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| format!("未配置加密密钥: {}", key_id))?;
+        let payload = BASE64
+            .decode(payload)
+            .map_err(|e| format!("密文格式错误: {}", e))?;
+        if payload.len() < NONCE_LEN {
+            return Err("密文格式错误".to_string());
+        }
+        let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| format!("使用密钥{}解密失败", key_id))?;
+        String::from_utf8(plaintext).map_err(|e| e.to_string())
+    }
+
+    /// 存储的值是否需要用当前密钥重新加密：历史明文或使用旧密钥加密的密文
+    pub fn needs_rotation(&self, stored: &str) -> bool {
+        let Some(current_key) = &self.current_key else {
+            return false;
+        };
+        if stored.is_empty() {
+            return false;
+        }
+        match stored
+            .strip_prefix(CIPHERTEXT_PREFIX)
+            .and_then(|rest| rest.split_once(':'))
+        {
+            Some((key_id, _)) => key_id != current_key,
+            None => true,
+        }
+    }
+
+    /// 计算盲索引，明文为空时返回None
+    pub fn blind_index(&self, plaintext: &str) -> Option<String> {
+        if plaintext.is_empty() {
+            return None;
+        }
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&self.blind_index_key)
+            .expect("HMAC 支持任意长度的密钥");
+        mac.update(plaintext.as_bytes());
+        Some(
+            mac.finalize()
+                .into_bytes()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect(),
+        )
+    }
+}
+
+/// 根据配置初始化全局字段加解密器，应用启动时调用
+pub fn init_field_cipher(settings: &EncryptionSettings) -> Result<(), Error> {
+    install_field_cipher(FieldCipher::from_settings(settings)?);
+    Ok(())
+}
+
+/// 替换全局字段加解密器
+pub fn install_field_cipher(cipher: FieldCipher) {
+    *FIELD_CIPHER.write().unwrap() = Arc::new(cipher);
+}
+
+/// 全局字段加解密器
+pub fn field_cipher() -> Arc<FieldCipher> {
+    FIELD_CIPHER.read().unwrap().clone()
+}
+
+/// 使用全局字段加解密器计算盲索引，用于加密字段的等值查询
+pub fn blind_index(plaintext: &str) -> Option<String> {
+    field_cipher().blind_index(plaintext)
+}
+
+/// 加密存储的字符串字段，内存和接口中为明文，写入数据库时加密
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+pub struct Encrypted(String);
+
+impl Encrypted {
+    /// 创建加密字段
+    pub fn new(plaintext: impl Into<String>) -> Self {
+        Self(plaintext.into())
+    }
+
+    /// 明文
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 取出明文
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Debug for Encrypted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Encrypted(***)")
+    }
+}
+
+impl From<String> for Encrypted {
+    fn from(plaintext: String) -> Self {
+        Self(plaintext)
+    }
+}
+
+impl From<&str> for Encrypted {
+    fn from(plaintext: &str) -> Self {
+        Self(plaintext.to_string())
+    }
+}
+
+impl From<Encrypted> for String {
+    fn from(value: Encrypted) -> Self {
+        value.0
+    }
+}
+
+impl From<Encrypted> for Value {
+    fn from(value: Encrypted) -> Self {
+        Value::String(Some(Box::new(field_cipher().encrypt(&value.0))))
+    }
+}
+
+impl TryGetable for Encrypted {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let stored = String::try_get_by(res, index)?;
+        field_cipher()
+            .decrypt(&stored)
+            .map(Encrypted)
+            .map_err(|e| TryGetError::DbErr(DbErr::Type(e)))
+    }
+}
+
+impl ValueType for Encrypted {
+    fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+        match value {
+            Value::String(Some(stored)) => field_cipher()
+                .decrypt(&stored)
+                .map(Encrypted)
+                .map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Encrypted".to_string()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::String(None)
+    }
+}
+
+impl Nullable for Encrypted {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+/// 加密字段声明
+#[derive(Debug, Clone, Copy)]
+pub struct EncryptedField<C: ColumnTrait> {
+    /// 加密列
+    pub column: C,
+    /// 盲索引列，为None时不支持等值查询
+    pub blind_index: Option<C>,
+}
+
+impl<C: ColumnTrait> EncryptedField<C> {
+    /// 声明加密列
+    pub fn new(column: C) -> Self {
+        Self {
+            column,
+            blind_index: None,
+        }
+    }
+
+    /// 指定盲索引列
+    pub fn with_blind_index(mut self, column: C) -> Self {
+        self.blind_index = Some(column);
+        self
+    }
+}
+
+/// 包含加密字段的实体
+pub trait EncryptedEntity: EntityTrait {
+    /// 加密字段及其盲索引列
+    fn encrypted_fields() -> Vec<EncryptedField<Self::Column>>;
+}
+
+/// 根据加密字段的值填充盲索引列，修改时加密字段未改动则不处理
+pub fn fill_blind_indexes<A>(active_model: &mut A, insert: bool) -> Result<(), DbErr>
+where
+    A: ActiveModelTrait,
+    A::Entity: EncryptedEntity,
+{
+    let cipher = field_cipher();
+    for field in <A::Entity as EncryptedEntity>::encrypted_fields() {
+        let Some(index_column) = field.blind_index else {
+            continue;
+        };
+        // 由 Model 转换的 ActiveModel 字段为 Unchanged，新增时同样写入
+        let value = match active_model.get(field.column) {
+            ActiveValue::Set(value) => value,
+            ActiveValue::Unchanged(value) if insert => value,
+            _ => continue,
+        };
+        let index = match value {
+            Value::String(Some(stored)) => cipher
+                .blind_index(&cipher.decrypt(&stored).map_err(DbErr::Type)?)
+                .map(Box::new),
+            _ => None,
+        };
+        active_model.set(index_column, Value::String(index));
+    }
+    Ok(())
+}
+
+/// 用当前密钥重新加密实体的加密字段并重建盲索引，返回更新的记录数
+///
+/// 用于轮换密钥、更换盲索引密钥或加密历史明文数据，只更新需要变化的记录，
+/// 按主键分批处理，实体需要有单列整数主键。不区分租户和删除标志，处理全表数据
+pub async fn rotate_encrypted_fields<E, C>(db: &C) -> Result<u64, DbErr>
+where
+    E: EncryptedEntity,
+    C: ConnectionTrait,
+{
+    update_encrypted_rows::<E, C>(db, Condition::all(), true).await
+}
+
+/// 补齐缺失的盲索引，返回更新的记录数
+///
+/// 新增盲索引列后历史数据的索引为空，按索引查询（如唯一性校验）会漏掉这些记录，
+/// 应用启动时执行，只处理加密字段有值但盲索引为空的记录，不重新加密
+pub async fn backfill_blind_indexes<E, C>(db: &C) -> Result<u64, DbErr>
+where
+    E: EncryptedEntity,
+    C: ConnectionTrait,
+{
+    let mut missing = Condition::any();
+    for field in E::encrypted_fields() {
+        if let Some(index_column) = field.blind_index {
+            missing = missing.add(
+                Condition::all()
+                    .add(field.column.is_not_null())
+                    .add(field.column.ne(""))
+                    .add(index_column.is_null()),
+            );
+        }
+    }
+    if missing.is_empty() {
+        return Ok(0);
+    }
+    update_encrypted_rows::<E, C>(db, missing, false).await
+}
+
+/// 按主键分批更新满足条件的记录，重建盲索引，`rotate` 为true时同时用当前密钥重新加密
+async fn update_encrypted_rows<E, C>(
+    db: &C,
+    condition: Condition,
+    rotate: bool,
+) -> Result<u64, DbErr>
+where
+    E: EncryptedEntity,
+    C: ConnectionTrait,
+{
+    let cipher = field_cipher();
+    let fields = E::encrypted_fields();
+    let pk = E::PrimaryKey::iter()
+        .next()
+        .ok_or_else(|| DbErr::Custom("实体没有主键".to_string()))?
+        .into_column();
+
+    let mut select = E::find().select_only().column(pk).filter(condition);
+    for field in &fields {
+        select = select.column(field.column);
+        if let Some(index_column) = field.blind_index {
+            select = select.column(index_column);
+        }
+    }
+
+    let backend = db.get_database_backend();
+    let mut updated = 0;
+    let mut last_id = i64::MIN;
+    loop {
+        let stmt = select
+            .clone()
+            .filter(pk.gt(last_id))
+            .order_by_asc(pk)
+            .limit(ROTATE_BATCH_SIZE)
+            .build(backend);
+        let rows = db.query_all(stmt).await?;
+        for row in &rows {
+            last_id = row.try_get("", pk.as_str())?;
+            let mut update = E::update_many().filter(pk.eq(last_id));
+            let mut changed = false;
+            for field in &fields {
+                let stored: Option<String> = row.try_get("", field.column.as_str())?;
+                let plaintext = match &stored {
+                    Some(stored) => Some(cipher.decrypt(stored).map_err(DbErr::Type)?),
+                    None => None,
+                };
+                if let (true, Some(stored), Some(plaintext)) = (rotate, &stored, &plaintext) {
+                    if cipher.needs_rotation(stored) {
+                        update =
+                            update.col_expr(field.column, Expr::value(cipher.encrypt(plaintext)));
+                        changed = true;
+                    }
+                }
+                if let Some(index_column) = field.blind_index {
+                    let current: Option<String> = row.try_get("", index_column.as_str())?;
+                    let expected = plaintext.as_deref().and_then(|p| cipher.blind_index(p));
+                    if current != expected {
+                        update = update.col_expr(index_column, Expr::value(expected));
+                        changed = true;
+                    }
+                }
+            }
+            if changed {
+                update.exec(db).await?;
+                updated += 1;
+            }
+        }
+        if (rows.len() as u64) < ROTATE_BATCH_SIZE {
+            break;
+        }
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db::DbSettings;
+    use crate::db::DbManager;
+    use sea_orm::{DatabaseBackend, IntoActiveModel, Statement};
+
+    mod contact {
+        use super::super::{EncryptedEntity, EncryptedField};
+        use crate::db::encryption::{fill_blind_indexes, Encrypted};
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+        #[sea_orm(table_name = "sys_contact")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i64,
+            pub phone: Option<Encrypted>,
+            pub phone_index: Option<String>,
+            pub id_card: Option<Encrypted>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        #[async_trait::async_trait]
+        impl ActiveModelBehavior for ActiveModel {
+            async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+            where
+                C: ConnectionTrait,
+            {
+                fill_blind_indexes(&mut self, insert)?;
+                Ok(self)
+            }
+        }
+
+        impl EncryptedEntity for Entity {
+            fn encrypted_fields() -> Vec<EncryptedField<Column>> {
+                vec![
+                    EncryptedField::new(Column::Phone).with_blind_index(Column::PhoneIndex),
+                    EncryptedField::new(Column::IdCard),
+                ]
+            }
+        }
+    }
+
+    fn settings(current_key: Option<&str>, keys: &[(&str, u8)]) -> EncryptionSettings {
+        EncryptionSettings {
+            current_key: current_key.map(str::to_string),
+            keys: keys
+                .iter()
+                .map(|(key_id, byte)| (key_id.to_string(), BASE64.encode([*byte; 32])))
+                .collect(),
+            blind_index_key: Some("test_blind_index_key".to_string()),
+        }
+    }
+
+    async fn raw_phones(db: &sea_orm::DatabaseConnection) -> Vec<String> {
+        db.query_all(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            "select phone from sys_contact order by id".to_string(),
+        ))
+        .await
+        .unwrap()
+        .iter()
+        .map(|row| row.try_get("", "phone").unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn test_encrypt_and_rotate_keys() {
+        let v1 = FieldCipher::from_settings(&settings(Some("1"), &[("1", 1)])).unwrap();
+        let stored = v1.encrypt("15888888888");
+        assert!(stored.starts_with("ENC:1:"));
+        assert_ne!(stored, v1.encrypt("15888888888"));
+        assert_eq!(v1.decrypt(&stored).unwrap(), "15888888888");
+        assert_eq!(v1.decrypt("15888888888").unwrap(), "15888888888");
+        assert!(!v1.needs_rotation(&stored));
+        assert!(v1.needs_rotation("15888888888"));
+
+        // 轮换后旧密文仍可解密，但需要重新加密
+        let v2 = FieldCipher::from_settings(&settings(Some("2"), &[("1", 1), ("2", 2)])).unwrap();
+        assert_eq!(v2.decrypt(&stored).unwrap(), "15888888888");
+        assert!(v2.needs_rotation(&stored));
+        assert!(v2.encrypt("15888888888").starts_with("ENC:2:"));
+
+        // 盲索引与加密密钥无关
+        assert_eq!(v1.blind_index("15888888888"), v2.blind_index("15888888888"));
+        assert_ne!(v1.blind_index("15888888888"), v1.blind_index("15888888889"));
+        assert_eq!(v1.blind_index(""), None);
+
+        // 缺少密钥或密钥长度错误
+        let removed = FieldCipher::from_settings(&settings(Some("2"), &[("2", 2)])).unwrap();
+        assert!(removed.decrypt(&stored).is_err());
+        assert!(FieldCipher::from_settings(&settings(Some("3"), &[("1", 1)])).is_err());
+        let mut short = settings(None, &[]);
+        short.keys.insert("1".to_string(), BASE64.encode([1u8; 16]));
+        assert!(FieldCipher::from_settings(&short).is_err());
+
+        // 启用加密时盲索引密钥必须配置，且不能使用默认值
+        let mut missing = settings(Some("1"), &[("1", 1)]);
+        missing.blind_index_key = None;
+        assert!(FieldCipher::from_settings(&missing).is_err());
+        missing.blind_index_key = Some(PLAINTEXT_BLIND_INDEX_KEY.to_string());
+        assert!(FieldCipher::from_settings(&missing).is_err());
+        missing.current_key = None;
+        assert!(FieldCipher::from_settings(&missing).is_ok());
+        assert!(FieldCipher::from_settings(&EncryptionSettings::default()).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_entity_fields_and_rotation() {
+        let db = DbManager::new(DbSettings::from_url("sqlite::memory:"))
+            .await
+            .unwrap()
+            .get_connection();
+        db.execute_unprepared(
+            "create table sys_contact (id integer primary key autoincrement, phone varchar(255), \
+             phone_index varchar(64), id_card varchar(255));
+             insert into sys_contact (phone, id_card) values ('13800000000', '110101199001011234');",
+        )
+        .await
+        .unwrap();
+        install_field_cipher(
+            FieldCipher::from_settings(&settings(Some("1"), &[("1", 1)])).unwrap(),
+        );
+
+        // 写入时加密并填充盲索引，读取时解密，历史明文原样读取
+        let created = contact::Model {
+            id: 2,
+            phone: Some(Encrypted::new("15888888888")),
+            phone_index: None,
+            id_card: None,
+        }
+        .into_active_model()
+        .insert(db.as_ref())
+        .await
+        .unwrap();
+        assert_eq!(created.phone, Some(Encrypted::new("15888888888")));
+        assert_eq!(created.phone_index, blind_index("15888888888"));
+        let raw = raw_phones(&db).await;
+        assert_eq!(raw[0], "13800000000");
+        assert!(raw[1].starts_with("ENC:1:"));
+        let found = contact::Entity::find()
+            .filter(contact::Column::PhoneIndex.eq(blind_index("15888888888")))
+            .one(db.as_ref())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, created.id);
+
+        // 补齐历史数据的盲索引，不重新加密
+        assert_eq!(
+            backfill_blind_indexes::<contact::Entity, _>(db.as_ref())
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            backfill_blind_indexes::<contact::Entity, _>(db.as_ref())
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(raw_phones(&db).await[0], "13800000000");
+        assert!(contact::Entity::find()
+            .filter(contact::Column::PhoneIndex.eq(blind_index("13800000000")))
+            .one(db.as_ref())
+            .await
+            .unwrap()
+            .is_some());
+
+        // 轮换密钥后重新加密历史明文和旧密文，并补齐盲索引
+        install_field_cipher(
+            FieldCipher::from_settings(&settings(Some("2"), &[("1", 1), ("2", 2)])).unwrap(),
+        );
+        assert_eq!(
+            rotate_encrypted_fields::<contact::Entity, _>(db.as_ref())
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            rotate_encrypted_fields::<contact::Entity, _>(db.as_ref())
+                .await
+                .unwrap(),
+            0
+        );
+        assert!(raw_phones(&db)
+            .await
+            .iter()
+            .all(|phone| phone.starts_with("ENC:2:")));
+        let legacy = contact::Entity::find()
+            .filter(contact::Column::PhoneIndex.eq(blind_index("13800000000")))
+            .one(db.as_ref())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(legacy.id_card, Some(Encrypted::new("110101199001011234")));
+    }
+}
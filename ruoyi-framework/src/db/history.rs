@@ -0,0 +1,322 @@
+// ruoyi-framework/src/db/history.rs
+//! 实体变更历史模块，记录修改和删除前后的字段差异
+//!
+//! 通过 [`BaseRepository::with_change_history`](crate::db::repository::BaseRepository::with_change_history)
+//! 启用后，仓库的 `update`、`delete_*`、`restore_by_ids`、`purge_by_ids` 会在同一连接中写入 sys_change_log，
+//! 每条记录包含实体名称（表名）、主键、操作人、变更时间和字段差异：
+//!
+//! ```json
+//! {"status": {"before": "0", "after": "1"}, "password": {"before": "******", "after": "******"}}
+//! ```
+//!
+//! 字段名统一为数据库列名，update_by、update_time、version 每次修改都会变化，不计入差异；
+//! 绕过仓库直接执行的 `update_many`、`delete_many` 不会记录
+
+use chrono::Utc;
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::{
+    ColumnTrait, EntityName, EntityTrait, IdenStatic, Iterable, ModelTrait, PrimaryKeyToColumn,
+    Value,
+};
+use serde::Serialize;
+use serde_json::{json, Map, Value as JsonValue};
+
+use ruoyi_common::utils::string::to_snake_case;
+
+use crate::db::audit::current_operator;
+use crate::tenant::{current_tenant_id_or_default, TENANT_COLUMN};
+
+pub use change_log::{
+    ActiveModel as ChangeLogActiveModel, Column as ChangeLogColumn, Entity as ChangeLogEntity,
+    Model as ChangeLogModel,
+};
+
+/// 不计入差异的字段，每次修改都会变化，操作人和时间已单独记录
+const IGNORED_FIELDS: [&str; 3] = ["update_by", "update_time", "version"];
+
+/// 敏感字段在差异中的占位值
+pub const MASKED_VALUE: &str = "******";
+
+/// 变更历史实体
+pub mod change_log {
+    use chrono::{DateTime, Utc};
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+    #[sea_orm(table_name = "sys_change_log")]
+    #[serde(rename_all = "camelCase")]
+    pub struct Model {
+        /// 变更主键
+        #[sea_orm(primary_key)]
+        pub change_id: i64,
+        /// 租户ID
+        pub tenant_id: i64,
+        /// 实体名称（表名）
+        pub entity_name: String,
+        /// 实体主键，复合主键以逗号分隔
+        pub entity_id: String,
+        /// 变更类型
+        pub change_type: String,
+        /// 字段差异（JSON）
+        #[sea_orm(column_type = "Text", nullable)]
+        pub changes: Option<String>,
+        /// 操作人员
+        pub oper_name: Option<String>,
+        /// 变更时间
+        pub change_time: Option<DateTime<Utc>>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+/// 变更类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeType {
+    /// 修改
+    Update,
+    /// 删除（启用逻辑删除时为标记删除）
+    Delete,
+    /// 恢复逻辑删除的记录
+    Restore,
+    /// 彻底删除
+    Purge,
+}
+
+impl ChangeType {
+    /// 存储的变更类型名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeType::Update => "update",
+            ChangeType::Delete => "delete",
+            ChangeType::Restore => "restore",
+            ChangeType::Purge => "purge",
+        }
+    }
+}
+
+/// 变更历史配置
+///
+/// ```ignore
+/// // 密码、加密存储的手机号码和邮箱只记录是否修改，不记录内容
+/// ChangeHistory::new().with_masked_columns([
+///     UserColumn::Password,
+///     UserColumn::Email,
+///     UserColumn::Phonenumber,
+/// ])
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChangeHistory<C: ColumnTrait> {
+    /// 需要脱敏的字段
+    masked_columns: Vec<C>,
+}
+
+impl<C: ColumnTrait> Default for ChangeHistory<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: ColumnTrait> ChangeHistory<C> {
+    /// 创建变更历史配置，实体名称取表名
+    pub fn new() -> Self {
+        Self {
+            masked_columns: Vec::new(),
+        }
+    }
+
+    /// 设置需要脱敏的字段，差异中只保留是否修改，前后的值均替换为 [`MASKED_VALUE`]
+    pub fn with_masked_columns(mut self, columns: impl IntoIterator<Item = C>) -> Self {
+        self.masked_columns = columns.into_iter().collect();
+        self
+    }
+
+    /// 实体名称（表名）
+    pub fn entity_name(&self) -> String {
+        C::EntityName::default().table_name().to_string()
+    }
+
+    /// 比较变更前后的实体，返回字段差异，没有差异时返回None
+    ///
+    /// 变更前或变更后为空时（如物理删除），另一侧的所有非空字段都计入差异
+    pub fn diff<M: Serialize>(&self, before: Option<&M>, after: Option<&M>) -> Option<JsonValue> {
+        let before = snapshot(before);
+        let after = snapshot(after);
+
+        let mut changes = Map::new();
+        for field in before.keys().chain(after.keys()) {
+            if changes.contains_key(field) || IGNORED_FIELDS.contains(&field.as_str()) {
+                continue;
+            }
+            let old = before.get(field).unwrap_or(&JsonValue::Null);
+            let new = after.get(field).unwrap_or(&JsonValue::Null);
+            if old == new {
+                continue;
+            }
+            let masked = self.masked_columns.iter().any(|c| c.as_str() == field);
+            changes.insert(
+                field.clone(),
+                json!({
+                    "before": if masked { mask(old) } else { old.clone() },
+                    "after": if masked { mask(new) } else { new.clone() },
+                }),
+            );
+        }
+        (!changes.is_empty()).then_some(JsonValue::Object(changes))
+    }
+
+    /// 生成变更历史记录，没有字段差异或前后都为空时返回None
+    pub fn change_log<M>(
+        &self,
+        change_type: ChangeType,
+        before: Option<&M>,
+        after: Option<&M>,
+    ) -> Option<ChangeLogActiveModel>
+    where
+        M: ModelTrait + Serialize,
+    {
+        let model = before.or(after)?;
+        let changes = self.diff(before, after)?;
+        Some(ChangeLogActiveModel {
+            change_id: NotSet,
+            tenant_id: Set(model_tenant_id(model)),
+            entity_name: Set(self.entity_name()),
+            entity_id: Set(model_key(model)),
+            change_type: Set(change_type.as_str().to_string()),
+            changes: Set(Some(changes.to_string())),
+            oper_name: Set(Some(current_operator())),
+            change_time: Set(Some(Utc::now())),
+        })
+    }
+}
+
+/// 实体序列化后的字段，字段名统一转为数据库列名
+fn snapshot<M: Serialize>(model: Option<&M>) -> Map<String, JsonValue> {
+    match model.map(serde_json::to_value) {
+        Some(Ok(JsonValue::Object(fields))) => fields
+            .into_iter()
+            .map(|(field, value)| (to_snake_case(&field), value))
+            .collect(),
+        _ => Map::new(),
+    }
+}
+
+/// 脱敏，空值保持为空以区分设置和清除
+fn mask(value: &JsonValue) -> JsonValue {
+    if value.is_null() {
+        JsonValue::Null
+    } else {
+        JsonValue::from(MASKED_VALUE)
+    }
+}
+
+/// 实体主键，复合主键以逗号分隔
+pub fn model_key<M: ModelTrait>(model: &M) -> String {
+    <M::Entity as EntityTrait>::PrimaryKey::iter()
+        .map(|key| value_to_string(model.get(key.into_column())))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// 实体所属租户，实体中没有租户列时取当前租户
+fn model_tenant_id<M: ModelTrait>(model: &M) -> i64 {
+    <M::Entity as EntityTrait>::Column::iter()
+        .find(|column| column.as_str() == TENANT_COLUMN)
+        .and_then(|column| match model.get(column) {
+            Value::BigInt(Some(tenant_id)) => Some(tenant_id),
+            _ => None,
+        })
+        .unwrap_or_else(current_tenant_id_or_default)
+}
+
+/// 主键值转为字符串
+fn value_to_string(value: Value) -> String {
+    match value {
+        Value::TinyInt(Some(v)) => v.to_string(),
+        Value::SmallInt(Some(v)) => v.to_string(),
+        Value::Int(Some(v)) => v.to_string(),
+        Value::BigInt(Some(v)) => v.to_string(),
+        Value::TinyUnsigned(Some(v)) => v.to_string(),
+        Value::SmallUnsigned(Some(v)) => v.to_string(),
+        Value::Unsigned(Some(v)) => v.to_string(),
+        Value::BigUnsigned(Some(v)) => v.to_string(),
+        Value::String(Some(v)) => *v,
+        value => format!("{:?}", value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod article {
+        use sea_orm::entity::prelude::*;
+        use serde::Serialize;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
+        #[sea_orm(table_name = "sys_article")]
+        #[serde(rename_all = "camelCase")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i64,
+            pub tenant_id: i64,
+            pub title: Option<String>,
+            pub author_phone: Option<String>,
+            pub update_by: Option<String>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn article(title: &str, phone: Option<&str>, update_by: &str) -> article::Model {
+        article::Model {
+            id: 7,
+            tenant_id: 2,
+            title: Some(title.to_string()),
+            author_phone: phone.map(str::to_string),
+            update_by: Some(update_by.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_diff_and_change_log() {
+        let history = ChangeHistory::new().with_masked_columns([article::Column::AuthorPhone]);
+        let before = article("a", None, "admin");
+        let after = article("b", Some("13800000000"), "ry");
+
+        // 字段名转为列名，更新者不计入差异，脱敏字段只保留是否设置
+        assert_eq!(
+            history.diff(Some(&before), Some(&after)),
+            Some(json!({
+                "title": {"before": "a", "after": "b"},
+                "author_phone": {"before": null, "after": MASKED_VALUE},
+            }))
+        );
+        assert_eq!(history.diff(Some(&before), Some(&before)), None);
+
+        let log = history
+            .change_log(ChangeType::Delete, Some(&before), None)
+            .unwrap();
+        assert_eq!(log.entity_name, Set("sys_article".to_string()));
+        assert_eq!(log.entity_id, Set("7".to_string()));
+        assert_eq!(log.tenant_id, Set(2));
+        assert_eq!(log.change_type, Set("delete".to_string()));
+        let changes: JsonValue =
+            serde_json::from_str(log.changes.unwrap().unwrap().as_str()).unwrap();
+        assert_eq!(changes["id"], json!({"before": 7, "after": null}));
+        assert!(changes.get("author_phone").is_none());
+
+        assert!(history
+            .change_log(ChangeType::Update, Some(&before), Some(&before))
+            .is_none());
+        assert!(history
+            .change_log::<article::Model>(ChangeType::Update, None, None)
+            .is_none());
+    }
+}
@@ -0,0 +1,569 @@
+// ruoyi-framework/src/db/migration.rs
+//! 数据库版本迁移模块
+//!
+//! 各模块（system、generator、cms）按版本提供迁移脚本，分为表结构（schema）和初始数据（seed）两类，
+//! 每个迁移为 MySQL、PostgreSQL、SQLite 各准备一份脚本。执行记录保存在 `sys_migration` 表中：
+//! 1. 按模块顺序执行，同一模块内按版本号递增执行，同一版本先执行表结构迁移再执行初始数据迁移，
+//!    初始数据脚本只需兼容同版本的表结构，后续版本新增的字段不会影响旧版本的初始数据
+//! 2. 每个迁移在单独的事务中执行（MySQL 的 DDL 会隐式提交，失败时需要人工处理）
+//! 3. 已执行脚本被修改、数据库中存在程序不认识的版本、或仍有未执行的表结构迁移时，校验失败
+
+use chrono::Utc;
+use log::info;
+use sea_orm::{
+    ActiveValue::Set, ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, EntityTrait,
+    Schema, TransactionTrait,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// 迁移记录表实体
+mod history {
+    use chrono::{DateTime, Utc};
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+    #[sea_orm(table_name = "sys_migration")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(32))")]
+        pub module: String,
+        #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(16))")]
+        pub kind: String,
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub version: i64,
+        #[sea_orm(column_type = "String(Some(100))")]
+        pub name: String,
+        #[sea_orm(column_type = "String(Some(16))")]
+        pub checksum: String,
+        pub applied_at: DateTime<Utc>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+/// 迁移类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MigrationKind {
+    /// 表结构
+    Schema,
+    /// 初始数据
+    Seed,
+}
+
+impl MigrationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MigrationKind::Schema => "schema",
+            MigrationKind::Seed => "seed",
+        }
+    }
+}
+
+impl fmt::Display for MigrationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 一个迁移在各数据库下的脚本
+#[derive(Debug, Clone, Copy)]
+pub struct MigrationSql {
+    pub mysql: &'static str,
+    pub postgres: &'static str,
+    pub sqlite: &'static str,
+}
+
+impl MigrationSql {
+    /// 获取指定数据库类型的脚本
+    pub fn for_backend(&self, backend: DatabaseBackend) -> &'static str {
+        match backend {
+            DatabaseBackend::MySql => self.mysql,
+            DatabaseBackend::Postgres => self.postgres,
+            DatabaseBackend::Sqlite => self.sqlite,
+        }
+    }
+}
+
+/// 引入当前 crate 下 `migrations/{mysql,postgres,sqlite}/<path>.sql` 三份脚本
+///
+/// ```ignore
+/// Migration::schema("system", 1, "init", migration_sql!("schema/V1__init"))
+/// ```
+#[macro_export]
+macro_rules! migration_sql {
+    ($path:literal) => {
+        $crate::db::migration::MigrationSql {
+            mysql: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/migrations/mysql/",
+                $path,
+                ".sql"
+            )),
+            postgres: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/migrations/postgres/",
+                $path,
+                ".sql"
+            )),
+            sqlite: include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/migrations/sqlite/",
+                $path,
+                ".sql"
+            )),
+        }
+    };
+}
+
+/// 版本迁移
+#[derive(Debug, Clone)]
+pub struct Migration {
+    /// 所属模块
+    pub module: &'static str,
+    /// 版本号，同一模块同一类型内递增
+    pub version: i64,
+    /// 迁移名称
+    pub name: &'static str,
+    /// 迁移类型
+    pub kind: MigrationKind,
+    /// 迁移脚本
+    pub sql: MigrationSql,
+}
+
+impl Migration {
+    /// 创建表结构迁移
+    pub fn schema(
+        module: &'static str,
+        version: i64,
+        name: &'static str,
+        sql: MigrationSql,
+    ) -> Self {
+        Self {
+            module,
+            version,
+            name,
+            kind: MigrationKind::Schema,
+            sql,
+        }
+    }
+
+    /// 创建初始数据迁移
+    pub fn seed(module: &'static str, version: i64, name: &'static str, sql: MigrationSql) -> Self {
+        Self {
+            module,
+            version,
+            name,
+            kind: MigrationKind::Seed,
+            sql,
+        }
+    }
+
+    /// 脚本校验和（FNV-1a 64位），用于发现已执行脚本被修改
+    pub fn checksum(&self, backend: DatabaseBackend) -> String {
+        let hash = self
+            .sql
+            .for_backend(backend)
+            .bytes()
+            .fold(0xcbf29ce484222325u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+        format!("{:016x}", hash)
+    }
+
+    fn key(&self) -> (String, MigrationKind, i64) {
+        (self.module.to_string(), self.kind, self.version)
+    }
+}
+
+/// 迁移状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    /// 已执行
+    Applied,
+    /// 未执行
+    Pending,
+    /// 已执行，但脚本内容与执行时不一致
+    Modified,
+    /// 数据库中存在，程序中没有（数据库版本高于程序版本）
+    Unknown,
+}
+
+impl fmt::Display for MigrationState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MigrationState::Applied => "已执行",
+            MigrationState::Pending => "未执行",
+            MigrationState::Modified => "脚本已修改",
+            MigrationState::Unknown => "未知版本",
+        })
+    }
+}
+
+/// 单个迁移的状态
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub module: String,
+    pub kind: MigrationKind,
+    pub version: i64,
+    pub name: String,
+    pub state: MigrationState,
+}
+
+impl fmt::Display for MigrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} V{}__{} {}",
+            self.module, self.kind, self.version, self.name, self.state
+        )
+    }
+}
+
+/// 数据库迁移执行器
+pub struct Migrator {
+    db: Arc<DatabaseConnection>,
+    migrations: Vec<Migration>,
+}
+
+impl Migrator {
+    /// 创建迁移执行器，迁移按模块传入顺序、版本号、类型（表结构在前）排序
+    pub fn new(db: Arc<DatabaseConnection>, migrations: Vec<Migration>) -> Self {
+        let mut module_order: Vec<&'static str> = Vec::new();
+        for migration in &migrations {
+            if !module_order.contains(&migration.module) {
+                module_order.push(migration.module);
+            }
+        }
+        let mut migrations = migrations;
+        migrations.sort_by_key(|m| {
+            let module = module_order.iter().position(|name| *name == m.module);
+            (module, m.version, m.kind)
+        });
+        Self { db, migrations }
+    }
+
+    /// 创建迁移记录表
+    async fn ensure_history_table(&self) -> Result<(), DbErr> {
+        let backend = self.db.get_database_backend();
+        let mut stmt = Schema::new(backend).create_table_from_entity(history::Entity);
+        stmt.if_not_exists();
+        self.db.execute(backend.build(&stmt)).await?;
+        Ok(())
+    }
+
+    /// 已执行的迁移记录
+    async fn applied(&self) -> Result<Vec<history::Model>, DbErr> {
+        self.ensure_history_table().await?;
+        history::Entity::find().all(self.db.as_ref()).await
+    }
+
+    /// 查询所有迁移的状态
+    pub async fn status(&self) -> Result<Vec<MigrationStatus>, DbErr> {
+        let backend = self.db.get_database_backend();
+        let mut applied: HashMap<(String, MigrationKind, i64), history::Model> = HashMap::new();
+        let mut unknown = Vec::new();
+        for record in self.applied().await? {
+            let kind = match record.kind.as_str() {
+                "schema" => MigrationKind::Schema,
+                "seed" => MigrationKind::Seed,
+                _ => {
+                    unknown.push(record);
+                    continue;
+                }
+            };
+            applied.insert((record.module.clone(), kind, record.version), record);
+        }
+
+        let mut result: Vec<MigrationStatus> = self
+            .migrations
+            .iter()
+            .map(|migration| {
+                let state = match applied.remove(&migration.key()) {
+                    Some(record) if record.checksum == migration.checksum(backend) => {
+                        MigrationState::Applied
+                    }
+                    Some(_) => MigrationState::Modified,
+                    None => MigrationState::Pending,
+                };
+                MigrationStatus {
+                    module: migration.module.to_string(),
+                    kind: migration.kind,
+                    version: migration.version,
+                    name: migration.name.to_string(),
+                    state,
+                }
+            })
+            .collect();
+
+        let mut unknown: Vec<MigrationStatus> = applied
+            .into_iter()
+            .map(|((module, kind, version), record)| MigrationStatus {
+                module,
+                kind,
+                version,
+                name: record.name,
+                state: MigrationState::Unknown,
+            })
+            .chain(unknown.into_iter().map(|record| MigrationStatus {
+                module: record.module,
+                kind: MigrationKind::Schema,
+                version: record.version,
+                name: record.name,
+                state: MigrationState::Unknown,
+            }))
+            .collect();
+        unknown.sort_by(|a, b| (&a.module, a.kind, a.version).cmp(&(&b.module, b.kind, b.version)));
+        result.extend(unknown);
+        Ok(result)
+    }
+
+    /// 执行未执行的迁移，`with_seed` 为 false 时只执行表结构迁移，返回本次执行的迁移
+    ///
+    /// 存在被修改的脚本或未知版本时不执行任何迁移
+    pub async fn up(&self, with_seed: bool) -> Result<Vec<MigrationStatus>, DbErr> {
+        let status = self.status().await?;
+        check_conflicts(&status)?;
+
+        let backend = self.db.get_database_backend();
+        let mut executed = Vec::new();
+        for (migration, status) in self.migrations.iter().zip(status) {
+            if status.state != MigrationState::Pending
+                || (migration.kind == MigrationKind::Seed && !with_seed)
+            {
+                continue;
+            }
+            info!("执行数据库迁移: {}", status);
+            let txn = self.db.begin().await?;
+            txn.execute_unprepared(migration.sql.for_backend(backend))
+                .await
+                .map_err(|e| DbErr::Migration(format!("{} 执行失败: {}", status, e)))?;
+            history::Entity::insert(record(migration, backend))
+                .exec_without_returning(&txn)
+                .await?;
+            txn.commit().await?;
+            executed.push(MigrationStatus {
+                state: MigrationState::Applied,
+                ..status
+            });
+        }
+        Ok(executed)
+    }
+
+    /// 将版本号不超过 `version` 的未执行迁移标记为已执行，但不执行脚本
+    ///
+    /// 用于接管在引入迁移之前已经手工初始化的数据库
+    pub async fn baseline(&self, version: i64) -> Result<Vec<MigrationStatus>, DbErr> {
+        let status = self.status().await?;
+        check_conflicts(&status)?;
+
+        let backend = self.db.get_database_backend();
+        let mut marked = Vec::new();
+        for (migration, status) in self.migrations.iter().zip(status) {
+            if status.state != MigrationState::Pending || migration.version > version {
+                continue;
+            }
+            history::Entity::insert(record(migration, backend))
+                .exec_without_returning(self.db.as_ref())
+                .await?;
+            marked.push(MigrationStatus {
+                state: MigrationState::Applied,
+                ..status
+            });
+        }
+        Ok(marked)
+    }
+
+    /// 校验数据库版本与程序是否一致，未执行的初始数据迁移不影响启动
+    pub async fn verify(&self) -> Result<(), DbErr> {
+        let status = self.status().await?;
+        check_conflicts(&status)?;
+
+        let pending: Vec<String> = status
+            .iter()
+            .filter(|s| s.kind == MigrationKind::Schema && s.state == MigrationState::Pending)
+            .map(|s| s.to_string())
+            .collect();
+        if !pending.is_empty() {
+            return Err(DbErr::Migration(format!(
+                "数据库表结构版本低于程序版本，请执行迁移: {}",
+                pending.join(", ")
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 存在被修改的脚本或未知版本时返回错误
+fn check_conflicts(status: &[MigrationStatus]) -> Result<(), DbErr> {
+    let conflicts: Vec<String> = status
+        .iter()
+        .filter(|s| matches!(s.state, MigrationState::Modified | MigrationState::Unknown))
+        .map(|s| s.to_string())
+        .collect();
+    if conflicts.is_empty() {
+        Ok(())
+    } else {
+        Err(DbErr::Migration(format!(
+            "数据库版本与程序不一致: {}",
+            conflicts.join(", ")
+        )))
+    }
+}
+
+fn record(migration: &Migration, backend: DatabaseBackend) -> history::ActiveModel {
+    history::ActiveModel {
+        module: Set(migration.module.to_string()),
+        kind: Set(migration.kind.as_str().to_string()),
+        version: Set(migration.version),
+        name: Set(migration.name.to_string()),
+        checksum: Set(migration.checksum(backend)),
+        applied_at: Set(Utc::now()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db::DbSettings;
+    use crate::db::DbManager;
+    use sea_orm::{FromQueryResult, Statement};
+
+    fn sql(text: &'static str) -> MigrationSql {
+        MigrationSql {
+            mysql: text,
+            postgres: text,
+            sqlite: text,
+        }
+    }
+
+    fn migrations() -> Vec<Migration> {
+        vec![
+            Migration::seed(
+                "demo",
+                1,
+                "init_data",
+                sql("insert into demo_item (id, name) values (1, 'a');"),
+            ),
+            Migration::schema(
+                "demo",
+                2,
+                "add_remark",
+                sql("alter table demo_item add column remark varchar(100);"),
+            ),
+            Migration::schema(
+                "demo",
+                1,
+                "init",
+                sql("create table demo_item (id integer primary key, name varchar(30));"),
+            ),
+        ]
+    }
+
+    async fn connection() -> Arc<DatabaseConnection> {
+        DbManager::new(DbSettings::from_url("sqlite::memory:"))
+            .await
+            .unwrap()
+            .get_connection()
+    }
+
+    async fn count_items(db: &DatabaseConnection) -> i64 {
+        #[derive(FromQueryResult)]
+        struct Count {
+            count: i64,
+        }
+        Count::find_by_statement(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            "select count(*) as count from demo_item".to_string(),
+        ))
+        .one(db)
+        .await
+        .unwrap()
+        .unwrap()
+        .count
+    }
+
+    #[tokio::test]
+    async fn test_up_runs_in_order_once() {
+        let db = connection().await;
+        let migrator = Migrator::new(db.clone(), migrations());
+        assert!(migrator.verify().await.is_err());
+
+        let executed = migrator.up(true).await.unwrap();
+        let order: Vec<(MigrationKind, i64)> =
+            executed.iter().map(|s| (s.kind, s.version)).collect();
+        assert_eq!(
+            order,
+            vec![
+                (MigrationKind::Schema, 1),
+                (MigrationKind::Seed, 1),
+                (MigrationKind::Schema, 2)
+            ]
+        );
+        assert_eq!(count_items(&db).await, 1);
+
+        assert!(migrator.up(true).await.unwrap().is_empty());
+        assert!(migrator.verify().await.is_ok());
+        assert!(migrator
+            .status()
+            .await
+            .unwrap()
+            .iter()
+            .all(|s| s.state == MigrationState::Applied));
+    }
+
+    #[tokio::test]
+    async fn test_pending_seed_does_not_block_startup() {
+        let db = connection().await;
+        let migrator = Migrator::new(db.clone(), migrations());
+        assert_eq!(migrator.up(false).await.unwrap().len(), 2);
+        assert_eq!(count_items(&db).await, 0);
+        assert!(migrator.verify().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_version_mismatch() {
+        let db = connection().await;
+        Migrator::new(db.clone(), migrations())
+            .up(true)
+            .await
+            .unwrap();
+
+        // 程序版本低于数据库版本
+        let older: Vec<Migration> = migrations()
+            .into_iter()
+            .filter(|m| m.version == 1)
+            .collect();
+        let status = Migrator::new(db.clone(), older).status().await.unwrap();
+        assert!(status
+            .iter()
+            .any(|s| s.version == 2 && s.state == MigrationState::Unknown));
+
+        // 已执行的脚本被修改
+        let mut modified = migrations();
+        modified[0].sql = sql("insert into demo_item (id, name) values (1, 'b');");
+        let migrator = Migrator::new(db.clone(), modified);
+        assert!(migrator.verify().await.is_err());
+        assert!(migrator.up(true).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_baseline() {
+        let db = connection().await;
+        db.execute_unprepared("create table demo_item (id integer primary key, name varchar(30));")
+            .await
+            .unwrap();
+
+        let migrator = Migrator::new(db.clone(), migrations());
+        let marked = migrator.baseline(1).await.unwrap();
+        assert_eq!(marked.len(), 2);
+
+        let executed = migrator.up(true).await.unwrap();
+        assert_eq!(executed.len(), 1);
+        assert_eq!(executed[0].version, 2);
+        assert_eq!(count_items(&db).await, 0);
+    }
+}
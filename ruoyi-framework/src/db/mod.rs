@@ -1,20 +1,33 @@
 // ruoyi-framework/src/db/mod.rs
 //! 数据库模块，负责数据库连接和操作
 
-use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr};
+use sea_orm::{
+    ConnectOptions, ConnectionTrait, Database, DatabaseBackend, DatabaseConnection, DbErr,
+};
 use std::sync::Arc;
 use std::time::Duration;
 
-use crate::config::db::DbSettings;
+use crate::config::db::{is_sqlite_memory_url, DbSettings};
 
+pub mod audit;
+pub mod encryption;
+pub mod history;
+pub mod migration;
+pub mod query;
+pub mod replica;
 pub mod repository;
 pub mod transaction;
 
+use replica::ReadRouter;
+use transaction::CurrentConnection;
+
 /// 数据库连接管理器
 #[derive(Clone)]
 pub struct DbManager {
-    /// 数据库连接
+    /// 数据库连接（主库）
     conn: Arc<DatabaseConnection>,
+    /// 只读查询路由
+    read_router: ReadRouter,
     /// 数据库配置
     config: Arc<DbSettings>,
 }
@@ -23,14 +36,27 @@ impl DbManager {
     /// 创建数据库连接管理器
     pub async fn new(config: DbSettings) -> Result<Self, DbErr> {
         let config = Arc::new(config);
-        let conn = Self::create_connection(&config).await?;
+        let conn = Arc::new(Self::create_connection(&config, &config.url).await?);
+
+        // 连接只读副本并定时检查健康状态
+        let read_router = ReadRouter::connect(conn.clone(), config.clone()).await;
+        read_router.spawn_health_check(Duration::from_secs(
+            config.replica.health_check_interval.max(1),
+        ));
 
-        Ok(Self { conn: Arc::new(conn), config })
+        Ok(Self {
+            conn,
+            read_router,
+            config,
+        })
     }
 
-    /// 创建数据库连接
-    async fn create_connection(config: &DbSettings) -> Result<DatabaseConnection, DbErr> {
-        let mut opt = ConnectOptions::new(config.url.clone());
+    /// 创建数据库连接，只读副本与主库使用相同的连接池配置
+    pub(crate) async fn create_connection(
+        config: &DbSettings,
+        url: &str,
+    ) -> Result<DatabaseConnection, DbErr> {
+        let mut opt = ConnectOptions::new(url.to_string());
 
         opt.max_connections(config.max_connections)
             .min_connections(config.min_connections)
@@ -39,16 +65,121 @@ impl DbManager {
             .max_lifetime(Duration::from_secs(config.max_lifetime))
             .sqlx_logging(true);
 
+        // SQLite内存数据库的每个连接都是独立的库，只保留一个永不回收的连接
+        if is_sqlite_memory_url(url) {
+            let forever = Duration::from_secs(u32::MAX as u64);
+            opt.max_connections(1)
+                .min_connections(1)
+                .idle_timeout(forever)
+                .max_lifetime(forever);
+        }
+
         Database::connect(opt).await
     }
 
-    /// 获取数据库连接
+    /// 获取数据库连接（主库），写操作和事务使用
     pub fn get_connection(&self) -> Arc<DatabaseConnection> {
         self.conn.clone()
     }
 
+    /// 获取只读查询使用的连接，处于环境事务中时使用当前事务，否则优先使用健康的只读副本
+    pub fn get_read_connection(&self) -> CurrentConnection<'_> {
+        self.read_router.connection()
+    }
+
+    /// 获取只读查询路由，供仓库按查询选择连接
+    pub fn read_router(&self) -> ReadRouter {
+        self.read_router.clone()
+    }
+
+    /// 获取数据库类型，由连接URL决定（MySQL、PostgreSQL、SQLite）
+    pub fn backend(&self) -> DatabaseBackend {
+        self.conn.get_database_backend()
+    }
+
     /// 获取数据库配置
     pub fn get_config(&self) -> Arc<DbSettings> {
         self.config.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::repository::{unset_zero_primary_key, BaseRepository, Repository};
+    use crate::logger::entity::{
+        LoginInfoActiveModel, LoginInfoEntity, LoginInfoModel, OperLogEntity,
+    };
+    use sea_orm::{EntityTrait, IntoActiveModel, PaginatorTrait};
+
+    /// 使用SQLite内存数据库并执行系统模块的建表脚本
+    async fn sqlite_manager() -> DbManager {
+        let manager = DbManager::new(DbSettings::from_url("sqlite::memory:"))
+            .await
+            .unwrap();
+        for sql in [
+            include_str!("../../../ruoyi-system/migrations/sqlite/schema/V1__init.sql"),
+            include_str!("../../../ruoyi-system/migrations/sqlite/schema/V3__tenant.sql"),
+        ] {
+            manager
+                .get_connection()
+                .execute_unprepared(sql)
+                .await
+                .unwrap();
+        }
+        manager
+    }
+
+    fn login_info(user_name: &str) -> LoginInfoActiveModel {
+        LoginInfoModel {
+            info_id: 0,
+            user_name: Some(user_name.to_string()),
+            ipaddr: None,
+            login_location: None,
+            browser: None,
+            os: None,
+            status: Some("0".to_string()),
+            msg: None,
+            login_time: Some(chrono::Utc::now()),
+            tenant_id: 1,
+        }
+        .into_active_model()
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_schema() {
+        let manager = sqlite_manager().await;
+        assert_eq!(manager.backend(), DatabaseBackend::Sqlite);
+
+        let conn = manager.get_connection();
+        assert_eq!(OperLogEntity::find().count(conn.as_ref()).await.unwrap(), 0);
+        assert_eq!(
+            LoginInfoEntity::find().count(conn.as_ref()).await.unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_zero_primary_key() {
+        let manager = sqlite_manager().await;
+        let conn = manager.get_connection();
+        let repository =
+            BaseRepository::<LoginInfoEntity, LoginInfoActiveModel>::new(conn.as_ref().clone());
+
+        let first = repository.insert(login_info("admin")).await.unwrap();
+        let second = repository.insert(login_info("ry")).await.unwrap();
+        assert!(first.info_id > 0);
+        assert_eq!(second.info_id, first.info_id + 1);
+
+        let mut models = vec![login_info("a"), login_info("b")];
+        models.iter_mut().for_each(unset_zero_primary_key);
+        LoginInfoEntity::insert_many(models)
+            .exec(conn.as_ref())
+            .await
+            .unwrap();
+        assert_eq!(
+            LoginInfoEntity::find().count(conn.as_ref()).await.unwrap(),
+            4
+        );
+    }
+}
@@ -0,0 +1,201 @@
+// ruoyi-framework/src/db/query.rs
+//! 查询条件模块，配合 `#[derive(QueryCondition)]` 将查询参数转换为 sea-orm 的 `Condition`
+//!
+//! ```ignore
+//! use ruoyi_framework::db::query::QueryCondition;
+//!
+//! #[derive(Deserialize, QueryCondition)]
+//! #[query(columns = "crate::entity::prelude::PostColumn")]
+//! pub struct PostQuery {
+//!     #[query(like)]
+//!     pub post_name: Option<String>,
+//!     #[query(eq)]
+//!     pub status: Option<String>,
+//! }
+//! ```
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+pub use ruoyi_macros::QueryCondition;
+pub use sea_orm::{ColumnTrait, Condition};
+
+/// 可转换为查询条件的查询参数
+pub trait QueryCondition {
+    /// 生成查询条件，所有字段条件之间为且关系
+    fn to_condition(&self) -> Condition;
+}
+
+/// 查询参数的字段值
+pub trait QueryValue {
+    /// 是否为空值，空值不添加查询条件
+    fn is_blank(&self) -> bool {
+        false
+    }
+}
+
+impl QueryValue for String {
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl QueryValue for &str {
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl<T> QueryValue for Vec<T> {
+    fn is_blank(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+macro_rules! impl_query_value {
+    ($($ty:ty),*) => {
+        $(impl QueryValue for $ty {})*
+    };
+}
+
+impl_query_value!(
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64,
+    f32,
+    f64,
+    DateTime<Utc>,
+    NaiveDateTime,
+    NaiveDate
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::encryption::blind_index;
+    use sea_orm::{DbBackend, EntityTrait, QueryFilter, QueryTrait};
+
+    mod article {
+        use chrono::{DateTime, Utc};
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+        #[sea_orm(table_name = "sys_article")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub article_id: i64,
+            pub title: Option<String>,
+            pub status: Option<String>,
+            pub category_id: Option<i64>,
+            pub publish_time: Option<DateTime<Utc>>,
+            pub author_phone_index: Option<String>,
+            pub create_time: Option<DateTime<Utc>>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[derive(Default, QueryCondition)]
+    #[query(columns = "article::Column")]
+    struct ArticleQuery {
+        #[query(eq, column = "article_id")]
+        id: Option<i64>,
+        #[query(like)]
+        title: Option<String>,
+        #[query(eq)]
+        status: String,
+        #[query(in, column = "category_id")]
+        category_ids: Option<Vec<i64>>,
+        #[query(between = "begin_time,end_time")]
+        begin_time: Option<DateTime<Utc>>,
+        end_time: Option<DateTime<Utc>>,
+        #[query(between = "publish_begin, publish_end", column = "publish_time")]
+        publish_begin: Option<DateTime<Utc>>,
+        publish_end: Option<DateTime<Utc>>,
+        #[query(blind_index)]
+        author_phone: Option<String>,
+        /// 不参与查询的字段
+        #[allow(dead_code)]
+        page_num: u64,
+    }
+
+    fn sql(query: &ArticleQuery) -> String {
+        article::Entity::find()
+            .filter(query.to_condition())
+            .build(DbBackend::Sqlite)
+            .to_string()
+    }
+
+    #[test]
+    fn test_empty_query() {
+        let query = ArticleQuery {
+            title: Some("  ".to_string()),
+            category_ids: Some(vec![]),
+            page_num: 1,
+            ..Default::default()
+        };
+        let sql = sql(&query);
+        // 空条件生成 WHERE TRUE
+        assert!(sql.ends_with("FROM \"sys_article\" WHERE TRUE"), "{}", sql);
+    }
+
+    #[test]
+    fn test_query_condition() {
+        let time = "2024-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let query = ArticleQuery {
+            id: Some(1),
+            title: Some("若依".to_string()),
+            status: "0".to_string(),
+            category_ids: Some(vec![1, 2]),
+            begin_time: Some(time),
+            publish_end: Some(time),
+            author_phone: Some("15888888888".to_string()),
+            ..Default::default()
+        };
+        let sql = sql(&query);
+        assert!(sql.contains(r#""sys_article"."article_id" = 1"#), "{}", sql);
+        assert!(
+            sql.contains(r#""sys_article"."title" LIKE '%若依%'"#),
+            "{}",
+            sql
+        );
+        assert!(sql.contains(r#""sys_article"."status" = '0'"#), "{}", sql);
+        assert!(
+            sql.contains(r#""sys_article"."category_id" IN (1, 2)"#),
+            "{}",
+            sql
+        );
+        assert!(
+            sql.contains(r#""sys_article"."create_time" >= '2024-01-01"#),
+            "{}",
+            sql
+        );
+        assert!(
+            !sql.contains(r#""sys_article"."create_time" <="#),
+            "{}",
+            sql
+        );
+        assert!(
+            sql.contains(r#""sys_article"."publish_time" <= '2024-01-01"#),
+            "{}",
+            sql
+        );
+        let index = blind_index("15888888888").unwrap();
+        assert!(
+            sql.contains(&format!(
+                r#""sys_article"."author_phone_index" = '{}'"#,
+                index
+            )),
+            "{}",
+            sql
+        );
+    }
+}
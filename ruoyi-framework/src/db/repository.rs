@@ -1,15 +1,189 @@
 // ruoyi-framework/src/db/repository.rs
 //! 数据库仓库模块，提供通用的 CRUD 操作接口
+//!
+//! 通过 [`BaseRepository::with_soft_delete`] 启用逻辑删除后：
+//! 1. `select`、`find_*`、`paginate` 自动排除已删除的记录
+//! 2. `delete_*` 只将删除标志改为已删除值
+//! 3. `restore_by_ids` 恢复已删除的记录，`purge_by_ids` 物理删除已删除的记录
+//!
+//! 通过 [`BaseRepository::with_optimistic_lock`] 启用乐观锁后，`update` 以版本号或更新时间为条件更新，
+//! 记录已被其他人修改时返回 [`optimistic_lock_conflict`]，转换为 [`ruoyi_common::error::Error::Conflict`]
+//!
+//! 通过 [`BaseRepository::with_tenant`] 启用多租户后，查询、修改和删除自动限定在当前租户，参见 [`crate::tenant`]
+//!
+//! 通过 [`BaseRepository::with_change_history`] 启用变更历史后，修改、删除、恢复和彻底删除记录字段差异，
+//! 参见 [`crate::db::history`]
+//!
+//! 仓库的读写操作会加入当前的环境事务，参见 [`crate::db::transaction`]
+//!
+//! 分页查询支持两种方式：
+//! 1. `paginate`：按页码分页，支持多字段排序，排序字段可通过 [`BaseRepository::with_sortable_columns`] 限定
+//! 2. `paginate_by_cursor`：按主键倒序的游标分页，不统计总数，适合数据量很大的日志表
 
+use chrono::{Local, Utc};
+use sea_orm::sea_query::{IntoValueTuple, SimpleExpr};
 use sea_orm::{
-    ActiveModelBehavior, ActiveModelTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
-    IntoActiveModel, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, Select, TransactionTrait,
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait,
+    DatabaseConnection, DbErr, EntityTrait, IdenStatic, IntoActiveModel, Iterable, ModelTrait,
+    PaginatorTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryOrder, QuerySelect,
+    Select, TransactionTrait, Value,
 };
+use serde::Serialize;
 use std::fmt::Debug;
-use std::str::FromStr;
 
+use ruoyi_common::error::optimistic_lock_conflict;
 use ruoyi_common::vo::PageParam;
 
+use crate::db::audit::fill_audit_fields;
+use crate::db::history::{model_key, ChangeHistory, ChangeLogEntity, ChangeType};
+use crate::db::transaction::{current_connection, CurrentConnection};
+use crate::tenant::tenant_condition;
+
+/// 清除自增主键上的0值占位
+///
+/// 服务层构造新增实体时用0填充自增主键，MySQL会为0自动分配主键，
+/// PostgreSQL和SQLite则会原样写入0，因此插入前将其改为未设置，由数据库分配主键
+pub fn unset_zero_primary_key<A: ActiveModelTrait>(active_model: &mut A) {
+    if !<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment() {
+        return;
+    }
+    for key in <A::Entity as EntityTrait>::PrimaryKey::iter() {
+        let column = key.into_column();
+        let is_zero = matches!(
+            active_model.get(column).into_value(),
+            Some(Value::TinyInt(Some(0)))
+                | Some(Value::SmallInt(Some(0)))
+                | Some(Value::Int(Some(0)))
+                | Some(Value::BigInt(Some(0)))
+                | Some(Value::TinyUnsigned(Some(0)))
+                | Some(Value::SmallUnsigned(Some(0)))
+                | Some(Value::Unsigned(Some(0)))
+                | Some(Value::BigUnsigned(Some(0)))
+        );
+        if is_zero {
+            active_model.not_set(column);
+        }
+    }
+}
+
+/// 逻辑删除配置
+///
+/// ```ignore
+/// // sys_user：0代表存在 2代表删除
+/// SoftDelete::new(UserColumn::DelFlag, "2", "0")
+/// // cms_file_info：N未删除 Y被删除
+/// SoftDelete::new(FileInfoColumn::DelFlag, "Y", "N")
+/// ```
+#[derive(Debug, Clone)]
+pub struct SoftDelete<C: ColumnTrait> {
+    /// 删除标志列
+    column: C,
+    /// 已删除的值
+    deleted: Value,
+    /// 正常（未删除）的值
+    normal: Value,
+}
+
+impl<C: ColumnTrait> SoftDelete<C> {
+    /// 创建逻辑删除配置
+    pub fn new(column: C, deleted: impl Into<Value>, normal: impl Into<Value>) -> Self {
+        Self {
+            column,
+            deleted: deleted.into(),
+            normal: normal.into(),
+        }
+    }
+
+    /// 删除标志列
+    pub fn column(&self) -> C {
+        self.column
+    }
+
+    /// 未删除的条件，删除标志为空的历史数据视为未删除
+    pub fn not_deleted(&self) -> Condition {
+        Condition::any()
+            .add(self.column.ne(self.deleted.clone()))
+            .add(self.column.is_null())
+    }
+
+    /// 已删除的条件
+    pub fn deleted(&self) -> SimpleExpr {
+        self.column.eq(self.deleted.clone())
+    }
+}
+
+/// 乐观锁配置
+///
+/// ```ignore
+/// // 整数版本号，每次更新加1
+/// OptimisticLock::version(RoleColumn::Version)
+/// // 更新时间，精度受数据库时间类型限制，同一时刻内的并发修改无法识别
+/// OptimisticLock::update_time(ConfigColumn::UpdateTime)
+/// ```
+#[derive(Debug, Clone)]
+pub enum OptimisticLock<C: ColumnTrait> {
+    /// 版本号列
+    Version(C),
+    /// 更新时间列
+    UpdateTime(C),
+}
+
+impl<C: ColumnTrait> OptimisticLock<C> {
+    /// 使用版本号列
+    pub fn version(column: C) -> Self {
+        OptimisticLock::Version(column)
+    }
+
+    /// 使用更新时间列
+    pub fn update_time(column: C) -> Self {
+        OptimisticLock::UpdateTime(column)
+    }
+
+    /// 乐观锁列
+    pub fn column(&self) -> C {
+        match self {
+            OptimisticLock::Version(column) | OptimisticLock::UpdateTime(column) => *column,
+        }
+    }
+
+    /// 更新后的值，版本号加1，更新时间取当前时间，不支持的类型返回None
+    fn next_value(&self, current: &Value) -> Option<Value> {
+        match (self, current) {
+            (OptimisticLock::Version(_), Value::SmallInt(Some(v))) => Some(Value::from(v + 1)),
+            (OptimisticLock::Version(_), Value::Int(Some(v))) => Some(Value::from(v + 1)),
+            (OptimisticLock::Version(_), Value::BigInt(Some(v))) => Some(Value::from(v + 1)),
+            (OptimisticLock::Version(_), Value::Unsigned(Some(v))) => Some(Value::from(v + 1)),
+            (OptimisticLock::Version(_), Value::BigUnsigned(Some(v))) => Some(Value::from(v + 1)),
+            (OptimisticLock::UpdateTime(_), Value::ChronoDateTimeUtc(_)) => {
+                Some(Value::from(Utc::now()))
+            }
+            (OptimisticLock::UpdateTime(_), Value::ChronoDateTimeLocal(_)) => {
+                Some(Value::from(Local::now()))
+            }
+            (OptimisticLock::UpdateTime(_), Value::ChronoDateTime(_)) => {
+                Some(Value::from(Local::now().naive_local()))
+            }
+            _ => None,
+        }
+    }
+
+    /// 乐观锁列等于期望值的条件，从未更新过的记录更新时间为空
+    fn matches(&self, expected: Value) -> SimpleExpr {
+        let column = self.column();
+        let is_null = matches!(
+            expected,
+            Value::ChronoDateTimeUtc(None)
+                | Value::ChronoDateTimeLocal(None)
+                | Value::ChronoDateTime(None)
+        );
+        if is_null {
+            column.is_null()
+        } else {
+            column.eq(expected)
+        }
+    }
+}
+
 /// 通用仓库特征
 #[async_trait::async_trait]
 pub trait Repository<E, A>: Send + Sync
@@ -52,12 +226,33 @@ where
             + Send
             + Sync;
 
+    /// 根据主键列表恢复逻辑删除的实体
+    async fn restore_by_ids<T>(&self, ids: Vec<T>) -> Result<u64, DbErr>
+    where
+        T: Into<<<E as EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>
+            + Send
+            + Sync;
+
+    /// 根据主键列表物理删除实体，启用逻辑删除时只清除已删除的记录
+    async fn purge_by_ids<T>(&self, ids: Vec<T>) -> Result<u64, DbErr>
+    where
+        T: Into<<<E as EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>
+            + Send
+            + Sync;
+
     /// 分页查询
     async fn paginate(
         &self,
         page_param: &PageParam,
         condition: Option<Condition>,
     ) -> Result<(Vec<E::Model>, u64), DbErr>;
+
+    /// 游标分页查询，返回当前页数据和下一页的游标，没有下一页时游标为None
+    async fn paginate_by_cursor(
+        &self,
+        page_param: &PageParam,
+        condition: Option<Condition>,
+    ) -> Result<(Vec<E::Model>, Option<i64>), DbErr>;
 }
 
 /// 基础仓库实现
@@ -68,6 +263,16 @@ where
 {
     /// 数据库连接
     db: DatabaseConnection,
+    /// 逻辑删除配置，为None时物理删除
+    soft_delete: Option<SoftDelete<E::Column>>,
+    /// 允许排序的字段，为None时允许实体的所有字段
+    sortable_columns: Option<Vec<E::Column>>,
+    /// 乐观锁配置，为None时直接按主键更新
+    optimistic_lock: Option<OptimisticLock<E::Column>>,
+    /// 租户列，为None时不区分租户
+    tenant: Option<E::Column>,
+    /// 变更历史配置，为None时不记录
+    change_history: Option<ChangeHistory<E::Column>>,
     /// 实体类型
     _phantom: std::marker::PhantomData<(E, A)>,
 }
@@ -81,14 +286,414 @@ where
     pub fn new(db: DatabaseConnection) -> Self {
         Self {
             db,
+            soft_delete: None,
+            sortable_columns: None,
+            optimistic_lock: None,
+            tenant: None,
+            change_history: None,
             _phantom: std::marker::PhantomData,
         }
     }
 
-    /// 获取查询选择器
+    /// 当前连接，处于环境事务中时加入事务
+    pub fn conn(&self) -> CurrentConnection<'_> {
+        current_connection(&self.db)
+    }
+
+    /// 启用逻辑删除
+    pub fn with_soft_delete(mut self, soft_delete: SoftDelete<E::Column>) -> Self {
+        self.soft_delete = Some(soft_delete);
+        self
+    }
+
+    /// 限定允许排序的字段，前端传入其他字段时返回错误
+    pub fn with_sortable_columns(mut self, columns: impl IntoIterator<Item = E::Column>) -> Self {
+        self.sortable_columns = Some(columns.into_iter().collect());
+        self
+    }
+
+    /// 启用乐观锁
+    pub fn with_optimistic_lock(mut self, optimistic_lock: OptimisticLock<E::Column>) -> Self {
+        self.optimistic_lock = Some(optimistic_lock);
+        self
+    }
+
+    /// 启用多租户，按租户列限定在当前租户
+    pub fn with_tenant(mut self, column: E::Column) -> Self {
+        self.tenant = Some(column);
+        self
+    }
+
+    /// 启用变更历史
+    pub fn with_change_history(mut self, change_history: ChangeHistory<E::Column>) -> Self {
+        self.change_history = Some(change_history);
+        self
+    }
+
+    /// 限定在当前租户的条件，未启用多租户时不做限制
+    pub fn tenant_condition(&self) -> Condition {
+        match self.tenant {
+            Some(column) => tenant_condition(column),
+            None => Condition::all(),
+        }
+    }
+
+    /// 逻辑删除配置
+    pub fn soft_delete(&self) -> Option<&SoftDelete<E::Column>> {
+        self.soft_delete.as_ref()
+    }
+
+    /// 获取查询选择器，启用逻辑删除时排除已删除的记录
     pub fn select(&self) -> Select<E> {
-        E::find()
+        match &self.soft_delete {
+            Some(soft_delete) => self.select_with_deleted().filter(soft_delete.not_deleted()),
+            None => self.select_with_deleted(),
+        }
+    }
+
+    /// 获取包含已删除记录的查询选择器
+    pub fn select_with_deleted(&self) -> Select<E> {
+        E::find().filter(self.tenant_condition())
+    }
+
+    /// 获取只包含已删除记录的查询选择器，未启用逻辑删除时查询结果为空
+    pub fn select_deleted(&self) -> Select<E> {
+        match &self.soft_delete {
+            Some(soft_delete) => self.select_with_deleted().filter(soft_delete.deleted()),
+            None => self.select_with_deleted().filter(Condition::any()),
+        }
+    }
+
+    /// 在指定连接（如事务）中根据主键列表删除实体，启用逻辑删除时只标记为已删除
+    pub async fn delete_by_ids_with<C, T>(&self, conn: &C, ids: Vec<T>) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+        T: Into<<<E as EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>,
+        E::Model: Serialize,
+    {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let condition = Condition::all()
+            .add(primary_key_condition::<E, T>(ids))
+            .add(self.tenant_condition());
+        let res = match &self.soft_delete {
+            Some(soft_delete) => {
+                let condition = condition.add(soft_delete.not_deleted());
+                let before = self.snapshot(conn, condition.clone()).await?;
+                let rows_affected = self
+                    .mark(soft_delete, &soft_delete.deleted)
+                    .filter(condition)
+                    .exec(conn)
+                    .await?
+                    .rows_affected;
+                self.record_changes(conn, ChangeType::Delete, before)
+                    .await?;
+                rows_affected
+            }
+            None => {
+                let before = self.snapshot(conn, condition.clone()).await?;
+                let rows_affected = E::delete_many()
+                    .filter(condition)
+                    .exec(conn)
+                    .await?
+                    .rows_affected;
+                self.record_changes(conn, ChangeType::Delete, before)
+                    .await?;
+                rows_affected
+            }
+        };
+        Ok(res)
+    }
+
+    /// 在指定连接（如事务）中根据主键列表物理删除实体，启用逻辑删除时只清除已删除的记录
+    pub async fn purge_by_ids_with<C, T>(&self, conn: &C, ids: Vec<T>) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+        T: Into<<<E as EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>,
+        E::Model: Serialize,
+    {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let mut condition = Condition::all()
+            .add(primary_key_condition::<E, T>(ids))
+            .add(self.tenant_condition());
+        if let Some(soft_delete) = &self.soft_delete {
+            condition = condition.add(soft_delete.deleted());
+        }
+        let before = self.snapshot(conn, condition.clone()).await?;
+        let rows_affected = E::delete_many()
+            .filter(condition)
+            .exec(conn)
+            .await?
+            .rows_affected;
+        self.record_changes(conn, ChangeType::Purge, before).await?;
+        Ok(rows_affected)
+    }
+
+    /// 在指定连接（如事务）中更新实体，启用乐观锁时以乐观锁列等于期望值为条件更新
+    ///
+    /// 期望值取实体中乐观锁列的值，未设置时取数据库中的当前值；
+    /// 记录已被其他人修改时返回 [`optimistic_lock_conflict`]，
+    /// 记录不存在、已删除或不属于当前租户时返回 [`DbErr::RecordNotFound`]
+    pub async fn update_with<C>(&self, conn: &C, active_model: A) -> Result<E::Model, DbErr>
+    where
+        C: ConnectionTrait,
+        E::Model: IntoActiveModel<A> + Serialize,
+    {
+        self.update_as(conn, active_model, ChangeType::Update).await
+    }
+
+    /// 更新实体并按指定的变更类型记录变更历史
+    async fn update_as<C>(
+        &self,
+        conn: &C,
+        active_model: A,
+        change_type: ChangeType,
+    ) -> Result<E::Model, DbErr>
+    where
+        C: ConnectionTrait,
+        E::Model: IntoActiveModel<A> + Serialize,
+    {
+        let before = match (
+            &self.change_history,
+            active_model_key_condition(&active_model),
+        ) {
+            (Some(_), Some(key)) => self.snapshot(conn, key).await?,
+            _ => Vec::new(),
+        };
+        let model = self.update_model(conn, active_model).await?;
+        if let (Some(history), Some(before)) = (&self.change_history, before.first()) {
+            if let Some(change_log) = history.change_log(change_type, Some(before), Some(&model)) {
+                ChangeLogEntity::insert(change_log).exec(conn).await?;
+            }
+        }
+        Ok(model)
+    }
+
+    /// 更新实体，不记录变更历史
+    async fn update_model<C>(&self, conn: &C, mut active_model: A) -> Result<E::Model, DbErr>
+    where
+        C: ConnectionTrait,
+        E::Model: IntoActiveModel<A>,
+    {
+        if self.optimistic_lock.is_none() && self.tenant.is_none() {
+            return active_model.update(conn).await;
+        }
+        let key = active_model_key_condition(&active_model)
+            .ok_or_else(|| DbErr::Custom("更新的实体未设置主键".to_string()))?;
+
+        let mut condition = self.tenant_condition();
+        if let Some(lock) = &self.optimistic_lock {
+            let column = lock.column();
+            let expected = match active_model.get(column).into_value() {
+                Some(value) => value,
+                None => self
+                    .select()
+                    .filter(key.clone())
+                    .one(conn)
+                    .await?
+                    .ok_or_else(|| DbErr::RecordNotFound(E::default().as_str().to_string()))?
+                    .get(column),
+            };
+            let next = lock.next_value(&expected).ok_or_else(|| {
+                DbErr::Custom(format!("乐观锁字段类型不支持: {}", column.as_str()))
+            })?;
+            active_model.set(column, next);
+            condition = condition.add(lock.matches(expected));
+        }
+
+        let active_model = A::before_save(active_model, conn, false).await?;
+        match E::update(active_model).filter(condition).exec(conn).await {
+            Ok(model) => A::after_save(model, conn, false).await,
+            // 区分记录已被修改和记录不存在
+            Err(DbErr::RecordNotUpdated) => {
+                if self.optimistic_lock.is_some()
+                    && self.select().filter(key).one(conn).await?.is_some()
+                {
+                    Err(optimistic_lock_conflict())
+                } else {
+                    Err(DbErr::RecordNotFound(E::default().as_str().to_string()))
+                }
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// 按分页参数中的排序字段排序，字段不存在或不允许排序时返回错误
+    pub fn apply_order<Q: QueryOrder>(
+        &self,
+        mut query: Q,
+        page_param: &PageParam,
+    ) -> Result<Q, DbErr> {
+        for (name, asc) in page_param.order_by() {
+            let column = self
+                .sortable_column(&name)
+                .ok_or_else(|| DbErr::Custom(format!("不支持的排序字段: {}", name)))?;
+            query = if asc {
+                query.order_by_asc(column)
+            } else {
+                query.order_by_desc(column)
+            };
+        }
+        Ok(query)
+    }
+
+    /// 根据列名查找允许排序的字段
+    fn sortable_column(&self, name: &str) -> Option<E::Column> {
+        let column = E::Column::iter().find(|column| column.as_str() == name)?;
+        match &self.sortable_columns {
+            Some(columns) => columns
+                .iter()
+                .any(|sortable| sortable.as_str() == name)
+                .then_some(column),
+            None => Some(column),
+        }
+    }
+
+    /// 在指定连接（如只读副本）中按主键倒序游标分页
+    ///
+    /// 查询主键小于游标的下一页数据，多取一条判断是否还有下一页，
+    /// 只支持单列整数主键，分页参数中的排序字段和页码会被忽略
+    pub async fn paginate_by_cursor_with<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        query: Select<E>,
+        page_param: &PageParam,
+    ) -> Result<(Vec<E::Model>, Option<i64>), DbErr> {
+        let column = single_primary_key::<E>()
+            .ok_or_else(|| DbErr::Custom("游标分页只支持单列主键".to_string()))?;
+
+        let mut query = query.order_by_desc(column);
+        if let Some(cursor) = page_param.cursor {
+            query = query.filter(column.lt(cursor));
+        }
+        let limit = page_param.limit();
+        let mut items = query.limit(limit + 1).all(conn).await?;
+
+        if items.len() as u64 <= limit {
+            return Ok((items, None));
+        }
+        items.truncate(limit as usize);
+        let next_cursor = items
+            .last()
+            .and_then(|item| value_to_i64(item.get(column)))
+            .ok_or_else(|| DbErr::Custom("游标分页只支持整数主键".to_string()))?;
+        Ok((items, Some(next_cursor)))
     }
+
+    /// 启用变更历史时查询变更前的记录（包含已删除的记录），未启用时不查询
+    async fn snapshot<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        condition: Condition,
+    ) -> Result<Vec<E::Model>, DbErr> {
+        if self.change_history.is_none() {
+            return Ok(Vec::new());
+        }
+        self.select_with_deleted().filter(condition).all(conn).await
+    }
+
+    /// 记录批量操作的变更历史，操作后按主键重新查询，已物理删除的记录变更后为空
+    async fn record_changes<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        change_type: ChangeType,
+        before: Vec<E::Model>,
+    ) -> Result<(), DbErr>
+    where
+        E::Model: Serialize,
+    {
+        let Some(history) = &self.change_history else {
+            return Ok(());
+        };
+        if before.is_empty() {
+            return Ok(());
+        }
+        let keys = before.iter().fold(Condition::any(), |condition, model| {
+            condition.add(model_key_condition(model))
+        });
+        let after = E::find().filter(keys).all(conn).await?;
+        let change_logs: Vec<_> = before
+            .iter()
+            .filter_map(|model| {
+                let key = model_key(model);
+                let after = after.iter().find(|after| model_key(*after) == key);
+                history.change_log(change_type, Some(model), after)
+            })
+            .collect();
+        if !change_logs.is_empty() {
+            ChangeLogEntity::insert_many(change_logs).exec(conn).await?;
+        }
+        Ok(())
+    }
+
+    /// 将删除标志改为指定值，批量更新不会执行 before_save，手动填充更新者和更新时间
+    fn mark(&self, soft_delete: &SoftDelete<E::Column>, value: &Value) -> sea_orm::UpdateMany<E> {
+        let mut active_model = A::default();
+        active_model.set(soft_delete.column, value.clone());
+        fill_audit_fields(&mut active_model, false);
+        E::update_many().set(active_model)
+    }
+}
+
+/// 单列主键对应的列，复合主键返回None
+fn single_primary_key<E: EntityTrait>() -> Option<E::Column> {
+    let mut keys = E::PrimaryKey::iter();
+    match (keys.next(), keys.next()) {
+        (Some(key), None) => Some(key.into_column()),
+        _ => None,
+    }
+}
+
+/// 整数主键值转为游标
+fn value_to_i64(value: Value) -> Option<i64> {
+    match value {
+        Value::TinyInt(Some(v)) => Some(v.into()),
+        Value::SmallInt(Some(v)) => Some(v.into()),
+        Value::Int(Some(v)) => Some(v.into()),
+        Value::BigInt(Some(v)) => Some(v),
+        Value::TinyUnsigned(Some(v)) => Some(v.into()),
+        Value::SmallUnsigned(Some(v)) => Some(v.into()),
+        Value::Unsigned(Some(v)) => Some(v.into()),
+        Value::BigUnsigned(Some(v)) => i64::try_from(v).ok(),
+        _ => None,
+    }
+}
+
+/// 实体主键对应的查询条件，主键未设置时返回None
+fn active_model_key_condition<A: ActiveModelTrait>(active_model: &A) -> Option<Condition> {
+    <A::Entity as EntityTrait>::PrimaryKey::iter().try_fold(Condition::all(), |condition, key| {
+        let column = key.into_column();
+        let value = active_model.get(column).into_value()?;
+        Some(condition.add(column.eq(value)))
+    })
+}
+
+/// 实体主键对应的查询条件
+fn model_key_condition<M: ModelTrait>(model: &M) -> Condition {
+    <M::Entity as EntityTrait>::PrimaryKey::iter().fold(Condition::all(), |condition, key| {
+        let column = key.into_column();
+        condition.add(column.eq(model.get(column)))
+    })
+}
+
+/// 主键列表对应的查询条件，支持复合主键
+fn primary_key_condition<E, T>(ids: Vec<T>) -> Condition
+where
+    E: EntityTrait,
+    T: Into<<<E as EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>,
+{
+    ids.into_iter().fold(Condition::any(), |condition, id| {
+        let values = id.into().into_value_tuple();
+        let key = E::PrimaryKey::iter()
+            .zip(values)
+            .fold(Condition::all(), |key, (column, value)| {
+                key.add(column.into_column().eq(value))
+            });
+        condition.add(key)
+    })
 }
 
 #[async_trait::async_trait]
@@ -96,12 +701,10 @@ impl<E, A> Repository<E, A> for BaseRepository<E, A>
 where
     E: EntityTrait,
     A: ActiveModelTrait<Entity = E> + ActiveModelBehavior + Send + Sync,
-    E::Model: Debug + Send + Sync + IntoActiveModel<A>,
-    E::Column: FromStr + Debug,
-    <E::Column as FromStr>::Err: Debug,
+    E::Model: Debug + Send + Sync + IntoActiveModel<A> + Serialize,
 {
     async fn find_all(&self) -> Result<Vec<E::Model>, DbErr> {
-        self.select().all(&self.db).await
+        self.select().all(&self.conn()).await
     }
 
     async fn find_by_id<T>(&self, id: T) -> Result<Option<E::Model>, DbErr>
@@ -110,22 +713,48 @@ where
             + Send
             + Sync,
     {
-        E::find_by_id(id).one(&self.db).await
+        let mut query = E::find_by_id(id).filter(self.tenant_condition());
+        if let Some(soft_delete) = &self.soft_delete {
+            query = query.filter(soft_delete.not_deleted());
+        }
+        query.one(&self.conn()).await
     }
 
-    async fn insert(&self, active_model: A) -> Result<E::Model, DbErr> {
-        active_model.insert(&self.db).await
+    async fn insert(&self, mut active_model: A) -> Result<E::Model, DbErr> {
+        unset_zero_primary_key(&mut active_model);
+        active_model.insert(&self.conn()).await
     }
 
     async fn update(&self, active_model: A) -> Result<E::Model, DbErr> {
-        active_model.update(&self.db).await
+        self.update_with(&self.conn(), active_model).await
     }
 
     async fn delete_by_model(&self, model: E::Model) -> Result<u64, DbErr>
     where
         E::Model: IntoActiveModel<A>,
     {
-        model.delete(&self.db).await.map(|res| res.rows_affected)
+        match &self.soft_delete {
+            Some(soft_delete) => {
+                let mut active_model = model.into_active_model();
+                active_model.set(soft_delete.column, soft_delete.deleted.clone());
+                self.update_as(&self.conn(), active_model, ChangeType::Delete)
+                    .await
+                    .map(|_| 1)
+            }
+            None => {
+                let conn = self.conn();
+                let rows_affected = E::delete(model.clone().into_active_model())
+                    .filter(self.tenant_condition())
+                    .exec(&conn)
+                    .await?
+                    .rows_affected;
+                if rows_affected > 0 {
+                    self.record_changes(&conn, ChangeType::Delete, vec![model])
+                        .await?;
+                }
+                Ok(rows_affected)
+            }
+        }
     }
 
     async fn delete_by_id<T>(&self, id: T) -> Result<u64, DbErr>
@@ -134,10 +763,7 @@ where
             + Send
             + Sync,
     {
-        E::delete_by_id(id)
-            .exec(&self.db)
-            .await
-            .map(|res| res.rows_affected)
+        self.delete_by_ids_with(&self.conn(), vec![id]).await
     }
 
     async fn delete_by_ids<T>(&self, ids: Vec<T>) -> Result<u64, DbErr>
@@ -146,20 +772,51 @@ where
             + Send
             + Sync,
     {
-        let tx = self.db.begin().await?;
-        let mut total = 0;
-        for id in ids {
-            let rows_affected = E::delete_by_id(id)
-                .exec(&tx)
-                .await
-                .map(|res| res.rows_affected)
-                .unwrap_or(0);
-            total += rows_affected;
-        }
+        let tx = self.conn().begin().await?;
+        let total = self.delete_by_ids_with(&tx, ids).await?;
         tx.commit().await?;
         Ok(total)
     }
 
+    async fn restore_by_ids<T>(&self, ids: Vec<T>) -> Result<u64, DbErr>
+    where
+        T: Into<<<E as EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>
+            + Send
+            + Sync,
+    {
+        let soft_delete = self
+            .soft_delete
+            .as_ref()
+            .ok_or_else(|| DbErr::Custom("未启用逻辑删除，无法恢复".to_string()))?;
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let condition = Condition::all()
+            .add(primary_key_condition::<E, T>(ids))
+            .add(self.tenant_condition())
+            .add(soft_delete.deleted());
+        let conn = self.conn();
+        let before = self.snapshot(&conn, condition.clone()).await?;
+        let rows_affected = self
+            .mark(soft_delete, &soft_delete.normal)
+            .filter(condition)
+            .exec(&conn)
+            .await?
+            .rows_affected;
+        self.record_changes(&conn, ChangeType::Restore, before)
+            .await?;
+        Ok(rows_affected)
+    }
+
+    async fn purge_by_ids<T>(&self, ids: Vec<T>) -> Result<u64, DbErr>
+    where
+        T: Into<<<E as EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>
+            + Send
+            + Sync,
+    {
+        self.purge_by_ids_with(&self.conn(), ids).await
+    }
+
     async fn paginate(
         &self,
         page_param: &PageParam,
@@ -171,27 +828,410 @@ where
             query = query.filter(condition);
         }
         // 排序
-        if let Some(order_by_column) = &page_param.order_by_column {
-            if let Ok(column) = E::Column::from_str(order_by_column) {
-                if let Some(is_asc) = &page_param.is_asc {
-                    if is_asc.to_uppercase() == "ASC" {
-                        query = query.order_by_asc(column);
-                    } else {
-                        query = query.order_by_desc(column);
-                    }
-                }
-            }
-        }
+        let query = self.apply_order(query, page_param)?;
 
         // 创建分页器
-        let paginator = query.paginate(&self.db, page_param.page_size);
+        let conn = self.conn();
+        let paginator = query.paginate(&conn, page_param.limit());
 
         // 获取总记录数
         let total = paginator.num_items().await?;
 
         // 获取当前页数据
-        let items = paginator.fetch_page(page_param.page_num - 1).await?;
+        let items = paginator.fetch_page(page_param.page_index()).await?;
 
         Ok((items, total))
     }
+
+    async fn paginate_by_cursor(
+        &self,
+        page_param: &PageParam,
+        condition: Option<Condition>,
+    ) -> Result<(Vec<E::Model>, Option<i64>), DbErr> {
+        let mut query = self.select();
+        if let Some(condition) = condition {
+            query = query.filter(condition);
+        }
+        self.paginate_by_cursor_with(&self.conn(), query, page_param)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db::DbSettings;
+    use crate::db::DbManager;
+    use sea_orm::ActiveValue::Set;
+    use sea_orm::QueryTrait;
+
+    mod article {
+        use chrono::{DateTime, Utc};
+        use sea_orm::entity::prelude::*;
+        use serde::Serialize;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
+        #[sea_orm(table_name = "sys_article")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i64,
+            pub title: Option<String>,
+            pub del_flag: Option<String>,
+            pub update_by: Option<String>,
+            pub update_time: Option<DateTime<Utc>>,
+            pub version: i32,
+            pub tenant_id: i64,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    async fn repository() -> BaseRepository<article::Entity, article::ActiveModel> {
+        let db = DbManager::new(DbSettings::from_url("sqlite::memory:"))
+            .await
+            .unwrap()
+            .get_connection();
+        db.execute_unprepared(
+            "create table sys_article (id integer primary key autoincrement, title varchar(50), \
+             del_flag char(1) default 'N', update_by varchar(64), update_time datetime, \
+             version integer not null default 0, tenant_id bigint not null default 1)",
+        )
+        .await
+        .unwrap();
+        let repository = BaseRepository::new(db.as_ref().clone());
+        for title in ["a", "b", "c"] {
+            repository
+                .insert(article::ActiveModel {
+                    id: Set(0),
+                    title: Set(Some(title.to_string())),
+                    del_flag: Set(Some("N".to_string())),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+        repository
+    }
+
+    #[tokio::test]
+    async fn test_hard_delete() {
+        let repository = repository().await;
+        assert_eq!(repository.delete_by_ids(vec![1, 2]).await.unwrap(), 2);
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+        assert_eq!(
+            repository
+                .select_with_deleted()
+                .count(&repository.db)
+                .await
+                .unwrap(),
+            1
+        );
+        assert!(repository.restore_by_ids(vec![1]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_restore_and_purge() {
+        let repository = repository().await.with_soft_delete(SoftDelete::new(
+            article::Column::DelFlag,
+            "Y",
+            "N",
+        ));
+
+        assert_eq!(repository.delete_by_ids(vec![1, 2]).await.unwrap(), 2);
+        // 重复删除不会再次修改
+        assert_eq!(repository.delete_by_id(1).await.unwrap(), 0);
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+        assert!(repository.find_by_id(1).await.unwrap().is_none());
+        let (items, total) = repository
+            .paginate(&PageParam::default(), None)
+            .await
+            .unwrap();
+        assert_eq!((items.len(), total), (1, 1));
+
+        let deleted = repository
+            .select_deleted()
+            .all(&repository.db)
+            .await
+            .unwrap();
+        assert_eq!(deleted.len(), 2);
+        assert_eq!(
+            deleted[0].update_by.as_deref(),
+            Some(crate::db::audit::SYSTEM_OPERATOR)
+        );
+
+        // 只恢复已删除的记录
+        assert_eq!(repository.restore_by_ids(vec![1, 3]).await.unwrap(), 1);
+        assert_eq!(repository.find_all().await.unwrap().len(), 2);
+
+        // 只清除已删除的记录
+        assert_eq!(repository.purge_by_ids(vec![1, 2]).await.unwrap(), 1);
+        assert_eq!(
+            repository
+                .select_with_deleted()
+                .count(&repository.db)
+                .await
+                .unwrap(),
+            2
+        );
+
+        // 根据模型删除
+        let model = repository.find_by_id(3).await.unwrap().unwrap();
+        assert_eq!(repository.delete_by_model(model).await.unwrap(), 1);
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sort_whitelist() {
+        let repository = repository()
+            .await
+            .with_sortable_columns([article::Column::Id, article::Column::Title]);
+        let page_param = |order_by_column: &str, is_asc: &str| PageParam {
+            order_by_column: Some(order_by_column.to_string()),
+            is_asc: Some(is_asc.to_string()),
+            ..Default::default()
+        };
+
+        let (items, _) = repository
+            .paginate(&page_param("title", "descending"), None)
+            .await
+            .unwrap();
+        let titles: Vec<_> = items.iter().map(|item| item.title.clone()).collect();
+        assert_eq!(
+            titles,
+            [Some("c".into()), Some("b".into()), Some("a".into())]
+        );
+
+        // 驼峰字段名转为下划线，不在白名单中的字段返回错误
+        let err = repository
+            .apply_order(
+                repository.select(),
+                &page_param("delFlag,title, id", "asc,desc"),
+            )
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("del_flag"), "{}", err);
+
+        // 多字段排序
+        let query = repository
+            .apply_order(repository.select(), &page_param("title,id", "asc,desc"))
+            .unwrap();
+        let sql = query.build(sea_orm::DbBackend::Sqlite).to_string();
+        assert!(
+            sql.ends_with(r#"ORDER BY "sys_article"."title" ASC, "sys_article"."id" DESC"#),
+            "{}",
+            sql
+        );
+
+        // 不存在的字段
+        assert!(repository
+            .paginate(&page_param("title;drop table", "asc"), None)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_paginate_by_cursor() {
+        let repository = repository().await;
+        let mut page_param = PageParam {
+            page_size: 2,
+            ..Default::default()
+        };
+
+        let (items, cursor) = repository
+            .paginate_by_cursor(&page_param, None)
+            .await
+            .unwrap();
+        let ids: Vec<_> = items.iter().map(|item| item.id).collect();
+        assert_eq!((ids, cursor), (vec![3, 2], Some(2)));
+
+        page_param.cursor = cursor;
+        let (items, cursor) = repository
+            .paginate_by_cursor(&page_param, None)
+            .await
+            .unwrap();
+        let ids: Vec<_> = items.iter().map(|item| item.id).collect();
+        assert_eq!((ids, cursor), (vec![1], None));
+    }
+
+    #[tokio::test]
+    async fn test_optimistic_lock() {
+        let repository = repository()
+            .await
+            .with_optimistic_lock(OptimisticLock::version(article::Column::Version));
+
+        // 两个用户读取同一版本后先后保存
+        let model = repository.find_by_id(1).await.unwrap().unwrap();
+        let mut first = model.clone().into_active_model();
+        first.title = Set(Some("first".to_string()));
+        let mut second = model.into_active_model();
+        second.title = Set(Some("second".to_string()));
+        assert_eq!(repository.update(first).await.unwrap().version, 1);
+        assert_eq!(
+            repository.update(second).await.unwrap_err(),
+            optimistic_lock_conflict()
+        );
+
+        // 未设置版本时以数据库中的当前值为准
+        let updated = repository
+            .update(article::ActiveModel {
+                id: Set(1),
+                title: Set(Some("third".to_string())),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            (updated.title.as_deref(), updated.version),
+            (Some("third"), 2)
+        );
+
+        // 记录不存在
+        let missing = article::ActiveModel {
+            id: Set(9),
+            version: Set(0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            repository.update(missing).await,
+            Err(DbErr::RecordNotFound(_))
+        ));
+
+        // 以更新时间为乐观锁列，从未更新过的记录更新时间为空
+        let repository =
+            BaseRepository::<article::Entity, article::ActiveModel>::new(repository.db.clone())
+                .with_optimistic_lock(OptimisticLock::update_time(article::Column::UpdateTime));
+        let model = repository.find_by_id(2).await.unwrap().unwrap();
+        let stale = model.clone().into_active_model();
+        let updated = repository.update(model.into_active_model()).await.unwrap();
+        assert!(updated.update_time.is_some());
+        assert_eq!(
+            repository.update(stale).await.unwrap_err(),
+            optimistic_lock_conflict()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tenant_isolation() {
+        use crate::web::context::RequestContext;
+
+        let repository = repository().await.with_tenant(article::Column::TenantId);
+        repository
+            .insert(article::ActiveModel {
+                id: Set(0),
+                title: Set(Some("d".to_string())),
+                tenant_id: Set(2),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        RequestContext::tenant(2)
+            .scope(async {
+                let ids: Vec<_> = repository
+                    .find_all()
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|item| item.id)
+                    .collect();
+                assert_eq!(ids, vec![4]);
+                assert!(repository.find_by_id(1).await.unwrap().is_none());
+
+                // 不能修改和删除其他租户的记录
+                let other = article::ActiveModel {
+                    id: Set(1),
+                    title: Set(Some("x".to_string())),
+                    ..Default::default()
+                };
+                assert!(matches!(
+                    repository.update(other).await,
+                    Err(DbErr::RecordNotFound(_))
+                ));
+                assert_eq!(repository.delete_by_ids(vec![1, 4]).await.unwrap(), 1);
+            })
+            .await;
+
+        // 没有租户上下文时不做过滤
+        let titles: Vec<_> = repository
+            .find_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|item| item.title)
+            .collect();
+        assert_eq!(
+            titles,
+            [Some("a".into()), Some("b".into()), Some("c".into())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_change_history() {
+        use crate::db::history::{ChangeLogColumn, ChangeLogEntity};
+
+        let repository = repository()
+            .await
+            .with_soft_delete(SoftDelete::new(article::Column::DelFlag, "Y", "N"))
+            .with_optimistic_lock(OptimisticLock::version(article::Column::Version))
+            .with_change_history(ChangeHistory::new());
+        repository
+            .db
+            .execute_unprepared(
+                "create table sys_change_log (change_id integer primary key autoincrement, \
+                 tenant_id bigint not null, entity_name varchar(64) not null, \
+                 entity_id varchar(64) not null, change_type varchar(16) not null, changes text, \
+                 oper_name varchar(64), change_time datetime)",
+            )
+            .await
+            .unwrap();
+
+        // 只有版本号变化的修改不记录
+        let model = repository.find_by_id(1).await.unwrap().unwrap();
+        let model = repository.update(model.into_active_model()).await.unwrap();
+        let mut active_model = model.into_active_model();
+        active_model.title = Set(Some("x".to_string()));
+        repository.update(active_model).await.unwrap();
+        repository.delete_by_ids(vec![1, 2]).await.unwrap();
+        repository.restore_by_ids(vec![2]).await.unwrap();
+        repository.purge_by_ids(vec![1]).await.unwrap();
+
+        let logs = ChangeLogEntity::find()
+            .order_by_asc(ChangeLogColumn::ChangeId)
+            .all(&repository.db)
+            .await
+            .unwrap();
+        let summary: Vec<_> = logs
+            .iter()
+            .map(|log| (log.entity_id.as_str(), log.change_type.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("1", "update"),
+                ("1", "delete"),
+                ("2", "delete"),
+                ("2", "restore"),
+                ("1", "purge")
+            ]
+        );
+        assert!(logs.iter().all(|log| log.entity_name == "sys_article"));
+
+        let changes = |index: usize| -> serde_json::Value {
+            serde_json::from_str(logs[index].changes.as_deref().unwrap()).unwrap()
+        };
+        assert_eq!(
+            changes(0),
+            serde_json::json!({"title": {"before": "a", "after": "x"}})
+        );
+        assert_eq!(
+            changes(1),
+            serde_json::json!({"del_flag": {"before": "N", "after": "Y"}})
+        );
+        assert_eq!(changes(4)["title"]["before"], "x");
+        assert!(changes(4)["title"]["after"].is_null());
+    }
 }
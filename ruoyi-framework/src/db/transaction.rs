@@ -1,9 +1,40 @@
 // ruoyi-framework/src/db/transaction.rs
 //! 事务管理模块
+//!
+//! 除了显式传递 `&DatabaseTransaction` 的 [`TransactionManager`]，还支持任务级的环境事务：
+//! 1. [`transactional`]（或 `#[transactional]` 属性宏）开启事务并保存在当前任务中，
+//!    返回 `Ok` 时提交，返回 `Err` 时回滚
+//! 2. 仓库通过 [`current_connection`] 获取连接，存在环境事务时自动加入，否则使用连接池
+//! 3. 嵌套调用时使用保存点，内层失败只回滚到保存点，由外层决定是否继续
+//!
+//! ```ignore
+//! #[async_trait]
+//! impl UserService for UserServiceImpl {
+//!     #[transactional]
+//!     async fn create_user(&self, req: CreateOrUpdateUserRequest) -> Result<UserModel> {
+//!         let user = self.user_repository.create_user(user).await?;
+//!         self.role_repository.set_user_roles(user.user_id, role_ids).await?;
+//!         Ok(user)
+//!     }
+//! }
+//! ```
+//!
+//! 环境事务保存在 tokio 任务本地变量中，`tokio::spawn` 的新任务不会继承
 
-use sea_orm::{DatabaseConnection, DbErr, TransactionTrait};
+use sea_orm::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    ExecResult, IsolationLevel, QueryResult, Statement, TransactionError, TransactionTrait,
+};
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
+
+pub use ruoyi_macros::transactional;
+
+tokio::task_local! {
+    /// 当前任务的环境事务
+    static CURRENT_TRANSACTION: Arc<DatabaseTransaction>;
+}
 
 /// 事务管理器
 pub struct TransactionManager<'a> {
@@ -40,3 +71,296 @@ impl<'a> TransactionManager<'a> {
         }
     }
 }
+
+/// 当前任务的环境事务
+pub fn current_transaction() -> Option<Arc<DatabaseTransaction>> {
+    CURRENT_TRANSACTION.try_with(Arc::clone).ok()
+}
+
+/// 是否处于环境事务中
+pub fn in_transaction() -> bool {
+    CURRENT_TRANSACTION.try_with(|_| ()).is_ok()
+}
+
+/// 在环境事务中执行操作，返回 `Ok` 时提交，返回 `Err` 时回滚
+///
+/// 已处于环境事务中时在当前事务上创建保存点，提交和回滚只作用于保存点
+pub async fn transactional<F, Fut, T, E>(db: &DatabaseConnection, f: F) -> Result<T, E>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: From<DbErr>,
+{
+    let txn = match current_transaction() {
+        Some(parent) => parent.begin().await,
+        None => db.begin().await,
+    }
+    .map(Arc::new)
+    .map_err(E::from)?;
+
+    let result = CURRENT_TRANSACTION.scope(txn.clone(), f()).await;
+
+    // 操作结束后不应再有对事务的引用，否则无法提交
+    let txn = Arc::try_unwrap(txn).map_err(|_| {
+        E::from(DbErr::Custom(
+            "事务在操作结束后仍被引用（可能被新任务持有），无法提交".to_string(),
+        ))
+    })?;
+    match result {
+        Ok(value) => {
+            txn.commit().await.map_err(E::from)?;
+            Ok(value)
+        }
+        Err(e) => {
+            // 回滚事务（忽略可能的回滚错误）
+            let _ = txn.rollback().await;
+            Err(e)
+        }
+    }
+}
+
+/// 获取仓库使用的连接：处于环境事务中时加入事务，否则使用连接池
+pub fn current_connection(db: &DatabaseConnection) -> CurrentConnection<'_> {
+    match current_transaction() {
+        Some(txn) => CurrentConnection::Transaction(txn),
+        None => CurrentConnection::Pool(db),
+    }
+}
+
+/// 仓库使用的连接，由 [`current_connection`] 或读写分离路由创建
+///
+/// 在该连接上 `begin` 时，处于环境事务中会创建保存点
+pub enum CurrentConnection<'a> {
+    /// 连接池
+    Pool(&'a DatabaseConnection),
+    /// 环境事务
+    Transaction(Arc<DatabaseTransaction>),
+    /// 只读副本
+    Replica(Arc<DatabaseConnection>),
+}
+
+impl CurrentConnection<'_> {
+    fn as_connection(&self) -> &(dyn ConnectionTrait + Send) {
+        match self {
+            CurrentConnection::Pool(db) => *db,
+            CurrentConnection::Transaction(txn) => txn.as_ref(),
+            CurrentConnection::Replica(db) => db.as_ref(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for CurrentConnection<'_> {
+    fn get_database_backend(&self) -> DbBackend {
+        self.as_connection().get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.as_connection().execute(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.as_connection().execute_unprepared(sql).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.as_connection().query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.as_connection().query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.as_connection().support_returning()
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for CurrentConnection<'_> {
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            CurrentConnection::Pool(db) => db.begin().await,
+            CurrentConnection::Transaction(txn) => txn.begin().await,
+            CurrentConnection::Replica(db) => db.begin().await,
+        }
+    }
+
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            CurrentConnection::Pool(db) => db.begin_with_config(isolation_level, access_mode).await,
+            CurrentConnection::Transaction(txn) => {
+                txn.begin_with_config(isolation_level, access_mode).await
+            }
+            CurrentConnection::Replica(db) => {
+                db.begin_with_config(isolation_level, access_mode).await
+            }
+        }
+    }
+
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        match self {
+            CurrentConnection::Pool(db) => db.transaction(callback).await,
+            CurrentConnection::Transaction(txn) => txn.transaction(callback).await,
+            CurrentConnection::Replica(db) => db.transaction(callback).await,
+        }
+    }
+
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        match self {
+            CurrentConnection::Pool(db) => {
+                db.transaction_with_config(callback, isolation_level, access_mode)
+                    .await
+            }
+            CurrentConnection::Transaction(txn) => {
+                txn.transaction_with_config(callback, isolation_level, access_mode)
+                    .await
+            }
+            CurrentConnection::Replica(db) => {
+                db.transaction_with_config(callback, isolation_level, access_mode)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db::DbSettings;
+    use crate::db::DbManager;
+
+    async fn database() -> Arc<DatabaseConnection> {
+        let db = DbManager::new(DbSettings::from_url("sqlite::memory:"))
+            .await
+            .unwrap()
+            .get_connection();
+        db.execute_unprepared(
+            "create table sys_article (id integer primary key, title varchar(50))",
+        )
+        .await
+        .unwrap();
+        db
+    }
+
+    /// 通过当前连接插入，内存数据库只有一个连接，未加入环境事务时会一直等待
+    async fn insert(db: &DatabaseConnection, id: i64) -> Result<(), DbErr> {
+        current_connection(db)
+            .execute_unprepared(&format!(
+                "insert into sys_article (id, title) values ({}, 'a')",
+                id
+            ))
+            .await
+            .map(|_| ())
+    }
+
+    async fn ids(db: &DatabaseConnection) -> Vec<i64> {
+        db.query_all(Statement::from_string(
+            db.get_database_backend(),
+            "select id from sys_article order by id",
+        ))
+        .await
+        .unwrap()
+        .iter()
+        .map(|row| row.try_get_by_index(0).unwrap())
+        .collect()
+    }
+
+    struct ArticleService {
+        db: Arc<DatabaseConnection>,
+    }
+
+    #[async_trait::async_trait]
+    trait ArticleApi {
+        async fn create(&self, id: i64, fail: bool) -> Result<i64, DbErr>;
+    }
+
+    #[async_trait::async_trait]
+    impl ArticleApi for ArticleService {
+        #[transactional]
+        async fn create(&self, id: i64, fail: bool) -> Result<i64, DbErr> {
+            insert(&self.db, id).await?;
+            insert(&self.db, id + 1).await?;
+            if fail {
+                return Err(DbErr::Custom("失败".to_string()));
+            }
+            Ok(id)
+        }
+    }
+
+    impl ArticleService {
+        #[transactional(self.db)]
+        async fn create_nested(&self) -> Result<(), DbErr> {
+            assert!(in_transaction());
+            insert(&self.db, 1).await?;
+            // 内层失败只回滚到保存点
+            assert!(self.create(10, true).await.is_err());
+            self.create(20, false).await?;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_commit_and_rollback() {
+        let db = database().await;
+        let result: Result<(), DbErr> = transactional(&db, || async {
+            insert(&db, 1).await?;
+            insert(&db, 2).await
+        })
+        .await;
+        assert!(result.is_ok());
+        assert!(!in_transaction());
+
+        let result: Result<(), DbErr> = transactional(&db, || async {
+            insert(&db, 3).await?;
+            // 主键冲突
+            insert(&db, 1).await
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(ids(&db).await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_transactional_macro_and_savepoint() {
+        let service = ArticleService {
+            db: database().await,
+        };
+        assert_eq!(service.create(1, false).await.unwrap(), 1);
+        assert!(service.create(5, true).await.is_err());
+        assert_eq!(ids(&service.db).await, vec![1, 2]);
+
+        service
+            .db
+            .execute_unprepared("delete from sys_article")
+            .await
+            .unwrap();
+        service.create_nested().await.unwrap();
+        assert_eq!(ids(&service.db).await, vec![1, 20, 21]);
+    }
+}
@@ -6,4 +6,9 @@ pub mod config;
 pub mod db;
 pub mod logger;
 pub mod web;
-pub mod rms;
\ No newline at end of file
+pub mod rms;
+pub mod tenant;
+
+// 派生宏生成的代码通过 ::ruoyi_framework 路径引用本模块，测试中在本 crate 内使用派生宏
+#[cfg(test)]
+extern crate self as ruoyi_framework;
\ No newline at end of file
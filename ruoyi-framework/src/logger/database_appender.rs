@@ -9,6 +9,7 @@ use tokio::{
 };
 
 use super::entity::*;
+use crate::db::repository::unset_zero_primary_key;
 
 #[derive(Debug)]
 pub enum LogMessage {
@@ -110,6 +111,7 @@ impl DatabaseAppender {
             return;
         }
 
+        buffer.iter_mut().for_each(unset_zero_primary_key);
         let logs_to_insert = std::mem::take(buffer);
         let count = logs_to_insert.len();
         match LoginInfoEntity::insert_many(logs_to_insert).exec(db).await {
@@ -128,6 +130,7 @@ impl DatabaseAppender {
             return;
         }
 
+        buffer.iter_mut().for_each(unset_zero_primary_key);
         let logs_to_insert = std::mem::take(buffer);
         let count = logs_to_insert.len();
         match OperLogEntity::insert_many(logs_to_insert).exec(db).await {
@@ -5,9 +5,13 @@ use chrono::{DateTime, Utc};
 use ruoyi_common::utils::{http, ip};
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+use crate::tenant::current_tenant_id_or_default;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, ToSchema)]
 #[sea_orm(table_name = "sys_login_info")]
+#[schema(as = SysLoginInfo)]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
     #[sea_orm(primary_key)]
@@ -20,6 +24,7 @@ pub struct Model {
     pub status: Option<String>,
     pub msg: Option<String>,
     pub login_time: Option<DateTime<Utc>>,
+    pub tenant_id: i64,
 }
 
 impl Model {
@@ -35,6 +40,7 @@ impl Model {
             status: Some("".to_string()),
             msg: Some(msg.to_string()),
             login_time: Some(Utc::now()),
+            tenant_id: current_tenant_id_or_default(),
         }
     }
 }
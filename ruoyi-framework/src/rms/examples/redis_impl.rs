@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 use futures::StreamExt;
-use redis::{aio::ConnectionManager, AsyncCommands, Client};
+use log::warn;
+use redis::{aio::PubSub, AsyncCommands, Client};
 use serde::{Deserialize, Serialize};
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::{future::Future, pin::Pin, sync::Arc, time::Duration};
 use tokio::sync::{mpsc, Mutex};
 use uuid::Uuid;
 
+use crate::cache::{CacheError, RedisConfig, RedisConnectionType, RedisSentinel};
 use crate::rms::{
     channel::MessageChannelFactory, consumer::MessageConsumer, producer::MessageProducer,
     AckCallback, AcknowledgeableMessage, Message, MessageHeaders,
@@ -18,6 +20,8 @@ pub enum RedisMessageError {
     Redis(#[from] redis::RedisError),
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+    #[error("Sentinel error: {0}")]
+    Sentinel(#[from] CacheError),
     #[error("Channel closed")]
     ChannelClosed,
 }
@@ -30,16 +34,108 @@ struct RedisMessage {
     headers: MessageHeaders,
 }
 
+/// Redis连接来源，哨兵模式下每次连接前从哨兵获取当前主节点
+#[derive(Clone)]
+enum RedisEndpoint {
+    Direct(Client),
+    Sentinel(Arc<RedisSentinel>),
+}
+
+impl RedisEndpoint {
+    /// 获取当前可用的客户端
+    async fn client(&self) -> Result<Client, RedisMessageError> {
+        match self {
+            RedisEndpoint::Direct(client) => Ok(client.clone()),
+            RedisEndpoint::Sentinel(sentinel) => Ok(sentinel.get_client().await?),
+        }
+    }
+
+    /// 连接出错时，哨兵模式下丢弃当前主节点以便重新发现
+    async fn on_error(&self, err: &redis::RedisError) {
+        if let RedisEndpoint::Sentinel(sentinel) = self {
+            if RedisSentinel::is_failover_error(err) {
+                sentinel.invalidate().await;
+            }
+        }
+    }
+
+    /// 创建订阅指定主题的PubSub连接
+    async fn subscribe(&self, destination: &str) -> Result<PubSub, RedisMessageError> {
+        let result = async {
+            let mut pubsub = self
+                .client()
+                .await?
+                .get_async_connection()
+                .await?
+                .into_pubsub();
+            pubsub.subscribe(destination).await?;
+            Ok::<_, RedisMessageError>(pubsub)
+        }
+        .await;
+        if let Err(RedisMessageError::Redis(e)) = &result {
+            self.on_error(e).await;
+        }
+        result
+    }
+
+    /// 订阅连接断开后持续重试，直到重新订阅成功或收到关闭信号
+    async fn resubscribe(
+        &self,
+        destination: &str,
+        shutdown: &mut mpsc::Receiver<String>,
+    ) -> Option<PubSub> {
+        loop {
+            match self.subscribe(destination).await {
+                Ok(pubsub) => return Some(pubsub),
+                Err(e) => warn!("重新订阅Redis主题 {} 失败: {}", destination, e),
+            }
+            tokio::select! {
+                _ = tokio::time::sleep(RESUBSCRIBE_INTERVAL) => {},
+                _ = shutdown.recv() => return None,
+            }
+        }
+    }
+}
+
+/// 订阅连接断开后的重试间隔
+const RESUBSCRIBE_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Redis消息通道工厂
 pub struct RedisMessageChannelFactory {
-    client: Client,
+    endpoint: RedisEndpoint,
 }
 
 impl RedisMessageChannelFactory {
     /// 创建一个新的Redis消息通道工厂
     pub fn new(redis_url: &str) -> Result<Self, RedisMessageError> {
         let client = Client::open(redis_url)?;
-        Ok(Self { client })
+        Ok(Self {
+            endpoint: RedisEndpoint::Direct(client),
+        })
+    }
+
+    /// 根据缓存模块的Redis配置创建消息通道工厂，支持单机模式和哨兵模式
+    pub fn from_config(config: &RedisConfig) -> Result<Self, RedisMessageError> {
+        match config.connection_type {
+            RedisConnectionType::Sentinel => Ok(Self {
+                endpoint: RedisEndpoint::Sentinel(Arc::new(RedisSentinel::new(config)?)),
+            }),
+            RedisConnectionType::Standalone => {
+                let url = config.url.clone().ok_or_else(|| {
+                    CacheError::Configuration("Redis单机模式需要提供url配置".to_string())
+                })?;
+                let url = match &config.password {
+                    Some(password) if !url.contains('@') => {
+                        url.replacen("://", &format!("://:{}@", password), 1)
+                    }
+                    _ => url,
+                };
+                Self::new(&url)
+            }
+            RedisConnectionType::Cluster => {
+                Err(CacheError::Configuration("Redis消息通道不支持集群模式".to_string()).into())
+            }
+        }
     }
 }
 
@@ -50,16 +146,15 @@ impl MessageChannelFactory for RedisMessageChannelFactory {
     type Error = RedisMessageError;
 
     fn create_producer(&self, _config: &str) -> Result<Self::Producer, Self::Error> {
-        // 返回一个带有 client 的生产者，实际的连接将在首次使用时创建
+        // 返回一个带有连接来源的生产者，实际的连接将在首次使用时创建
         Ok(RedisMessageProducer {
-            client: self.client.clone(),
+            endpoint: self.endpoint.clone(),
         })
     }
 
     fn create_consumer(&self, _config: &str) -> Result<Self::Consumer, Self::Error> {
-        let client = self.client.clone();
         Ok(RedisMessageConsumer {
-            client,
+            endpoint: self.endpoint.clone(),
             subscribers: Arc::new(Mutex::new(Vec::new())),
         })
     }
@@ -74,7 +169,7 @@ impl MessageChannelFactory for RedisMessageChannelFactory {
 
 /// Redis消息生产者
 pub struct RedisMessageProducer {
-    client: Client,
+    endpoint: RedisEndpoint,
 }
 
 #[async_trait]
@@ -94,10 +189,17 @@ impl MessageProducer for RedisMessageProducer {
         };
 
         // 每次发送时创建一个新的连接
-        let mut conn = self.client.get_async_connection().await?;
         let serialized = serde_json::to_string(&redis_message)?;
-        conn.publish::<_, _, ()>(destination, serialized).await?;
-        Ok(())
+        let result = async {
+            let mut conn = self.endpoint.client().await?.get_async_connection().await?;
+            conn.publish::<_, _, ()>(destination, serialized).await?;
+            Ok(())
+        }
+        .await;
+        if let Err(RedisMessageError::Redis(e)) = &result {
+            self.endpoint.on_error(e).await;
+        }
+        result
     }
 
     async fn send_and_wait(
@@ -113,7 +215,7 @@ impl MessageProducer for RedisMessageProducer {
 
 /// Redis消息消费者
 pub struct RedisMessageConsumer {
-    client: Client,
+    endpoint: RedisEndpoint,
     subscribers: Arc<Mutex<Vec<(String, mpsc::Sender<String>)>>>,
 }
 
@@ -138,48 +240,60 @@ impl MessageConsumer for RedisMessageConsumer {
         }
 
         // 创建Redis PubSub连接
-        let mut pubsub = self.client.get_async_connection().await?.into_pubsub();
-        pubsub.subscribe(destination).await?;
+        let mut pubsub = self.endpoint.subscribe(destination).await?;
 
         // 启动监听任务
         let callback = Arc::new(callback);
+        let endpoint = self.endpoint.clone();
+        let destination = destination.to_string();
 
         tokio::spawn(async move {
-            let mut pubsub_stream = pubsub.on_message();
-
-            loop {
-                tokio::select! {
-                    Some(msg) = pubsub_stream.next() => {
-                        if let Ok(payload) = msg.get_payload::<String>() {
-                            if let Ok(redis_message) = serde_json::from_str::<RedisMessage>(&payload) {
-                                let message = Message {
-                                    payload: redis_message.payload,
-                                    headers: redis_message.headers,
-                                };
-
-                                let ack_callback: AckCallback = Box::new(|success| {
-                                    // 在实际应用中，这里可以记录确认状态
-                                    if success {
-                                        println!("Message processed successfully");
-                                    } else {
-                                        println!("Message processing failed");
-                                    }
-                                });
-
-                                let ack_message = AcknowledgeableMessage {
-                                    message,
-                                    ack: ack_callback,
-                                };
-
-                                callback(ack_message).await;
+            'connection: loop {
+                let mut pubsub_stream = pubsub.on_message();
+
+                loop {
+                    tokio::select! {
+                        msg = pubsub_stream.next() => {
+                            // 连接断开（例如主从切换）时重新订阅
+                            let Some(msg) = msg else { break };
+                            if let Ok(payload) = msg.get_payload::<String>() {
+                                if let Ok(redis_message) = serde_json::from_str::<RedisMessage>(&payload) {
+                                    let message = Message {
+                                        payload: redis_message.payload,
+                                        headers: redis_message.headers,
+                                    };
+
+                                    let ack_callback: AckCallback = Box::new(|success| {
+                                        // 在实际应用中，这里可以记录确认状态
+                                        if success {
+                                            println!("Message processed successfully");
+                                        } else {
+                                            println!("Message processing failed");
+                                        }
+                                    });
+
+                                    let ack_message = AcknowledgeableMessage {
+                                        message,
+                                        ack: ack_callback,
+                                    };
+
+                                    callback(ack_message).await;
+                                }
                             }
+                        },
+                        _ = rx.recv() => {
+                            // 接收到关闭信号，退出循环
+                            break 'connection;
                         }
-                    },
-                    _ = rx.recv() => {
-                        // 接收到关闭信号，退出循环
-                        break;
                     }
                 }
+
+                drop(pubsub_stream);
+                warn!("Redis主题 {} 的订阅连接已断开，正在重新订阅", destination);
+                match endpoint.resubscribe(&destination, &mut rx).await {
+                    Some(new_pubsub) => pubsub = new_pubsub,
+                    None => break,
+                }
             }
         });
 
@@ -209,72 +323,72 @@ impl MessageConsumer for RedisMessageConsumer {
         }
 
         // 创建Redis PubSub连接
-        let mut pubsub = self.client.get_async_connection().await?.into_pubsub();
-        pubsub.subscribe(destination).await?;
+        let mut pubsub = self.endpoint.subscribe(destination).await?;
 
         // 启动监听任务
         let callback = Arc::new(callback);
+        let endpoint = self.endpoint.clone();
+        let destination = destination.to_string();
 
         tokio::spawn(async move {
-            let mut pubsub_stream = pubsub.on_message();
             let mut batch = Vec::with_capacity(batch_size);
-            let mut should_exit = false;
-
-            loop {
-                tokio::select! {
-                    Some(msg) = pubsub_stream.next(), if !should_exit => {
-                        if let Ok(payload) = msg.get_payload::<String>() {
-                            if let Ok(redis_message) = serde_json::from_str::<RedisMessage>(&payload) {
-                                let message = Message {
-                                    payload: redis_message.payload,
-                                    headers: redis_message.headers,
-                                };
-
-                                let ack_callback: AckCallback = Box::new(|success| {
-                                    if success {
-                                        println!("Message processed successfully");
-                                    } else {
-                                        println!("Message processing failed");
-                                    }
-                                });
 
-                                let ack_message = AcknowledgeableMessage {
-                                    message,
-                                    ack: ack_callback,
-                                };
-
-                                batch.push(ack_message);
-
-                                if batch.len() >= batch_size {
-                                    // 处理批次
-                                    let batch_to_process = std::mem::replace(&mut batch, Vec::with_capacity(batch_size));
-                                    callback(batch_to_process).await;
+            'connection: loop {
+                let mut pubsub_stream = pubsub.on_message();
+
+                loop {
+                    tokio::select! {
+                        msg = pubsub_stream.next() => {
+                            // 连接断开（例如主从切换）时重新订阅，未处理的批次保留到重连之后
+                            let Some(msg) = msg else { break };
+                            if let Ok(payload) = msg.get_payload::<String>() {
+                                if let Ok(redis_message) = serde_json::from_str::<RedisMessage>(&payload) {
+                                    let message = Message {
+                                        payload: redis_message.payload,
+                                        headers: redis_message.headers,
+                                    };
+
+                                    let ack_callback: AckCallback = Box::new(|success| {
+                                        if success {
+                                            println!("Message processed successfully");
+                                        } else {
+                                            println!("Message processing failed");
+                                        }
+                                    });
+
+                                    let ack_message = AcknowledgeableMessage {
+                                        message,
+                                        ack: ack_callback,
+                                    };
+
+                                    batch.push(ack_message);
+
+                                    if batch.len() >= batch_size {
+                                        // 处理批次
+                                        let batch_to_process = std::mem::replace(&mut batch, Vec::with_capacity(batch_size));
+                                        callback(batch_to_process).await;
+                                    }
                                 }
                             }
-                        }
-                    },
-                    _ = rx.recv() => {
-                        // 接收到关闭信号，标记退出但继续处理已接收的消息
-                        should_exit = true;
-
-                        // 处理剩余的消息并退出
-                        if !batch.is_empty() {
-                            callback(batch).await;
-                            batch = Vec::new();
-                        }
-
-                        // 如果不需要处理更多消息了，就退出循环
-                        if should_exit && batch.is_empty() {
-                            break;
+                        },
+                        _ = rx.recv() => {
+                            // 接收到关闭信号，处理剩余的消息后退出
+                            break 'connection;
                         }
                     }
                 }
 
-                // 如果标记了退出且没有未处理的消息，则退出循环
-                if should_exit && batch.is_empty() {
-                    break;
+                drop(pubsub_stream);
+                warn!("Redis主题 {} 的订阅连接已断开，正在重新订阅", destination);
+                match endpoint.resubscribe(&destination, &mut rx).await {
+                    Some(new_pubsub) => pubsub = new_pubsub,
+                    None => break,
                 }
             }
+
+            if !batch.is_empty() {
+                callback(batch).await;
+            }
         });
 
         Ok(())
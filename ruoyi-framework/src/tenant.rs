@@ -0,0 +1,161 @@
+// ruoyi-framework/src/tenant.rs
+//! 多租户模块，租户数据表通过 `tenant_id` 列隔离
+//!
+//! 1. 认证中间件从登录令牌的 `Claims` 中解析租户，未登录的请求（如登录）从请求头 [`TENANT_HEADER`] 解析
+//! 2. 实体在 `ActiveModelBehavior::before_save` 中调用 [`fill_tenant_id`]，新增时写入当前租户
+//! 3. 仓库通过 [`BaseRepository::with_tenant`](crate::db::repository::BaseRepository::with_tenant)
+//!    启用后，查询、修改和删除自动限定在当前租户，自定义查询使用 [`tenant_condition`]
+//! 4. 字典、参数配置和菜单为平台共享数据，不区分租户，只能在默认租户中修改
+//! 5. 租户数据的缓存键通过 [`tenant_cache_key`] 加上租户前缀
+//!
+//! 没有租户上下文时（定时任务、命令行、启动初始化等）不做过滤，新增的数据属于默认租户
+
+use actix_web::http::header::HeaderMap;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, EntityTrait, IdenStatic, Iterable, Value,
+};
+
+pub use ruoyi_common::constants::tenant::{DEFAULT_TENANT_ID, TENANT_HEADER};
+use ruoyi_common::error::Error;
+use ruoyi_common::Result;
+
+use crate::web::context::current_tenant;
+
+/// 租户列名
+pub const TENANT_COLUMN: &str = "tenant_id";
+
+/// 当前租户，没有租户上下文时为None
+pub fn current_tenant_id() -> Option<i64> {
+    current_tenant()
+}
+
+/// 当前租户，没有租户上下文时为默认租户，用于写入不经过 [`fill_tenant_id`] 的数据（如日志）
+pub fn current_tenant_id_or_default() -> i64 {
+    current_tenant_id().unwrap_or(DEFAULT_TENANT_ID)
+}
+
+/// 当前是否为默认租户（平台），没有租户上下文时视为平台
+pub fn is_platform_tenant() -> bool {
+    current_tenant_id().is_none_or(|tenant_id| tenant_id == DEFAULT_TENANT_ID)
+}
+
+/// 平台共享数据只能在默认租户中修改，其他租户返回禁止访问错误
+pub fn check_platform_tenant(name: &str) -> Result<()> {
+    if is_platform_tenant() {
+        Ok(())
+    } else {
+        Err(Error::Forbidden(format!(
+            "{}为平台共享数据，租户不能修改",
+            name
+        )))
+    }
+}
+
+/// 从请求头中解析租户，未指定或格式错误时返回None
+pub fn tenant_id_from_headers(headers: &HeaderMap) -> Option<i64> {
+    headers
+        .get(TENANT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+/// 限定在当前租户的条件，没有租户上下文时不做限制
+pub fn tenant_condition<C: ColumnTrait>(column: C) -> Condition {
+    match current_tenant_id() {
+        Some(tenant_id) => Condition::all().add(column.eq(tenant_id)),
+        None => Condition::all(),
+    }
+}
+
+/// 新增时填充租户，实体中不存在租户列时忽略
+///
+/// 代码中已显式设置（Set 且非0）的租户保持不变，0视为占位值，
+/// 没有租户上下文时写入默认租户
+pub fn fill_tenant_id<A: ActiveModelTrait>(active_model: &mut A, insert: bool) {
+    if !insert {
+        return;
+    }
+    let Some(column) =
+        <A::Entity as EntityTrait>::Column::iter().find(|column| column.as_str() == TENANT_COLUMN)
+    else {
+        return;
+    };
+    let explicitly_set = matches!(
+        active_model.get(column),
+        ActiveValue::Set(Value::BigInt(Some(tenant_id))) if tenant_id != 0
+    );
+    if !explicitly_set {
+        active_model.set(column, Value::BigInt(Some(current_tenant_id_or_default())));
+    }
+}
+
+/// 租户数据的缓存键，格式为 `前缀 + 租户ID: + 键`，没有租户上下文时不加租户
+pub fn tenant_cache_key(prefix: &str, key: &str) -> String {
+    match current_tenant_id() {
+        Some(tenant_id) => tenant_cache_key_of(tenant_id, prefix, key),
+        None => format!("{}{}", prefix, key),
+    }
+}
+
+/// 指定租户的缓存键，用于没有租户上下文但已知租户的场景（如退出登录）
+pub fn tenant_cache_key_of(tenant_id: i64, prefix: &str, key: &str) -> String {
+    format!("{}{}:{}", prefix, tenant_id, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web::context::RequestContext;
+    use sea_orm::ActiveValue::{NotSet, Set};
+
+    mod article {
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+        #[sea_orm(table_name = "sys_article")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i64,
+            pub tenant_id: i64,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn test_fill_tenant_id() {
+        // 没有租户上下文时写入默认租户
+        let mut model = article::ActiveModel {
+            tenant_id: Set(0),
+            ..Default::default()
+        };
+        fill_tenant_id(&mut model, true);
+        assert_eq!(model.tenant_id, Set(DEFAULT_TENANT_ID));
+        assert_eq!(tenant_cache_key("login_tokens:", "abc"), "login_tokens:abc");
+
+        RequestContext::tenant(2).sync_scope(|| {
+            let mut model = <article::ActiveModel as Default>::default();
+            fill_tenant_id(&mut model, true);
+            assert_eq!(model.tenant_id, Set(2));
+            assert_eq!(
+                tenant_cache_key("login_tokens:", "abc"),
+                "login_tokens:2:abc"
+            );
+            assert!(!is_platform_tenant());
+
+            // 显式设置的租户保持不变，修改时不填充
+            let mut model = article::ActiveModel {
+                tenant_id: Set(3),
+                ..Default::default()
+            };
+            fill_tenant_id(&mut model, true);
+            assert_eq!(model.tenant_id, Set(3));
+            let mut model = <article::ActiveModel as Default>::default();
+            fill_tenant_id(&mut model, false);
+            assert_eq!(model.tenant_id, NotSet);
+        });
+    }
+}
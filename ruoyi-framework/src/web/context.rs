@@ -0,0 +1,210 @@
+// ruoyi-framework/src/web/context.rs
+//! 请求上下文模块，保存当前请求的登录用户和租户
+//!
+//! 上下文保存在 tokio 任务本地变量中，由认证中间件包裹整个请求的 Future：
+//! 1. 请求在其他线程上恢复执行，或线程被其他请求复用时，读取到的仍是本请求的上下文
+//! 2. [`current_user`]、[`current_tenant`] 在上下文之外（定时任务、命令行等）返回None
+//! 3. `tokio::spawn` 的新任务不会继承上下文，需要时使用 [`spawn`] 或 [`RequestContext::scope`] 显式传递
+//!
+//! ```ignore
+//! // 后台任务中以当前用户的身份写入数据
+//! context::spawn(async move {
+//!     notice_service.send_notice(notice).await
+//! });
+//! ```
+
+use std::future::Future;
+use std::net::IpAddr;
+use tokio::task::futures::TaskLocalFuture;
+use tokio::task::JoinHandle;
+use tokio::task_local;
+
+/// 登录用户上下文
+#[derive(Debug, Clone)]
+pub struct UserContext {
+    pub user_id: i64,
+    pub user_name: String,
+    pub ip: IpAddr,
+    pub token_id: String,
+    pub tenant_id: i64,
+}
+
+/// 请求上下文，未登录的请求（如登录）没有用户，只有租户
+#[derive(Debug, Clone, Default)]
+pub struct RequestContext {
+    /// 登录用户
+    pub user: Option<UserContext>,
+    /// 当前租户
+    pub tenant_id: Option<i64>,
+}
+
+task_local! {
+    /// 当前任务的请求上下文
+    static REQUEST_CONTEXT: RequestContext;
+}
+
+impl RequestContext {
+    /// 已登录用户的上下文，租户取用户所属租户
+    pub fn user(user: UserContext) -> Self {
+        Self {
+            tenant_id: Some(user.tenant_id),
+            user: Some(user),
+        }
+    }
+
+    /// 未登录请求的上下文
+    pub fn tenant(tenant_id: i64) -> Self {
+        Self {
+            user: None,
+            tenant_id: Some(tenant_id),
+        }
+    }
+
+    /// 当前任务的请求上下文，不在上下文中时返回None
+    pub fn current() -> Option<Self> {
+        REQUEST_CONTEXT.try_with(Clone::clone).ok()
+    }
+
+    /// 在该上下文中执行异步操作
+    pub fn scope<F: Future>(self, f: F) -> TaskLocalFuture<RequestContext, F> {
+        REQUEST_CONTEXT.scope(self, f)
+    }
+
+    /// 在该上下文中执行同步操作，用于 `spawn_blocking` 等场景
+    pub fn sync_scope<F: FnOnce() -> R, R>(self, f: F) -> R {
+        REQUEST_CONTEXT.sync_scope(self, f)
+    }
+}
+
+/// 当前登录用户
+pub fn current_user() -> Option<UserContext> {
+    REQUEST_CONTEXT
+        .try_with(|context| context.user.clone())
+        .ok()
+        .flatten()
+}
+
+/// 当前租户
+pub fn current_tenant() -> Option<i64> {
+    REQUEST_CONTEXT
+        .try_with(|context| context.tenant_id)
+        .ok()
+        .flatten()
+}
+
+/// 切换到指定租户执行异步操作，保留当前用户，如登录时按请求中的租户查询用户
+pub fn with_tenant<F: Future>(tenant_id: i64, f: F) -> TaskLocalFuture<RequestContext, F> {
+    let context = RequestContext {
+        tenant_id: Some(tenant_id),
+        ..RequestContext::current().unwrap_or_default()
+    };
+    context.scope(f)
+}
+
+/// 创建继承当前请求上下文的新任务，不在上下文中时与 `tokio::spawn` 相同
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    match RequestContext::current() {
+        Some(context) => tokio::spawn(context.scope(future)),
+        None => tokio::spawn(future),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web::middleware::auth::Authentication;
+    use actix_web::{test, web, App};
+    use ruoyi_common::utils::jwt::generate_token;
+    use std::time::Duration;
+
+    fn user(user_name: &str, tenant_id: i64) -> UserContext {
+        UserContext {
+            user_id: 1,
+            user_name: user_name.to_string(),
+            ip: IpAddr::from([127, 0, 0, 1]),
+            token_id: "token".to_string(),
+            tenant_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scope_and_spawn() {
+        assert!(current_user().is_none());
+        assert!(current_tenant().is_none());
+
+        RequestContext::user(user("admin", 2))
+            .scope(async {
+                assert_eq!(current_tenant(), Some(2));
+
+                // 切换租户时保留用户
+                with_tenant(3, async {
+                    assert_eq!(current_tenant(), Some(3));
+                    assert_eq!(current_user().unwrap().user_name, "admin");
+                })
+                .await;
+                assert_eq!(current_tenant(), Some(2));
+
+                // 只有显式传递时新任务才能读取上下文
+                let inherited = spawn(async { current_user().map(|user| user.user_name) });
+                let detached = tokio::spawn(async { current_user() });
+                assert_eq!(inherited.await.unwrap().as_deref(), Some("admin"));
+                assert!(detached.await.unwrap().is_none());
+
+                let context = RequestContext::current().unwrap();
+                let blocking =
+                    tokio::task::spawn_blocking(move || context.sync_scope(current_tenant));
+                assert_eq!(blocking.await.unwrap(), Some(2));
+            })
+            .await;
+
+        assert!(current_user().is_none());
+    }
+
+    /// 返回当前用户和租户，中途让出执行权，使并发请求交替执行
+    async fn whoami(delay: web::Path<u64>) -> String {
+        tokio::time::sleep(Duration::from_millis(*delay)).await;
+        tokio::task::yield_now().await;
+        let user = spawn(async { current_user() }).await.unwrap();
+        format!(
+            "{}@{}",
+            user.map(|user| user.user_name).unwrap_or_default(),
+            current_tenant().unwrap_or_default()
+        )
+    }
+
+    #[actix_web::test]
+    async fn test_concurrent_requests() {
+        let secret = "context_test_secret";
+        let app = test::init_service(
+            App::new()
+                .wrap(Authentication::new(secret.to_string(), vec![]))
+                .route("/whoami/{delay}", web::get().to(whoami)),
+        )
+        .await;
+
+        let requests = (0..20).map(|i| {
+            let user_name = format!("user{}", i);
+            let token = generate_token("token", i, &user_name, i % 3 + 1, secret, 60).unwrap();
+            // 先发出的请求等待更久，保证各请求交替执行
+            let req = test::TestRequest::get()
+                .uri(&format!("/whoami/{}", 40 - i * 2))
+                .peer_addr("127.0.0.1:8080".parse().unwrap())
+                .insert_header(("Authorization", format!("Bearer {}", token)))
+                .to_request();
+            let app = &app;
+            async move {
+                let body = test::call_and_read_body(app, req).await;
+                (format!("{}@{}", user_name, i % 3 + 1), body)
+            }
+        });
+
+        for (expected, body) in futures::future::join_all(requests).await {
+            assert_eq!(String::from_utf8(body.to_vec()).unwrap(), expected);
+        }
+        assert!(current_user().is_none());
+    }
+}
@@ -6,18 +6,27 @@ pub mod common {
 
     use log::error;
 
-    use ruoyi_common::{enums::CaptchaType, vo::R};
+    use ruoyi_common::{
+        enums::CaptchaType,
+        vo::{NoData, R},
+    };
     use serde::Serialize;
     use std::collections::HashMap;
+    use utoipa::ToSchema;
 
     use crate::web::service::captcha::{CaptchaService, InMemoryCaptchaService};
     /// 健康检查
+    #[utoipa::path(
+        tag = "通用接口",
+        security(()),
+        responses((status = 200, description = "服务正常", body = R<NoData>))
+    )]
     #[get("/health")]
     pub async fn health_check() -> HttpResponse {
         HttpResponse::Ok().json(R::<()>::ok_with_msg("健康检查成功"))
     }
     /// 验证码响应
-    #[derive(Debug, Serialize)]
+    #[derive(Debug, Serialize, ToSchema)]
     pub struct CaptchaResponse {
         /// 验证码图片 Base64
         pub img: String,
@@ -32,6 +41,15 @@ pub mod common {
     }
 
     /// 验证码接口
+    #[utoipa::path(
+        tag = "通用接口",
+        security(()),
+        params(("type" = Option<String>, Query, description = "验证码类型，math 为算术验证码，默认为字母数字")),
+        responses(
+            (status = 200, description = "验证码图片和唯一标识", body = R<CaptchaResponse>),
+            (status = 500, description = "生成验证码失败", body = R<NoData>)
+        )
+    )]
     #[get("/captchaImage")]
     pub async fn captcha_image(
         query: web::Query<HashMap<String, String>>,
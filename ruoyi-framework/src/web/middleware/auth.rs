@@ -11,7 +11,8 @@ use std::sync::Arc;
 
 use ruoyi_common::utils::jwt::validate_token;
 
-use crate::web::tls;
+use crate::tenant::{tenant_id_from_headers, DEFAULT_TENANT_ID};
+use crate::web::context::{RequestContext, UserContext};
 
 /// 认证中间件
 pub struct Authentication {
@@ -81,8 +82,10 @@ where
         // 检查是否需要认证
         if self.exclude_paths.iter().any(|p| path.starts_with(p)) {
             debug!("路径 {} 不需要认证", path);
+            // 未登录的请求从请求头中获取租户
+            let tenant_id = tenant_id_from_headers(req.headers()).unwrap_or(DEFAULT_TENANT_ID);
             let fut = self.service.call(req);
-            return Box::pin(async move { fut.await });
+            return Box::pin(RequestContext::tenant(tenant_id).scope(fut));
         }
         // 获取 Authorization 头
         let auth_header = req.headers().get("Authorization");
@@ -135,15 +138,16 @@ where
 
         // 获取ip地址
         let ip = req.peer_addr().unwrap().ip();
-        // 设置用户上下文
-        tls::set_sync_user_context(tls::UserContext {
+        // 用户上下文包裹整个请求，已登录的请求以令牌中的租户为准，忽略请求头
+        let context = RequestContext::user(UserContext {
             user_id: claims.user_id,
             user_name: claims.user_name.clone(),
             ip,
             token_id: claims.token_id.clone(),
+            tenant_id: claims.tenant_id,
         });
 
         let fut = self.service.call(req);
-        Box::pin(async move { fut.await })
+        Box::pin(context.scope(fut))
     }
 }
@@ -1,12 +1,19 @@
 //! Web 相关功能模块
 
 use actix_web::web;
+use utoipa::OpenApi;
 
+pub mod context;
 pub mod controller;
 pub mod filter;
 pub mod middleware;
+pub mod openapi;
 pub mod service;
-pub mod tls;
+
+/// 通用接口文档
+#[derive(OpenApi)]
+#[openapi(paths(controller::common::health_check, controller::common::captcha_image))]
+pub struct CommonApi;
 
 pub fn register_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(controller::common::health_check);
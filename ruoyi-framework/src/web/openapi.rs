@@ -0,0 +1,153 @@
+// ruoyi-framework/src/web/openapi.rs
+//! 接口文档模块，提供 OpenAPI 文档和 Swagger UI
+//!
+//! 各模块在控制器上标注 `#[utoipa::path]`，由模块的 `OpenApi` 汇总，启动时合并后提供：
+//! 1. `/v3/api-docs`：OpenAPI 3 文档（JSON）
+//! 2. `/swagger-ui/`：Swagger UI，静态资源已编译进程序，不依赖外网
+//!
+//! 需要登录的接口使用 [`BEARER_AUTH`] 认证，在 Swagger UI 中点击 Authorize 填入登录返回的令牌即可调试
+
+use actix_web::{web, HttpResponse};
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::OpenApi;
+use utoipa::Modify;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::config::server::ApiDocSettings;
+
+/// OpenAPI 文档路径
+pub const API_DOCS_PATH: &str = "/v3/api-docs";
+
+/// Swagger UI 路径
+pub const SWAGGER_UI_PATH: &str = "/swagger-ui";
+
+/// 令牌认证方案名称
+pub const BEARER_AUTH: &str = "bearer_auth";
+
+/// 添加令牌认证方案，与认证中间件一致，从 `Authorization: Bearer <token>` 中读取令牌
+pub struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            BEARER_AUTH,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .description(Some("登录接口返回的令牌"))
+                    .build(),
+            ),
+        );
+    }
+}
+
+/// 按配置注册 OpenAPI 文档和 Swagger UI 路由
+pub fn configure_api_docs(
+    cfg: &mut web::ServiceConfig,
+    settings: &ApiDocSettings,
+    openapi: OpenApi,
+) {
+    if !settings.enabled {
+        return;
+    }
+    if settings.swagger_ui {
+        // Swagger UI 同时提供其加载的文档
+        cfg.service(
+            SwaggerUi::new(format!("{}/{{_:.*}}", SWAGGER_UI_PATH)).url(API_DOCS_PATH, openapi),
+        );
+    } else {
+        cfg.app_data(web::Data::new(openapi))
+            .route(API_DOCS_PATH, web::get().to(api_docs));
+    }
+}
+
+/// 不需要认证的接口文档路径，未启用时为空
+pub fn api_docs_paths(settings: &ApiDocSettings) -> Vec<String> {
+    let mut paths = Vec::new();
+    if settings.enabled {
+        paths.push(API_DOCS_PATH.to_string());
+        if settings.swagger_ui {
+            paths.push(SWAGGER_UI_PATH.to_string());
+        }
+    }
+    paths
+}
+
+/// OpenAPI 文档
+async fn api_docs(openapi: web::Data<OpenApi>) -> HttpResponse {
+    HttpResponse::Ok().json(openapi.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+    use utoipa::openapi::OpenApiBuilder;
+
+    fn settings(enabled: bool, swagger_ui: bool) -> ApiDocSettings {
+        ApiDocSettings {
+            enabled,
+            swagger_ui,
+        }
+    }
+
+    fn openapi() -> OpenApi {
+        let mut openapi = OpenApiBuilder::new().build();
+        SecurityAddon.modify(&mut openapi);
+        openapi
+    }
+
+    async fn status(settings: &ApiDocSettings, uri: &str) -> StatusCode {
+        let app = test::init_service(
+            App::new().configure(|cfg| configure_api_docs(cfg, settings, openapi())),
+        )
+        .await;
+        let req = test::TestRequest::get().uri(uri).to_request();
+        test::call_service(&app, req).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_configure_api_docs() {
+        let all = settings(true, true);
+        assert_eq!(status(&all, API_DOCS_PATH).await, StatusCode::OK);
+        assert_eq!(status(&all, "/swagger-ui/").await, StatusCode::OK);
+        assert_eq!(api_docs_paths(&all), vec![API_DOCS_PATH, SWAGGER_UI_PATH]);
+
+        // 只提供文档
+        let docs_only = settings(true, false);
+        let app = test::init_service(
+            App::new().configure(|cfg| configure_api_docs(cfg, &docs_only, openapi())),
+        )
+        .await;
+        let req = test::TestRequest::get().uri(API_DOCS_PATH).to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(
+            body["components"]["securitySchemes"][BEARER_AUTH]["scheme"],
+            "bearer"
+        );
+        assert_eq!(
+            status(&docs_only, "/swagger-ui/").await,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(api_docs_paths(&docs_only), vec![API_DOCS_PATH]);
+
+        // 关闭文档时 Swagger UI 也不提供
+        let disabled = settings(false, true);
+        assert_eq!(
+            status(&disabled, API_DOCS_PATH).await,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            status(&disabled, "/swagger-ui/").await,
+            StatusCode::NOT_FOUND
+        );
+        assert!(api_docs_paths(&disabled).is_empty());
+
+        // 默认关闭，不对外暴露接口列表
+        let default = ApiDocSettings::default();
+        assert_eq!(status(&default, API_DOCS_PATH).await, StatusCode::NOT_FOUND);
+        assert!(api_docs_paths(&default).is_empty());
+    }
+}
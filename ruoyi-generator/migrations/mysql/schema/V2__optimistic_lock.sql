@@ -0,0 +1,3 @@
+-- 乐观锁版本号
+
+alter table gen_table add column version int(11) not null default 0 comment '乐观锁版本号';
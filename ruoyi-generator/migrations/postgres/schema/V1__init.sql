@@ -0,0 +1,105 @@
+-- 代码生成模块表结构
+
+-- ----------------------------
+-- 代码生成业务表
+-- ----------------------------
+create table gen_table (
+  table_id             bigint generated by default as identity,
+  table_name           varchar(200) default '',
+  table_comment        varchar(500) default '',
+  sub_table_name       varchar(64),
+  sub_table_fk_name    varchar(64),
+  class_name           varchar(100) default '',
+  tpl_category         varchar(200) default 'crud',
+  tpl_web_type         varchar(30) default 'element-ui',
+  package_name         varchar(100),
+  module_name          varchar(30),
+  business_name        varchar(30),
+  function_name        varchar(50),
+  function_author      varchar(50),
+  gen_type             char(1) default '0',
+  gen_path             varchar(200) default '/',
+  parent_menu_id       bigint default 0,
+  options              varchar(1000),
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500),
+  primary key (table_id)
+);
+comment on table gen_table is '代码生成业务表';
+comment on column gen_table.table_id is '编号';
+comment on column gen_table.table_name is '表名称';
+comment on column gen_table.table_comment is '表描述';
+comment on column gen_table.sub_table_name is '关联子表的表名';
+comment on column gen_table.sub_table_fk_name is '子表关联的外键名';
+comment on column gen_table.class_name is '实体类名称';
+comment on column gen_table.tpl_category is '使用的模板（crud单表操作 tree树表操作）';
+comment on column gen_table.tpl_web_type is '前端模板类型（element-ui模版 element-plus模版）';
+comment on column gen_table.package_name is '生成包路径';
+comment on column gen_table.module_name is '生成模块名';
+comment on column gen_table.business_name is '生成业务名';
+comment on column gen_table.function_name is '生成功能名';
+comment on column gen_table.function_author is '生成功能作者';
+comment on column gen_table.gen_type is '生成代码方式（0zip压缩包 1自定义路径）';
+comment on column gen_table.gen_path is '生成路径（不填默认项目路径）';
+comment on column gen_table.parent_menu_id is '父菜单ID';
+comment on column gen_table.options is '其它生成选项';
+comment on column gen_table.create_by is '创建者';
+comment on column gen_table.create_time is '创建时间';
+comment on column gen_table.update_by is '更新者';
+comment on column gen_table.update_time is '更新时间';
+comment on column gen_table.remark is '备注';
+
+-- ----------------------------
+-- 代码生成业务表字段
+-- ----------------------------
+create table gen_table_column (
+  column_id            bigint generated by default as identity,
+  table_id             bigint,
+  column_name          varchar(200),
+  column_comment       varchar(500),
+  column_type          varchar(100),
+  java_type            varchar(500),
+  java_field           varchar(200),
+  is_pk                char(1),
+  is_increment         char(1),
+  is_required          char(1),
+  is_insert            char(1),
+  is_edit              char(1),
+  is_list              char(1),
+  is_query             char(1),
+  query_type           varchar(200) default 'EQ',
+  html_type            varchar(200),
+  dict_type            varchar(200) default '',
+  sort                 integer,
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (column_id)
+);
+comment on table gen_table_column is '代码生成业务表字段';
+comment on column gen_table_column.column_id is '编号';
+comment on column gen_table_column.table_id is '归属表编号';
+comment on column gen_table_column.column_name is '列名称';
+comment on column gen_table_column.column_comment is '列描述';
+comment on column gen_table_column.column_type is '列类型';
+comment on column gen_table_column.java_type is 'JAVA类型';
+comment on column gen_table_column.java_field is 'JAVA字段名';
+comment on column gen_table_column.is_pk is '是否主键（1是）';
+comment on column gen_table_column.is_increment is '是否自增（1是）';
+comment on column gen_table_column.is_required is '是否必填（1是）';
+comment on column gen_table_column.is_insert is '是否为插入字段（1是）';
+comment on column gen_table_column.is_edit is '是否编辑字段（1是）';
+comment on column gen_table_column.is_list is '是否列表字段（1是）';
+comment on column gen_table_column.is_query is '是否查询字段（1是）';
+comment on column gen_table_column.query_type is '查询方式（等于、不等于、大于、小于、范围）';
+comment on column gen_table_column.html_type is '显示类型（文本框、文本域、下拉框、复选框、单选框、日期控件）';
+comment on column gen_table_column.dict_type is '字典类型';
+comment on column gen_table_column.sort is '排序';
+comment on column gen_table_column.create_by is '创建者';
+comment on column gen_table_column.create_time is '创建时间';
+comment on column gen_table_column.update_by is '更新者';
+comment on column gen_table_column.update_time is '更新时间';
@@ -0,0 +1,5 @@
+-- 乐观锁版本号
+
+alter table gen_table add column version integer not null default 0;
+
+comment on column gen_table.version is '乐观锁版本号';
@@ -0,0 +1,57 @@
+-- 代码生成模块表结构
+
+-- ----------------------------
+-- 代码生成业务表
+-- ----------------------------
+create table gen_table (  -- 代码生成业务表
+  table_id             integer primary key autoincrement,  -- 编号
+  table_name           varchar(200) default '',  -- 表名称
+  table_comment        varchar(500) default '',  -- 表描述
+  sub_table_name       varchar(64),  -- 关联子表的表名
+  sub_table_fk_name    varchar(64),  -- 子表关联的外键名
+  class_name           varchar(100) default '',  -- 实体类名称
+  tpl_category         varchar(200) default 'crud',  -- 使用的模板（crud单表操作 tree树表操作）
+  tpl_web_type         varchar(30) default 'element-ui',  -- 前端模板类型（element-ui模版 element-plus模版）
+  package_name         varchar(100),  -- 生成包路径
+  module_name          varchar(30),  -- 生成模块名
+  business_name        varchar(30),  -- 生成业务名
+  function_name        varchar(50),  -- 生成功能名
+  function_author      varchar(50),  -- 生成功能作者
+  gen_type             char(1) default '0',  -- 生成代码方式（0zip压缩包 1自定义路径）
+  gen_path             varchar(200) default '/',  -- 生成路径（不填默认项目路径）
+  parent_menu_id       bigint default 0,  -- 父菜单ID
+  options              varchar(1000),  -- 其它生成选项
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500)  -- 备注
+);
+
+-- ----------------------------
+-- 代码生成业务表字段
+-- ----------------------------
+create table gen_table_column (  -- 代码生成业务表字段
+  column_id            integer primary key autoincrement,  -- 编号
+  table_id             bigint,  -- 归属表编号
+  column_name          varchar(200),  -- 列名称
+  column_comment       varchar(500),  -- 列描述
+  column_type          varchar(100),  -- 列类型
+  java_type            varchar(500),  -- JAVA类型
+  java_field           varchar(200),  -- JAVA字段名
+  is_pk                char(1),  -- 是否主键（1是）
+  is_increment         char(1),  -- 是否自增（1是）
+  is_required          char(1),  -- 是否必填（1是）
+  is_insert            char(1),  -- 是否为插入字段（1是）
+  is_edit              char(1),  -- 是否编辑字段（1是）
+  is_list              char(1),  -- 是否列表字段（1是）
+  is_query             char(1),  -- 是否查询字段（1是）
+  query_type           varchar(200) default 'EQ',  -- 查询方式（等于、不等于、大于、小于、范围）
+  html_type            varchar(200),  -- 显示类型（文本框、文本域、下拉框、复选框、单选框、日期控件）
+  dict_type            varchar(200) default '',  -- 字典类型
+  sort                 int,  -- 排序
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime  -- 更新时间
+);
@@ -0,0 +1,3 @@
+-- 乐观锁版本号
+
+alter table gen_table add column version int not null default 0;  -- 乐观锁版本号
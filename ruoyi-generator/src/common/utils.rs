@@ -35,6 +35,7 @@ pub async fn init_table(
         update_by: NotSet,
         update_time: NotSet,
         remark: NotSet,
+        version: NotSet,
     };
     if let Some(table_name) = table_name {
         gen_table.class_name = Set(Some(convert_class_name(&table_name, gen_config.clone())));
@@ -1,24 +1,27 @@
 use actix_web::{web, HttpResponse, Responder};
 use log::error;
-use ruoyi_common::vo::{PageParam, RData, R};
+use ruoyi_common::vo::{NoData, PageParam, RData, TableDataInfo, R};
 use ruoyi_framework::web::service::file::download_file;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
 use crate::config::GenConfig;
 use crate::entity::prelude::*;
 use crate::repository::GenTableQuery;
-use crate::service::gen_table_service::UpdateGenTableRequest;
+use crate::service::gen_table_service::{GenTableDetail, UpdateGenTableRequest};
 use crate::service::{GenTableService, TemplateData};
 
 /// 预览代码响应
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PreviewCodeResponse {
     pub templates: Vec<TemplateData>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// 表名参数
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
 pub struct TablesQuery {
+    /// 表名，多个以逗号分隔
     pub tables: String,
 }
 
@@ -233,109 +236,196 @@ impl GenController {
     }
 }
 
+/// 查询代码生成列表
+#[utoipa::path(
+    get,
+    path = "/list",
+    params(PageParam, GenTableQuery),
+    responses((status = 200, description = "已导入的业务表", body = R<TableDataInfo<GenTableModel>>))
+)]
+async fn list(
+    gen: web::Data<GenController>,
+    page_param: web::Query<PageParam>,
+    query: web::Query<GenTableQuery>,
+) -> impl Responder {
+    gen.list(page_param, query).await
+}
+
+/// 查询数据库列表
+#[utoipa::path(
+    get,
+    path = "/db/list",
+    params(PageParam, GenTableQuery),
+    responses((status = 200, description = "未导入的数据库表", body = R<TableDataInfo<GenTableModel>>))
+)]
+async fn db_list(
+    gen: web::Data<GenController>,
+    page_param: web::Query<PageParam>,
+    query: web::Query<GenTableQuery>,
+) -> impl Responder {
+    gen.db_list(page_param, query).await
+}
+
+/// 查询表详细信息
+#[utoipa::path(
+    get,
+    path = "/{tableId}",
+    params(("tableId" = i64, Path, description = "业务表ID")),
+    responses((status = 200, description = "业务表及其字段", body = RData<GenTableDetail>))
+)]
+async fn get_info(gen: web::Data<GenController>, path: web::Path<i64>) -> impl Responder {
+    gen.get_info(path).await
+}
+
+/// 查询表字段信息
+#[utoipa::path(
+    get,
+    path = "/column/{tableId}",
+    params(("tableId" = i64, Path, description = "业务表ID")),
+    responses((status = 200, description = "业务表及其字段", body = RData<GenTableDetail>))
+)]
+async fn get_columns(gen: web::Data<GenController>, path: web::Path<i64>) -> impl Responder {
+    gen.get_info(path).await
+}
+
+/// 导入表结构
+#[utoipa::path(
+    post,
+    path = "/importTable",
+    params(TablesQuery),
+    responses((status = 200, description = "导入结果", body = R<NoData>))
+)]
+async fn import_table(
+    gen: web::Data<GenController>,
+    query: web::Query<TablesQuery>,
+    gen_config: web::Data<GenConfig>,
+) -> impl Responder {
+    gen.import_table(query, gen_config).await
+}
+
+/// 创建表
+#[utoipa::path(
+    post,
+    path = "/createTable",
+    request_body = GenTableModel,
+    responses((status = 200, description = "创建结果", body = R<NoData>))
+)]
+async fn create_table(
+    gen: web::Data<GenController>,
+    table: web::Json<GenTableModel>,
+) -> impl Responder {
+    gen.create_table(table).await
+}
+
+/// 修改代码生成业务
+#[utoipa::path(
+    put,
+    path = "",
+    request_body = UpdateGenTableRequest,
+    responses((status = 200, description = "修改结果", body = R<NoData>))
+)]
+async fn update(
+    gen: web::Data<GenController>,
+    table: web::Json<UpdateGenTableRequest>,
+) -> impl Responder {
+    gen.update(table).await
+}
+
+/// 删除代码生成
+#[utoipa::path(
+    delete,
+    path = "/{tableIds}",
+    params(("tableIds" = String, Path, description = "业务表ID，多个以逗号分隔")),
+    responses(
+        (status = 200, description = "删除结果", body = R<NoData>),
+        (status = 400, description = "业务表ID无效", body = R<NoData>)
+    )
+)]
+async fn delete(gen: web::Data<GenController>, path: web::Path<String>) -> impl Responder {
+    gen.delete(path).await
+}
+
+/// 预览代码
+#[utoipa::path(
+    get,
+    path = "/preview/{tableId}",
+    params(("tableId" = i64, Path, description = "业务表ID")),
+    responses((status = 200, description = "各模板生成的代码", body = R<PreviewCodeResponse>))
+)]
+async fn preview(gen: web::Data<GenController>, path: web::Path<i64>) -> impl Responder {
+    gen.preview(path).await
+}
+
+/// 生成代码（下载方式）
+#[utoipa::path(
+    get,
+    path = "/download/{tableName}",
+    params(("tableName" = i64, Path, description = "业务表ID")),
+    responses((status = 200, description = "生成代码的ZIP文件", content_type = "application/octet-stream", body = Vec<u8>))
+)]
+async fn download(gen: web::Data<GenController>, path: web::Path<i64>) -> impl Responder {
+    gen.download(path).await
+}
+
+/// 同步数据库
+#[utoipa::path(
+    get,
+    path = "/synchDb/{tableName}",
+    params(("tableName" = i64, Path, description = "业务表ID")),
+    responses((status = 200, description = "同步结果", body = R<NoData>))
+)]
+async fn synch_db(gen: web::Data<GenController>, path: web::Path<i64>) -> impl Responder {
+    gen.synch_db(path).await
+}
+
+/// 批量生成代码
+#[utoipa::path(
+    get,
+    path = "/batchGenCode",
+    params(TablesQuery),
+    responses((status = 200, description = "生成代码的ZIP文件", content_type = "application/octet-stream", body = Vec<u8>))
+)]
+async fn batch_gen_code(
+    gen: web::Data<GenController>,
+    query: web::Query<TablesQuery>,
+) -> impl Responder {
+    gen.batch_gen_code(query).await
+}
+
+/// 代码生成接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    list,
+    db_list,
+    get_info,
+    get_columns,
+    import_table,
+    create_table,
+    update,
+    delete,
+    preview,
+    download,
+    synch_db,
+    batch_gen_code
+))]
+pub struct GenApi;
+
 /// 配置代码生成路由
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/tool/gen")
             // 表相关接口
-            .route(
-                "/list",
-                web::get().to(
-                    |gen: web::Data<GenController>,
-                     page_param: web::Query<PageParam>,
-                     query: web::Query<GenTableQuery>| async move {
-                        gen.list(page_param, query).await
-                    },
-                ),
-            )
-            .route(
-                "/db/list",
-                web::get().to(
-                    |gen: web::Data<GenController>,
-                     page_param: web::Query<PageParam>,
-                     query: web::Query<GenTableQuery>| async move {
-                        gen.db_list(page_param, query).await
-                    },
-                ),
-            )
-            .route(
-                "/column/{tableId}",
-                web::get().to(
-                    |gen: web::Data<GenController>, path: web::Path<i64>| async move {
-                        gen.get_info(path).await
-                    },
-                ),
-            )
-            .route(
-                "/importTable",
-                web::post().to(
-                    |gen: web::Data<GenController>, query: web::Query<TablesQuery>, gen_config: web::Data<GenConfig>| async move {
-                        gen.import_table(query, gen_config).await
-                    },
-                ),
-            )
-            .route(
-                "/createTable",
-                web::post().to(
-                    |gen: web::Data<GenController>, table: web::Json<GenTableModel>| async move {
-                        gen.create_table(table).await
-                    },
-                ),
-            )
-            .route(
-                "",
-                web::put().to(
-                    |gen: web::Data<GenController>, table: web::Json<UpdateGenTableRequest>| async move {
-                        gen.update(table).await
-                    },
-                ),
-            )
-            .route(
-                "/{tableIds}",
-                web::delete().to(
-                    |gen: web::Data<GenController>, path: web::Path<String>| async move {
-                        gen.delete(path).await
-                    },
-                ),
-            )
-            .route(
-                "/preview/{tableId}",
-                web::get().to(
-                    |gen: web::Data<GenController>, path: web::Path<i64>| async move {
-                        gen.preview(path).await
-                    },
-                ),
-            )
-            .route(
-                "/download/{tableName}",
-                web::get().to(
-                    |gen: web::Data<GenController>, path: web::Path<i64>| async move {
-                        gen.download(path).await
-                    },
-                ),
-            )
-            .route(
-                "/synchDb/{tableName}",
-                web::get().to(
-                    |gen: web::Data<GenController>, path: web::Path<i64>| async move {
-                        gen.synch_db(path).await
-                    },
-                ),
-            )
-            .route(
-                "/batchGenCode",
-                web::get().to(
-                    |gen: web::Data<GenController>, query: web::Query<TablesQuery>| async move {
-                        gen.batch_gen_code(query).await
-                    },
-                ),
-            ).route(
-                "/{tableId}",
-                web::get().to(
-                    |gen: web::Data<GenController>, path: web::Path<i64>| async move {
-                        gen.get_info(path).await
-                    },
-                ),
-            ),
+            .route("/list", web::get().to(list))
+            .route("/db/list", web::get().to(db_list))
+            .route("/column/{tableId}", web::get().to(get_columns))
+            .route("/importTable", web::post().to(import_table))
+            .route("/createTable", web::post().to(create_table))
+            .route("", web::put().to(update))
+            .route("/{tableIds}", web::delete().to(delete))
+            .route("/preview/{tableId}", web::get().to(preview))
+            .route("/download/{tableName}", web::get().to(download))
+            .route("/synchDb/{tableName}", web::get().to(synch_db))
+            .route("/batchGenCode", web::get().to(batch_gen_code))
+            .route("/{tableId}", web::get().to(get_info)),
     );
 }
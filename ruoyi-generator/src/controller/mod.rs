@@ -1,3 +1,3 @@
 mod gen_controller;
 
-pub use gen_controller::{config, GenController};
+pub use gen_controller::{config, GenApi, GenController};
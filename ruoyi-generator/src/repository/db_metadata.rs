@@ -0,0 +1,224 @@
+//! 数据库元数据查询
+//!
+//! 按连接的数据库类型查询当前库中的表和字段信息：
+//! 1. MySQL 查询 information_schema
+//! 2. PostgreSQL 查询当前 schema 下的 pg_catalog，字段类型转换为与 MySQL 一致的写法
+//! 3. SQLite 查询 sqlite_master 和 pragma_table_info，注释取自建表语句中的 `--` 行尾注释
+//!
+//! 表名等来自请求的值一律通过绑定参数传入，不拼接到SQL中
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, FromQueryResult, Statement,
+};
+
+/// 数据库中的表
+#[derive(FromQueryResult, Debug)]
+pub(crate) struct DbTable {
+    pub table_name: Option<String>,
+    pub table_comment: Option<String>,
+    pub create_time: Option<DateTime<Utc>>,
+    pub update_time: Option<DateTime<Utc>>,
+}
+
+/// 数据库表中的字段
+#[derive(FromQueryResult, Debug)]
+pub(crate) struct TableColumn {
+    pub column_name: Option<String>,
+    pub is_required: Option<String>,
+    pub is_pk: Option<String>,
+    pub sort: Option<i64>,
+    pub column_comment: Option<String>,
+    pub is_increment: Option<String>,
+    pub column_type: Option<String>,
+}
+
+/// 查询当前库中所有表的SQL，结果列为 table_name、table_comment、create_time、update_time
+///
+/// PostgreSQL 和 SQLite 不记录表的创建和更新时间，这两列为NULL
+pub(crate) fn db_table_sql(backend: DatabaseBackend) -> &'static str {
+    match backend {
+        DatabaseBackend::MySql => {
+            "SELECT table_name AS `table_name`, table_comment AS `table_comment`,
+            create_time AS `create_time`, update_time AS `update_time`
+            FROM information_schema.tables
+            WHERE table_schema = (SELECT DATABASE())"
+        }
+        DatabaseBackend::Postgres => {
+            "SELECT c.relname::text AS table_name,
+            obj_description(c.oid, 'pg_class') AS table_comment,
+            CAST(NULL AS timestamptz) AS create_time, CAST(NULL AS timestamptz) AS update_time
+            FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relkind IN ('r', 'p') AND n.nspname = current_schema()"
+        }
+        DatabaseBackend::Sqlite => {
+            "SELECT name AS table_name,
+            CASE WHEN instr(sql, '--') > 0 AND instr(sql, '--') < instr(sql, char(10))
+            THEN trim(substr(sql, instr(sql, '--') + 2, instr(sql, char(10)) - instr(sql, '--') - 2))
+            END AS table_comment,
+            NULL AS create_time, NULL AS update_time
+            FROM sqlite_master
+            WHERE type = 'table' AND name NOT LIKE 'sqlite_%'"
+        }
+    }
+}
+
+/// 第 `index` 个（从1开始）绑定参数的占位符，PostgreSQL 为 `$n`，MySQL 和 SQLite 为 `?`
+pub(crate) fn placeholder(backend: DatabaseBackend, index: usize) -> String {
+    match backend {
+        DatabaseBackend::Postgres => format!("${}", index),
+        DatabaseBackend::MySql | DatabaseBackend::Sqlite => "?".to_string(),
+    }
+}
+
+/// 查询表的字段信息，按字段顺序排列
+pub(crate) async fn select_table_columns(
+    db: &DatabaseConnection,
+    table_name: &str,
+) -> Result<Vec<TableColumn>, DbErr> {
+    let backend = db.get_database_backend();
+    let sql = match backend {
+        DatabaseBackend::MySql => {
+            "select column_name as `column_name`, (case when (is_nullable = 'no' and column_key != 'PRI') then '1' else '0' end) as is_required, (case when column_key = 'PRI' then '1' else '0' end) as is_pk, cast(ordinal_position as signed) as sort, column_comment as `column_comment`, (case when extra = 'auto_increment' then '1' else '0' end) as is_increment, column_type as `column_type`
+            from information_schema.columns where table_schema = (select database()) and table_name = ?
+            order by ordinal_position"
+        }
+        DatabaseBackend::Postgres => {
+            "select a.attname::text as column_name,
+            (case when a.attnotnull and pk.contype is null then '1' else '0' end) as is_required,
+            (case when pk.contype is not null then '1' else '0' end) as is_pk,
+            a.attnum::int8 as sort,
+            col_description(a.attrelid, a.attnum) as column_comment,
+            (case when a.attidentity <> '' or pg_get_expr(d.adbin, d.adrelid) like 'nextval(%' then '1' else '0' end) as is_increment,
+            (case t.typname
+                when 'int8' then 'bigint' when 'int4' then 'int' when 'int2' then 'smallint'
+                when 'bool' then 'tinyint(1)' when 'timestamptz' then 'datetime' when 'timestamp' then 'datetime'
+                when 'float8' then 'double' when 'float4' then 'float' when 'bytea' then 'longblob'
+                when 'numeric' then replace(format_type(a.atttypid, a.atttypmod), 'numeric', 'decimal')
+                else replace(replace(format_type(a.atttypid, a.atttypmod), 'character varying', 'varchar'), 'character', 'char')
+            end) as column_type
+            from pg_attribute a
+            join pg_class c on c.oid = a.attrelid
+            join pg_namespace n on n.oid = c.relnamespace
+            join pg_type t on t.oid = a.atttypid
+            left join pg_attrdef d on d.adrelid = a.attrelid and d.adnum = a.attnum
+            left join pg_constraint pk on pk.conrelid = a.attrelid and pk.contype = 'p' and a.attnum = any(pk.conkey)
+            where n.nspname = current_schema() and c.relname::text = $1 and a.attnum > 0 and not a.attisdropped
+            order by a.attnum"
+        }
+        DatabaseBackend::Sqlite => return select_sqlite_table_columns(db, table_name).await,
+    };
+    TableColumn::find_by_statement(Statement::from_sql_and_values(
+        backend,
+        sql,
+        [table_name.into()],
+    ))
+    .all(db)
+    .await
+}
+
+/// SQLite 的字段信息来自 pragma_table_info，注释从建表语句中解析
+async fn select_sqlite_table_columns(
+    db: &DatabaseConnection,
+    table_name: &str,
+) -> Result<Vec<TableColumn>, DbErr> {
+    #[derive(FromQueryResult)]
+    struct PragmaColumn {
+        cid: i64,
+        name: String,
+        column_type: String,
+        notnull: i64,
+        pk: i64,
+    }
+
+    #[derive(FromQueryResult)]
+    struct TableSql {
+        sql: Option<String>,
+    }
+
+    let create_sql = TableSql::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?",
+        [table_name.into()],
+    ))
+    .one(db)
+    .await?
+    .and_then(|table| table.sql)
+    .unwrap_or_default();
+
+    let columns = PragmaColumn::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT cid, name, type AS column_type, \"notnull\", pk FROM pragma_table_info(?) ORDER BY cid",
+        [table_name.into()],
+    ))
+    .all(db)
+    .await?;
+
+    // INTEGER PRIMARY KEY 是 rowid 的别名，插入时自动分配
+    let pk_count = columns.iter().filter(|column| column.pk > 0).count();
+    Ok(columns
+        .into_iter()
+        .map(|column| {
+            let is_pk = column.pk > 0;
+            let is_increment =
+                is_pk && pk_count == 1 && column.column_type.eq_ignore_ascii_case("integer");
+            TableColumn {
+                column_comment: sqlite_column_comment(&create_sql, &column.name),
+                column_name: Some(column.name),
+                is_required: Some(flag(column.notnull != 0 && !is_pk)),
+                is_pk: Some(flag(is_pk)),
+                sort: Some(column.cid + 1),
+                is_increment: Some(flag(is_increment)),
+                column_type: Some(column.column_type.to_lowercase()),
+            }
+        })
+        .collect())
+}
+
+fn flag(value: bool) -> String {
+    if value { "1" } else { "0" }.to_string()
+}
+
+/// 从建表语句中取出字段定义行尾的 `--` 注释
+fn sqlite_column_comment(create_sql: &str, column_name: &str) -> Option<String> {
+    create_sql.lines().find_map(|line| {
+        let line = line.trim_start();
+        let rest = line.strip_prefix(column_name)?;
+        if !rest.starts_with(char::is_whitespace) {
+            return None;
+        }
+        let (_, comment) = rest.split_once("--")?;
+        Some(comment.trim().to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::Database;
+
+    #[tokio::test]
+    async fn test_select_sqlite_table_columns() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db.execute_unprepared(
+            "CREATE TABLE sys_notice ( -- 通知公告
+            notice_id INTEGER PRIMARY KEY, -- 公告ID
+            notice_title VARCHAR(50) NOT NULL -- 公告标题
+            )",
+        )
+        .await
+        .unwrap();
+
+        let columns = select_table_columns(&db, "sys_notice").await.unwrap();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].is_increment.as_deref(), Some("1"));
+        assert_eq!(columns[1].column_comment.as_deref(), Some("公告标题"));
+        assert_eq!(columns[1].is_required.as_deref(), Some("1"));
+
+        // 表名作为绑定参数传入，引号不会改变SQL
+        let injected = select_table_columns(&db, "sys_notice') UNION SELECT 1,2,3,4,5 --")
+            .await
+            .unwrap();
+        assert!(injected.is_empty());
+    }
+}
@@ -1,13 +1,13 @@
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::Result;
-use ruoyi_framework::{db::repository::BaseRepository, web::tls::get_sync_user_context};
+use ruoyi_framework::db::repository::{unset_zero_primary_key, BaseRepository};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseBackend, DatabaseConnection, DatabaseTransaction,
-    EntityTrait, FromQueryResult, QueryFilter, QueryOrder, Set, Statement,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction, EntityTrait,
+    QueryFilter, QueryOrder,
 };
 use std::sync::Arc;
 
+use super::db_metadata::select_table_columns;
 use crate::entity::prelude::*;
 
 /// 代码生成表字段仓库接口
@@ -67,25 +67,7 @@ impl GenTableColumnRepository for GenTableColumnRepositoryImpl {
         &self,
         table_name: &str,
     ) -> Result<Vec<GenTableColumnModel>> {
-        let sql = format!("select column_name as `column_name`, (case when (is_nullable = 'no' and column_key != 'PRI') then '1' else '0' end) as is_required, (case when column_key = 'PRI' then '1' else '0' end) as is_pk, ordinal_position as sort, column_comment as `column_comment`, (case when extra = 'auto_increment' then '1' else '0' end) as is_increment, column_type as `column_type`
-		from information_schema.columns where table_schema = (select database()) and table_name = ('{}')
-		order by ordinal_position", table_name);
-        // 为 information_schema.columns 创建自定义结构
-        #[derive(FromQueryResult, Debug)]
-        struct TableColumn {
-            column_name: Option<String>,
-            is_required: Option<String>,
-            is_pk: Option<String>,
-            sort: Option<u32>,
-            column_comment: Option<String>,
-            is_increment: Option<String>,
-            column_type: Option<String>,
-        }
-
-        let columns =
-            TableColumn::find_by_statement(Statement::from_string(DatabaseBackend::MySql, sql))
-                .all(&*self.db)
-                .await?;
+        let columns = select_table_columns(&self.db, table_name).await?;
         let columns = columns
             .into_iter()
             .map(|column| GenTableColumnModel {
@@ -135,25 +117,16 @@ impl GenTableColumnRepository for GenTableColumnRepositoryImpl {
         tx: &DatabaseTransaction,
     ) -> Result<GenTableColumnModel> {
         // 插入业务字段的实现
-        if let Some(user_context) = get_sync_user_context() {
-            gen_table_column.create_by = Set(Some(user_context.user_name.clone()));
-            gen_table_column.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        gen_table_column.create_time = Set(Some(Utc::now()));
-        gen_table_column.update_time = Set(Some(Utc::now()));
+        unset_zero_primary_key(&mut gen_table_column);
         Ok(gen_table_column.insert(tx).await?)
     }
 
     async fn update_gen_table_column(
         &self,
-        mut gen_table_column: GenTableColumnActiveModel,
+        gen_table_column: GenTableColumnActiveModel,
         tx: &DatabaseTransaction,
     ) -> Result<GenTableColumnModel> {
         // 更新业务字段的实现
-        if let Some(user_context) = get_sync_user_context() {
-            gen_table_column.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        gen_table_column.update_time = Set(Some(Utc::now()));
         Ok(gen_table_column.update(tx).await?)
     }
 
@@ -1,22 +1,23 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use ruoyi_common::{
-    utils::{self, time::deserialize_optional_datetime},
+    utils::time::deserialize_optional_datetime,
     vo::PageParam,
     Result,
 };
-use ruoyi_framework::{
-    db::repository::{BaseRepository, Repository},
-    web::tls::get_sync_user_context,
+use ruoyi_framework::db::query::QueryCondition;
+use ruoyi_framework::db::repository::{
+    unset_zero_primary_key, BaseRepository, OptimisticLock, Repository,
 };
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseBackend, DatabaseConnection,
-    DatabaseTransaction, EntityTrait, FromQueryResult, PaginatorTrait, QueryFilter, QueryOrder,
-    Set, Statement,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    DatabaseTransaction, EntityTrait, FromQueryResult, QueryFilter, Statement, Value,
 };
 use serde::Deserialize;
-use std::{str::FromStr, sync::Arc};
+use std::sync::Arc;
+use utoipa::IntoParams;
 
+use super::db_metadata::{db_table_sql, placeholder, DbTable};
 use crate::entity::prelude::*;
 
 /// 代码生成表格仓库接口
@@ -56,23 +57,27 @@ pub trait GenTableRepository: Send + Sync {
     ) -> Result<GenTableModel>;
 
     /// 更新业务表
-    async fn update_gen_table(&self, mut gen_table: GenTableActiveModel, tx: &DatabaseTransaction) -> Result<GenTableModel>;
+    async fn update_gen_table(&self, gen_table: GenTableActiveModel, tx: &DatabaseTransaction) -> Result<GenTableModel>;
 
     /// 批量删除业务表
     async fn delete_gen_table_by_ids(&self, table_ids: Vec<i64>, tx: &DatabaseTransaction) -> Result<u64>;
 }
 
 /// 代码生成表查询参数
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::GenTableColumn")]
 pub struct GenTableQuery {
+    #[query(like)]
     pub table_name: Option<String>,
+    #[query(like)]
     pub table_comment: Option<String>,
 
     /// 开始时间
     #[serde(rename = "params[beginTime]", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_optional_datetime")]
+    #[query(between = "begin_time,end_time")]
     pub begin_time: Option<DateTime<Utc>>,
 
     /// 结束时间
@@ -92,37 +97,18 @@ impl GenTableRepositoryImpl {
     /// 创建代码生成表格仓库
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
-            repository: BaseRepository::new(db.as_ref().clone()),
+            repository: BaseRepository::new(db.as_ref().clone()).with_sortable_columns([
+                GenTableColumn::TableName,
+                GenTableColumn::TableComment,
+                GenTableColumn::ClassName,
+                GenTableColumn::CreateTime,
+                GenTableColumn::UpdateTime,
+            ])
+            .with_optimistic_lock(OptimisticLock::version(GenTableColumn::Version)),
             db,
         }
     }
 
-    /// 构建查询条件
-    fn build_query_condition(&self, query: &GenTableQuery) -> Condition {
-        let mut condition = Condition::all();
-
-        if let Some(ref table_name) = query.table_name {
-            if !table_name.is_empty() {
-                condition = condition.add(GenTableColumn::TableName.contains(table_name));
-            }
-        }
-
-        if let Some(ref table_comment) = query.table_comment {
-            if !table_comment.is_empty() {
-                condition = condition.add(GenTableColumn::TableComment.contains(table_comment));
-            }
-        }
-
-        if let Some(begin_time) = query.begin_time {
-            condition = condition.add(GenTableColumn::CreateTime.gte(begin_time));
-        }
-
-        if let Some(end_time) = query.end_time {
-            condition = condition.add(GenTableColumn::CreateTime.lte(end_time));
-        }
-
-        condition
-    }
 }
 
 #[async_trait]
@@ -132,25 +118,10 @@ impl GenTableRepository for GenTableRepositoryImpl {
         page_param: &PageParam,
         query: &GenTableQuery,
     ) -> Result<(Vec<GenTableModel>, u64)> {
-        let condition = self.build_query_condition(query);
-        let mut query = self.repository.select();
-        // 过滤条件
-        query = query.filter(condition);
-        // 排序
-        if let Some(order_by_column) = &page_param.order_by_column {
-            let order_by_column = utils::string::to_snake_case(&order_by_column);
-            if page_param.is_asc == Some("ascending".to_string()) {
-                query = query.order_by_asc(GenTableColumn::from_str(&order_by_column).unwrap());
-            } else {
-                query = query.order_by_desc(GenTableColumn::from_str(&order_by_column).unwrap());
-            }
-        }
-        let paginator = query.paginate(self.db.as_ref(), page_param.page_size);
-
-        let total = paginator.num_items().await?;
-        let tables = paginator.fetch_page(page_param.page_num - 1).await?;
-
-        Ok((tables, total))
+        Ok(self
+            .repository
+            .paginate(page_param, Some(query.to_condition()))
+            .await?)
     }
 
     async fn select_gen_table_by_id(&self, table_id: i64) -> Result<Option<GenTableModel>> {
@@ -175,63 +146,29 @@ impl GenTableRepository for GenTableRepositoryImpl {
         page_param: &PageParam,
         query: &GenTableQuery,
     ) -> Result<(Vec<GenTableModel>, u64)> {
-        // 查询所有表，排除已存在的表
-        let sql = format!(
-            "SELECT table_name AS `table_name`, table_comment AS `table_comment`, 
-            create_time AS `create_time`, update_time AS `update_time` 
-            FROM information_schema.tables
-            WHERE table_schema = (SELECT DATABASE()) 
-            AND table_name NOT LIKE 'qrtz_%' AND table_name NOT LIKE 'gen_%'
-            AND table_name NOT IN (SELECT table_name FROM gen_table)
-            {}{}
-            ORDER BY create_time DESC",
-            if let Some(table_name) = &query.table_name {
-                if !table_name.is_empty() {
-                    format!(" AND table_name LIKE '%{}%'", table_name)
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            },
-            if let Some(table_comment) = &query.table_comment {
-                if !table_comment.is_empty() {
-                    format!(" AND table_comment LIKE '%{}%'", table_comment)
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            }
+        // 查询所有表，排除已存在的表，查询条件通过绑定参数传入
+        let backend = self.db.get_database_backend();
+        let mut condition = format!(
+            "FROM ({}) t
+            WHERE table_name NOT LIKE 'qrtz_%' AND table_name NOT LIKE 'gen_%'
+            AND table_name NOT IN (SELECT table_name FROM gen_table)",
+            db_table_sql(backend)
         );
-
-        // 执行分页查询
-        let count_sql = format!(
-            "SELECT COUNT(*) as count
-            FROM information_schema.tables
-            WHERE table_schema = (SELECT DATABASE()) 
-            AND table_name NOT LIKE 'qrtz_%' AND table_name NOT LIKE 'gen_%'
-            AND table_name NOT IN (SELECT table_name FROM gen_table)
-            {}{}",
-            if let Some(table_name) = &query.table_name {
-                if !table_name.is_empty() {
-                    format!(" AND table_name LIKE '%{}%'", table_name)
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
-            },
-            if let Some(table_comment) = &query.table_comment {
-                if !table_comment.is_empty() {
-                    format!(" AND table_comment LIKE '%{}%'", table_comment)
-                } else {
-                    String::new()
-                }
-            } else {
-                String::new()
+        let mut values: Vec<Value> = Vec::new();
+        let filters = [
+            ("table_name", &query.table_name),
+            ("table_comment", &query.table_comment),
+        ];
+        for (column, filter) in filters {
+            if let Some(filter) = filter.as_deref().filter(|filter| !filter.is_empty()) {
+                values.push(format!("%{}%", filter).into());
+                condition.push_str(&format!(
+                    " AND {} LIKE {}",
+                    column,
+                    placeholder(backend, values.len())
+                ));
             }
-        );
+        }
 
         // 计算总数
         #[derive(FromQueryResult)]
@@ -239,7 +176,11 @@ impl GenTableRepository for GenTableRepositoryImpl {
             count: i64,
         }
 
-        let count_stmt = Statement::from_string(DatabaseBackend::MySql, count_sql);
+        let count_stmt = Statement::from_sql_and_values(
+            backend,
+            format!("SELECT COUNT(*) AS count {}", condition),
+            values.clone(),
+        );
         let count_result = CountResult::find_by_statement(count_stmt)
             .one(self.db.as_ref())
             .await?;
@@ -248,21 +189,12 @@ impl GenTableRepository for GenTableRepositoryImpl {
 
         // 查询分页数据
         let page_sql = format!(
-            "{} LIMIT {} OFFSET {}",
-            sql,
-            page_param.page_size,
-            (page_param.page_num - 1) * page_param.page_size
+            "SELECT * {} ORDER BY create_time DESC, table_name LIMIT {} OFFSET {}",
+            condition,
+            page_param.limit(),
+            page_param.page_index() * page_param.limit()
         );
-        // 为 information_schema.tables 创建自定义结构
-        #[derive(FromQueryResult, Debug)]
-        struct DbTable {
-            table_name: Option<String>,
-            table_comment: Option<String>,
-            create_time: Option<DateTime<Utc>>,
-            update_time: Option<DateTime<Utc>>,
-        }
-
-        let stmt = Statement::from_string(DatabaseBackend::MySql, page_sql);
+        let stmt = Statement::from_sql_and_values(backend, page_sql, values);
         let db_tables = DbTable::find_by_statement(stmt)
             .all(self.db.as_ref())
             .await?;
@@ -293,6 +225,7 @@ impl GenTableRepository for GenTableRepositoryImpl {
                 update_by: None,
                 update_time: db_table.update_time,
                 remark: None,
+                version: 0,
             })
             .collect();
 
@@ -300,24 +233,14 @@ impl GenTableRepository for GenTableRepositoryImpl {
     }
 
     async fn select_db_table_by_name(&self, table_name: &str) -> Result<Option<GenTableModel>> {
+        let backend = self.db.get_database_backend();
         let sql = format!(
-            "SELECT table_name as `table_name`, table_comment as `table_comment`, create_time as `create_time`, update_time as `update_time` 
-            FROM information_schema.tables
-            WHERE table_schema = (SELECT DATABASE()) 
-            AND table_name = '{}'",
-            table_name
+            "SELECT * FROM ({}) t WHERE table_name = {}",
+            db_table_sql(backend),
+            placeholder(backend, 1)
         );
 
-        // 为 information_schema.tables 创建自定义结构
-        #[derive(FromQueryResult, Debug)]
-        struct DbTable {
-            table_name: Option<String>,
-            table_comment: Option<String>,
-            create_time: Option<DateTime<Utc>>,
-            update_time: Option<DateTime<Utc>>,
-        }
-
-        let stmt = Statement::from_string(DatabaseBackend::MySql, sql);
+        let stmt = Statement::from_sql_and_values(backend, sql, [table_name.into()]);
         let db_table = DbTable::find_by_statement(stmt)
             .one(self.db.as_ref())
             .await?;
@@ -350,6 +273,7 @@ impl GenTableRepository for GenTableRepositoryImpl {
             update_by: None,
             update_time: db_table.update_time,
             remark: None,
+            version: 0,
         };
 
         Ok(Some(table))
@@ -360,21 +284,12 @@ impl GenTableRepository for GenTableRepositoryImpl {
         mut gen_table: GenTableActiveModel,
         tx: &DatabaseTransaction,
     ) -> Result<GenTableModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            gen_table.create_by = Set(Some(user_context.user_name.clone()));
-            gen_table.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        gen_table.create_time = Set(Some(Utc::now()));
-        gen_table.update_time = Set(Some(Utc::now()));
+        unset_zero_primary_key(&mut gen_table);
         Ok(gen_table.insert(tx).await?)
     }
 
-    async fn update_gen_table(&self, mut gen_table: GenTableActiveModel, tx: &DatabaseTransaction) -> Result<GenTableModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            gen_table.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        gen_table.update_time = Set(Some(Utc::now()));
-        let table = gen_table.update(tx).await?;
+    async fn update_gen_table(&self, gen_table: GenTableActiveModel, tx: &DatabaseTransaction) -> Result<GenTableModel> {
+        let table = self.repository.update_with(tx, gen_table).await?;
         Ok(table)
     }
 
@@ -1,3 +1,4 @@
+mod db_metadata;
 mod gen_table_column_repository;
 mod gen_table_repository;
 
@@ -5,6 +5,7 @@ use sea_orm::{DatabaseConnection, TransactionTrait};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::{fmt::Debug, sync::Arc};
+use utoipa::ToSchema;
 
 use crate::common::utils;
 use crate::config::GenConfig;
@@ -64,7 +65,7 @@ pub trait GenTableService: Send + Sync {
 }
 
 /// 代码生成表详情（包含字段信息）
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct GenTableDetail {
     pub info: GenTableModel,
     pub rows: Vec<GenTableColumnModel>,
@@ -72,7 +73,7 @@ pub struct GenTableDetail {
 }
 
 /// 修改代码生成业务表请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateGenTableRequest {
     // 表基本信息
@@ -97,6 +98,8 @@ pub struct UpdateGenTableRequest {
     pub parent_menu_id: Option<i64>,
     pub options: Option<String>,
     pub remark: Option<String>,
+    // 乐观锁版本号
+    pub version: Option<i32>,
 
     #[serde(skip)]
     pub create_by: Option<String>,
@@ -110,7 +113,7 @@ pub struct UpdateGenTableRequest {
 }
 
 /// 模板数据
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct TemplateData {
     pub template_name: String,
     pub content: String,
@@ -268,6 +271,8 @@ impl GenTableService for GenTableServiceImpl {
             update_by: NotSet,
             update_time: NotSet, // 设置为当前时间
             remark: Set(request.remark),
+            // 携带版本号时校验是否已被其他人修改
+            version: request.version.map_or(NotSet, Set),
         };
         // 如果有params，则更新params
         if let Some(params) = request.params {
@@ -3,9 +3,13 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::{
-    parse_macro_input, AttributeArgs, FnArg, ItemFn, Lit, Meta, MetaNameValue, NestedMeta, PatType,
+    parse_macro_input, AttributeArgs, DeriveInput, FnArg, ItemFn, Lit, Meta, MetaNameValue,
+    NestedMeta, PatType,
 };
 
+mod query_condition;
+mod transactional;
+
 /// 操作日志宏，用于标记控制器方法
 ///
 /// # 示例
@@ -87,7 +91,8 @@ pub fn oper_log(args: TokenStream, input: TokenStream) -> TokenStream {
             use actix_web::HttpRequest;
             use chrono::Utc;
             use ruoyi_framework::logger::entity::OperLogModel;
-            use ruoyi_framework::web::tls::get_sync_user_context;
+            use ruoyi_framework::web::context::current_user;
+            use ruoyi_framework::tenant::current_tenant_id_or_default;
 
             // 记录操作日志开始
             let start_time = std::time::Instant::now();
@@ -111,6 +116,7 @@ pub fn oper_log(args: TokenStream, input: TokenStream) -> TokenStream {
                 error_msg: None,
                 oper_time: Some(Utc::now()),
                 cost_time: None,
+                tenant_id: current_tenant_id_or_default(),
             };
 
             // 从请求中获取更多信息填充日志
@@ -132,7 +138,7 @@ pub fn oper_log(args: TokenStream, input: TokenStream) -> TokenStream {
                 oper_log.oper_param = http::get_request_params(req);
 
                 // 获取用户信息(如果有的话)
-                if let Some(user_context) = get_sync_user_context() {
+                if let Some(user_context) = current_user() {
                     oper_log.oper_name = Some(user_context.user_name.clone());
                 }
             }
@@ -174,4 +180,74 @@ fn find_http_request_param(
     quote! {
         let __http_request: Option<&HttpRequest> = None;
     }
-}
\ No newline at end of file
+}
+
+/// 查询条件派生宏，根据查询参数结构体生成 sea-orm 的 `Condition`
+///
+/// 结构体上用 `#[query(columns = "...")]` 指定实体的列枚举，字段上的属性：
+/// - `#[query(eq)]`：等于
+/// - `#[query(like)]`：模糊匹配
+/// - `#[query(in)]`：包含于列表，字段类型为 `Vec<T>`
+/// - `#[query(blind_index)]`：加密字段按盲索引精确匹配，默认列为字段名加 `_index` 后缀
+/// - `#[query(between = "begin_time,end_time")]`：范围（包含边界），写在起始字段上，默认列为 create_time
+/// - `#[query(column = "...")]`：对应的列名，默认与字段名相同
+///
+/// `Option` 字段为 `None`、字符串为空白、列表为空时不添加条件，没有 `#[query]` 属性的字段不参与查询
+///
+/// # 示例
+///
+/// ```ignore
+/// use ruoyi_framework::db::query::QueryCondition;
+///
+/// #[derive(Deserialize, QueryCondition)]
+/// #[query(columns = "crate::entity::prelude::ConfigColumn")]
+/// pub struct ConfigQuery {
+///     #[query(like)]
+///     pub config_name: Option<String>,
+///     #[query(eq)]
+///     pub config_type: Option<String>,
+///     #[query(between = "begin_time,end_time")]
+///     pub begin_time: Option<DateTime<Utc>>,
+///     pub end_time: Option<DateTime<Utc>>,
+/// }
+///
+/// let condition = query.to_condition();
+/// ```
+#[proc_macro_derive(QueryCondition, attributes(query))]
+pub fn derive_query_condition(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    query_condition::expand(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// 事务宏，在环境事务中执行方法，返回 `Ok` 时提交，返回 `Err` 时回滚
+///
+/// 参数为数据库连接表达式，默认为 `self.db`；方法中通过仓库执行的操作都会加入该事务，
+/// 已处于事务中时创建保存点。可以和 `#[async_trait]` 一起使用
+///
+/// # 示例
+///
+/// ```ignore
+/// use ruoyi_framework::db::transaction::transactional;
+///
+/// #[async_trait]
+/// impl UserService for UserServiceImpl {
+///     #[transactional]
+///     async fn create_user(&self, req: CreateOrUpdateUserRequest) -> Result<UserModel> {
+///         let user = self.user_repository.create_user(user).await?;
+///         self.role_repository.set_user_roles(user.user_id, role_ids).await?;
+///         Ok(user)
+///     }
+///
+///     #[transactional(self.db_manager.get_connection())]
+///     async fn delete_user(&self, user_id: i64) -> Result<()> { ... }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn transactional(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as ItemFn);
+    transactional::expand(attr.into(), item)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
@@ -0,0 +1,262 @@
+// ruoyi-macros/src/query_condition.rs
+//! 查询条件派生宏的实现
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Data, DeriveInput, Error, Fields, GenericArgument, Ident, Lit, Meta, NestedMeta, Path,
+    PathArguments, Result, Type,
+};
+
+/// 字段的查询方式
+enum Operator {
+    /// 等于
+    Eq,
+    /// 模糊匹配
+    Like,
+    /// 包含于列表
+    In,
+    /// 加密字段按盲索引列等值匹配
+    BlindIndex,
+    /// 范围，记录起止字段
+    Between(Ident, Ident),
+}
+
+/// 字段的查询配置
+struct QueryField {
+    /// 查询方式
+    operator: Operator,
+    /// 对应的列名
+    column: String,
+    /// 字段名
+    ident: Ident,
+}
+
+/// 展开 `#[derive(QueryCondition)]`
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let columns = parse_columns(&input)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(name, "QueryCondition 只能用于结构体"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            name,
+            "QueryCondition 只能用于具名字段的结构体",
+        ));
+    };
+
+    let mut filters = Vec::new();
+    for field in &fields.named {
+        let Some(query) = parse_field(field)? else {
+            continue;
+        };
+        let column = format_ident!("{}", to_upper_camel(&query.column));
+        let column = quote! { #columns::#column };
+        let filter = match &query.operator {
+            Operator::Eq => bind(fields, &query.ident, quote! { #column.eq(value.clone()) })?,
+            Operator::Like => bind(
+                fields,
+                &query.ident,
+                quote! { #column.contains(value.clone()) },
+            )?,
+            Operator::In => bind(
+                fields,
+                &query.ident,
+                quote! { #column.is_in(value.clone()) },
+            )?,
+            Operator::BlindIndex => bind(
+                fields,
+                &query.ident,
+                quote! { #column.eq(::ruoyi_framework::db::encryption::blind_index(value)) },
+            )?,
+            Operator::Between(begin, end) => {
+                let begin = bind(fields, begin, quote! { #column.gte(value.clone()) })?;
+                let end = bind(fields, end, quote! { #column.lte(value.clone()) })?;
+                quote! { #begin #end }
+            }
+        };
+        filters.push(filter);
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::ruoyi_framework::db::query::QueryCondition for #name #ty_generics #where_clause {
+            fn to_condition(&self) -> ::ruoyi_framework::db::query::Condition {
+                #[allow(unused_imports)]
+                use ::ruoyi_framework::db::query::{ColumnTrait as _, QueryValue as _};
+                #[allow(unused_mut)]
+                let mut condition = ::ruoyi_framework::db::query::Condition::all();
+                #(#filters)*
+                condition
+            }
+        }
+    })
+}
+
+/// 解析结构体上的 `#[query(columns = "...")]`
+fn parse_columns(input: &DeriveInput) -> Result<Path> {
+    let mut columns = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("query"))
+    {
+        for nested in nested_metas(attr)? {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("columns") => {
+                    columns = Some(match &nv.lit {
+                        Lit::Str(lit) => lit.parse()?,
+                        lit => return Err(Error::new_spanned(lit, "columns 必须是字符串")),
+                    });
+                }
+                other => return Err(Error::new_spanned(other, "未知的查询配置，可用: columns")),
+            }
+        }
+    }
+    columns
+        .ok_or_else(|| Error::new_spanned(&input.ident, "缺少 #[query(columns = \"实体列枚举\")]"))
+}
+
+/// 解析字段上的 `#[query(...)]`，没有该属性的字段不参与查询
+fn parse_field(field: &syn::Field) -> Result<Option<QueryField>> {
+    let ident = field.ident.clone().expect("具名字段");
+    let mut operator = None;
+    let mut column = None;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("query"))
+    {
+        for nested in nested_metas(attr)? {
+            match &nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("eq") => {
+                    operator = Some(Operator::Eq)
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("like") => {
+                    operator = Some(Operator::Like)
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("in") => {
+                    operator = Some(Operator::In)
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("blind_index") => {
+                    operator = Some(Operator::BlindIndex)
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("between") => {
+                    let value = lit_str(&nv.lit)?;
+                    let bounds: Vec<&str> = value.split(',').map(str::trim).collect();
+                    let [begin, end] = bounds[..] else {
+                        return Err(Error::new_spanned(
+                            &nv.lit,
+                            "between 格式为 \"起始字段,结束字段\"",
+                        ));
+                    };
+                    operator = Some(Operator::Between(
+                        format_ident!("{}", begin),
+                        format_ident!("{}", end),
+                    ));
+                    // 范围查询默认按创建时间
+                    column.get_or_insert_with(|| "create_time".to_string());
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("column") => {
+                    column = Some(lit_str(&nv.lit)?);
+                }
+                other => {
+                    return Err(Error::new_spanned(
+                        other,
+                        "未知的查询方式，可用: eq、like、in、between、blind_index、column",
+                    ))
+                }
+            }
+        }
+    }
+
+    match operator {
+        Some(operator) => {
+            // 盲索引列默认为字段名加 _index 后缀
+            let column = column.unwrap_or_else(|| match operator {
+                Operator::BlindIndex => format!("{}_index", ident),
+                _ => ident.to_string(),
+            });
+            Ok(Some(QueryField {
+                operator,
+                column,
+                ident,
+            }))
+        }
+        None if column.is_some() => Err(Error::new_spanned(
+            &ident,
+            "指定 column 时还需要指定查询方式: eq、like、in、between、blind_index",
+        )),
+        None => Ok(None),
+    }
+}
+
+/// 生成取字段值并添加条件的代码，Option 字段为 None 或值为空时跳过
+fn bind(fields: &syn::FieldsNamed, ident: &Ident, expr: TokenStream) -> Result<TokenStream> {
+    let field = fields
+        .named
+        .iter()
+        .find(|field| field.ident.as_ref() == Some(ident))
+        .ok_or_else(|| Error::new_spanned(ident, format!("字段不存在: {}", ident)))?;
+
+    let value = if is_option(&field.ty) {
+        quote! { self.#ident.as_ref() }
+    } else {
+        quote! { Some(&self.#ident) }
+    };
+    Ok(quote! {
+        if let Some(value) = #value {
+            if !value.is_blank() {
+                condition = condition.add(#expr);
+            }
+        }
+    })
+}
+
+/// 属性参数列表
+fn nested_metas(attr: &syn::Attribute) -> Result<Vec<NestedMeta>> {
+    match attr.parse_meta()? {
+        Meta::List(list) => Ok(list.nested.into_iter().collect()),
+        meta => Err(Error::new_spanned(meta, "格式应为 #[query(...)]")),
+    }
+}
+
+fn lit_str(lit: &Lit) -> Result<String> {
+    match lit {
+        Lit::Str(lit) => Ok(lit.value()),
+        lit => Err(Error::new_spanned(lit, "必须是字符串")),
+    }
+}
+
+/// 是否为 `Option<T>`
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path.path.segments.last().is_some_and(|segment| {
+        segment.ident == "Option"
+            && matches!(
+                &segment.arguments,
+                PathArguments::AngleBracketed(args)
+                    if matches!(args.args.first(), Some(GenericArgument::Type(_)))
+            )
+    })
+}
+
+/// 列名转为 sea-orm 实体列枚举的变体名，如 create_time 转为 CreateTime
+fn to_upper_camel(column: &str) -> String {
+    column
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,47 @@
+// ruoyi-macros/src/transactional.rs
+//! 事务属性宏的实现
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Expr, ItemFn, Result, ReturnType};
+
+/// 展开 `#[transactional]`，参数为数据库连接表达式，默认为 `self.db`
+pub fn expand(attr: TokenStream, mut item: ItemFn) -> Result<TokenStream> {
+    let db: Expr = if attr.is_empty() {
+        syn::parse_quote!(self.db)
+    } else {
+        syn::parse2(attr)?
+    };
+    let block = &item.block;
+
+    let body = if item.sig.asyncness.is_some() {
+        let output = match &item.sig.output {
+            ReturnType::Type(_, ty) => ty,
+            ReturnType::Default => {
+                return Err(syn::Error::new_spanned(
+                    &item.sig,
+                    "#[transactional] 的方法需要返回 Result",
+                ))
+            }
+        };
+        quote! {{
+            ::ruoyi_framework::db::transaction::transactional(&#db, move || async move {
+                let __result: #output = async move #block.await;
+                __result
+            })
+            .await
+        }}
+    } else {
+        // 经过 #[async_trait] 展开后的方法，函数体返回装箱的 Future
+        quote! {{
+            let __db = &#db;
+            let __future = #block;
+            ::std::boxed::Box::pin(::ruoyi_framework::db::transaction::transactional(
+                __db,
+                move || __future,
+            ))
+        }}
+    };
+    item.block = syn::parse2(body)?;
+    Ok(quote!(#item))
+}
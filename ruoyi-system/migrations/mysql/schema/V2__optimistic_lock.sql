@@ -0,0 +1,5 @@
+-- 乐观锁版本号
+
+alter table sys_role   add column version int(11) not null default 0 comment '乐观锁版本号';
+alter table sys_menu   add column version int(11) not null default 0 comment '乐观锁版本号';
+alter table sys_config add column version int(11) not null default 0 comment '乐观锁版本号';
@@ -0,0 +1,61 @@
+-- 多租户
+
+-- ----------------------------
+-- 租户套餐表
+-- ----------------------------
+create table sys_tenant_package (
+  package_id           bigint(20)      not null auto_increment    comment '套餐ID',
+  package_name         varchar(50)     not null                   comment '套餐名称',
+  menu_check_strictly  tinyint(1)      default 1                  comment '菜单树选择项是否关联显示',
+  status               char(1)         default '0'                comment '状态（0正常 1停用）',
+  del_flag             char(1)         default '0'                comment '删除标志（0代表存在 2代表删除）',
+  create_by            varchar(64)     default ''                 comment '创建者',
+  create_time          datetime                                   comment '创建时间',
+  update_by            varchar(64)     default ''                 comment '更新者',
+  update_time          datetime                                   comment '更新时间',
+  remark               varchar(500)    default null               comment '备注',
+  primary key (package_id)
+) engine=innodb auto_increment=100 comment = '租户套餐表';
+
+-- ----------------------------
+-- 租户套餐和菜单关联表
+-- ----------------------------
+create table sys_tenant_package_menu (
+  package_id   bigint(20) not null comment '套餐ID',
+  menu_id      bigint(20) not null comment '菜单ID',
+  primary key(package_id, menu_id)
+) engine=innodb comment = '租户套餐和菜单关联表';
+
+-- ----------------------------
+-- 租户表
+-- ----------------------------
+create table sys_tenant (
+  tenant_id            bigint(20)      not null auto_increment    comment '租户ID',
+  tenant_name          varchar(50)     not null                   comment '租户名称',
+  contact_user_name    varchar(30)     default ''                 comment '联系人',
+  contact_phone        varchar(20)     default ''                 comment '联系电话',
+  package_id           bigint(20)      default null               comment '租户套餐ID',
+  expire_time          datetime                                   comment '过期时间',
+  status               char(1)         default '0'                comment '状态（0正常 1停用）',
+  del_flag             char(1)         default '0'                comment '删除标志（0代表存在 2代表删除）',
+  create_by            varchar(64)     default ''                 comment '创建者',
+  create_time          datetime                                   comment '创建时间',
+  update_by            varchar(64)     default ''                 comment '更新者',
+  update_time          datetime                                   comment '更新时间',
+  remark               varchar(500)    default null               comment '备注',
+  primary key (tenant_id)
+) engine=innodb auto_increment=100 comment = '租户表';
+
+insert into sys_tenant (tenant_id, tenant_name, contact_user_name, status, del_flag, create_by, create_time, remark)
+values (1, '默认租户', 'admin', '0', '0', 'admin', sysdate(), '平台默认租户');
+
+-- ----------------------------
+-- 租户隔离字段
+-- ----------------------------
+alter table sys_user       add column tenant_id bigint(20) not null default 1 comment '租户ID';
+alter table sys_dept       add column tenant_id bigint(20) not null default 1 comment '租户ID';
+alter table sys_role       add column tenant_id bigint(20) not null default 1 comment '租户ID';
+alter table sys_post       add column tenant_id bigint(20) not null default 1 comment '租户ID';
+alter table sys_notice     add column tenant_id bigint(20) not null default 1 comment '租户ID';
+alter table sys_oper_log   add column tenant_id bigint(20) not null default 1 comment '租户ID';
+alter table sys_login_info add column tenant_id bigint(20) not null default 1 comment '租户ID';
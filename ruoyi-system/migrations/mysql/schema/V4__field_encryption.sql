@@ -0,0 +1,9 @@
+-- 敏感字段加密：手机号码和邮箱以密文存储，等值查询使用盲索引
+
+alter table sys_user modify column email       varchar(255) default '' comment '用户邮箱（加密存储）';
+alter table sys_user modify column phonenumber varchar(255) default '' comment '手机号码（加密存储）';
+alter table sys_user add column email_index       varchar(64) default null comment '用户邮箱盲索引';
+alter table sys_user add column phonenumber_index varchar(64) default null comment '手机号码盲索引';
+
+create index idx_sys_user_email_index on sys_user (email_index);
+create index idx_sys_user_phonenumber_index on sys_user (phonenumber_index);
@@ -0,0 +1,15 @@
+-- 实体变更历史：记录修改和删除前后的字段差异
+
+create table sys_change_log (
+  change_id         bigint(20)      not null auto_increment    comment '变更主键',
+  tenant_id         bigint(20)      not null default 1         comment '租户ID',
+  entity_name       varchar(64)     not null                   comment '实体名称（表名）',
+  entity_id         varchar(64)     not null                   comment '实体主键',
+  change_type       varchar(16)     not null                   comment '变更类型（update修改 delete删除 restore恢复 purge彻底删除）',
+  changes           text                                       comment '字段差异（JSON）',
+  oper_name         varchar(64)     default ''                 comment '操作人员',
+  change_time       datetime                                   comment '变更时间',
+  primary key (change_id),
+  key idx_sys_change_log_entity (entity_name, entity_id),
+  key idx_sys_change_log_ct (change_time)
+) engine=innodb auto_increment=100 comment = '实体变更历史';
@@ -0,0 +1,493 @@
+-- 系统模块表结构
+
+-- ----------------------------
+-- 部门表
+-- ----------------------------
+create table sys_dept (
+  dept_id              bigint generated by default as identity (start with 200),
+  parent_id            bigint default 0,
+  ancestors            varchar(50) default '',
+  dept_name            varchar(30) default '',
+  order_num            integer default 0,
+  leader               varchar(20),
+  phone                varchar(11),
+  email                varchar(50),
+  status               char(1) default '0',
+  del_flag             char(1) default '0',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  primary key (dept_id)
+);
+comment on table sys_dept is '部门表';
+comment on column sys_dept.dept_id is '部门id';
+comment on column sys_dept.parent_id is '父部门id';
+comment on column sys_dept.ancestors is '祖级列表';
+comment on column sys_dept.dept_name is '部门名称';
+comment on column sys_dept.order_num is '显示顺序';
+comment on column sys_dept.leader is '负责人';
+comment on column sys_dept.phone is '联系电话';
+comment on column sys_dept.email is '邮箱';
+comment on column sys_dept.status is '部门状态（0正常 1停用）';
+comment on column sys_dept.del_flag is '删除标志（0代表存在 2代表删除）';
+comment on column sys_dept.create_by is '创建者';
+comment on column sys_dept.create_time is '创建时间';
+comment on column sys_dept.update_by is '更新者';
+comment on column sys_dept.update_time is '更新时间';
+
+-- ----------------------------
+-- 用户信息表
+-- ----------------------------
+create table sys_user (
+  user_id              bigint generated by default as identity (start with 100),
+  dept_id              bigint,
+  user_name            varchar(30) not null,
+  nick_name            varchar(30) not null,
+  user_type            varchar(2) default '00',
+  email                varchar(50) default '',
+  phonenumber          varchar(11) default '',
+  sex                  char(1) default '0',
+  avatar               varchar(100) default '',
+  password             varchar(100) default '',
+  status               char(1) default '0',
+  del_flag             char(1) default '0',
+  login_ip             varchar(128) default '',
+  login_date           timestamptz,
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500),
+  primary key (user_id)
+);
+comment on table sys_user is '用户信息表';
+comment on column sys_user.user_id is '用户ID';
+comment on column sys_user.dept_id is '部门ID';
+comment on column sys_user.user_name is '用户账号';
+comment on column sys_user.nick_name is '用户昵称';
+comment on column sys_user.user_type is '用户类型（00系统用户）';
+comment on column sys_user.email is '用户邮箱';
+comment on column sys_user.phonenumber is '手机号码';
+comment on column sys_user.sex is '用户性别（0男 1女 2未知）';
+comment on column sys_user.avatar is '头像地址';
+comment on column sys_user.password is '密码';
+comment on column sys_user.status is '账号状态（0正常 1停用）';
+comment on column sys_user.del_flag is '删除标志（0代表存在 2代表删除）';
+comment on column sys_user.login_ip is '最后登录IP';
+comment on column sys_user.login_date is '最后登录时间';
+comment on column sys_user.create_by is '创建者';
+comment on column sys_user.create_time is '创建时间';
+comment on column sys_user.update_by is '更新者';
+comment on column sys_user.update_time is '更新时间';
+comment on column sys_user.remark is '备注';
+
+-- ----------------------------
+-- 岗位信息表
+-- ----------------------------
+create table sys_post (
+  post_id              bigint generated by default as identity,
+  post_code            varchar(64) not null,
+  post_name            varchar(50) not null,
+  post_sort            integer not null,
+  status               char(1) not null,
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500),
+  primary key (post_id)
+);
+comment on table sys_post is '岗位信息表';
+comment on column sys_post.post_id is '岗位ID';
+comment on column sys_post.post_code is '岗位编码';
+comment on column sys_post.post_name is '岗位名称';
+comment on column sys_post.post_sort is '显示顺序';
+comment on column sys_post.status is '状态（0正常 1停用）';
+comment on column sys_post.create_by is '创建者';
+comment on column sys_post.create_time is '创建时间';
+comment on column sys_post.update_by is '更新者';
+comment on column sys_post.update_time is '更新时间';
+comment on column sys_post.remark is '备注';
+
+-- ----------------------------
+-- 角色信息表
+-- ----------------------------
+create table sys_role (
+  role_id              bigint generated by default as identity (start with 100),
+  role_name            varchar(30) not null,
+  role_key             varchar(100) not null,
+  role_sort            integer not null,
+  data_scope           char(1) default '1',
+  menu_check_strictly  boolean default true,
+  dept_check_strictly  boolean default true,
+  status               char(1) not null,
+  del_flag             char(1) default '0',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500),
+  primary key (role_id)
+);
+comment on table sys_role is '角色信息表';
+comment on column sys_role.role_id is '角色ID';
+comment on column sys_role.role_name is '角色名称';
+comment on column sys_role.role_key is '角色权限字符串';
+comment on column sys_role.role_sort is '显示顺序';
+comment on column sys_role.data_scope is '数据范围（1：全部数据权限 2：自定数据权限 3：本部门数据权限 4：本部门及以下数据权限）';
+comment on column sys_role.menu_check_strictly is '菜单树选择项是否关联显示';
+comment on column sys_role.dept_check_strictly is '部门树选择项是否关联显示';
+comment on column sys_role.status is '角色状态（0正常 1停用）';
+comment on column sys_role.del_flag is '删除标志（0代表存在 2代表删除）';
+comment on column sys_role.create_by is '创建者';
+comment on column sys_role.create_time is '创建时间';
+comment on column sys_role.update_by is '更新者';
+comment on column sys_role.update_time is '更新时间';
+comment on column sys_role.remark is '备注';
+
+-- ----------------------------
+-- 菜单权限表
+-- ----------------------------
+create table sys_menu (
+  menu_id              bigint generated by default as identity (start with 2000),
+  menu_name            varchar(50) not null,
+  parent_id            bigint default 0,
+  order_num            integer default 0,
+  path                 varchar(200) default '',
+  component            varchar(255),
+  query                varchar(255),
+  route_name           varchar(50) default '',
+  is_frame             integer default 1,
+  is_cache             integer default 0,
+  menu_type            char(1) default '',
+  visible              char(1) default 0,
+  status               char(1) default 0,
+  perms                varchar(100),
+  icon                 varchar(100) default '#',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500) default '',
+  primary key (menu_id)
+);
+comment on table sys_menu is '菜单权限表';
+comment on column sys_menu.menu_id is '菜单ID';
+comment on column sys_menu.menu_name is '菜单名称';
+comment on column sys_menu.parent_id is '父菜单ID';
+comment on column sys_menu.order_num is '显示顺序';
+comment on column sys_menu.path is '路由地址';
+comment on column sys_menu.component is '组件路径';
+comment on column sys_menu.query is '路由参数';
+comment on column sys_menu.route_name is '路由名称';
+comment on column sys_menu.is_frame is '是否为外链（0是 1否）';
+comment on column sys_menu.is_cache is '是否缓存（0缓存 1不缓存）';
+comment on column sys_menu.menu_type is '菜单类型（M目录 C菜单 F按钮）';
+comment on column sys_menu.visible is '菜单状态（0显示 1隐藏）';
+comment on column sys_menu.status is '菜单状态（0正常 1停用）';
+comment on column sys_menu.perms is '权限标识';
+comment on column sys_menu.icon is '菜单图标';
+comment on column sys_menu.create_by is '创建者';
+comment on column sys_menu.create_time is '创建时间';
+comment on column sys_menu.update_by is '更新者';
+comment on column sys_menu.update_time is '更新时间';
+comment on column sys_menu.remark is '备注';
+
+-- ----------------------------
+-- 用户和角色关联表
+-- ----------------------------
+create table sys_user_role (
+  user_id              bigint not null,
+  role_id              bigint not null,
+  primary key (user_id, role_id)
+);
+comment on table sys_user_role is '用户和角色关联表';
+comment on column sys_user_role.user_id is '用户ID';
+comment on column sys_user_role.role_id is '角色ID';
+
+-- ----------------------------
+-- 角色和菜单关联表
+-- ----------------------------
+create table sys_role_menu (
+  role_id              bigint not null,
+  menu_id              bigint not null,
+  primary key (role_id, menu_id)
+);
+comment on table sys_role_menu is '角色和菜单关联表';
+comment on column sys_role_menu.role_id is '角色ID';
+comment on column sys_role_menu.menu_id is '菜单ID';
+
+-- ----------------------------
+-- 角色和部门关联表
+-- ----------------------------
+create table sys_role_dept (
+  role_id              bigint not null,
+  dept_id              bigint not null,
+  primary key (role_id, dept_id)
+);
+comment on table sys_role_dept is '角色和部门关联表';
+comment on column sys_role_dept.role_id is '角色ID';
+comment on column sys_role_dept.dept_id is '部门ID';
+
+-- ----------------------------
+-- 用户与岗位关联表
+-- ----------------------------
+create table sys_user_post (
+  user_id              bigint not null,
+  post_id              bigint not null,
+  primary key (user_id, post_id)
+);
+comment on table sys_user_post is '用户与岗位关联表';
+comment on column sys_user_post.user_id is '用户ID';
+comment on column sys_user_post.post_id is '岗位ID';
+
+-- ----------------------------
+-- 操作日志记录
+-- ----------------------------
+create table sys_oper_log (
+  oper_id              bigint generated by default as identity (start with 100),
+  title                varchar(50) default '',
+  business_type        integer default 0,
+  method               varchar(200) default '',
+  request_method       varchar(10) default '',
+  operator_type        integer default 0,
+  oper_name            varchar(50) default '',
+  dept_name            varchar(50) default '',
+  oper_url             varchar(255) default '',
+  oper_ip              varchar(128) default '',
+  oper_location        varchar(255) default '',
+  oper_param           varchar(2000) default '',
+  json_result          varchar(2000) default '',
+  status               integer default 0,
+  error_msg            varchar(2000) default '',
+  oper_time            timestamptz,
+  cost_time            bigint default 0,
+  primary key (oper_id)
+);
+create index idx_sys_oper_log_bt on sys_oper_log (business_type);
+create index idx_sys_oper_log_s on sys_oper_log (status);
+create index idx_sys_oper_log_ot on sys_oper_log (oper_time);
+comment on table sys_oper_log is '操作日志记录';
+comment on column sys_oper_log.oper_id is '日志主键';
+comment on column sys_oper_log.title is '模块标题';
+comment on column sys_oper_log.business_type is '业务类型（0其它 1新增 2修改 3删除）';
+comment on column sys_oper_log.method is '方法名称';
+comment on column sys_oper_log.request_method is '请求方式';
+comment on column sys_oper_log.operator_type is '操作类别（0其它 1后台用户 2手机端用户）';
+comment on column sys_oper_log.oper_name is '操作人员';
+comment on column sys_oper_log.dept_name is '部门名称';
+comment on column sys_oper_log.oper_url is '请求URL';
+comment on column sys_oper_log.oper_ip is '主机地址';
+comment on column sys_oper_log.oper_location is '操作地点';
+comment on column sys_oper_log.oper_param is '请求参数';
+comment on column sys_oper_log.json_result is '返回参数';
+comment on column sys_oper_log.status is '操作状态（0正常 1异常）';
+comment on column sys_oper_log.error_msg is '错误消息';
+comment on column sys_oper_log.oper_time is '操作时间';
+comment on column sys_oper_log.cost_time is '消耗时间';
+
+-- ----------------------------
+-- 字典类型表
+-- ----------------------------
+create table sys_dict_type (
+  dict_id              bigint generated by default as identity (start with 100),
+  dict_name            varchar(100) default '',
+  dict_type            varchar(100) default '',
+  status               char(1) default '0',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500),
+  primary key (dict_id),
+  unique (dict_type)
+);
+comment on table sys_dict_type is '字典类型表';
+comment on column sys_dict_type.dict_id is '字典主键';
+comment on column sys_dict_type.dict_name is '字典名称';
+comment on column sys_dict_type.dict_type is '字典类型';
+comment on column sys_dict_type.status is '状态（0正常 1停用）';
+comment on column sys_dict_type.create_by is '创建者';
+comment on column sys_dict_type.create_time is '创建时间';
+comment on column sys_dict_type.update_by is '更新者';
+comment on column sys_dict_type.update_time is '更新时间';
+comment on column sys_dict_type.remark is '备注';
+
+-- ----------------------------
+-- 字典数据表
+-- ----------------------------
+create table sys_dict_data (
+  dict_code            bigint generated by default as identity (start with 100),
+  dict_sort            integer default 0,
+  dict_label           varchar(100) default '',
+  dict_value           varchar(100) default '',
+  dict_type            varchar(100) default '',
+  css_class            varchar(100),
+  list_class           varchar(100),
+  is_default           char(1) default 'N',
+  status               char(1) default '0',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500),
+  primary key (dict_code)
+);
+comment on table sys_dict_data is '字典数据表';
+comment on column sys_dict_data.dict_code is '字典编码';
+comment on column sys_dict_data.dict_sort is '字典排序';
+comment on column sys_dict_data.dict_label is '字典标签';
+comment on column sys_dict_data.dict_value is '字典键值';
+comment on column sys_dict_data.dict_type is '字典类型';
+comment on column sys_dict_data.css_class is '样式属性（其他样式扩展）';
+comment on column sys_dict_data.list_class is '表格回显样式';
+comment on column sys_dict_data.is_default is '是否默认（Y是 N否）';
+comment on column sys_dict_data.status is '状态（0正常 1停用）';
+comment on column sys_dict_data.create_by is '创建者';
+comment on column sys_dict_data.create_time is '创建时间';
+comment on column sys_dict_data.update_by is '更新者';
+comment on column sys_dict_data.update_time is '更新时间';
+comment on column sys_dict_data.remark is '备注';
+
+-- ----------------------------
+-- 参数配置表
+-- ----------------------------
+create table sys_config (
+  config_id            integer generated by default as identity (start with 100),
+  config_name          varchar(100) default '',
+  config_key           varchar(100) default '',
+  config_value         varchar(500) default '',
+  config_type          char(1) default 'N',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500),
+  primary key (config_id)
+);
+comment on table sys_config is '参数配置表';
+comment on column sys_config.config_id is '参数主键';
+comment on column sys_config.config_name is '参数名称';
+comment on column sys_config.config_key is '参数键名';
+comment on column sys_config.config_value is '参数键值';
+comment on column sys_config.config_type is '系统内置（Y是 N否）';
+comment on column sys_config.create_by is '创建者';
+comment on column sys_config.create_time is '创建时间';
+comment on column sys_config.update_by is '更新者';
+comment on column sys_config.update_time is '更新时间';
+comment on column sys_config.remark is '备注';
+
+-- ----------------------------
+-- 系统访问记录
+-- ----------------------------
+create table sys_login_info (
+  info_id              bigint generated by default as identity (start with 100),
+  user_name            varchar(50) default '',
+  ipaddr               varchar(128) default '',
+  login_location       varchar(255) default '',
+  browser              varchar(50) default '',
+  os                   varchar(50) default '',
+  status               char(1) default '0',
+  msg                  varchar(255) default '',
+  login_time           timestamptz,
+  primary key (info_id)
+);
+create index idx_sys_logininfor_s on sys_login_info (status);
+create index idx_sys_logininfor_lt on sys_login_info (login_time);
+comment on table sys_login_info is '系统访问记录';
+comment on column sys_login_info.info_id is '访问ID';
+comment on column sys_login_info.user_name is '用户账号';
+comment on column sys_login_info.ipaddr is '登录IP地址';
+comment on column sys_login_info.login_location is '登录地点';
+comment on column sys_login_info.browser is '浏览器类型';
+comment on column sys_login_info.os is '操作系统';
+comment on column sys_login_info.status is '登录状态（0成功 1失败）';
+comment on column sys_login_info.msg is '提示消息';
+comment on column sys_login_info.login_time is '访问时间';
+
+-- ----------------------------
+-- 定时任务调度表
+-- ----------------------------
+create table sys_job (
+  job_id               bigint generated by default as identity (start with 100),
+  job_name             varchar(64) default '',
+  job_group            varchar(64) default 'DEFAULT',
+  invoke_target        varchar(500) not null,
+  cron_expression      varchar(255) default '',
+  misfire_policy       varchar(20) default '3',
+  concurrent           char(1) default '1',
+  status               char(1) default '0',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500) default '',
+  primary key (job_id, job_name, job_group)
+);
+comment on table sys_job is '定时任务调度表';
+comment on column sys_job.job_id is '任务ID';
+comment on column sys_job.job_name is '任务名称';
+comment on column sys_job.job_group is '任务组名';
+comment on column sys_job.invoke_target is '调用目标字符串';
+comment on column sys_job.cron_expression is 'cron执行表达式';
+comment on column sys_job.misfire_policy is '计划执行错误策略（1立即执行 2执行一次 3放弃执行）';
+comment on column sys_job.concurrent is '是否并发执行（0允许 1禁止）';
+comment on column sys_job.status is '状态（0正常 1暂停）';
+comment on column sys_job.create_by is '创建者';
+comment on column sys_job.create_time is '创建时间';
+comment on column sys_job.update_by is '更新者';
+comment on column sys_job.update_time is '更新时间';
+comment on column sys_job.remark is '备注信息';
+
+-- ----------------------------
+-- 定时任务调度日志表
+-- ----------------------------
+create table sys_job_log (
+  job_log_id           bigint generated by default as identity,
+  job_name             varchar(64) not null,
+  job_group            varchar(64) not null,
+  invoke_target        varchar(500) not null,
+  job_message          varchar(500),
+  status               char(1) default '0',
+  exception_info       varchar(2000) default '',
+  create_time          timestamptz,
+  primary key (job_log_id)
+);
+comment on table sys_job_log is '定时任务调度日志表';
+comment on column sys_job_log.job_log_id is '任务日志ID';
+comment on column sys_job_log.job_name is '任务名称';
+comment on column sys_job_log.job_group is '任务组名';
+comment on column sys_job_log.invoke_target is '调用目标字符串';
+comment on column sys_job_log.job_message is '日志信息';
+comment on column sys_job_log.status is '执行状态（0正常 1失败）';
+comment on column sys_job_log.exception_info is '异常信息';
+comment on column sys_job_log.create_time is '创建时间';
+
+-- ----------------------------
+-- 通知公告表
+-- ----------------------------
+create table sys_notice (
+  notice_id            integer generated by default as identity (start with 10),
+  notice_title         varchar(50) not null,
+  notice_type          char(1) not null,
+  notice_content       bytea,
+  status               char(1) default '0',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(255),
+  primary key (notice_id)
+);
+comment on table sys_notice is '通知公告表';
+comment on column sys_notice.notice_id is '公告ID';
+comment on column sys_notice.notice_title is '公告标题';
+comment on column sys_notice.notice_type is '公告类型（1通知 2公告）';
+comment on column sys_notice.notice_content is '公告内容';
+comment on column sys_notice.status is '公告状态（0正常 1关闭）';
+comment on column sys_notice.create_by is '创建者';
+comment on column sys_notice.create_time is '创建时间';
+comment on column sys_notice.update_by is '更新者';
+comment on column sys_notice.update_time is '更新时间';
+comment on column sys_notice.remark is '备注';
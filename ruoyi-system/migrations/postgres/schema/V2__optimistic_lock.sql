@@ -0,0 +1,9 @@
+-- 乐观锁版本号
+
+alter table sys_role   add column version integer not null default 0;
+alter table sys_menu   add column version integer not null default 0;
+alter table sys_config add column version integer not null default 0;
+
+comment on column sys_role.version is '乐观锁版本号';
+comment on column sys_menu.version is '乐观锁版本号';
+comment on column sys_config.version is '乐观锁版本号';
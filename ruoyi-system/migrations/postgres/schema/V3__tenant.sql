@@ -0,0 +1,97 @@
+-- 多租户
+
+-- ----------------------------
+-- 租户套餐表
+-- ----------------------------
+create table sys_tenant_package (
+  package_id           bigint generated by default as identity (start with 100),
+  package_name         varchar(50) not null,
+  menu_check_strictly  boolean default true,
+  status               char(1) default '0',
+  del_flag             char(1) default '0',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500),
+  primary key (package_id)
+);
+comment on table sys_tenant_package is '租户套餐表';
+comment on column sys_tenant_package.package_id is '套餐ID';
+comment on column sys_tenant_package.package_name is '套餐名称';
+comment on column sys_tenant_package.menu_check_strictly is '菜单树选择项是否关联显示';
+comment on column sys_tenant_package.status is '状态（0正常 1停用）';
+comment on column sys_tenant_package.del_flag is '删除标志（0代表存在 2代表删除）';
+comment on column sys_tenant_package.create_by is '创建者';
+comment on column sys_tenant_package.create_time is '创建时间';
+comment on column sys_tenant_package.update_by is '更新者';
+comment on column sys_tenant_package.update_time is '更新时间';
+comment on column sys_tenant_package.remark is '备注';
+
+-- ----------------------------
+-- 租户套餐和菜单关联表
+-- ----------------------------
+create table sys_tenant_package_menu (
+  package_id           bigint not null,
+  menu_id              bigint not null,
+  primary key (package_id, menu_id)
+);
+comment on table sys_tenant_package_menu is '租户套餐和菜单关联表';
+comment on column sys_tenant_package_menu.package_id is '套餐ID';
+comment on column sys_tenant_package_menu.menu_id is '菜单ID';
+
+-- ----------------------------
+-- 租户表
+-- ----------------------------
+create table sys_tenant (
+  tenant_id            bigint generated by default as identity (start with 100),
+  tenant_name          varchar(50) not null,
+  contact_user_name    varchar(30) default '',
+  contact_phone        varchar(20) default '',
+  package_id           bigint,
+  expire_time          timestamptz,
+  status               char(1) default '0',
+  del_flag             char(1) default '0',
+  create_by            varchar(64) default '',
+  create_time          timestamptz,
+  update_by            varchar(64) default '',
+  update_time          timestamptz,
+  remark               varchar(500),
+  primary key (tenant_id)
+);
+comment on table sys_tenant is '租户表';
+comment on column sys_tenant.tenant_id is '租户ID';
+comment on column sys_tenant.tenant_name is '租户名称';
+comment on column sys_tenant.contact_user_name is '联系人';
+comment on column sys_tenant.contact_phone is '联系电话';
+comment on column sys_tenant.package_id is '租户套餐ID';
+comment on column sys_tenant.expire_time is '过期时间';
+comment on column sys_tenant.status is '状态（0正常 1停用）';
+comment on column sys_tenant.del_flag is '删除标志（0代表存在 2代表删除）';
+comment on column sys_tenant.create_by is '创建者';
+comment on column sys_tenant.create_time is '创建时间';
+comment on column sys_tenant.update_by is '更新者';
+comment on column sys_tenant.update_time is '更新时间';
+comment on column sys_tenant.remark is '备注';
+
+insert into sys_tenant (tenant_id, tenant_name, contact_user_name, status, del_flag, create_by, create_time, remark)
+values (1, '默认租户', 'admin', '0', '0', 'admin', now(), '平台默认租户');
+
+-- ----------------------------
+-- 租户隔离字段
+-- ----------------------------
+alter table sys_user       add column tenant_id bigint not null default 1;
+alter table sys_dept       add column tenant_id bigint not null default 1;
+alter table sys_role       add column tenant_id bigint not null default 1;
+alter table sys_post       add column tenant_id bigint not null default 1;
+alter table sys_notice     add column tenant_id bigint not null default 1;
+alter table sys_oper_log   add column tenant_id bigint not null default 1;
+alter table sys_login_info add column tenant_id bigint not null default 1;
+
+comment on column sys_user.tenant_id is '租户ID';
+comment on column sys_dept.tenant_id is '租户ID';
+comment on column sys_role.tenant_id is '租户ID';
+comment on column sys_post.tenant_id is '租户ID';
+comment on column sys_notice.tenant_id is '租户ID';
+comment on column sys_oper_log.tenant_id is '租户ID';
+comment on column sys_login_info.tenant_id is '租户ID';
@@ -0,0 +1,14 @@
+-- 敏感字段加密：手机号码和邮箱以密文存储，等值查询使用盲索引
+
+alter table sys_user alter column email       type varchar(255);
+alter table sys_user alter column phonenumber type varchar(255);
+alter table sys_user add column email_index       varchar(64) default null;
+alter table sys_user add column phonenumber_index varchar(64) default null;
+
+create index idx_sys_user_email_index on sys_user (email_index);
+create index idx_sys_user_phonenumber_index on sys_user (phonenumber_index);
+
+comment on column sys_user.email is '用户邮箱（加密存储）';
+comment on column sys_user.phonenumber is '手机号码（加密存储）';
+comment on column sys_user.email_index is '用户邮箱盲索引';
+comment on column sys_user.phonenumber_index is '手机号码盲索引';
@@ -0,0 +1,25 @@
+-- 实体变更历史：记录修改和删除前后的字段差异
+
+create table sys_change_log (
+  change_id            bigint generated by default as identity (start with 100),
+  tenant_id            bigint not null default 1,
+  entity_name          varchar(64) not null,
+  entity_id            varchar(64) not null,
+  change_type          varchar(16) not null,
+  changes              text,
+  oper_name            varchar(64) default '',
+  change_time          timestamptz,
+  primary key (change_id)
+);
+create index idx_sys_change_log_entity on sys_change_log (entity_name, entity_id);
+create index idx_sys_change_log_ct on sys_change_log (change_time);
+
+comment on table sys_change_log is '实体变更历史';
+comment on column sys_change_log.change_id is '变更主键';
+comment on column sys_change_log.tenant_id is '租户ID';
+comment on column sys_change_log.entity_name is '实体名称（表名）';
+comment on column sys_change_log.entity_id is '实体主键';
+comment on column sys_change_log.change_type is '变更类型（update修改 delete删除 restore恢复 purge彻底删除）';
+comment on column sys_change_log.changes is '字段差异（JSON）';
+comment on column sys_change_log.oper_name is '操作人员';
+comment on column sys_change_log.change_time is '变更时间';
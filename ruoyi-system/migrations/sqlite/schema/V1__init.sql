@@ -0,0 +1,300 @@
+-- 系统模块表结构
+
+-- ----------------------------
+-- 部门表
+-- ----------------------------
+create table sys_dept (  -- 部门表
+  dept_id              integer primary key autoincrement,  -- 部门id
+  parent_id            bigint default 0,  -- 父部门id
+  ancestors            varchar(50) default '',  -- 祖级列表
+  dept_name            varchar(30) default '',  -- 部门名称
+  order_num            int default 0,  -- 显示顺序
+  leader               varchar(20),  -- 负责人
+  phone                varchar(11),  -- 联系电话
+  email                varchar(50),  -- 邮箱
+  status               char(1) default '0',  -- 部门状态（0正常 1停用）
+  del_flag             char(1) default '0',  -- 删除标志（0代表存在 2代表删除）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime  -- 更新时间
+);
+insert into sqlite_sequence (name, seq) values ('sys_dept', 199);
+
+-- ----------------------------
+-- 用户信息表
+-- ----------------------------
+create table sys_user (  -- 用户信息表
+  user_id              integer primary key autoincrement,  -- 用户ID
+  dept_id              bigint,  -- 部门ID
+  user_name            varchar(30) not null,  -- 用户账号
+  nick_name            varchar(30) not null,  -- 用户昵称
+  user_type            varchar(2) default '00',  -- 用户类型（00系统用户）
+  email                varchar(50) default '',  -- 用户邮箱
+  phonenumber          varchar(11) default '',  -- 手机号码
+  sex                  char(1) default '0',  -- 用户性别（0男 1女 2未知）
+  avatar               varchar(100) default '',  -- 头像地址
+  password             varchar(100) default '',  -- 密码
+  status               char(1) default '0',  -- 账号状态（0正常 1停用）
+  del_flag             char(1) default '0',  -- 删除标志（0代表存在 2代表删除）
+  login_ip             varchar(128) default '',  -- 最后登录IP
+  login_date           datetime,  -- 最后登录时间
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500)  -- 备注
+);
+insert into sqlite_sequence (name, seq) values ('sys_user', 99);
+
+-- ----------------------------
+-- 岗位信息表
+-- ----------------------------
+create table sys_post (  -- 岗位信息表
+  post_id              integer primary key autoincrement,  -- 岗位ID
+  post_code            varchar(64) not null,  -- 岗位编码
+  post_name            varchar(50) not null,  -- 岗位名称
+  post_sort            int not null,  -- 显示顺序
+  status               char(1) not null,  -- 状态（0正常 1停用）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500)  -- 备注
+);
+
+-- ----------------------------
+-- 角色信息表
+-- ----------------------------
+create table sys_role (  -- 角色信息表
+  role_id              integer primary key autoincrement,  -- 角色ID
+  role_name            varchar(30) not null,  -- 角色名称
+  role_key             varchar(100) not null,  -- 角色权限字符串
+  role_sort            int not null,  -- 显示顺序
+  data_scope           char(1) default '1',  -- 数据范围（1：全部数据权限 2：自定数据权限 3：本部门数据权限 4：本部门及以下数据权限）
+  menu_check_strictly  tinyint(1) default 1,  -- 菜单树选择项是否关联显示
+  dept_check_strictly  tinyint(1) default 1,  -- 部门树选择项是否关联显示
+  status               char(1) not null,  -- 角色状态（0正常 1停用）
+  del_flag             char(1) default '0',  -- 删除标志（0代表存在 2代表删除）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500)  -- 备注
+);
+insert into sqlite_sequence (name, seq) values ('sys_role', 99);
+
+-- ----------------------------
+-- 菜单权限表
+-- ----------------------------
+create table sys_menu (  -- 菜单权限表
+  menu_id              integer primary key autoincrement,  -- 菜单ID
+  menu_name            varchar(50) not null,  -- 菜单名称
+  parent_id            bigint default 0,  -- 父菜单ID
+  order_num            int default 0,  -- 显示顺序
+  path                 varchar(200) default '',  -- 路由地址
+  component            varchar(255),  -- 组件路径
+  query                varchar(255),  -- 路由参数
+  route_name           varchar(50) default '',  -- 路由名称
+  is_frame             int default 1,  -- 是否为外链（0是 1否）
+  is_cache             int default 0,  -- 是否缓存（0缓存 1不缓存）
+  menu_type            char(1) default '',  -- 菜单类型（M目录 C菜单 F按钮）
+  visible              char(1) default 0,  -- 菜单状态（0显示 1隐藏）
+  status               char(1) default 0,  -- 菜单状态（0正常 1停用）
+  perms                varchar(100),  -- 权限标识
+  icon                 varchar(100) default '#',  -- 菜单图标
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500) default ''  -- 备注
+);
+insert into sqlite_sequence (name, seq) values ('sys_menu', 1999);
+
+-- ----------------------------
+-- 用户和角色关联表
+-- ----------------------------
+create table sys_user_role (  -- 用户和角色关联表
+  user_id              bigint not null,  -- 用户ID
+  role_id              bigint not null,  -- 角色ID
+  primary key (user_id, role_id)
+);
+
+-- ----------------------------
+-- 角色和菜单关联表
+-- ----------------------------
+create table sys_role_menu (  -- 角色和菜单关联表
+  role_id              bigint not null,  -- 角色ID
+  menu_id              bigint not null,  -- 菜单ID
+  primary key (role_id, menu_id)
+);
+
+-- ----------------------------
+-- 角色和部门关联表
+-- ----------------------------
+create table sys_role_dept (  -- 角色和部门关联表
+  role_id              bigint not null,  -- 角色ID
+  dept_id              bigint not null,  -- 部门ID
+  primary key (role_id, dept_id)
+);
+
+-- ----------------------------
+-- 用户与岗位关联表
+-- ----------------------------
+create table sys_user_post (  -- 用户与岗位关联表
+  user_id              bigint not null,  -- 用户ID
+  post_id              bigint not null,  -- 岗位ID
+  primary key (user_id, post_id)
+);
+
+-- ----------------------------
+-- 操作日志记录
+-- ----------------------------
+create table sys_oper_log (  -- 操作日志记录
+  oper_id              integer primary key autoincrement,  -- 日志主键
+  title                varchar(50) default '',  -- 模块标题
+  business_type        int default 0,  -- 业务类型（0其它 1新增 2修改 3删除）
+  method               varchar(200) default '',  -- 方法名称
+  request_method       varchar(10) default '',  -- 请求方式
+  operator_type        int default 0,  -- 操作类别（0其它 1后台用户 2手机端用户）
+  oper_name            varchar(50) default '',  -- 操作人员
+  dept_name            varchar(50) default '',  -- 部门名称
+  oper_url             varchar(255) default '',  -- 请求URL
+  oper_ip              varchar(128) default '',  -- 主机地址
+  oper_location        varchar(255) default '',  -- 操作地点
+  oper_param           varchar(2000) default '',  -- 请求参数
+  json_result          varchar(2000) default '',  -- 返回参数
+  status               int default 0,  -- 操作状态（0正常 1异常）
+  error_msg            varchar(2000) default '',  -- 错误消息
+  oper_time            datetime,  -- 操作时间
+  cost_time            bigint default 0  -- 消耗时间
+);
+create index idx_sys_oper_log_bt on sys_oper_log (business_type);
+create index idx_sys_oper_log_s on sys_oper_log (status);
+create index idx_sys_oper_log_ot on sys_oper_log (oper_time);
+insert into sqlite_sequence (name, seq) values ('sys_oper_log', 99);
+
+-- ----------------------------
+-- 字典类型表
+-- ----------------------------
+create table sys_dict_type (  -- 字典类型表
+  dict_id              integer primary key autoincrement,  -- 字典主键
+  dict_name            varchar(100) default '',  -- 字典名称
+  dict_type            varchar(100) default '',  -- 字典类型
+  status               char(1) default '0',  -- 状态（0正常 1停用）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500),  -- 备注
+  unique (dict_type)
+);
+insert into sqlite_sequence (name, seq) values ('sys_dict_type', 99);
+
+-- ----------------------------
+-- 字典数据表
+-- ----------------------------
+create table sys_dict_data (  -- 字典数据表
+  dict_code            integer primary key autoincrement,  -- 字典编码
+  dict_sort            int default 0,  -- 字典排序
+  dict_label           varchar(100) default '',  -- 字典标签
+  dict_value           varchar(100) default '',  -- 字典键值
+  dict_type            varchar(100) default '',  -- 字典类型
+  css_class            varchar(100),  -- 样式属性（其他样式扩展）
+  list_class           varchar(100),  -- 表格回显样式
+  is_default           char(1) default 'N',  -- 是否默认（Y是 N否）
+  status               char(1) default '0',  -- 状态（0正常 1停用）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500)  -- 备注
+);
+insert into sqlite_sequence (name, seq) values ('sys_dict_data', 99);
+
+-- ----------------------------
+-- 参数配置表
+-- ----------------------------
+create table sys_config (  -- 参数配置表
+  config_id            integer primary key autoincrement,  -- 参数主键
+  config_name          varchar(100) default '',  -- 参数名称
+  config_key           varchar(100) default '',  -- 参数键名
+  config_value         varchar(500) default '',  -- 参数键值
+  config_type          char(1) default 'N',  -- 系统内置（Y是 N否）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500)  -- 备注
+);
+insert into sqlite_sequence (name, seq) values ('sys_config', 99);
+
+-- ----------------------------
+-- 系统访问记录
+-- ----------------------------
+create table sys_login_info (  -- 系统访问记录
+  info_id              integer primary key autoincrement,  -- 访问ID
+  user_name            varchar(50) default '',  -- 用户账号
+  ipaddr               varchar(128) default '',  -- 登录IP地址
+  login_location       varchar(255) default '',  -- 登录地点
+  browser              varchar(50) default '',  -- 浏览器类型
+  os                   varchar(50) default '',  -- 操作系统
+  status               char(1) default '0',  -- 登录状态（0成功 1失败）
+  msg                  varchar(255) default '',  -- 提示消息
+  login_time           datetime  -- 访问时间
+);
+create index idx_sys_logininfor_s on sys_login_info (status);
+create index idx_sys_logininfor_lt on sys_login_info (login_time);
+insert into sqlite_sequence (name, seq) values ('sys_login_info', 99);
+
+-- ----------------------------
+-- 定时任务调度表
+-- ----------------------------
+create table sys_job (  -- 定时任务调度表
+  job_id               integer primary key autoincrement,  -- 任务ID
+  job_name             varchar(64) default '',  -- 任务名称
+  job_group            varchar(64) default 'DEFAULT',  -- 任务组名
+  invoke_target        varchar(500) not null,  -- 调用目标字符串
+  cron_expression      varchar(255) default '',  -- cron执行表达式
+  misfire_policy       varchar(20) default '3',  -- 计划执行错误策略（1立即执行 2执行一次 3放弃执行）
+  concurrent           char(1) default '1',  -- 是否并发执行（0允许 1禁止）
+  status               char(1) default '0',  -- 状态（0正常 1暂停）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500) default '',  -- 备注信息
+  unique (job_id, job_name, job_group)
+);
+insert into sqlite_sequence (name, seq) values ('sys_job', 99);
+
+-- ----------------------------
+-- 定时任务调度日志表
+-- ----------------------------
+create table sys_job_log (  -- 定时任务调度日志表
+  job_log_id           integer primary key autoincrement,  -- 任务日志ID
+  job_name             varchar(64) not null,  -- 任务名称
+  job_group            varchar(64) not null,  -- 任务组名
+  invoke_target        varchar(500) not null,  -- 调用目标字符串
+  job_message          varchar(500),  -- 日志信息
+  status               char(1) default '0',  -- 执行状态（0正常 1失败）
+  exception_info       varchar(2000) default '',  -- 异常信息
+  create_time          datetime  -- 创建时间
+);
+
+-- ----------------------------
+-- 通知公告表
+-- ----------------------------
+create table sys_notice (  -- 通知公告表
+  notice_id            integer primary key autoincrement,  -- 公告ID
+  notice_title         varchar(50) not null,  -- 公告标题
+  notice_type          char(1) not null,  -- 公告类型（1通知 2公告）
+  notice_content       blob,  -- 公告内容
+  status               char(1) default '0',  -- 公告状态（0正常 1关闭）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(255)  -- 备注
+);
+insert into sqlite_sequence (name, seq) values ('sys_notice', 9);
@@ -0,0 +1,60 @@
+-- 多租户
+
+-- ----------------------------
+-- 租户套餐表
+-- ----------------------------
+create table sys_tenant_package (  -- 租户套餐表
+  package_id           integer primary key autoincrement,  -- 套餐ID
+  package_name         varchar(50) not null,  -- 套餐名称
+  menu_check_strictly  boolean default 1,  -- 菜单树选择项是否关联显示
+  status               char(1) default '0',  -- 状态（0正常 1停用）
+  del_flag             char(1) default '0',  -- 删除标志（0代表存在 2代表删除）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500)  -- 备注
+);
+insert into sqlite_sequence (name, seq) values ('sys_tenant_package', 99);
+
+-- ----------------------------
+-- 租户套餐和菜单关联表
+-- ----------------------------
+create table sys_tenant_package_menu (  -- 租户套餐和菜单关联表
+  package_id           bigint not null,  -- 套餐ID
+  menu_id              bigint not null,  -- 菜单ID
+  primary key (package_id, menu_id)
+);
+
+-- ----------------------------
+-- 租户表
+-- ----------------------------
+create table sys_tenant (  -- 租户表
+  tenant_id            integer primary key autoincrement,  -- 租户ID
+  tenant_name          varchar(50) not null,  -- 租户名称
+  contact_user_name    varchar(30) default '',  -- 联系人
+  contact_phone        varchar(20) default '',  -- 联系电话
+  package_id           bigint,  -- 租户套餐ID
+  expire_time          datetime,  -- 过期时间
+  status               char(1) default '0',  -- 状态（0正常 1停用）
+  del_flag             char(1) default '0',  -- 删除标志（0代表存在 2代表删除）
+  create_by            varchar(64) default '',  -- 创建者
+  create_time          datetime,  -- 创建时间
+  update_by            varchar(64) default '',  -- 更新者
+  update_time          datetime,  -- 更新时间
+  remark               varchar(500)  -- 备注
+);
+insert into sys_tenant (tenant_id, tenant_name, contact_user_name, status, del_flag, create_by, create_time, remark)
+values (1, '默认租户', 'admin', '0', '0', 'admin', datetime('now'), '平台默认租户');
+update sqlite_sequence set seq = 99 where name = 'sys_tenant';
+
+-- ----------------------------
+-- 租户隔离字段
+-- ----------------------------
+alter table sys_user       add column tenant_id bigint not null default 1;  -- 租户ID
+alter table sys_dept       add column tenant_id bigint not null default 1;  -- 租户ID
+alter table sys_role       add column tenant_id bigint not null default 1;  -- 租户ID
+alter table sys_post       add column tenant_id bigint not null default 1;  -- 租户ID
+alter table sys_notice     add column tenant_id bigint not null default 1;  -- 租户ID
+alter table sys_oper_log   add column tenant_id bigint not null default 1;  -- 租户ID
+alter table sys_login_info add column tenant_id bigint not null default 1;  -- 租户ID
@@ -0,0 +1,8 @@
+-- 敏感字段加密：手机号码和邮箱以密文存储，等值查询使用盲索引
+-- SQLite 不限制 varchar 长度，email、phonenumber 无需修改类型
+
+alter table sys_user add column email_index       varchar(64) default null;  -- 用户邮箱盲索引
+alter table sys_user add column phonenumber_index varchar(64) default null;  -- 手机号码盲索引
+
+create index idx_sys_user_email_index on sys_user (email_index);
+create index idx_sys_user_phonenumber_index on sys_user (phonenumber_index);
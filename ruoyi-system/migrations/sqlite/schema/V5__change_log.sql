@@ -0,0 +1,15 @@
+-- 实体变更历史：记录修改和删除前后的字段差异
+
+create table sys_change_log (  -- 实体变更历史
+  change_id            integer primary key autoincrement,  -- 变更主键
+  tenant_id            bigint not null default 1,  -- 租户ID
+  entity_name          varchar(64) not null,  -- 实体名称（表名）
+  entity_id            varchar(64) not null,  -- 实体主键
+  change_type          varchar(16) not null,  -- 变更类型（update修改 delete删除 restore恢复 purge彻底删除）
+  changes              text,  -- 字段差异（JSON）
+  oper_name            varchar(64) default '',  -- 操作人员
+  change_time          datetime  -- 变更时间
+);
+create index idx_sys_change_log_entity on sys_change_log (entity_name, entity_id);
+create index idx_sys_change_log_ct on sys_change_log (change_time);
+insert into sqlite_sequence (name, seq) values ('sys_change_log', 99);
@@ -0,0 +1,51 @@
+// ruoyi-system/src/controller/change_log_controller.rs
+//! 实体变更历史控制器
+
+use crate::service::change_log_service::{
+    ChangeLogResponse, ChangeLogService, ChangeLogServiceImpl,
+};
+use actix_web::{get, web, HttpResponse, Responder};
+use log::{error, info};
+use ruoyi_common::vo::{RData, R};
+use utoipa::OpenApi;
+
+/// 获取记录的变更历史时间线，按变更时间倒序
+///
+/// entity 为 user、role、menu、dept、config 或对应的表名
+#[utoipa::path(
+    params(
+        ("entity" = String, Path, description = "实体名称或表名"),
+        ("id" = String, Path, description = "记录主键")
+    ),
+    responses((status = 200, description = "变更历史", body = RData<Vec<ChangeLogResponse>>))
+)]
+#[get("/{entity}/{id}")]
+pub async fn get_change_history(
+    path: web::Path<(String, String)>,
+    change_log_service: web::Data<ChangeLogServiceImpl>,
+) -> impl Responder {
+    let (entity, entity_id) = path.into_inner();
+    info!("查询变更历史: {} {}", entity, entity_id);
+
+    match change_log_service.get_history(&entity, &entity_id).await {
+        Ok(Some(history)) => HttpResponse::Ok().json(RData::ok(history)),
+        Ok(None) => HttpResponse::Ok().json(R::<String>::fail(&format!(
+            "不支持查询变更历史: {}",
+            entity
+        ))),
+        Err(e) => {
+            error!("查询变更历史失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("查询变更历史失败: {}", e)))
+        }
+    }
+}
+
+/// 实体变更历史接口文档
+#[derive(OpenApi)]
+#[openapi(paths(get_change_history))]
+pub struct ChangeLogApi;
+
+/// 加载实体变更历史路由
+pub fn load_change_log_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::scope("/changelog").service(get_change_history));
+}
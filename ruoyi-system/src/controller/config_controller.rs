@@ -2,27 +2,34 @@ use crate::service::config_service::{ConfigService, ConfigServiceImpl};
 use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
 use chrono::{DateTime, Utc};
 use log::{error, info};
-use ruoyi_common::constants;
 use ruoyi_common::utils::string::option_is_empty;
 use ruoyi_common::utils::time::deserialize_optional_datetime;
-use ruoyi_common::vo::{PageParam, RData, R};
-use ruoyi_framework::cache::get_global_cache;
+use ruoyi_common::vo::{NoData, PageParam, RData, TableDataInfo, R};
+use ruoyi_framework::db::query::QueryCondition;
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
-#[derive(Debug, Deserialize)]
+use crate::entity::prelude::ConfigModel;
+
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::ConfigColumn")]
 pub struct ConfigQuery {
     /// 参数键名
+    #[query(like)]
     pub config_key: Option<String>,
     /// 参数名称
+    #[query(like)]
     pub config_name: Option<String>,
     /// 参数类型
+    #[query(eq)]
     pub config_type: Option<String>,
 
     /// 开始时间
     #[serde(rename = "params[beginTime]", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_optional_datetime")]
+    #[query(between = "begin_time,end_time")]
     pub begin_time: Option<DateTime<Utc>>,
 
     /// 结束时间
@@ -32,7 +39,7 @@ pub struct ConfigQuery {
     pub end_time: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrUpdateConfigRequest {
     pub config_id: Option<i32>,
@@ -41,8 +48,14 @@ pub struct CreateOrUpdateConfigRequest {
     pub config_value: Option<String>,
     pub config_type: Option<String>,
     pub remark: Option<String>,
+    pub version: Option<i32>,
 }
 
+/// 查询参数配置列表
+#[utoipa::path(
+    params(ConfigQuery, PageParam),
+    responses((status = 200, description = "参数配置列表", body = R<TableDataInfo<ConfigModel>>))
+)]
 #[get("/list")]
 pub async fn get_config_list(
     query: web::Query<ConfigQuery>,
@@ -63,6 +76,8 @@ pub async fn get_config_list(
     }
 }
 
+/// 查询参数配置详情
+#[utoipa::path(responses((status = 200, description = "参数配置信息", body = RData<ConfigModel>)))]
 #[get("/{configId}")]
 pub async fn get_config(
     path: web::Path<i32>,
@@ -79,6 +94,8 @@ pub async fn get_config(
     }
 }
 
+/// 新增参数配置
+#[utoipa::path(responses((status = 200, description = "新增的参数配置", body = RData<ConfigModel>)))]
 #[post("")]
 pub async fn create_config(
     req: web::Json<CreateOrUpdateConfigRequest>,
@@ -98,6 +115,8 @@ pub async fn create_config(
     }
 }
 
+/// 修改参数配置
+#[utoipa::path(responses((status = 200, description = "修改后的参数配置", body = RData<ConfigModel>)))]
 #[put("")]
 pub async fn update_config(
     req: web::Json<CreateOrUpdateConfigRequest>,
@@ -117,6 +136,11 @@ pub async fn update_config(
     }
 }
 
+/// 删除参数配置
+#[utoipa::path(
+    params(("configIds" = String, Path, description = "参数主键，多个以逗号分隔")),
+    responses((status = 200, description = "删除结果", body = R<NoData>))
+)]
 #[delete("/{configIds}")]
 pub async fn delete_configs(
     path: web::Path<String>,
@@ -194,6 +218,9 @@ async fn check_config_valid(
 
     (true, None)
 }
+
+/// 根据参数键名查询参数值
+#[utoipa::path(responses((status = 200, description = "参数值在 msg 中返回", body = R<NoData>)))]
 #[get("/configKey/{configKey}")]
 pub async fn get_config_key(
     path: web::Path<String>,
@@ -210,35 +237,33 @@ pub async fn get_config_key(
     }
 }
 
+/// 刷新参数缓存
+#[utoipa::path(responses((status = 200, description = "刷新结果", body = R<NoData>)))]
 #[delete("/refreshCache")]
 pub async fn refresh_cache(config_service: web::Data<ConfigServiceImpl>) -> impl Responder {
     info!("刷新配置缓存");
-    if let Ok(cache) = get_global_cache() {
-        match config_service.get_all_configs().await {
-            Ok(configs) => {
-                for config in configs {
-                    let _ = cache
-                        .set_string(
-                            &format!(
-                                "{}{}",
-                                constants::cache::SYS_CONFIG_PREFIX,
-                                config.config_key.unwrap()
-                            ),
-                            &config.config_value.unwrap_or_default(),
-                        )
-                        .await;
-                }
-                return HttpResponse::Ok().json(R::<String>::ok_with_msg("刷新缓存成功"));
-            }
-            Err(e) => {
-                error!("刷新缓存失败: {}", e);
-                return HttpResponse::Ok().json(R::<String>::fail(&format!("刷新缓存失败: {}", e)));
-            }
-        };
+    match config_service.reset_config_cache().await {
+        Ok(_) => HttpResponse::Ok().json(R::<String>::ok_with_msg("刷新缓存成功")),
+        Err(e) => {
+            error!("刷新缓存失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("刷新缓存失败: {}", e)))
+        }
     }
-    HttpResponse::Ok().json(R::<String>::fail(&format!("刷新缓存失败")))
 }
 
+/// 参数配置接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_config_list,
+    get_config,
+    create_config,
+    update_config,
+    delete_configs,
+    get_config_key,
+    refresh_cache
+))]
+pub struct ConfigApi;
+
 /// 注册配置控制器路由
 pub fn load_config_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -4,24 +4,30 @@
 use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
 use log::{error, info};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
-use ruoyi_common::{utils::string::option_is_empty, vo::{RData, RList, R}};
+use ruoyi_common::{utils::string::option_is_empty, vo::{NoData, RData, RList, R}};
 
+use crate::entity::prelude::DeptModel;
 use crate::service::dept_service::{DeptService, DeptServiceImpl};
+use ruoyi_framework::db::query::QueryCondition;
 
 /// 部门查询参数
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::DeptColumn")]
 pub struct DeptQuery {
     /// 部门名称
+    #[query(like)]
     pub dept_name: Option<String>,
 
     /// 部门状态（0正常 1停用）
+    #[query(eq)]
     pub status: Option<String>,
 }
 
 /// 创建部门请求
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrUpdateDeptRequest {
     /// 部门ID
@@ -50,6 +56,10 @@ pub struct CreateOrUpdateDeptRequest {
 }
 
 /// 获取部门列表
+#[utoipa::path(
+    params(DeptQuery),
+    responses((status = 200, description = "部门列表", body = RList<DeptModel>))
+)]
 #[get("/list")]
 pub async fn list_depts(
     req: web::Query<DeptQuery>,
@@ -66,6 +76,11 @@ pub async fn list_depts(
     }
 }
 
+/// 查询部门列表（排除节点）
+#[utoipa::path(
+    params(DeptQuery),
+    responses((status = 200, description = "排除该部门及其子部门后的部门列表", body = RList<DeptModel>))
+)]
 #[get("/list/exclude/{id}")]
 pub async fn list_depts_exclude_self(
     path: web::Path<i64>,
@@ -84,6 +99,7 @@ pub async fn list_depts_exclude_self(
     }
 }
 /// 获取部门详情
+#[utoipa::path(responses((status = 200, description = "部门信息", body = RData<DeptModel>)))]
 #[get("/{id}")]
 pub async fn get_dept(
     path: web::Path<i64>,
@@ -102,6 +118,7 @@ pub async fn get_dept(
 }
 
 /// 创建部门
+#[utoipa::path(responses((status = 200, description = "创建结果", body = R<NoData>)))]
 #[post("")]
 pub async fn create_dept(
     req: web::Json<CreateOrUpdateDeptRequest>,
@@ -122,6 +139,7 @@ pub async fn create_dept(
 }
 
 /// 更新部门
+#[utoipa::path(responses((status = 200, description = "更新结果", body = R<NoData>)))]
 #[put("")]
 pub async fn update_dept(
     req: web::Json<CreateOrUpdateDeptRequest>,
@@ -143,6 +161,7 @@ pub async fn update_dept(
 }
 
 /// 删除部门    
+#[utoipa::path(responses((status = 200, description = "删除结果", body = R<NoData>)))]
 #[delete("/{id}")]
 pub async fn delete_dept(
     path: web::Path<i64>,
@@ -204,6 +223,18 @@ async fn check_dept_valid(
     (true, None)
 }
 
+/// 部门管理接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_depts,
+    list_depts_exclude_self,
+    get_dept,
+    create_dept,
+    update_dept,
+    delete_dept
+))]
+pub struct DeptApi;
+
 /// 注册部门控制器路由
 pub fn load_dept_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
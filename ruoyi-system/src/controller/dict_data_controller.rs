@@ -1,23 +1,32 @@
 /// 字典数据控制器
 use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
 use log::{error, info};
-use ruoyi_common::{utils::string::option_is_empty, vo::{PageParam, RData, RList, R}};
+use ruoyi_common::{
+    utils::string::option_is_empty,
+    vo::{NoData, PageParam, RData, RList, TableDataInfo, R},
+};
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
 use crate::{
     entity::prelude::*,
     service::dict_data_service::{DictDataService, DictDataServiceImpl},
 };
+use ruoyi_framework::db::query::QueryCondition;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::DictDataColumn")]
 pub struct DictDataQuery {
+    #[query(eq)]
     pub dict_type: Option<String>,
+    #[query(like)]
     pub dict_label: Option<String>,
+    #[query(eq)]
     pub status: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrUpdateDictDataRequest {
     pub dict_code: Option<i64>,
@@ -32,6 +41,11 @@ pub struct CreateOrUpdateDictDataRequest {
     pub dict_sort: Option<i32>,
 }
 
+/// 查询字典数据列表
+#[utoipa::path(
+    params(DictDataQuery, PageParam),
+    responses((status = 200, description = "字典数据列表", body = R<TableDataInfo<DictDataModel>>))
+)]
 #[get("/list")]
 pub async fn get_dict_data_list(
     query: web::Query<DictDataQuery>,
@@ -55,6 +69,8 @@ pub async fn get_dict_data_list(
     }
 }
 
+/// 查询字典数据详情
+#[utoipa::path(responses((status = 200, description = "字典数据", body = RData<DictDataModel>)))]
 #[get("/{dict_code}")]
 pub async fn get_dict_data(
     path: web::Path<i64>,
@@ -71,6 +87,8 @@ pub async fn get_dict_data(
     }
 }
 
+/// 新增字典数据
+#[utoipa::path(responses((status = 200, description = "新增的字典数据", body = RData<DictDataModel>)))]
 #[post("")]
 pub async fn create_dict_data(
     req: web::Json<CreateOrUpdateDictDataRequest>,
@@ -90,6 +108,8 @@ pub async fn create_dict_data(
     }
 }
 
+/// 修改字典数据
+#[utoipa::path(responses((status = 200, description = "修改后的字典数据", body = RData<DictDataModel>)))]
 #[put("")]
 pub async fn update_dict_data(
     req: web::Json<CreateOrUpdateDictDataRequest>,
@@ -109,6 +129,11 @@ pub async fn update_dict_data(
     }
 }
 
+/// 删除字典数据
+#[utoipa::path(
+    params(("ids" = String, Path, description = "字典编码，多个以逗号分隔")),
+    responses((status = 200, description = "删除结果", body = R<NoData>))
+)]
 #[delete("/{ids}")]
 pub async fn delete_dict_data(
     path: web::Path<String>,
@@ -129,6 +154,8 @@ pub async fn delete_dict_data(
     }
 }
 
+/// 根据字典类型查询字典数据
+#[utoipa::path(responses((status = 200, description = "该类型的字典数据", body = RList<DictDataModel>)))]
 #[get("/type/{dictType}")]
 pub async fn dict_type(
     path: web::Path<String>,
@@ -197,6 +224,19 @@ async fn check_dict_data_valid(
 
     (true, None)
 }
+
+/// 字典数据接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_dict_data_list,
+    dict_type,
+    get_dict_data,
+    create_dict_data,
+    update_dict_data,
+    delete_dict_data
+))]
+pub struct DictDataApi;
+
 /// 路由注册
 pub fn load_dict_data_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -3,23 +3,31 @@ use crate::service::dict_type_service::{DictTypeService, DictTypeServiceImpl};
 use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
 use chrono::{DateTime, Utc};
 use log::{error, info};
-use ruoyi_common::constants;
 use ruoyi_common::utils::string::option_is_empty;
 use ruoyi_common::utils::time::deserialize_optional_datetime;
-use ruoyi_common::vo::{PageParam, RData, RList, R};
-use ruoyi_framework::cache::get_global_cache;
+use ruoyi_common::vo::{NoData, PageParam, RData, RList, TableDataInfo, R};
+use ruoyi_framework::cache::DistributedLock;
+use ruoyi_framework::db::query::QueryCondition;
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
-#[derive(Debug, Deserialize)]
+use crate::entity::prelude::DictTypeModel;
+
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::DictTypeColumn")]
 pub struct DictTypeQuery {
+    #[query(like)]
     pub dict_name: Option<String>,
+    #[query(like)]
     pub dict_type: Option<String>,
+    #[query(eq)]
     pub status: Option<String>,
     /// 开始时间
     #[serde(rename = "params[beginTime]", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_optional_datetime")]
+    #[query(between = "begin_time,end_time")]
     pub begin_time: Option<DateTime<Utc>>,
 
     /// 结束时间
@@ -29,7 +37,7 @@ pub struct DictTypeQuery {
     pub end_time: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrUpdateDictTypeRequest {
     pub dict_id: Option<i64>,
@@ -39,6 +47,11 @@ pub struct CreateOrUpdateDictTypeRequest {
     pub remark: Option<String>,
 }
 
+/// 查询字典类型列表
+#[utoipa::path(
+    params(DictTypeQuery, PageParam),
+    responses((status = 200, description = "字典类型列表", body = R<TableDataInfo<DictTypeModel>>))
+)]
 #[get("/list")]
 pub async fn get_dict_type_list(
     query: web::Query<DictTypeQuery>,
@@ -61,6 +74,8 @@ pub async fn get_dict_type_list(
     }
 }
 
+/// 查询字典类型详情
+#[utoipa::path(responses((status = 200, description = "字典类型", body = RData<DictTypeModel>)))]
 #[get("/{id}")]
 pub async fn get_dict_type(
     path: web::Path<i64>,
@@ -77,6 +92,8 @@ pub async fn get_dict_type(
     }
 }
 
+/// 新增字典类型
+#[utoipa::path(responses((status = 200, description = "创建结果", body = R<NoData>)))]
 #[post("")]
 pub async fn create_dict_type(
     req: web::Json<CreateOrUpdateDictTypeRequest>,
@@ -96,6 +113,8 @@ pub async fn create_dict_type(
     }
 }
 
+/// 修改字典类型
+#[utoipa::path(responses((status = 200, description = "更新结果", body = R<NoData>)))]
 #[put("")]
 pub async fn update_dict_type(
     req: web::Json<CreateOrUpdateDictTypeRequest>,
@@ -115,6 +134,11 @@ pub async fn update_dict_type(
     }
 }
 
+/// 删除字典类型
+#[utoipa::path(
+    params(("ids" = String, Path, description = "字典主键，多个以逗号分隔")),
+    responses((status = 200, description = "删除结果", body = R<NoData>))
+)]
 #[delete("/{ids}")]
 pub async fn delete_dict_types(
     path: web::Path<String>,
@@ -135,6 +159,8 @@ pub async fn delete_dict_types(
     }
 }
 
+/// 查询字典类型选项
+#[utoipa::path(responses((status = 200, description = "全部字典类型", body = RList<DictTypeModel>)))]
 #[get("/optionselect")]
 pub async fn option_select(dict_type_service: web::Data<DictTypeServiceImpl>) -> impl Responder {
     match dict_type_service.get_all_dict_types().await {
@@ -146,40 +172,50 @@ pub async fn option_select(dict_type_service: web::Data<DictTypeServiceImpl>) ->
     }
 }
 
+/// 刷新字典缓存
+#[utoipa::path(responses((status = 200, description = "刷新结果", body = R<NoData>)))]
 #[delete("/refreshCache")]
 pub async fn refresh_cache(
     dict_type_service: web::Data<DictTypeServiceImpl>,
     dict_data_service: web::Data<DictDataServiceImpl>,
 ) -> impl Responder {
     info!("刷新字典类型缓存");
-    if let Ok(cache) = get_global_cache() {
-        match dict_type_service.get_all_dict_types().await {
-            Ok(dict_types) => {
-                // 遍历所有的字典类型并查询字典数据
-                for dict_type in dict_types {
-                    if let Some(dict_type) = dict_type.dict_type {
-                        if let Ok(dict_data) =
-                            dict_data_service.get_dict_data_by_type(&dict_type).await
-                        {
-                            // 将字典数据缓存到redis
-                            let _ = cache
-                                .set_string(
-                                    &format!("{}{}", constants::cache::SYS_DICT_PREFIX, dict_type),
-                                    &serde_json::to_string(&dict_data).unwrap(),
-                                )
-                                .await;
-                        }
-                    }
-                }
-                return HttpResponse::Ok().json(R::<String>::ok_with_msg("刷新缓存成功"));
-            }
-            Err(e) => {
-                error!("刷新缓存失败: {}", e);
-                return HttpResponse::Ok().json(R::<String>::fail(&format!("刷新缓存失败: {}", e)));
-            }
-        };
+    let dict_types = match dict_type_service.get_all_dict_types().await {
+        Ok(dict_types) => dict_types,
+        Err(e) => {
+            error!("刷新缓存失败: {}", e);
+            return HttpResponse::Ok().json(R::<String>::fail(&format!("刷新缓存失败: {}", e)));
+        }
+    };
+    let dict_types: Vec<String> = dict_types.into_iter().filter_map(|t| t.dict_type).collect();
+    // 多个实例同时刷新时只允许一个实例重建缓存
+    let guard = match DistributedLock::global("refresh_dict_cache") {
+        Ok(lock) => lock.acquire().await,
+        Err(e) => Err(e),
+    };
+    let guard = match guard {
+        Ok(Some(guard)) => guard,
+        Ok(None) => {
+            return HttpResponse::Ok()
+                .json(R::<String>::fail("其他实例正在刷新字典缓存，请稍后再试"))
+        }
+        Err(e) => {
+            error!("刷新缓存失败: {}", e);
+            return HttpResponse::Ok().json(R::<String>::fail(&format!("刷新缓存失败: {}", e)));
+        }
+    };
+    let result = match dict_data_service.clear_dict_cache().await {
+        Ok(_) => dict_data_service.load_dict_cache(&dict_types).await,
+        Err(e) => Err(e),
+    };
+    let _ = guard.release().await;
+    match result {
+        Ok(_) => HttpResponse::Ok().json(R::<String>::ok_with_msg("刷新缓存成功")),
+        Err(e) => {
+            error!("刷新缓存失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("刷新缓存失败: {}", e)))
+        }
     }
-    HttpResponse::Ok().json(R::<String>::fail(&format!("刷新缓存失败")))
 }
 
 async fn check_dict_type_valid(
@@ -232,6 +268,19 @@ async fn check_dict_type_valid(
     (true, None)
 }
 
+/// 字典类型接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_dict_type_list,
+    option_select,
+    get_dict_type,
+    create_dict_type,
+    update_dict_type,
+    refresh_cache,
+    delete_dict_types
+))]
+pub struct DictTypeApi;
+
 pub fn load_dict_type_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/dict/type")
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use actix_web::{get, post, web, HttpMessage, HttpRequest, HttpResponse, Responder};
 use chrono::Utc;
@@ -10,28 +11,34 @@ use ruoyi_common::{
         jwt::{generate_token, Claims},
         password::verify_password,
     },
-    vo::{RList, R},
+    vo::{NoData, RList, R},
 };
 use ruoyi_framework::{
     cache::get_global_cache,
     config::AppConfig,
     logger::entity::LoginInfoModel,
+    tenant::{current_tenant_id, tenant_cache_key, tenant_cache_key_of, DEFAULT_TENANT_ID},
     web::{
+        context,
         service::captcha::{CaptchaService, InMemoryCaptchaService},
-        tls,
     },
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use utoipa::{OpenApi, ToSchema};
 
 use crate::{
-    entity::vo::user::{UserInfo, UserOnline},
+    entity::vo::{
+        router::RouterVo,
+        user::{UserInfo, UserOnline},
+    },
     service::{
         menu_service::{MenuService, MenuServiceImpl},
+        tenant_service::{TenantService, TenantServiceImpl},
         user_service::{UserService, UserServiceImpl},
     },
 };
 /// 登录请求参数
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct LoginRequest {
     /// 用户名
     pub username: String,
@@ -44,18 +51,77 @@ pub struct LoginRequest {
     /// 记住我
     #[serde(default)]
     pub remember_me: bool,
+    /// 租户ID，未指定时使用请求头中的租户或默认租户
+    #[serde(rename = "tenantId")]
+    pub tenant_id: Option<i64>,
+}
+
+/// 登录令牌
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LoginToken {
+    /// 访问令牌，请求时放在 `Authorization: Bearer <token>` 中
+    pub token: String,
+}
+
+/// 当前登录用户信息
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LoginUserInfo {
+    /// 权限标识
+    pub permissions: Vec<String>,
+    /// 角色标识
+    pub roles: Vec<String>,
+    /// 用户信息
+    pub user: UserInfo,
 }
 
 /// 用户登录
+#[utoipa::path(
+    tag = "登录认证",
+    security(()),
+    responses(
+        (status = 200, description = "登录成功返回令牌，失败时只返回状态码和消息", body = R<LoginToken>)
+    )
+)]
 #[post("/login")]
 pub async fn login(
     req: web::Json<LoginRequest>,
     request: HttpRequest,
     user_service: web::Data<UserServiceImpl>,
     captcha_service: web::Data<InMemoryCaptchaService>,
+    tenant_service: web::Data<TenantServiceImpl>,
     config: web::Data<Arc<AppConfig>>,
 ) -> impl Responder {
     info!("用户登录请求: username={}", req.username);
+    // 确定登录的租户，后续查询用户和记录日志都在该租户下进行
+    let tenant_id = req
+        .tenant_id
+        .or_else(current_tenant_id)
+        .unwrap_or(DEFAULT_TENANT_ID);
+    context::with_tenant(
+        tenant_id,
+        login_in_tenant(
+            tenant_id,
+            req,
+            request,
+            user_service,
+            captcha_service,
+            tenant_service,
+            config,
+        ),
+    )
+    .await
+}
+
+/// 在登录的租户中校验并登录
+async fn login_in_tenant(
+    tenant_id: i64,
+    req: web::Json<LoginRequest>,
+    request: HttpRequest,
+    user_service: web::Data<UserServiceImpl>,
+    captcha_service: web::Data<InMemoryCaptchaService>,
+    tenant_service: web::Data<TenantServiceImpl>,
+    config: web::Data<Arc<AppConfig>>,
+) -> HttpResponse {
     // 构建出登录日志结构体
     let ipaddr = ip::get_real_ip_by_request(&request);
     let login_location = ip::get_ip_location(&ipaddr);
@@ -69,6 +135,7 @@ pub async fn login(
         status: Some("".to_string()),
         msg: Some("".to_string()),
         login_time: Some(Utc::now()),
+        tenant_id,
     };
     // 1. 验证验证码
     if !captcha_service.verify_captcha(&req.uuid, &req.code) {
@@ -78,7 +145,15 @@ pub async fn login(
         return HttpResponse::Ok().json(R::<String>::fail("验证码错误"));
     }
 
-    // 2. 验证用户名和密码
+    // 2. 验证租户
+    if let Err(e) = tenant_service.check_tenant(tenant_id).await {
+        let msg = e.to_string();
+        login_info.msg = Some(msg.clone());
+        error!(target: "system::login_info", "{}", serde_json::to_string(&login_info).unwrap());
+        return HttpResponse::Ok().json(R::<String>::fail(&msg));
+    }
+
+    // 3. 验证用户名和密码
     match user_service.get_user_by_username(&req.username).await {
         Ok(user) => {
             if let Some(user) = user {
@@ -100,6 +175,7 @@ pub async fn login(
                         &short_uuid,
                         user.user_id as i64,
                         &user.user_name,
+                        tenant_id,
                         &config.jwt.secret,
                         config.jwt.expires_in,
                     ) {
@@ -122,18 +198,15 @@ pub async fn login(
                     // 缓存用户信息
                     if let Ok(cache) = get_global_cache() {
                         cache
-                            .set_string(
-                                &format!("{}{}", constants::cache::TOKEN_PREFIX, &short_uuid),
+                            .set_string_ex(
+                                &tenant_cache_key(constants::cache::TOKEN_PREFIX, &short_uuid),
                                 &serde_json::to_string(&user_online).unwrap(),
+                                Duration::from_secs(config.jwt.expires_in as u64),
                             )
                             .await
                             .unwrap();
                     }
-                    HttpResponse::Ok().json(serde_json::json!({
-                        "code": 200,
-                        "msg": "登录成功",
-                        "token": token
-                    }))
+                    HttpResponse::Ok().json(R::ok_with_data_msg(LoginToken { token }, "登录成功"))
                 } else {
                     login_info.msg = Some("用户已禁用".to_string());
                     error!(target: "system::login_info", "{}", serde_json::to_string(&login_info).unwrap());
@@ -154,13 +227,18 @@ pub async fn login(
 }
 
 /// 退出登录
+#[utoipa::path(tag = "登录认证", security(()), responses((status = 200, description = "退出结果", body = R<NoData>)))]
 #[post("/logout")]
 pub async fn logout() -> impl Responder {
     // 实际上服务端JWT不需要主动失效，前端清除token即可
-    if let Some(user_context) = tls::get_sync_user_context() {
+    if let Some(user_context) = context::current_user() {
         if let Ok(cache) = get_global_cache() {
             cache
-                .del(&format!("{}{}", constants::cache::TOKEN_PREFIX, user_context.token_id))
+                .del(&tenant_cache_key_of(
+                    user_context.tenant_id,
+                    constants::cache::TOKEN_PREFIX,
+                    &user_context.token_id,
+                ))
                 .await
                 .unwrap();
         }
@@ -169,6 +247,7 @@ pub async fn logout() -> impl Responder {
 }
 
 /// 获取用户信息
+#[utoipa::path(tag = "登录认证", responses((status = 200, description = "当前用户及其角色、权限", body = R<LoginUserInfo>)))]
 #[get("/getInfo")]
 pub async fn get_info(
     req: HttpRequest,
@@ -185,12 +264,11 @@ pub async fn get_info(
                 if UserInfo::is_admin(user_id) {
                     let roles = vec![String::from("admin")];
                     let permissions = vec![String::from("*:*:*")];
-                    return HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
-                            "permissions": permissions,
-                            "roles": roles,
-                            "user": user
-                        }
-                    )));
+                    return HttpResponse::Ok().json(R::ok_with_data(LoginUserInfo {
+                        permissions,
+                        roles,
+                        user,
+                    }));
                 }
                 let roles = user
                     .roles
@@ -207,11 +285,11 @@ pub async fn get_info(
                     }
                 };
 
-                HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
-                    "permissions": permissions,
-                    "roles": roles,
-                    "user": user
-                })))
+                HttpResponse::Ok().json(R::ok_with_data(LoginUserInfo {
+                    permissions,
+                    roles,
+                    user,
+                }))
             } else {
                 HttpResponse::Ok().json(R::<String>::fail("用户不存在"))
             }
@@ -224,6 +302,7 @@ pub async fn get_info(
 }
 
 /// 获取路由
+#[utoipa::path(tag = "登录认证", responses((status = 200, description = "当前用户的路由", body = RList<RouterVo>)))]
 #[get("/getRouters")]
 pub async fn get_routers(
     req: HttpRequest,
@@ -242,6 +321,11 @@ pub async fn get_routers(
     }
 }
 
+/// 登录认证接口文档
+#[derive(OpenApi)]
+#[openapi(paths(login, logout, get_info, get_routers))]
+pub struct LoginApi;
+
 /// 注册登录路由
 pub fn load_login_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(login)
@@ -1,14 +1,20 @@
 // ruoyi-system/src/controller/login_info_controller.rs
 //! 登录日志控制器
 
+use crate::entity::prelude::LoginInfoModel;
 use crate::service::login_info_service::{
     CreateLoginInfoRequest, LoginInfoQuery, LoginInfoService, LoginInfoServiceImpl,
 };
 use actix_web::{delete, get, post, web, HttpResponse, Responder};
 use log::{error, info};
-use ruoyi_common::vo::{PageParam, RData, R};
+use ruoyi_common::vo::{CursorData, NoData, PageParam, RData, TableDataInfo, R};
+use utoipa::OpenApi;
 
 /// 获取登录日志列表
+#[utoipa::path(
+    params(LoginInfoQuery, PageParam),
+    responses((status = 200, description = "登录日志列表", body = R<TableDataInfo<LoginInfoModel>>))
+)]
 #[get("/list")]
 pub async fn get_login_info_list(
     query: web::Query<LoginInfoQuery>,
@@ -32,7 +38,40 @@ pub async fn get_login_info_list(
     }
 }
 
+/// 按游标获取登录日志列表，适合数据量较大时翻页
+///
+/// 首页不传 cursor，之后传入上一页返回的 nextCursor，nextCursor 为空表示没有下一页
+#[utoipa::path(
+    params(LoginInfoQuery, PageParam),
+    responses((status = 200, description = "登录日志列表", body = R<CursorData<LoginInfoModel>>))
+)]
+#[get("/listByCursor")]
+pub async fn get_login_info_list_by_cursor(
+    query: web::Query<LoginInfoQuery>,
+    page_param: web::Query<PageParam>,
+    login_info_service: web::Data<LoginInfoServiceImpl>,
+) -> impl Responder {
+    info!("按游标查询登录日志列表: {:?}", query);
+
+    match login_info_service
+        .get_login_info_list_by_cursor(query.0, page_param.0)
+        .await
+    {
+        Ok((login_infos, next_cursor)) => {
+            HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
+                "rows": login_infos,
+                "nextCursor": next_cursor
+            })))
+        }
+        Err(e) => {
+            error!("查询登录日志列表失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("查询登录日志列表失败: {}", e)))
+        }
+    }
+}
+
 /// 获取登录日志详情
+#[utoipa::path(responses((status = 200, description = "登录日志详情", body = RData<LoginInfoModel>)))]
 #[get("/{id}")]
 pub async fn get_login_info(
     path: web::Path<i64>,
@@ -54,6 +93,7 @@ pub async fn get_login_info(
 }
 
 /// 记录登录信息
+#[utoipa::path(responses((status = 200, description = "记录结果", body = R<NoData>)))]
 #[post("")]
 pub async fn record_login_info(
     req: web::Json<CreateLoginInfoRequest>,
@@ -71,6 +111,10 @@ pub async fn record_login_info(
 }
 
 /// 删除登录日志
+#[utoipa::path(
+    params(("ids" = String, Path, description = "登录日志编号，多个以逗号分隔")),
+    responses((status = 200, description = "删除结果", body = R<NoData>))
+)]
 #[delete("/{ids}")]
 pub async fn delete_login_infos(
     path: web::Path<String>,
@@ -99,6 +143,7 @@ pub async fn delete_login_infos(
 }
 
 /// 清空登录日志
+#[utoipa::path(responses((status = 200, description = "清空结果", body = R<NoData>)))]
 #[delete("/clean")]
 pub async fn clean_login_info(
     login_info_service: web::Data<LoginInfoServiceImpl>,
@@ -114,11 +159,24 @@ pub async fn clean_login_info(
     }
 }
 
+/// 登录日志接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_login_info_list,
+    get_login_info_list_by_cursor,
+    get_login_info,
+    record_login_info,
+    delete_login_infos,
+    clean_login_info
+))]
+pub struct LoginInfoApi;
+
 /// 加载登录日志路由
 pub fn load_login_info_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/logininfor")
             .service(get_login_info_list)
+            .service(get_login_info_list_by_cursor)
             .service(get_login_info)
             .service(record_login_info)
             .service(clean_login_info)
@@ -3,24 +3,32 @@
 
 use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
 use log::{error, info};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
+use crate::entity::prelude::MenuModel;
+use crate::entity::vo::menu::MenuSelect;
 use crate::service::menu_service::{MenuService, MenuServiceImpl};
 use ruoyi_common::utils::string::{deserialize_str_to_i32, option_is_empty};
-use ruoyi_common::vo::{RData, RList, R};
+use ruoyi_common::vo::{NoData, RData, RList, R};
+use ruoyi_framework::db::query::QueryCondition;
 
 /// 菜单查询参数
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::MenuColumn")]
 pub struct MenuQuery {
     /// 菜单名称
+    #[query(like)]
     pub menu_name: Option<String>,
     /// 状态
+    #[query(eq)]
     pub status: Option<String>,
 }
 
 /// 创建菜单请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrUpdateMenuRequest {
     /// 菜单ID
@@ -55,9 +63,26 @@ pub struct CreateOrUpdateMenuRequest {
     pub icon: Option<String>,
     /// 备注
     pub remark: Option<String>,
+    /// 乐观锁版本号
+    pub version: Option<i32>,
+}
+
+/// 角色菜单树
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleMenuTree {
+    /// 角色已选的菜单ID
+    pub checked_keys: Vec<i64>,
+    /// 菜单下拉树
+    #[schema(value_type = Vec<MenuSelect>)]
+    pub menus: Vec<Arc<MenuSelect>>,
 }
 
 /// 获取菜单列表
+#[utoipa::path(
+    params(MenuQuery),
+    responses((status = 200, description = "菜单列表", body = RList<MenuModel>))
+)]
 #[get("/list")]
 pub async fn list_menus(
     req: web::Query<MenuQuery>,
@@ -75,6 +100,7 @@ pub async fn list_menus(
 }
 
 /// 获取菜单详情
+#[utoipa::path(responses((status = 200, description = "菜单详情", body = RData<MenuModel>)))]
 #[get("/{id}")]
 pub async fn get_menu(
     menu_id: web::Path<i64>,
@@ -92,6 +118,7 @@ pub async fn get_menu(
 }
 
 /// 创建菜单
+#[utoipa::path(responses((status = 200, description = "创建结果", body = R<NoData>)))]
 #[post("")]
 pub async fn create_menu(
     req: web::Json<CreateOrUpdateMenuRequest>,
@@ -112,6 +139,7 @@ pub async fn create_menu(
 }
 
 /// 更新菜单
+#[utoipa::path(responses((status = 200, description = "更新结果", body = R<NoData>)))]
 #[put("")]
 pub async fn update_menu(
     req: web::Json<CreateOrUpdateMenuRequest>,
@@ -132,6 +160,7 @@ pub async fn update_menu(
 }
 
 /// 删除菜单
+#[utoipa::path(responses((status = 200, description = "删除结果", body = R<NoData>)))]
 #[delete("/{id}")]
 pub async fn delete_menu(
     menu_id: web::Path<i64>,
@@ -149,6 +178,7 @@ pub async fn delete_menu(
 }
 
 /// 获取菜单树
+#[utoipa::path(responses((status = 200, description = "菜单下拉树", body = RList<MenuSelect>)))]
 #[get("/treeselect")]
 pub async fn get_menu_treeselect(menu_service: web::Data<MenuServiceImpl>) -> impl Responder {
     info!("获取菜单树");
@@ -157,7 +187,8 @@ pub async fn get_menu_treeselect(menu_service: web::Data<MenuServiceImpl>) -> im
     ))
 }
 
-/// 获取菜单树
+/// 获取角色的菜单树
+#[utoipa::path(responses((status = 200, description = "菜单下拉树及角色已选菜单", body = R<RoleMenuTree>)))]
 #[get("/roleMenuTreeselect/{roleId}")]
 pub async fn get_role_menu_treeselect(
     role_id: web::Path<i64>,
@@ -167,10 +198,10 @@ pub async fn get_role_menu_treeselect(
     info!("获取菜单树");
     let menu_ids = menu_service.get_menu_ids_by_role_id(role_id).await;
     let menu_tree = menu_service.get_menu_treeselect().await;
-    HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
-        "checkedKeys": menu_ids,
-        "menus": menu_tree,
-    })))
+    HttpResponse::Ok().json(R::ok_with_data(RoleMenuTree {
+        checked_keys: menu_ids,
+        menus: menu_tree,
+    }))
 }
 
 pub async fn check_menu_valid(
@@ -233,6 +264,19 @@ pub async fn check_menu_valid(
     (true, None)
 }
 
+/// 菜单管理接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_menus,
+    get_menu,
+    create_menu,
+    update_menu,
+    delete_menu,
+    get_menu_treeselect,
+    get_role_menu_treeselect
+))]
+pub struct MenuApi;
+
 /// 注册菜单控制器路由
 pub fn load_menu_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
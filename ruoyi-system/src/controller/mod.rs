@@ -10,9 +10,31 @@ pub mod menu_controller;
 pub mod notice_controller;
 pub mod post_controller;
 pub mod role_controller;
+pub mod tenant_controller;
+pub mod tenant_package_controller;
 pub mod user_controller;
 
 pub mod oper_log_controller;
 pub mod login_info_controller;
+pub mod change_log_controller;
 
 pub mod monitor;
+
+use actix_web::{HttpMessage, HttpRequest, HttpResponse};
+use ruoyi_common::{utils::jwt::Claims, vo::R};
+use std::sync::Arc;
+
+use crate::entity::vo::user::UserInfo;
+
+/// 恢复、彻底删除等操作只允许超级管理员执行，非管理员时返回禁止访问的响应
+pub(crate) fn admin_denied(req: &HttpRequest) -> Option<HttpResponse> {
+    let is_admin = req
+        .extensions()
+        .get::<Arc<Claims>>()
+        .is_some_and(|claims| UserInfo::is_admin(claims.user_id));
+    if is_admin {
+        None
+    } else {
+        Some(HttpResponse::Forbidden().json(R::<String>::fail("只有管理员可以执行此操作")))
+    }
+}
@@ -1,22 +1,41 @@
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use actix_web::{delete, get, web, HttpResponse, Responder};
+use actix_web::{delete, get, put, web, HttpMessage, HttpRequest, HttpResponse, Responder};
+use chrono::Utc;
 use lazy_static::lazy_static;
-use log::info;
+use log::{error, info};
+use ruoyi_common::enums::{OperLogBusinessType, OperLogOperatorType};
+use ruoyi_common::utils::jwt::Claims;
 use ruoyi_common::utils::string::{redis_command_stats_to_map, redis_info_to_map};
-use ruoyi_common::vo::{RData, RList};
+use ruoyi_common::utils::{http, ip};
+use ruoyi_common::vo::{NoData, RData, RList};
 use ruoyi_common::{constants, vo::R};
-use ruoyi_framework::cache::get_global_cache;
+use ruoyi_framework::cache::{get_global_cache, CacheBase, CacheKeyType, NamespaceStats};
 use ruoyi_framework::config::AppConfig;
-use serde::Serialize;
+use ruoyi_framework::logger::entity::OperLogModel;
+use ruoyi_framework::tenant::current_tenant_id_or_default;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
-#[derive(Clone, Serialize)]
+#[derive(Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CacheVO {
     pub cache_name: String,
     pub cache_key: String,
     pub cache_value: String,
     pub remark: String,
+    /// 键的数据类型，仅查询缓存值时返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<CacheKeyType>,
+    /// 剩余过期时间（秒），-1 表示永不过期，仅查询缓存值时返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<i64>,
+    /// 哈希表的字段，仅哈希表类型的键返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<CacheFieldVO>>,
 }
 
 impl CacheVO {
@@ -26,10 +45,88 @@ impl CacheVO {
             cache_key: String::new(),
             cache_value: String::new(),
             remark: remark.to_string(),
+            key_type: None,
+            ttl: None,
+            fields: None,
         }
     }
 }
 
+/// 哈希表字段
+#[derive(Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheFieldVO {
+    pub field: String,
+    pub value: String,
+}
+
+/// 缓存键详情，附带类型和剩余过期时间
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheKeyVO {
+    pub cache_key: String,
+    pub key_type: CacheKeyType,
+    pub ttl: i64,
+}
+
+/// 将缓存值转换为展示文本，字符串直接展示，其他类型展示为格式化的JSON
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => serde_json::to_string_pretty(other).unwrap_or_default(),
+    }
+}
+
+/// 将编辑后的文本转换为缓存值，原值为字符串时保持字符串，否则按JSON解析
+fn parse_value(original: Option<&Value>, text: &str) -> Result<Value, String> {
+    match original {
+        Some(Value::String(_)) => Ok(Value::String(text.to_string())),
+        _ => serde_json::from_str(text).map_err(|e| format!("缓存值不是有效的JSON: {}", e)),
+    }
+}
+
+/// 记录缓存管理的操作日志，写入 `system::oper_log` 后由数据库日志追加器入库
+fn record_oper_log(
+    req: &HttpRequest,
+    business_type: OperLogBusinessType,
+    method: &str,
+    param: &impl Serialize,
+    result: &Result<String, String>,
+    start_time: Instant,
+) {
+    let client_ip = ip::get_real_ip_by_request(req);
+    let oper_log = OperLogModel {
+        oper_id: 0,
+        title: Some("缓存监控".to_string()),
+        business_type: Some(business_type.into()),
+        method: Some(method.to_string()),
+        request_method: Some(req.method().to_string()),
+        operator_type: Some(OperLogOperatorType::Web.into()),
+        oper_name: req
+            .extensions()
+            .get::<Arc<Claims>>()
+            .map(|claims| claims.user_name.clone()),
+        dept_name: None,
+        oper_url: Some(req.uri().to_string()),
+        oper_location: Some(ip::get_ip_location(&client_ip)),
+        oper_ip: Some(client_ip),
+        oper_param: serde_json::to_string(param)
+            .ok()
+            .or_else(|| http::get_request_params(req)),
+        json_result: result.as_ref().ok().cloned(),
+        status: Some(if result.is_ok() { 0 } else { 1 }),
+        error_msg: result.as_ref().err().cloned(),
+        oper_time: Some(Utc::now()),
+        cost_time: Some(start_time.elapsed().as_millis() as i64),
+        tenant_id: current_tenant_id_or_default(),
+    };
+    let json = serde_json::to_string(&oper_log).unwrap();
+    match result {
+        Ok(_) => info!(target: "system::oper_log", "{}", json),
+        Err(_) => error!(target: "system::oper_log", "{}", json),
+    }
+}
+
 lazy_static! {
     static ref CACHE_NAME: Vec<CacheVO> = vec![
         CacheVO::new(constants::cache::TOKEN_PREFIX, "登录令牌"),
@@ -51,6 +148,8 @@ fn get_cache_vo(cache_name: &str) -> CacheVO {
     }
 }
 
+/// 获取缓存名称列表
+#[utoipa::path(responses((status = 200, description = "缓存名称及说明", body = RList<CacheVO>)))]
 #[get("/getNames")]
 pub async fn get_names() -> impl Responder {
     let cache_names = CACHE_NAME
@@ -64,15 +163,51 @@ pub async fn get_names() -> impl Responder {
     HttpResponse::Ok().json(RList::ok_with_data(cache_names))
 }
 
+/// 缓存键列表查询参数，不传分页参数时返回全部（最多MAX_SCAN_KEYS个）
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheKeysQuery {
+    pub page_num: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl CacheKeysQuery {
+    /// 按分页参数截取键列表
+    fn page<'a>(&self, keys: &'a [String]) -> impl Iterator<Item = &'a String> {
+        let (skip, take) = match (self.page_num, self.page_size) {
+            (Some(page_num), Some(page_size)) => {
+                let page_size = page_size.max(1) as usize;
+                ((page_num.max(1) as usize - 1) * page_size, page_size)
+            }
+            _ => (0, keys.len()),
+        };
+        keys.iter().skip(skip).take(take)
+    }
+}
+
+/// 获取缓存键列表
+#[utoipa::path(
+    params(CacheKeysQuery),
+    responses((status = 200, description = "去掉缓存名称前缀的键", body = RList<String>))
+)]
 #[get("/getKeys/{cache_name}")]
-pub async fn get_keys(path: web::Path<String>) -> impl Responder {
+pub async fn get_keys(
+    path: web::Path<String>,
+    query: web::Query<CacheKeysQuery>,
+) -> impl Responder {
     // 从全局缓存中获取
     let cache_name = path.into_inner();
     match get_global_cache() {
         Ok(cache) => {
-            if let Ok(keys) = cache.keys(&format!("{}:*", cache_name)).await {
-                let keys = keys
-                    .iter()
+            if let Ok(keys) = cache
+                .scan_keys(
+                    &format!("{}:*", cache_name),
+                    constants::cache::MAX_SCAN_KEYS,
+                )
+                .await
+            {
+                let keys = query
+                    .page(&keys)
                     .map(|key| key.replace(&format!("{}:", cache_name), ""))
                     .collect();
                 HttpResponse::Ok().json(RList::ok_with_data(keys))
@@ -84,6 +219,47 @@ pub async fn get_keys(path: web::Path<String>) -> impl Responder {
     }
 }
 
+/// 缓存键列表，附带每个键的类型和剩余过期时间
+#[utoipa::path(
+    params(CacheKeysQuery),
+    responses((status = 200, description = "缓存键详情", body = RList<CacheKeyVO>))
+)]
+#[get("/getKeyDetails/{cache_name}")]
+pub async fn get_key_details(
+    path: web::Path<String>,
+    query: web::Query<CacheKeysQuery>,
+) -> impl Responder {
+    let cache_name = path.into_inner();
+    let cache = match get_global_cache() {
+        Ok(cache) => cache,
+        Err(_) => return HttpResponse::Ok().json(RList::<CacheKeyVO>::ok_with_data(vec![])),
+    };
+    let keys = cache
+        .scan_keys(
+            &format!("{}:*", cache_name),
+            constants::cache::MAX_SCAN_KEYS,
+        )
+        .await
+        .unwrap_or_default();
+
+    let mut details = Vec::new();
+    for key in query.page(&keys) {
+        let key_type = cache.key_type(key).await.unwrap_or(CacheKeyType::None);
+        // 扫描后已过期或被删除的键不再展示
+        if key_type == CacheKeyType::None {
+            continue;
+        }
+        details.push(CacheKeyVO {
+            cache_key: key.replace(&format!("{}:", cache_name), ""),
+            key_type,
+            ttl: cache.ttl(key).await.unwrap_or(-2),
+        });
+    }
+    HttpResponse::Ok().json(RList::ok_with_data(details))
+}
+
+/// 获取缓存值
+#[utoipa::path(responses((status = 200, description = "缓存值、类型和剩余过期时间", body = RData<CacheVO>)))]
 #[get("/getValue/{cache_name}/{cache_key}")]
 pub async fn get_value(path: web::Path<(String, String)>) -> impl Responder {
     let (cache_name, cache_key) = path.into_inner();
@@ -92,67 +268,240 @@ pub async fn get_value(path: web::Path<(String, String)>) -> impl Responder {
         cache_name, cache_key
     );
 
-    if let Ok(cache) = get_global_cache() {
-        if let Ok(value) = cache
-            .get_string(&format!("{}:{}", cache_name, cache_key))
-            .await
-        {
-            if let Some(value) = value {
-                let mut cache_vo = get_cache_vo(&cache_name);
-                cache_vo.cache_key = cache_key;
-                cache_vo.cache_value = value;
-                return HttpResponse::Ok().json(RData::<CacheVO>::ok(cache_vo));
-            }
+    let cache = match get_global_cache() {
+        Ok(cache) => cache,
+        Err(_) => return HttpResponse::Ok().json(R::<String>::fail("无法获取全局缓存实例")),
+    };
+    let key = format!("{}:{}", cache_name, cache_key);
+    match read_value(cache.as_ref(), &key).await {
+        Ok((key_type, value, fields)) => {
+            let mut cache_vo = get_cache_vo(&cache_name);
+            cache_vo.cache_key = cache_key;
+            cache_vo.cache_value = value;
+            cache_vo.key_type = Some(key_type);
+            cache_vo.ttl = Some(cache.ttl(&key).await.unwrap_or(-2));
+            cache_vo.fields = fields;
+            HttpResponse::Ok().json(RData::<CacheVO>::ok(cache_vo))
         }
+        Err(msg) => HttpResponse::Ok().json(R::<String>::fail(&msg)),
     }
-    HttpResponse::Ok().json(R::<String>::fail("获取缓存值失败"))
 }
 
-#[get("")]
-pub async fn get_redis_info(config: web::Data<Arc<AppConfig>>) -> impl Responder {
-    // 获取Redis信息
-    use ruoyi_framework::cache::get_global_cache;
-    use ruoyi_framework::config::cache::CacheType;
+/// 读取缓存值的类型和展示文本，哈希表同时返回所有字段
+async fn read_value(
+    cache: &dyn CacheBase,
+    key: &str,
+) -> Result<(CacheKeyType, String, Option<Vec<CacheFieldVO>>), String> {
+    let key_type = cache
+        .key_type(key)
+        .await
+        .map_err(|e| format!("获取缓存值失败: {}", e))?;
+    match key_type {
+        CacheKeyType::None => Err("缓存键不存在".to_string()),
+        CacheKeyType::String => match cache.get_json(key).await {
+            Ok(Some(value)) => Ok((key_type, display_value(&value), None)),
+            Ok(None) => Err("缓存键不存在".to_string()),
+            Err(e) => Err(format!("缓存值无法解析: {}", e)),
+        },
+        CacheKeyType::Hash => {
+            let fields = cache
+                .hget_all_json(key)
+                .await
+                .map_err(|e| format!("缓存值无法解析: {}", e))?;
+            let value = Value::Object(fields.iter().cloned().collect());
+            let fields = fields
+                .into_iter()
+                .map(|(field, value)| CacheFieldVO {
+                    field,
+                    value: display_value(&value),
+                })
+                .collect();
+            Ok((key_type, display_value(&value), Some(fields)))
+        }
+        _ => Err(format!("暂不支持查看 {:?} 类型的缓存值", key_type)),
+    }
+}
+
+/// 修改缓存值或过期时间的请求参数
+#[derive(Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheUpdateBody {
+    pub cache_name: String,
+    pub cache_key: String,
+    /// 哈希表字段，修改哈希表的值时必填
+    pub field: Option<String>,
+    /// 新的值，不修改值时不传
+    pub cache_value: Option<String>,
+    /// 新的过期时间（秒），不修改过期时间时不传
+    pub ttl: Option<u64>,
+}
+
+/// 修改缓存值或过期时间
+#[utoipa::path(responses((status = 200, description = "修改结果", body = R<NoData>)))]
+#[put("/updateValue")]
+pub async fn update_value(req: HttpRequest, body: web::Json<CacheUpdateBody>) -> impl Responder {
+    let start_time = Instant::now();
+    let body = body.into_inner();
+    let result = match get_global_cache() {
+        Ok(cache) => update_cache_value(cache.as_ref(), &body).await,
+        Err(_) => Err("无法获取全局缓存实例".to_string()),
+    };
+    record_oper_log(
+        &req,
+        OperLogBusinessType::Update,
+        "update_value",
+        &body,
+        &result,
+        start_time,
+    );
+    match result {
+        Ok(msg) => HttpResponse::Ok().json(R::<String>::ok_with_msg(&msg)),
+        Err(msg) => HttpResponse::Ok().json(R::<String>::fail(&msg)),
+    }
+}
+
+/// 修改缓存值并保留原有的过期时间，再按需修改过期时间
+async fn update_cache_value(
+    cache: &dyn CacheBase,
+    body: &CacheUpdateBody,
+) -> Result<String, String> {
+    let key = format!("{}:{}", body.cache_name, body.cache_key);
+    if body.cache_value.is_none() && body.ttl.is_none() {
+        return Err("请指定要修改的值或过期时间".to_string());
+    }
+    if body.ttl == Some(0) {
+        return Err("过期时间必须大于0".to_string());
+    }
+    let key_type = cache.key_type(&key).await.map_err(|e| e.to_string())?;
+    let ttl = cache.ttl(&key).await.map_err(|e| e.to_string())?;
 
-    match config.cache.cache_type {
-        CacheType::Redis | CacheType::Multi => {
-            if let Some(_redis_url) = &config.cache.redis.url {
-                if let Ok(cache) = get_global_cache() {
-                    // 获取Redis信息
-                    let info = match cache.info(None).await {
-                        Ok(info) => info,
-                        _ => "".to_string(),
-                    };
-                    let info_map = redis_info_to_map(&info);
-                    // 获取数据库大小
-                    let db_size = match cache.dbsize().await {
-                        Ok(db_size) => db_size,
-                        _ => 0,
-                    };
-
-                    // 获取命令统计
-                    let command_stats = match cache.info(Some("commandstats".to_string())).await {
-                        Ok(command_stats) => command_stats,
-                        _ => "".to_string(),
-                    };
-                    let command_stats_map = redis_command_stats_to_map(&command_stats);
-                    HttpResponse::Ok().json(RData::<serde_json::Value>::ok(serde_json::json!({
-                        "info": info_map,
-                        "dbSize": db_size,
-                        "commandStats": command_stats_map
-                    })))
-                } else {
-                    HttpResponse::Ok().json(R::<String>::fail("无法获取全局缓存实例"))
+    if let Some(text) = &body.cache_value {
+        match key_type {
+            CacheKeyType::String => {
+                let original = cache.get_json(&key).await.map_err(|e| e.to_string())?;
+                let value = parse_value(original.as_ref(), text)?;
+                cache
+                    .set_json(&key, &value)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                // 写入字符串会清除过期时间，恢复原有的过期时间
+                if ttl > 0 && body.ttl.is_none() {
+                    cache
+                        .expire(&key, Duration::from_secs(ttl as u64))
+                        .await
+                        .map_err(|e| e.to_string())?;
                 }
-            } else {
-                HttpResponse::Ok().json(R::<String>::fail("Redis配置不存在"))
             }
+            CacheKeyType::Hash => {
+                let field = body
+                    .field
+                    .as_deref()
+                    .ok_or_else(|| "修改哈希表的值时需要指定字段".to_string())?;
+                let fields = cache.hget_all_json(&key).await.map_err(|e| e.to_string())?;
+                let original = fields.iter().find(|(f, _)| f == field).map(|(_, v)| v);
+                let value = parse_value(original, text)?;
+                cache
+                    .hset_json(&key, field, &value)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            CacheKeyType::None => return Err("缓存键不存在".to_string()),
+            _ => return Err(format!("暂不支持修改 {:?} 类型的缓存值", key_type)),
         }
-        CacheType::Local => {
-            HttpResponse::Ok().json(R::<String>::fail("当前使用的是本地缓存，无法获取Redis信息"))
-        }
+    } else if key_type == CacheKeyType::None {
+        return Err("缓存键不存在".to_string());
+    }
+
+    if let Some(ttl) = body.ttl {
+        cache
+            .expire(&key, Duration::from_secs(ttl))
+            .await
+            .map_err(|e| e.to_string())?;
     }
+    Ok("修改成功".to_string())
+}
+
+/// 缓存命名空间统计，附带监控页面中的缓存名称说明
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStatsVO {
+    #[serde(flatten)]
+    pub stats: NamespaceStats,
+    pub remark: String,
+}
+
+/// 缓存监控信息
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheInfoVO {
+    /// 缓存类型（redis、local、multi）
+    pub cache_type: String,
+    /// INFO 命令的结果
+    pub info: HashMap<String, String>,
+    /// 键数量
+    pub db_size: usize,
+    /// 命令统计
+    pub command_stats: Vec<HashMap<String, String>>,
+    /// 按命名空间统计的命中、加载和淘汰情况
+    pub cache_stats: Vec<CacheStatsVO>,
+}
+
+/// 获取缓存监控信息
+#[utoipa::path(responses((status = 200, description = "缓存监控信息", body = RData<CacheInfoVO>)))]
+#[get("")]
+pub async fn get_redis_info(config: web::Data<Arc<AppConfig>>) -> impl Responder {
+    // 获取缓存信息，本地缓存按Redis INFO的格式返回，三种缓存类型统一处理
+    use ruoyi_framework::config::cache::CacheType;
+
+    let cache = match get_global_cache() {
+        Ok(cache) => cache,
+        Err(_) => return HttpResponse::Ok().json(R::<String>::fail("无法获取全局缓存实例")),
+    };
+    let cache_type = match config.cache.cache_type {
+        CacheType::Redis => "redis",
+        CacheType::Local => "local",
+        CacheType::Multi => "multi",
+    };
+
+    let info = match cache.info(None).await {
+        Ok(info) => info,
+        _ => "".to_string(),
+    };
+    let info_map = redis_info_to_map(&info);
+    // 获取数据库大小
+    let db_size = match cache.dbsize().await {
+        Ok(db_size) => db_size,
+        _ => 0,
+    };
+
+    // 获取命令统计
+    let command_stats = match cache.info(Some("commandstats".to_string())).await {
+        Ok(command_stats) => command_stats,
+        _ => "".to_string(),
+    };
+    let command_stats_map = redis_command_stats_to_map(&command_stats);
+
+    // 按命名空间统计的命中、加载和淘汰情况
+    let cache_stats: Vec<CacheStatsVO> = cache
+        .stats()
+        .into_iter()
+        .map(|stats| CacheStatsVO {
+            remark: get_cache_vo(&stats.namespace).remark,
+            stats,
+        })
+        .collect();
+
+    HttpResponse::Ok().json(RData::ok(CacheInfoVO {
+        cache_type: cache_type.to_string(),
+        info: info_map,
+        db_size,
+        command_stats: command_stats_map,
+        cache_stats,
+    }))
 }
+
+/// 清除缓存名称下的所有键
+#[utoipa::path(responses((status = 200, description = "清除结果", body = R<NoData>)))]
 #[delete("/clearCacheName/{cache_name}")]
 pub async fn clear_cache_name(path: web::Path<String>) -> impl Responder {
     let cache_name = path.into_inner();
@@ -167,6 +516,8 @@ pub async fn clear_cache_name(path: web::Path<String>) -> impl Responder {
     HttpResponse::Ok().json(R::<String>::fail("无法获取全局缓存实例"))
 }
 
+/// 清除缓存键
+#[utoipa::path(responses((status = 200, description = "清除结果", body = R<NoData>)))]
 #[delete("/clearCacheKey/{cache_key}")]
 pub async fn clear_cache_key(path: web::Path<String>) -> impl Responder {
     let cache_key = path.into_inner();
@@ -181,6 +532,90 @@ pub async fn clear_cache_key(path: web::Path<String>) -> impl Responder {
     HttpResponse::Ok().json(R::<String>::fail("无法获取全局缓存实例"))
 }
 
+/// 按模式删除缓存的请求参数
+#[derive(Deserialize, Serialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct CachePatternQuery {
+    /// glob模式，如 `login_tokens:*`
+    pub pattern: String,
+    /// 只返回匹配的键，不删除
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// 按模式删除缓存的结果
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CachePatternResult {
+    /// 是否只预览
+    pub dry_run: bool,
+    /// 匹配的键数量
+    pub count: usize,
+    /// 匹配的键超过单次处理上限时为true
+    pub truncated: bool,
+    /// 匹配的键
+    pub keys: Vec<String>,
+}
+
+/// 按glob模式删除缓存，dry_run时只预览匹配的键。单次最多处理MAX_SCAN_KEYS个键
+#[utoipa::path(
+    params(CachePatternQuery),
+    responses((status = 200, description = "匹配或删除的键", body = RData<CachePatternResult>))
+)]
+#[delete("/clearCacheByPattern")]
+pub async fn clear_cache_by_pattern(
+    req: HttpRequest,
+    query: web::Query<CachePatternQuery>,
+) -> impl Responder {
+    let start_time = Instant::now();
+    let query = query.into_inner();
+    if query.pattern.trim().is_empty() {
+        return HttpResponse::Ok().json(R::<String>::fail("匹配模式不能为空"));
+    }
+    let cache = match get_global_cache() {
+        Ok(cache) => cache,
+        Err(_) => return HttpResponse::Ok().json(R::<String>::fail("无法获取全局缓存实例")),
+    };
+    let keys = match cache
+        .scan_keys(&query.pattern, constants::cache::MAX_SCAN_KEYS)
+        .await
+    {
+        Ok(keys) => keys,
+        Err(e) => return HttpResponse::Ok().json(R::<String>::fail(&e.to_string())),
+    };
+    let truncated = keys.len() >= constants::cache::MAX_SCAN_KEYS;
+
+    if !query.dry_run {
+        let mut result = Ok(format!("已删除{}个缓存键", keys.len()));
+        for key in &keys {
+            if let Err(e) = cache.del(key).await {
+                result = Err(format!("删除缓存键 {} 失败: {}", key, e));
+                break;
+            }
+        }
+        record_oper_log(
+            &req,
+            OperLogBusinessType::ClearData,
+            "clear_cache_by_pattern",
+            &query,
+            &result,
+            start_time,
+        );
+        if let Err(msg) = result {
+            return HttpResponse::Ok().json(R::<String>::fail(&msg));
+        }
+    }
+
+    HttpResponse::Ok().json(RData::ok(CachePatternResult {
+        dry_run: query.dry_run,
+        count: keys.len(),
+        truncated,
+        keys,
+    }))
+}
+
+/// 清除所有缓存
+#[utoipa::path(responses((status = 200, description = "清除结果", body = R<NoData>)))]
 #[delete("/clearCacheAll")]
 pub async fn clear_cache_all() -> impl Responder {
     if let Ok(cache) = get_global_cache() {
@@ -194,15 +629,34 @@ pub async fn clear_cache_all() -> impl Responder {
     HttpResponse::Ok().json(R::<String>::fail("无法获取全局缓存实例"))
 }
 
+/// 缓存监控接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_names,
+    get_keys,
+    get_key_details,
+    get_value,
+    update_value,
+    get_redis_info,
+    clear_cache_name,
+    clear_cache_key,
+    clear_cache_by_pattern,
+    clear_cache_all
+))]
+pub struct CacheApi;
+
 pub fn load_cache_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/cache")
             .service(get_names)
             .service(get_keys)
+            .service(get_key_details)
             .service(get_value)
+            .service(update_value)
             .service(get_redis_info)
             .service(clear_cache_name)
             .service(clear_cache_key)
+            .service(clear_cache_by_pattern)
             .service(clear_cache_all),
     );
 }
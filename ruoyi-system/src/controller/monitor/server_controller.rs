@@ -7,8 +7,9 @@ use actix_web::web;
 use actix_web::{get, HttpResponse, Responder};
 use ruoyi_common::vo::RData;
 use serde::Serialize;
+use utoipa::{OpenApi, ToSchema};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CpuInfo {
     // 核心数
@@ -25,7 +26,7 @@ pub struct CpuInfo {
     pub free: f32,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MemoryInfo {
     // 内存总量
@@ -38,7 +39,7 @@ pub struct MemoryInfo {
     pub usage: f64,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemInfo {
     // 服务器名称
@@ -57,7 +58,7 @@ pub struct SystemInfo {
     pub os_arch: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessInfo {
     // 进程名称
@@ -76,7 +77,7 @@ pub struct ProcessInfo {
     pub run_time: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 
 pub struct DiskInfo {
@@ -102,7 +103,7 @@ pub struct DiskInfo {
     pub usage: f64,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerInfo {
     // CPU相关信息
@@ -121,6 +122,8 @@ pub struct ServerInfo {
     pub process: ProcessInfo,
 }
 
+/// 获取服务器信息
+#[utoipa::path(responses((status = 200, description = "CPU、内存、磁盘和进程信息", body = RData<ServerInfo>)))]
 #[get("")]
 pub async fn get_server_info(config: web::Data<Arc<AppConfig>>) -> impl Responder {
     // 获取端口号
@@ -256,6 +259,11 @@ pub async fn get_server_info(config: web::Data<Arc<AppConfig>>) -> impl Responde
     HttpResponse::Ok().json(RData::<ServerInfo>::ok(server_info))
 }
 
+/// 服务监控接口文档
+#[derive(OpenApi)]
+#[openapi(paths(get_server_info))]
+pub struct ServerApi;
+
 pub fn load_server_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(web::scope("/server").service(get_server_info));
 }
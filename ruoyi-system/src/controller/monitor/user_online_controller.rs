@@ -4,25 +4,39 @@ use actix_web::{web, HttpResponse};
 use log::error;
 use ruoyi_common::constants;
 use ruoyi_common::utils::string::option_is_empty;
-use ruoyi_common::vo::R;
+use ruoyi_common::vo::{NoData, PageParam, TableDataInfo, R};
 use ruoyi_framework::cache::get_global_cache;
+use ruoyi_framework::tenant::tenant_cache_key;
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
-#[derive(Deserialize)]
+/// 在线用户查询参数
+#[derive(Deserialize, IntoParams)]
 #[serde(rename_all = "camelCase")]
 pub struct UserOnlineQueryParams {
     pub user_name: Option<String>,
     pub ipaddr: Option<String>,
 }
 
+/// 获取在线用户列表
+#[utoipa::path(
+    params(UserOnlineQueryParams, PageParam),
+    responses((status = 200, description = "在线用户列表", body = R<TableDataInfo<UserOnline>>))
+)]
 #[get("/list")]
-pub async fn get_user_online_list(params: web::Query<UserOnlineQueryParams>) -> impl Responder {
+pub async fn get_user_online_list(
+    params: web::Query<UserOnlineQueryParams>,
+    page_param: web::Query<PageParam>,
+) -> impl Responder {
     if let Ok(cache) = get_global_cache() {
         // 获取所有在线用户的token列表
         let mut online_users = Vec::new();
 
         if let Ok(keys) = cache
-            .keys(&format!("{}*", constants::cache::TOKEN_PREFIX))
+            .scan_keys(
+                &tenant_cache_key(constants::cache::TOKEN_PREFIX, "*"),
+                constants::cache::MAX_SCAN_KEYS,
+            )
             .await
         {
             for key in keys {
@@ -39,9 +53,6 @@ pub async fn get_user_online_list(params: web::Query<UserOnlineQueryParams>) ->
             }
         }
 
-        // 获取总数
-        let total = online_users.len();
-
         // 根据查询条件过滤
         if !option_is_empty(&params.user_name) || !option_is_empty(&params.ipaddr) {
             online_users = online_users
@@ -73,6 +84,16 @@ pub async fn get_user_online_list(params: web::Query<UserOnlineQueryParams>) ->
                 .collect::<Vec<_>>();
         }
 
+        // 获取总数并分页
+        let total = online_users.len();
+        let page_size = page_param.page_size.max(1) as usize;
+        let offset = (page_param.page_num.max(1) as usize - 1) * page_size;
+        let online_users = online_users
+            .into_iter()
+            .skip(offset)
+            .take(page_size)
+            .collect::<Vec<_>>();
+
         return HttpResponse::Ok().json(R::<serde_json::Value>::ok_with_data(serde_json::json!({
             "rows": online_users,
             "total": total,
@@ -84,18 +105,25 @@ pub async fn get_user_online_list(params: web::Query<UserOnlineQueryParams>) ->
     })))
 }
 
+/// 强制退出
+#[utoipa::path(responses((status = 200, description = "强制退出结果", body = R<NoData>)))]
 #[delete("/{tokenId}")]
 pub async fn force_logout(path: web::Path<String>) -> impl Responder {
     let token_id = path.into_inner();
     if let Ok(cache) = get_global_cache() {
         let _ = cache
-            .del(&format!("{}{}", constants::cache::TOKEN_PREFIX, token_id))
+            .del(&tenant_cache_key(constants::cache::TOKEN_PREFIX, &token_id))
             .await;
         HttpResponse::Ok().json(R::<String>::ok_with_msg("强制退出成功"))
     } else {
         HttpResponse::Ok().json(R::<String>::fail("强制退出失败"))
     }
 }
+/// 在线用户接口文档
+#[derive(OpenApi)]
+#[openapi(paths(get_user_online_list, force_logout))]
+pub struct UserOnlineApi;
+
 pub fn load_user_online_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/online")
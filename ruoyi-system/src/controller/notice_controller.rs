@@ -1,6 +1,7 @@
 // ruoyi-system/src/controller/notice_controller.rs
 //! 通知公告控制器
 
+use crate::entity::prelude::NoticeModel;
 use crate::service::notice_service::{
     CreateOrUpdateNoticeRequest, NoticeQuery, NoticeService, NoticeServiceImpl,
 };
@@ -8,10 +9,15 @@ use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
 use log::{error, info};
 use ruoyi_common::{
     utils::string::option_is_empty,
-    vo::{PageParam, RData, R},
+    vo::{NoData, PageParam, RData, TableDataInfo, R},
 };
+use utoipa::OpenApi;
 
 /// 获取通知公告列表
+#[utoipa::path(
+    params(NoticeQuery, PageParam),
+    responses((status = 200, description = "通知公告列表", body = R<TableDataInfo<NoticeModel>>))
+)]
 #[get("/list")]
 pub async fn get_notice_list(
     query: web::Query<NoticeQuery>,
@@ -33,6 +39,7 @@ pub async fn get_notice_list(
 }
 
 /// 获取通知公告详情
+#[utoipa::path(responses((status = 200, description = "通知公告详情", body = RData<NoticeModel>)))]
 #[get("/{id}")]
 pub async fn get_notice(
     path: web::Path<i32>,
@@ -54,6 +61,7 @@ pub async fn get_notice(
 }
 
 /// 新增通知公告
+#[utoipa::path(responses((status = 200, description = "新增结果", body = R<NoData>)))]
 #[post("")]
 pub async fn create_notice(
     req: web::Json<CreateOrUpdateNoticeRequest>,
@@ -75,6 +83,7 @@ pub async fn create_notice(
 }
 
 /// 修改通知公告
+#[utoipa::path(responses((status = 200, description = "修改结果", body = R<NoData>)))]
 #[put("")]
 pub async fn update_notice(
     req: web::Json<CreateOrUpdateNoticeRequest>,
@@ -97,6 +106,10 @@ pub async fn update_notice(
 }
 
 /// 删除通知公告
+#[utoipa::path(
+    params(("ids" = String, Path, description = "公告ID，多个以逗号分隔")),
+    responses((status = 200, description = "删除结果", body = R<NoData>))
+)]
 #[delete("/{ids}")]
 pub async fn delete_notices(
     path: web::Path<String>,
@@ -149,6 +162,18 @@ async fn check_notice_valid(
 
     (true, None)
 }
+
+/// 通知公告接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_notice_list,
+    get_notice,
+    create_notice,
+    update_notice,
+    delete_notices
+))]
+pub struct NoticeApi;
+
 /// 加载通知公告路由
 pub fn load_notice_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
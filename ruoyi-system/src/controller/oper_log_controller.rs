@@ -1,14 +1,20 @@
 // ruoyi-system/src/controller/oper_log_controller.rs
 //! 操作日志控制器
 
+use crate::entity::prelude::OperLogModel;
 use crate::service::oper_log_service::{
     CreateOperLogRequest, OperLogQuery, OperLogService, OperLogServiceImpl,
 };
 use actix_web::{delete, get, post, web, HttpResponse, Responder};
 use log::{error, info};
-use ruoyi_common::vo::{PageParam, RData, R};
+use ruoyi_common::vo::{CursorData, NoData, PageParam, RData, TableDataInfo, R};
+use utoipa::OpenApi;
 
 /// 获取操作日志列表
+#[utoipa::path(
+    params(OperLogQuery, PageParam),
+    responses((status = 200, description = "操作日志列表", body = R<TableDataInfo<OperLogModel>>))
+)]
 #[get("/list")]
 pub async fn get_oper_log_list(
     query: web::Query<OperLogQuery>,
@@ -32,7 +38,40 @@ pub async fn get_oper_log_list(
     }
 }
 
+/// 按游标获取操作日志列表，适合数据量较大时翻页
+///
+/// 首页不传 cursor，之后传入上一页返回的 nextCursor，nextCursor 为空表示没有下一页
+#[utoipa::path(
+    params(OperLogQuery, PageParam),
+    responses((status = 200, description = "操作日志列表", body = R<CursorData<OperLogModel>>))
+)]
+#[get("/listByCursor")]
+pub async fn get_oper_log_list_by_cursor(
+    query: web::Query<OperLogQuery>,
+    page_param: web::Query<PageParam>,
+    oper_log_service: web::Data<OperLogServiceImpl>,
+) -> impl Responder {
+    info!("按游标查询操作日志列表: {:?}", query);
+
+    match oper_log_service
+        .get_oper_log_list_by_cursor(query.0, page_param.0)
+        .await
+    {
+        Ok((oper_logs, next_cursor)) => {
+            HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
+                "rows": oper_logs,
+                "nextCursor": next_cursor
+            })))
+        }
+        Err(e) => {
+            error!("查询操作日志列表失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("查询操作日志列表失败: {}", e)))
+        }
+    }
+}
+
 /// 获取操作日志详情
+#[utoipa::path(responses((status = 200, description = "操作日志详情", body = RData<OperLogModel>)))]
 #[get("/{id}")]
 pub async fn get_oper_log(
     path: web::Path<i64>,
@@ -54,6 +93,7 @@ pub async fn get_oper_log(
 }
 
 /// 记录操作日志
+#[utoipa::path(responses((status = 200, description = "记录结果", body = R<NoData>)))]
 #[post("")]
 pub async fn record_oper_log(
     req: web::Json<CreateOperLogRequest>,
@@ -71,6 +111,10 @@ pub async fn record_oper_log(
 }
 
 /// 删除操作日志
+#[utoipa::path(
+    params(("ids" = String, Path, description = "操作日志编号，多个以逗号分隔")),
+    responses((status = 200, description = "删除结果", body = R<NoData>))
+)]
 #[delete("/{ids}")]
 pub async fn delete_oper_logs(
     path: web::Path<String>,
@@ -99,6 +143,7 @@ pub async fn delete_oper_logs(
 }
 
 /// 清空操作日志
+#[utoipa::path(responses((status = 200, description = "清空结果", body = R<NoData>)))]
 #[delete("/clean")]
 pub async fn clean_oper_log(oper_log_service: web::Data<OperLogServiceImpl>) -> impl Responder {
     info!("清空操作日志");
@@ -112,11 +157,24 @@ pub async fn clean_oper_log(oper_log_service: web::Data<OperLogServiceImpl>) ->
     }
 }
 
+/// 操作日志接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_oper_log_list,
+    get_oper_log_list_by_cursor,
+    get_oper_log,
+    record_oper_log,
+    delete_oper_logs,
+    clean_oper_log
+))]
+pub struct OperLogApi;
+
 /// 加载操作日志路由
 pub fn load_oper_log_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/operlog")
             .service(get_oper_log_list)
+            .service(get_oper_log_list_by_cursor)
             .service(get_oper_log)
             .service(record_oper_log)
             .service(clean_oper_log)
@@ -1,18 +1,26 @@
 use crate::service::post_service::{PostService, PostServiceImpl};
 use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
 use log::{error, info};
-use ruoyi_common::{utils::string::option_is_empty, vo::{PageParam, RData, R}};
+use ruoyi_common::{utils::string::option_is_empty, vo::{NoData, PageParam, RData, TableDataInfo, R}};
 use serde::Deserialize;
+use ruoyi_framework::db::query::QueryCondition;
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
-#[derive(Debug, Deserialize)]
+use crate::entity::prelude::PostModel;
+
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::PostColumn")]
 pub struct PostQuery {
+    #[query(like)]
     pub post_code: Option<String>,
+    #[query(like)]
     pub post_name: Option<String>,
+    #[query(eq)]
     pub status: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrUpdatePostRequest {
     pub post_id: Option<i64>,
@@ -23,6 +31,11 @@ pub struct CreateOrUpdatePostRequest {
     pub remark: Option<String>,
 }
 
+/// 查询岗位列表
+#[utoipa::path(
+    params(PostQuery, PageParam),
+    responses((status = 200, description = "岗位列表", body = R<TableDataInfo<PostModel>>))
+)]
 #[get("/list")]
 pub async fn get_post_list(
     req: web::Query<PostQuery>,
@@ -43,6 +56,8 @@ pub async fn get_post_list(
     }
 }
 
+/// 查询岗位详情
+#[utoipa::path(responses((status = 200, description = "岗位信息", body = RData<PostModel>)))]
 #[get("/{id}")]
 pub async fn get_post(
     path: web::Path<i64>,
@@ -59,6 +74,8 @@ pub async fn get_post(
     }
 }
 
+/// 新增岗位
+#[utoipa::path(responses((status = 200, description = "创建结果", body = R<NoData>)))]
 #[post("")]
 pub async fn create_post(
     req: web::Json<CreateOrUpdatePostRequest>,
@@ -78,6 +95,8 @@ pub async fn create_post(
     }
 }
 
+/// 修改岗位
+#[utoipa::path(responses((status = 200, description = "更新结果", body = R<NoData>)))]
 #[put("")]
 pub async fn update_post(
     req: web::Json<CreateOrUpdatePostRequest>,
@@ -97,6 +116,11 @@ pub async fn update_post(
     }
 }
 
+/// 删除岗位
+#[utoipa::path(
+    params(("ids" = String, Path, description = "岗位ID，多个以逗号分隔")),
+    responses((status = 200, description = "删除结果", body = R<NoData>))
+)]
 #[delete("/{ids}")]
 pub async fn delete_posts(
     path: web::Path<String>,
@@ -175,6 +199,11 @@ async fn check_post_valid(
     (true, None)
 }
 
+/// 岗位管理接口文档
+#[derive(OpenApi)]
+#[openapi(paths(get_post_list, get_post, create_post, update_post, delete_posts))]
+pub struct PostApi;
+
 pub fn load_post_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/post")
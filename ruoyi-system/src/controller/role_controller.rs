@@ -1,32 +1,44 @@
 // ruoyi-system/src/controller/role_controller.rs
 //! 角色管理控制器
 
-use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
+use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse, Responder};
 use chrono::{DateTime, Utc};
 use log::{error, info};
 use ruoyi_common::utils::string::option_is_empty;
 use ruoyi_common::utils::time::deserialize_optional_datetime;
-use ruoyi_common::vo::{PageParam, RData, R};
-use serde::Deserialize;
-
+use ruoyi_common::vo::{NoData, PageParam, RData, TableDataInfo, R};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::controller::admin_denied;
+use crate::entity::prelude::RoleModel;
+use crate::entity::vo::dept::DeptSelect;
+use crate::entity::vo::user::UserInfo;
 use crate::service::dept_service::{DeptService, DeptServiceImpl};
 use crate::service::role_service::{RoleService, RoleServiceImpl};
+use ruoyi_framework::db::query::QueryCondition;
 
 /// 角色查询参数
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::RoleColumn")]
 pub struct RoleQuery {
     /// 角色名称
+    #[query(like)]
     pub role_name: Option<String>,
     /// 权限字符
+    #[query(like)]
     pub role_key: Option<String>,
     /// 状态
+    #[query(eq)]
     pub status: Option<String>,
 
     /// 开始时间
     #[serde(rename = "params[beginTime]", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_optional_datetime")]
+    #[query(between = "begin_time,end_time")]
     pub begin_time: Option<DateTime<Utc>>,
 
     /// 结束时间
@@ -36,14 +48,18 @@ pub struct RoleQuery {
     pub end_time: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Deserialize)]
+/// 角色授权用户查询参数
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::UserColumn")]
 pub struct RoleAuthUserQuery {
     /// 角色ID
     pub role_id: i64,
     /// 用户名
+    #[query(like)]
     pub user_name: Option<String>,
-    /// 手机号码
+    /// 手机号码，加密存储只能精确匹配
+    #[query(blind_index)]
     pub phonenumber: Option<String>,
     /// 分页
     pub page_num: u64,
@@ -52,7 +68,7 @@ pub struct RoleAuthUserQuery {
 }
 
 /// 创建角色请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrUpdateRoleRequest {
     /// 角色ID
@@ -77,10 +93,12 @@ pub struct CreateOrUpdateRoleRequest {
     pub menu_ids: Option<Vec<i64>>,
     /// 部门ID列表
     pub dept_ids: Option<Vec<i64>>,
+    /// 乐观锁版本号
+    pub version: Option<i32>,
 }
 
 /// 角色状态更新请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangeRoleStatusRequest {
     /// 角色ID
@@ -90,7 +108,7 @@ pub struct ChangeRoleStatusRequest {
 }
 
 /// 角色授权用户请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthUserRoleRequest {
     /// 角色ID
@@ -99,7 +117,8 @@ pub struct AuthUserRoleRequest {
     pub user_ids: String,
 }
 
-#[derive(Debug, Deserialize)]
+/// 取消角色用户授权请求
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelSingleUserRoleRequest {
     /// 角色ID
@@ -108,7 +127,22 @@ pub struct CancelSingleUserRoleRequest {
     pub user_id: i64,
 }
 
+/// 角色部门树
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleDeptTree {
+    /// 部门下拉树
+    #[schema(value_type = Vec<DeptSelect>)]
+    pub depts: Vec<Arc<DeptSelect>>,
+    /// 角色已选的部门ID
+    pub checked_keys: Vec<i64>,
+}
+
 /// 获取角色列表
+#[utoipa::path(
+    params(RoleQuery, PageParam),
+    responses((status = 200, description = "角色列表", body = R<TableDataInfo<RoleModel>>))
+)]
 #[get("/list")]
 pub async fn list_roles(
     query: web::Query<RoleQuery>,
@@ -130,6 +164,7 @@ pub async fn list_roles(
 }
 
 /// 获取角色详情
+#[utoipa::path(responses((status = 200, description = "角色详情", body = RData<RoleModel>)))]
 #[get("/{id}")]
 pub async fn get_role(
     path: web::Path<i64>,
@@ -148,6 +183,7 @@ pub async fn get_role(
 }
 
 /// 创建角色
+#[utoipa::path(responses((status = 200, description = "创建结果", body = R<NoData>)))]
 #[post("")]
 pub async fn create_role(
     req: web::Json<CreateOrUpdateRoleRequest>,
@@ -170,6 +206,7 @@ pub async fn create_role(
 }
 
 /// 更新角色
+#[utoipa::path(responses((status = 200, description = "更新结果", body = R<NoData>)))]
 #[put("")]
 pub async fn update_role(
     req: web::Json<CreateOrUpdateRoleRequest>,
@@ -190,6 +227,10 @@ pub async fn update_role(
 }
 
 /// 删除角色
+#[utoipa::path(
+    params(("ids" = String, Path, description = "角色ID，多个以逗号分隔")),
+    responses((status = 200, description = "删除结果", body = R<NoData>))
+)]
 #[delete("/{ids}")]
 pub async fn delete_roles(
     ids: web::Path<String>,
@@ -215,7 +256,80 @@ pub async fn delete_roles(
     }
 }
 
+/// 恢复已删除的角色（仅管理员）
+#[utoipa::path(
+    params(("ids" = String, Path, description = "角色ID，多个以逗号分隔")),
+    responses(
+        (status = 200, description = "恢复结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[put("/restore/{ids}")]
+pub async fn restore_roles(
+    ids: web::Path<String>,
+    req: HttpRequest,
+    role_service: web::Data<RoleServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    let role_ids: Vec<i64> = ids
+        .split(',')
+        .filter_map(|id| id.trim().parse::<i64>().ok())
+        .collect();
+    info!("恢复角色: ids={:?}", role_ids);
+
+    match role_service.restore_roles(role_ids).await {
+        Ok(count) => HttpResponse::Ok().json(R::<String>::ok_with_msg(&format!(
+            "恢复角色成功: {}个",
+            count
+        ))),
+        Err(e) => {
+            error!("恢复角色失败: {}", e);
+            HttpResponse::InternalServerError()
+                .json(R::<String>::fail(&format!("恢复角色失败: {}", e)))
+        }
+    }
+}
+
+/// 彻底删除已删除的角色（仅管理员）
+#[utoipa::path(
+    params(("ids" = String, Path, description = "角色ID，多个以逗号分隔")),
+    responses(
+        (status = 200, description = "删除结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[delete("/purge/{ids}")]
+pub async fn purge_roles(
+    ids: web::Path<String>,
+    req: HttpRequest,
+    role_service: web::Data<RoleServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    let role_ids: Vec<i64> = ids
+        .split(',')
+        .filter_map(|id| id.trim().parse::<i64>().ok())
+        .collect();
+    info!("彻底删除角色: ids={:?}", role_ids);
+
+    match role_service.purge_roles(role_ids).await {
+        Ok(count) => HttpResponse::Ok().json(R::<String>::ok_with_msg(&format!(
+            "彻底删除角色成功: {}个",
+            count
+        ))),
+        Err(e) => {
+            error!("彻底删除角色失败: {}", e);
+            HttpResponse::InternalServerError()
+                .json(R::<String>::fail(&format!("彻底删除角色失败: {}", e)))
+        }
+    }
+}
+
 /// 修改角色状态
+#[utoipa::path(responses((status = 200, description = "修改结果", body = R<NoData>)))]
 #[put("/changeStatus")]
 pub async fn change_role_status(
     req: web::Json<ChangeRoleStatusRequest>,
@@ -236,6 +350,10 @@ pub async fn change_role_status(
     }
 }
 /// 获取角色分配的用户列表
+#[utoipa::path(
+    params(RoleAuthUserQuery),
+    responses((status = 200, description = "已分配的用户", body = R<TableDataInfo<UserInfo>>))
+)]
 #[get("/allocatedList")]
 pub async fn get_role_auth_users(
     req: web::Query<RoleAuthUserQuery>,
@@ -257,6 +375,10 @@ pub async fn get_role_auth_users(
 }
 
 /// 获取角色未分配的用户列表
+#[utoipa::path(
+    params(RoleAuthUserQuery),
+    responses((status = 200, description = "未分配的用户", body = R<TableDataInfo<UserInfo>>))
+)]
 #[get("/unallocatedList")]
 pub async fn get_role_unallocated_users(
     req: web::Query<RoleAuthUserQuery>,
@@ -278,6 +400,10 @@ pub async fn get_role_unallocated_users(
 }
 
 /// 给角色分配用户
+#[utoipa::path(
+    params(AuthUserRoleRequest),
+    responses((status = 200, description = "授权结果", body = R<NoData>))
+)]
 #[put("/selectAll")]
 pub async fn auth_role_users(
     req: web::Query<AuthUserRoleRequest>,
@@ -309,6 +435,7 @@ pub async fn auth_role_users(
 }
 
 /// 取消角色用户授权
+#[utoipa::path(responses((status = 200, description = "取消结果", body = R<NoData>)))]
 #[put("/cancel")]
 pub async fn cancel_role_user(
     req: web::Json<CancelSingleUserRoleRequest>,
@@ -335,6 +462,10 @@ pub async fn cancel_role_user(
 }
 
 /// 批量取消角色用户授权
+#[utoipa::path(
+    params(AuthUserRoleRequest),
+    responses((status = 200, description = "取消结果", body = R<NoData>))
+)]
 #[put("/cancelAll")]
 pub async fn cancel_role_users(
     req: web::Query<AuthUserRoleRequest>,
@@ -366,6 +497,8 @@ pub async fn cancel_role_users(
     }
 }
 
+/// 修改角色数据范围
+#[utoipa::path(responses((status = 200, description = "修改结果", body = R<NoData>)))]
 #[put("/dataScope")]
 pub async fn data_scope(
     req: web::Json<CreateOrUpdateRoleRequest>,
@@ -391,6 +524,7 @@ pub async fn data_scope(
 }
 
 /// 获取部门树
+#[utoipa::path(responses((status = 200, description = "部门下拉树及角色已选部门", body = R<RoleDeptTree>)))]
 #[get("/deptTree/{role_id}")]
 pub async fn get_dept_tree(
     path: web::Path<i64>,
@@ -399,10 +533,10 @@ pub async fn get_dept_tree(
     let role_id = path.into_inner();
     match dept_service.get_dept_tree().await {
         Ok(dept_tree) => match dept_service.get_dept_ids_by_role_id(role_id).await {
-            Ok(dept_ids) => HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
-                "depts": dept_tree,
-                "checkedKeys": dept_ids,
-            }))),
+            Ok(dept_ids) => HttpResponse::Ok().json(R::ok_with_data(RoleDeptTree {
+                depts: dept_tree,
+                checked_keys: dept_ids,
+            })),
             Err(e) => {
                 error!("获取角色部门失败: {}", e);
                 HttpResponse::Ok().json(R::<String>::fail(&format!("获取角色部门失败: {}", e)))
@@ -469,6 +603,33 @@ async fn check_role_valid(
 
     return (true, None);
 }
+/// 角色管理接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_roles,
+    get_role,
+    create_role,
+    update_role,
+    delete_roles,
+    restore_roles,
+    purge_roles,
+    change_role_status,
+    data_scope,
+    get_dept_tree
+))]
+pub struct RoleApi;
+
+/// 角色授权用户接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_role_auth_users,
+    get_role_unallocated_users,
+    auth_role_users,
+    cancel_role_user,
+    cancel_role_users
+))]
+pub struct RoleAuthUserApi;
+
 /// 注册角色控制器路由
 pub fn load_role_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -476,6 +637,8 @@ pub fn load_role_routes(cfg: &mut web::ServiceConfig) {
             .service(list_roles)
             .service(create_role)
             .service(update_role)
+            .service(restore_roles)
+            .service(purge_roles)
             .service(delete_roles)
             .service(change_role_status)
             .service(
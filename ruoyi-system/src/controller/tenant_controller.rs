@@ -0,0 +1,322 @@
+// ruoyi-system/src/controller/tenant_controller.rs
+//! 租户管理控制器，只有超级管理员可以访问
+
+use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use log::{error, info};
+use ruoyi_common::utils::string::option_is_empty;
+use ruoyi_common::utils::time::deserialize_optional_datetime;
+use ruoyi_common::vo::{NoData, PageParam, RData, TableDataInfo, R};
+use ruoyi_framework::db::query::QueryCondition;
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::controller::admin_denied;
+use crate::entity::prelude::TenantModel;
+use crate::service::tenant_service::{TenantService, TenantServiceImpl};
+
+/// 租户查询参数
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::TenantColumn")]
+pub struct TenantQuery {
+    /// 租户名称
+    #[query(like)]
+    pub tenant_name: Option<String>,
+    /// 联系人
+    #[query(like)]
+    pub contact_user_name: Option<String>,
+    /// 联系电话
+    #[query(like)]
+    pub contact_phone: Option<String>,
+    /// 状态
+    #[query(eq)]
+    pub status: Option<String>,
+}
+
+/// 创建或更新租户请求
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrUpdateTenantRequest {
+    /// 租户ID
+    pub tenant_id: Option<i64>,
+    /// 租户名称
+    pub tenant_name: Option<String>,
+    /// 联系人
+    pub contact_user_name: Option<String>,
+    /// 联系电话
+    pub contact_phone: Option<String>,
+    /// 租户套餐ID
+    pub package_id: Option<i64>,
+    /// 过期时间
+    #[serde(default, deserialize_with = "deserialize_optional_datetime")]
+    pub expire_time: Option<DateTime<Utc>>,
+    /// 状态
+    pub status: Option<String>,
+    /// 备注
+    pub remark: Option<String>,
+    /// 租户管理员账号，仅创建时使用
+    pub user_name: Option<String>,
+    /// 租户管理员密码，仅创建时使用
+    pub password: Option<String>,
+}
+
+/// 修改租户状态请求
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeTenantStatusRequest {
+    /// 租户ID
+    pub tenant_id: i64,
+    /// 状态
+    pub status: String,
+}
+
+/// 查询租户列表
+#[utoipa::path(
+    params(TenantQuery, PageParam),
+    responses(
+        (status = 200, description = "租户列表", body = R<TableDataInfo<TenantModel>>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[get("/list")]
+pub async fn list_tenants(
+    req: HttpRequest,
+    query: web::Query<TenantQuery>,
+    page_param: web::Query<PageParam>,
+    tenant_service: web::Data<TenantServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    info!("查询租户列表: {:?}", query);
+
+    match tenant_service
+        .get_tenant_list(query.into_inner(), page_param.into_inner())
+        .await
+    {
+        Ok((tenants, total)) => HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
+            "rows": tenants,
+            "total": total
+        }))),
+        Err(e) => {
+            error!("查询租户列表失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("查询租户列表失败: {}", e)))
+        }
+    }
+}
+
+/// 查询租户详情
+#[utoipa::path(
+    responses(
+        (status = 200, description = "租户详情", body = RData<TenantModel>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[get("/{id}")]
+pub async fn get_tenant(
+    req: HttpRequest,
+    path: web::Path<i64>,
+    tenant_service: web::Data<TenantServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    let tenant_id = path.into_inner();
+    info!("查询租户: {}", tenant_id);
+
+    match tenant_service.get_tenant(tenant_id).await {
+        Ok(Some(tenant)) => HttpResponse::Ok().json(RData::ok(tenant)),
+        Ok(None) => HttpResponse::Ok().json(R::<String>::fail("租户不存在")),
+        Err(e) => {
+            error!("查询租户失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("查询租户失败: {}", e)))
+        }
+    }
+}
+
+/// 创建租户
+#[utoipa::path(
+    responses(
+        (status = 200, description = "创建结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[post("")]
+pub async fn create_tenant(
+    req: HttpRequest,
+    body: web::Json<CreateOrUpdateTenantRequest>,
+    tenant_service: web::Data<TenantServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    if let Some(msg) = check_tenant_valid("create", &body, &tenant_service).await {
+        return HttpResponse::Ok().json(R::<String>::fail(&msg));
+    }
+    info!(
+        "创建租户: {}",
+        body.tenant_name.as_deref().unwrap_or_default()
+    );
+
+    match tenant_service.create_tenant(body.into_inner()).await {
+        Ok(_) => HttpResponse::Ok().json(R::<String>::ok_with_msg("创建租户成功")),
+        Err(e) => {
+            error!("创建租户失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("创建租户失败: {}", e)))
+        }
+    }
+}
+
+/// 更新租户
+#[utoipa::path(
+    responses(
+        (status = 200, description = "更新结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[put("")]
+pub async fn update_tenant(
+    req: HttpRequest,
+    body: web::Json<CreateOrUpdateTenantRequest>,
+    tenant_service: web::Data<TenantServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    if let Some(msg) = check_tenant_valid("update", &body, &tenant_service).await {
+        return HttpResponse::Ok().json(R::<String>::fail(&msg));
+    }
+    info!("更新租户: {:?}", body.tenant_id);
+
+    match tenant_service.update_tenant(body.into_inner()).await {
+        Ok(_) => HttpResponse::Ok().json(R::<String>::ok_with_msg("更新租户成功")),
+        Err(e) => {
+            error!("更新租户失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("更新租户失败: {}", e)))
+        }
+    }
+}
+
+/// 修改租户状态
+#[utoipa::path(
+    responses(
+        (status = 200, description = "修改结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[put("/changeStatus")]
+pub async fn change_tenant_status(
+    req: HttpRequest,
+    body: web::Json<ChangeTenantStatusRequest>,
+    tenant_service: web::Data<TenantServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    info!(
+        "修改租户状态: id={}, status={}",
+        body.tenant_id, body.status
+    );
+
+    match tenant_service
+        .change_status(body.tenant_id, &body.status)
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(R::<String>::ok_with_msg("修改租户状态成功")),
+        Err(e) => {
+            error!("修改租户状态失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("修改租户状态失败: {}", e)))
+        }
+    }
+}
+
+/// 删除租户
+#[utoipa::path(
+    params(("ids" = String, Path, description = "租户ID，多个以逗号分隔")),
+    responses(
+        (status = 200, description = "删除结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[delete("/{ids}")]
+pub async fn delete_tenants(
+    req: HttpRequest,
+    ids: web::Path<String>,
+    tenant_service: web::Data<TenantServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    let tenant_ids: Vec<i64> = ids
+        .split(',')
+        .filter_map(|id| id.trim().parse::<i64>().ok())
+        .collect();
+    info!("删除租户: ids={:?}", tenant_ids);
+
+    match tenant_service.delete_tenants(tenant_ids).await {
+        Ok(_) => HttpResponse::Ok().json(R::<String>::ok_with_msg("删除租户成功")),
+        Err(e) => {
+            error!("删除租户失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("删除租户失败: {}", e)))
+        }
+    }
+}
+
+/// 校验租户参数，不合法时返回错误信息
+async fn check_tenant_valid(
+    action: &str,
+    req: &CreateOrUpdateTenantRequest,
+    tenant_service: &TenantServiceImpl,
+) -> Option<String> {
+    if action == "update" && req.tenant_id.is_none() {
+        return Some("租户ID不能为空".to_string());
+    }
+    if option_is_empty(&req.tenant_name) {
+        return Some("租户名称不能为空".to_string());
+    }
+    if action == "create" {
+        if option_is_empty(&req.user_name) {
+            return Some("租户管理员账号不能为空".to_string());
+        }
+        if option_is_empty(&req.password) {
+            return Some("租户管理员密码不能为空".to_string());
+        }
+    }
+    match tenant_service
+        .check_tenant_name_unique(req.tenant_name.as_ref().unwrap(), req.tenant_id)
+        .await
+    {
+        Ok(true) => None,
+        Ok(false) => Some("租户名称已存在".to_string()),
+        Err(e) => {
+            error!("检查租户名称是否重复失败: {}", e);
+            Some("检查租户名称是否重复失败".to_string())
+        }
+    }
+}
+
+/// 租户管理接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_tenants,
+    get_tenant,
+    create_tenant,
+    update_tenant,
+    change_tenant_status,
+    delete_tenants
+))]
+pub struct TenantApi;
+
+/// 注册租户路由
+pub fn load_tenant_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/tenant")
+            .service(list_tenants)
+            .service(create_tenant)
+            .service(update_tenant)
+            .service(change_tenant_status)
+            .service(delete_tenants)
+            .service(get_tenant),
+    );
+}
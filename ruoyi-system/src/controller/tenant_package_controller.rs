@@ -0,0 +1,305 @@
+// ruoyi-system/src/controller/tenant_package_controller.rs
+//! 租户套餐管理控制器，只有超级管理员可以访问
+
+use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse, Responder};
+use log::{error, info};
+use ruoyi_common::utils::string::option_is_empty;
+use ruoyi_common::vo::{NoData, PageParam, RData, RList, TableDataInfo, R};
+use ruoyi_framework::db::query::QueryCondition;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::controller::admin_denied;
+use crate::entity::prelude::TenantPackageModel;
+use crate::service::tenant_package_service::{TenantPackageService, TenantPackageServiceImpl};
+
+/// 租户套餐查询参数
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::TenantPackageColumn")]
+pub struct TenantPackageQuery {
+    /// 套餐名称
+    #[query(like)]
+    pub package_name: Option<String>,
+    /// 状态
+    #[query(eq)]
+    pub status: Option<String>,
+}
+
+/// 创建或更新租户套餐请求
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrUpdateTenantPackageRequest {
+    /// 套餐ID
+    pub package_id: Option<i64>,
+    /// 套餐名称
+    pub package_name: Option<String>,
+    /// 菜单树选择项是否关联显示
+    pub menu_check_strictly: Option<bool>,
+    /// 状态
+    pub status: Option<String>,
+    /// 备注
+    pub remark: Option<String>,
+    /// 套餐包含的菜单ID
+    pub menu_ids: Option<Vec<i64>>,
+}
+
+/// 租户套餐详情
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPackageDetail {
+    /// 套餐信息
+    #[serde(flatten)]
+    pub package: TenantPackageModel,
+    /// 套餐包含的菜单ID
+    pub menu_ids: Vec<i64>,
+}
+
+/// 查询租户套餐列表
+#[utoipa::path(
+    params(TenantPackageQuery, PageParam),
+    responses(
+        (status = 200, description = "租户套餐列表", body = R<TableDataInfo<TenantPackageModel>>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[get("/list")]
+pub async fn list_tenant_packages(
+    req: HttpRequest,
+    query: web::Query<TenantPackageQuery>,
+    page_param: web::Query<PageParam>,
+    tenant_package_service: web::Data<TenantPackageServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    info!("查询租户套餐列表: {:?}", query);
+
+    match tenant_package_service
+        .get_package_list(query.into_inner(), page_param.into_inner())
+        .await
+    {
+        Ok((packages, total)) => HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
+            "rows": packages,
+            "total": total
+        }))),
+        Err(e) => {
+            error!("查询租户套餐列表失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("查询租户套餐列表失败: {}", e)))
+        }
+    }
+}
+
+/// 查询可选的租户套餐
+#[utoipa::path(
+    responses(
+        (status = 200, description = "可选的租户套餐", body = RList<TenantPackageModel>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[get("/optionselect")]
+pub async fn tenant_package_options(
+    req: HttpRequest,
+    tenant_package_service: web::Data<TenantPackageServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    match tenant_package_service.get_package_options().await {
+        Ok(packages) => HttpResponse::Ok().json(RList::ok_with_data(packages)),
+        Err(e) => {
+            error!("查询可选租户套餐失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("查询可选租户套餐失败: {}", e)))
+        }
+    }
+}
+
+/// 查询租户套餐详情，包含套餐的菜单ID
+#[utoipa::path(
+    responses(
+        (status = 200, description = "租户套餐详情", body = RData<TenantPackageDetail>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[get("/{id}")]
+pub async fn get_tenant_package(
+    req: HttpRequest,
+    path: web::Path<i64>,
+    tenant_package_service: web::Data<TenantPackageServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    let package_id = path.into_inner();
+    info!("查询租户套餐: {}", package_id);
+
+    let package = match tenant_package_service.get_package(package_id).await {
+        Ok(Some(package)) => package,
+        Ok(None) => return HttpResponse::Ok().json(R::<String>::fail("租户套餐不存在")),
+        Err(e) => {
+            error!("查询租户套餐失败: {}", e);
+            return HttpResponse::Ok().json(R::<String>::fail(&format!("查询租户套餐失败: {}", e)));
+        }
+    };
+    match tenant_package_service
+        .get_package_menu_ids(package_id)
+        .await
+    {
+        Ok(menu_ids) => HttpResponse::Ok().json(RData::ok(TenantPackageDetail {
+            package,
+            menu_ids,
+        })),
+        Err(e) => {
+            error!("查询租户套餐菜单失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("查询租户套餐菜单失败: {}", e)))
+        }
+    }
+}
+
+/// 创建租户套餐
+#[utoipa::path(
+    responses(
+        (status = 200, description = "创建结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[post("")]
+pub async fn create_tenant_package(
+    req: HttpRequest,
+    body: web::Json<CreateOrUpdateTenantPackageRequest>,
+    tenant_package_service: web::Data<TenantPackageServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    if let Some(msg) = check_tenant_package_valid("create", &body, &tenant_package_service).await {
+        return HttpResponse::Ok().json(R::<String>::fail(&msg));
+    }
+    info!("创建租户套餐: {:?}", body);
+
+    match tenant_package_service
+        .create_package(body.into_inner())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(R::<String>::ok_with_msg("创建租户套餐成功")),
+        Err(e) => {
+            error!("创建租户套餐失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("创建租户套餐失败: {}", e)))
+        }
+    }
+}
+
+/// 更新租户套餐
+#[utoipa::path(
+    responses(
+        (status = 200, description = "更新结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[put("")]
+pub async fn update_tenant_package(
+    req: HttpRequest,
+    body: web::Json<CreateOrUpdateTenantPackageRequest>,
+    tenant_package_service: web::Data<TenantPackageServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    if let Some(msg) = check_tenant_package_valid("update", &body, &tenant_package_service).await {
+        return HttpResponse::Ok().json(R::<String>::fail(&msg));
+    }
+    info!("更新租户套餐: {:?}", body);
+
+    match tenant_package_service
+        .update_package(body.into_inner())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json(R::<String>::ok_with_msg("更新租户套餐成功")),
+        Err(e) => {
+            error!("更新租户套餐失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("更新租户套餐失败: {}", e)))
+        }
+    }
+}
+
+/// 删除租户套餐
+#[utoipa::path(
+    params(("ids" = String, Path, description = "套餐ID，多个以逗号分隔")),
+    responses(
+        (status = 200, description = "删除结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[delete("/{ids}")]
+pub async fn delete_tenant_packages(
+    req: HttpRequest,
+    ids: web::Path<String>,
+    tenant_package_service: web::Data<TenantPackageServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    let package_ids: Vec<i64> = ids
+        .split(',')
+        .filter_map(|id| id.trim().parse::<i64>().ok())
+        .collect();
+    info!("删除租户套餐: ids={:?}", package_ids);
+
+    match tenant_package_service.delete_packages(package_ids).await {
+        Ok(_) => HttpResponse::Ok().json(R::<String>::ok_with_msg("删除租户套餐成功")),
+        Err(e) => {
+            error!("删除租户套餐失败: {}", e);
+            HttpResponse::Ok().json(R::<String>::fail(&format!("删除租户套餐失败: {}", e)))
+        }
+    }
+}
+
+/// 校验租户套餐参数，不合法时返回错误信息
+async fn check_tenant_package_valid(
+    action: &str,
+    req: &CreateOrUpdateTenantPackageRequest,
+    tenant_package_service: &TenantPackageServiceImpl,
+) -> Option<String> {
+    if action == "update" && req.package_id.is_none() {
+        return Some("套餐ID不能为空".to_string());
+    }
+    if option_is_empty(&req.package_name) {
+        return Some("套餐名称不能为空".to_string());
+    }
+    match tenant_package_service
+        .check_package_name_unique(req.package_name.as_ref().unwrap(), req.package_id)
+        .await
+    {
+        Ok(true) => None,
+        Ok(false) => Some("套餐名称已存在".to_string()),
+        Err(e) => {
+            error!("检查套餐名称是否重复失败: {}", e);
+            Some("检查套餐名称是否重复失败".to_string())
+        }
+    }
+}
+
+/// 租户套餐管理接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_tenant_packages,
+    tenant_package_options,
+    get_tenant_package,
+    create_tenant_package,
+    update_tenant_package,
+    delete_tenant_packages
+))]
+pub struct TenantPackageApi;
+
+/// 注册租户套餐路由
+pub fn load_tenant_package_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/tenant/package")
+            .service(list_tenant_packages)
+            .service(tenant_package_options)
+            .service(create_tenant_package)
+            .service(update_tenant_package)
+            .service(delete_tenant_packages)
+            .service(get_tenant_package),
+    );
+}
@@ -8,45 +8,58 @@ use chrono::{DateTime, Utc};
 use log::{error, info};
 use ruoyi_common::utils::{string::option_is_empty, time::deserialize_optional_datetime};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
 use ruoyi_common::{
     utils::jwt::Claims,
-    vo::{PageParam, RList, R},
+    vo::{NoData, PageParam, RList, TableDataInfo, R},
 };
 
+use crate::controller::admin_denied;
+use crate::entity::prelude::{PostModel, RoleModel};
+use crate::entity::vo::{dept::DeptSelect, user::UserInfo};
 use crate::service::{
     dept_service::{DeptService, DeptServiceImpl},
     post_service::{PostService, PostServiceImpl},
     role_service::{RoleService, RoleServiceImpl},
     user_service::{UserService, UserServiceImpl},
 };
+use ruoyi_framework::db::query::QueryCondition;
 
 /// 用户查询参数
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::UserColumn")]
 pub struct UserQuery {
     /// 用户ID
+    #[query(eq)]
     pub user_id: Option<i64>,
 
     /// 部门ID
+    #[query(eq)]
     pub dept_id: Option<i64>,
 
     /// 用户账号
+    #[query(like)]
     pub user_name: Option<String>,
 
     /// 用户昵称
+    #[query(like)]
     pub nick_name: Option<String>,
 
-    /// 手机号码
+    /// 手机号码，加密存储只能精确匹配
+    #[query(blind_index)]
     pub phonenumber: Option<String>,
 
     /// 帐号状态（0正常 1停用）
+    #[query(eq)]
     pub status: Option<String>,
 
     /// 开始时间
     #[serde(rename = "params[beginTime]", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_optional_datetime")]
+    #[query(between = "begin_time,end_time")]
     pub begin_time: Option<DateTime<Utc>>,
 
     /// 结束时间
@@ -57,7 +70,7 @@ pub struct UserQuery {
 }
 
 /// 创建用户请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrUpdateUserRequest {
     /// 用户ID
@@ -86,7 +99,8 @@ pub struct CreateOrUpdateUserRequest {
     pub post_ids: Option<Vec<i64>>,
 }
 
-#[derive(Debug, Deserialize)]
+/// 重置密码请求
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ResetPasswordRequest {
     /// 用户id
@@ -95,16 +109,55 @@ pub struct ResetPasswordRequest {
     pub password: String,
 }
 
-#[derive(Debug, Deserialize)]
+/// 用户状态更新请求
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangeStatusRequest {
     /// 用户id
     pub user_id: i64,
-    /// 新密码
+    /// 新状态
     pub status: String,
 }
 
+/// 用户详情，包含可选的岗位和角色
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDetail {
+    /// 用户信息
+    pub data: UserInfo,
+    /// 所有岗位
+    pub posts: Vec<PostModel>,
+    /// 所有角色
+    pub roles: Vec<RoleModel>,
+    /// 用户的角色ID
+    pub role_ids: Vec<i64>,
+    /// 用户的岗位ID
+    pub post_ids: Vec<i64>,
+}
+
+/// 可选的岗位和角色
+#[derive(Serialize, ToSchema)]
+pub struct PostAndRole {
+    /// 所有岗位
+    pub posts: Vec<PostModel>,
+    /// 所有角色
+    pub roles: Vec<RoleModel>,
+}
+
+/// 用户及其已授权的角色
+#[derive(Serialize, ToSchema)]
+pub struct UserAuthRole {
+    /// 用户的角色
+    pub roles: Vec<RoleModel>,
+    /// 用户信息
+    pub user: UserInfo,
+}
+
 /// 获取用户列表
+#[utoipa::path(
+    params(UserQuery, PageParam),
+    responses((status = 200, description = "用户列表", body = R<TableDataInfo<UserInfo>>))
+)]
 #[get("/list")]
 pub async fn list_users(
     query: web::Query<UserQuery>,
@@ -127,6 +180,7 @@ pub async fn list_users(
 }
 
 /// 获取用户详情
+#[utoipa::path(responses((status = 200, description = "用户详情及可选的岗位和角色", body = R<UserDetail>)))]
 #[get("/{id}")]
 pub async fn get_user(
     path: web::Path<i64>,
@@ -145,13 +199,13 @@ pub async fn get_user(
                 let posts = post_service.get_posts_all().await;
                 let roles = role_service.get_roles_all().await;
                 let role_ids: Vec<i64> = user.roles.iter().map(|role| role.role_id).collect();
-                HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
-                    "data": user,
-                    "posts": posts,
-                    "roles": roles,
-                    "roleIds": role_ids,
-                    "postIds": post_ids,
-                })))
+                HttpResponse::Ok().json(R::ok_with_data(UserDetail {
+                    data: user,
+                    posts,
+                    roles,
+                    role_ids,
+                    post_ids,
+                }))
             } else {
                 HttpResponse::Ok().json(R::<String>::fail("用户不存在"))
             }
@@ -164,6 +218,7 @@ pub async fn get_user(
 }
 
 /// 创建用户
+#[utoipa::path(responses((status = 200, description = "创建结果", body = R<NoData>)))]
 #[post("")]
 pub async fn create_user(
     req: web::Json<CreateOrUpdateUserRequest>,
@@ -186,6 +241,7 @@ pub async fn create_user(
 }
 
 /// 更新用户
+#[utoipa::path(responses((status = 200, description = "更新结果", body = R<NoData>)))]
 #[put("")]
 pub async fn update_user(
     req: web::Json<CreateOrUpdateUserRequest>,
@@ -214,6 +270,13 @@ pub async fn update_user(
 }
 
 /// 删除用户
+#[utoipa::path(
+    params(("ids" = String, Path, description = "用户ID，多个以逗号分隔")),
+    responses(
+        (status = 200, description = "删除结果", body = R<NoData>),
+        (status = 400, description = "用户ID无效或包含当前用户", body = R<NoData>)
+    )
+)]
 #[delete("/{ids}")]
 pub async fn delete_user(
     ids: web::Path<String>,
@@ -254,7 +317,80 @@ pub async fn delete_user(
     }
 }
 
+/// 恢复已删除的用户（仅管理员）
+#[utoipa::path(
+    params(("ids" = String, Path, description = "用户ID，多个以逗号分隔")),
+    responses(
+        (status = 200, description = "恢复结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[put("/restore/{ids}")]
+pub async fn restore_users(
+    ids: web::Path<String>,
+    req: HttpRequest,
+    user_service: web::Data<UserServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    let user_ids: Vec<i64> = ids
+        .split(',')
+        .filter_map(|id| id.trim().parse::<i64>().ok())
+        .collect();
+    info!("恢复用户: {:?}", user_ids);
+
+    match user_service.restore_user_by_ids(user_ids).await {
+        Ok(count) => HttpResponse::Ok().json(R::<String>::ok_with_msg(&format!(
+            "恢复用户成功: {}个",
+            count
+        ))),
+        Err(e) => {
+            error!("恢复用户失败: {}", e);
+            HttpResponse::InternalServerError()
+                .json(R::<String>::fail(&format!("恢复用户失败: {}", e)))
+        }
+    }
+}
+
+/// 彻底删除已删除的用户（仅管理员）
+#[utoipa::path(
+    params(("ids" = String, Path, description = "用户ID，多个以逗号分隔")),
+    responses(
+        (status = 200, description = "删除结果", body = R<NoData>),
+        (status = 403, description = "非管理员", body = R<NoData>)
+    )
+)]
+#[delete("/purge/{ids}")]
+pub async fn purge_users(
+    ids: web::Path<String>,
+    req: HttpRequest,
+    user_service: web::Data<UserServiceImpl>,
+) -> impl Responder {
+    if let Some(resp) = admin_denied(&req) {
+        return resp;
+    }
+    let user_ids: Vec<i64> = ids
+        .split(',')
+        .filter_map(|id| id.trim().parse::<i64>().ok())
+        .collect();
+    info!("彻底删除用户: {:?}", user_ids);
+
+    match user_service.purge_user_by_ids(user_ids).await {
+        Ok(count) => HttpResponse::Ok().json(R::<String>::ok_with_msg(&format!(
+            "彻底删除用户成功: {}个",
+            count
+        ))),
+        Err(e) => {
+            error!("彻底删除用户失败: {}", e);
+            HttpResponse::InternalServerError()
+                .json(R::<String>::fail(&format!("彻底删除用户失败: {}", e)))
+        }
+    }
+}
+
 /// 重置密码
+#[utoipa::path(responses((status = 200, description = "重置结果", body = R<NoData>)))]
 #[put("/resetPwd")]
 pub async fn reset_password(
     req: web::Json<ResetPasswordRequest>,
@@ -276,6 +412,7 @@ pub async fn reset_password(
 }
 
 /// 修改状态
+#[utoipa::path(responses((status = 200, description = "修改结果", body = R<NoData>)))]
 #[put("/changeStatus")]
 pub async fn change_status(
     req: web::Json<ChangeStatusRequest>,
@@ -297,6 +434,7 @@ pub async fn change_status(
 }
 
 /// 部门树
+#[utoipa::path(responses((status = 200, description = "部门下拉树", body = RList<DeptSelect>)))]
 #[get("/deptTree")]
 pub async fn get_dept_tree(dept_service: web::Data<DeptServiceImpl>) -> impl Responder {
     match dept_service.get_dept_tree().await {
@@ -309,6 +447,7 @@ pub async fn get_dept_tree(dept_service: web::Data<DeptServiceImpl>) -> impl Res
 }
 
 /// 新增用户时获取岗位和角色
+#[utoipa::path(responses((status = 200, description = "可选的岗位和角色", body = R<PostAndRole>)))]
 #[get("/")]
 pub async fn get_post_and_role(
     post_service: web::Data<PostServiceImpl>,
@@ -316,12 +455,11 @@ pub async fn get_post_and_role(
 ) -> impl Responder {
     let posts = post_service.get_posts_all().await;
     let roles = role_service.get_roles_all().await;
-    HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
-        "posts": posts,
-        "roles": roles,
-    })))
+    HttpResponse::Ok().json(R::ok_with_data(PostAndRole { posts, roles }))
 }
 
+/// 获取用户已授权的角色
+#[utoipa::path(responses((status = 200, description = "用户及其角色", body = R<UserAuthRole>)))]
 #[get("/authRole/{userId}")]
 pub async fn auth_role(
     user_id: web::Path<i64>,
@@ -331,10 +469,10 @@ pub async fn auth_role(
     match user_service.get_user_by_id(user_id).await {
         Ok(user) => {
             if let Some(user) = user {
-                HttpResponse::Ok().json(R::ok_with_data(serde_json::json!({
-                    "roles": user.roles,
-                    "user": user,
-                })))
+                HttpResponse::Ok().json(R::ok_with_data(UserAuthRole {
+                    roles: user.roles.clone(),
+                    user,
+                }))
             } else {
                 HttpResponse::Ok().json(R::<String>::fail("用户不存在"))
             }
@@ -382,9 +520,51 @@ async fn check_user_valid(
             return (false, Some("检查用户名是否重复失败".to_string()));
         }
     }
+
+    // 手机号码重复性校验，手机号码加密存储，按盲索引比较
+    if let Some(phone) = req.phonenumber.as_deref().filter(|phone| !phone.is_empty()) {
+        match user_service.check_phone_unique(phone, req.user_id).await {
+            Ok(true) => {}
+            Ok(false) => return (false, Some("手机号码已存在".to_string())),
+            Err(e) => {
+                error!("检查手机号码是否重复失败: {}", e);
+                return (false, Some("检查手机号码是否重复失败".to_string()));
+            }
+        }
+    }
+
+    // 邮箱重复性校验
+    if let Some(email) = req.email.as_deref().filter(|email| !email.is_empty()) {
+        match user_service.check_email_unique(email, req.user_id).await {
+            Ok(true) => {}
+            Ok(false) => return (false, Some("邮箱账号已存在".to_string())),
+            Err(e) => {
+                error!("检查邮箱是否重复失败: {}", e);
+                return (false, Some("检查邮箱是否重复失败".to_string()));
+            }
+        }
+    }
     (true, None)
 }
 
+/// 用户管理接口文档
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_users,
+    get_user,
+    create_user,
+    update_user,
+    delete_user,
+    restore_users,
+    purge_users,
+    reset_password,
+    change_status,
+    get_dept_tree,
+    get_post_and_role,
+    auth_role
+))]
+pub struct UserApi;
+
 /// 注册用户控制器路由
 pub fn load_user_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -394,6 +574,8 @@ pub fn load_user_routes(cfg: &mut web::ServiceConfig) {
             .service(change_status)
             .service(create_user)
             .service(update_user)
+            .service(restore_users)
+            .service(purge_users)
             .service(delete_user)
             .service(get_dept_tree)
             .service(get_user)
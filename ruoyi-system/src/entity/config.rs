@@ -1,11 +1,14 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.10
 
 use chrono::{DateTime, Utc};
+use ruoyi_framework::db::audit::fill_audit_fields;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, ToSchema)]
 #[sea_orm(table_name = "sys_config")]
+#[schema(as = SysConfig)]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
     /// 参数主键
@@ -29,9 +32,21 @@ pub struct Model {
     pub update_time: Option<DateTime<Utc>>,
     /// 备注
     pub remark: Option<String>,
+    /// 乐观锁版本号
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 保存前填充创建者、创建时间、更新者、更新时间
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        fill_audit_fields(&mut self, insert);
+        Ok(self)
+    }
+}
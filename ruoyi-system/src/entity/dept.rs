@@ -2,18 +2,25 @@
 //! 部门实体定义
 
 use chrono::{DateTime, Utc};
+use ruoyi_framework::db::audit::fill_audit_fields;
+use ruoyi_framework::tenant::fill_tenant_id;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 部门实体
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize, ToSchema)]
 #[sea_orm(table_name = "sys_dept")]
+#[schema(as = SysDept)]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
     /// 部门ID
     #[sea_orm(primary_key)]
     pub dept_id: i64,
 
+    /// 租户ID
+    pub tenant_id: i64,
+
     /// 父部门ID
     pub parent_id: Option<i64>,
 
@@ -74,4 +81,15 @@ impl Related<Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 保存前填充创建者、创建时间、更新者、更新时间，新增时填充租户
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        fill_audit_fields(&mut self, insert);
+        fill_tenant_id(&mut self, insert);
+        Ok(self)
+    }
+}
\ No newline at end of file
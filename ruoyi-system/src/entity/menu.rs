@@ -3,11 +3,14 @@
 
 use chrono::{DateTime, Utc};
 use ruoyi_common::utils::string::serialize_i32_to_string;
+use ruoyi_framework::db::audit::fill_audit_fields;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 /// 系统菜单实体
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize, ToSchema)]
 #[sea_orm(table_name = "sys_menu")]
+#[schema(as = SysMenu)]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
     /// 菜单ID
@@ -29,9 +32,11 @@ pub struct Model {
     pub route_name: Option<String>,
     /// 是否为外链（0否 1是）
     #[serde(serialize_with = "serialize_i32_to_string")]
+    #[schema(value_type = Option<String>)]
     pub is_frame: Option<i32>,
     /// 是否缓存（0否 1是）
     #[serde(serialize_with = "serialize_i32_to_string")]
+    #[schema(value_type = Option<String>)]
     pub is_cache: Option<i32>,
     /// 菜单类型（M目录 C菜单 F按钮）
     #[sea_orm(column_type = "Char(Some(1))")]
@@ -56,6 +61,8 @@ pub struct Model {
     pub update_time: Option<DateTime<Utc>>,
     /// 备注
     pub remark: Option<String>,
+    /// 乐观锁版本号
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -76,4 +83,14 @@ impl Related<Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 保存前填充创建者、创建时间、更新者、更新时间
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        fill_audit_fields(&mut self, insert);
+        Ok(self)
+    }
+}
@@ -18,3 +18,7 @@ pub mod dict_data;
 pub mod dict_type;
 
 pub mod notice;
+
+pub mod tenant;
+pub mod tenant_package;
+pub mod tenant_package_menu;
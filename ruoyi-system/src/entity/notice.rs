@@ -1,16 +1,22 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.10
 
 use chrono::{DateTime, Utc};
+use ruoyi_framework::db::audit::fill_audit_fields;
+use ruoyi_framework::tenant::fill_tenant_id;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use ruoyi_common::utils::string::serialize_vec_u8_to_string;
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, ToSchema)]
 #[sea_orm(table_name = "sys_notice")]
+#[schema(as = SysNotice)]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
     /// 公告ID
     #[sea_orm(primary_key)]
     pub notice_id: i32,
+    /// 租户ID
+    pub tenant_id: i64,
     /// 公告标题
     pub notice_title: String,
     /// 公告类型
@@ -18,6 +24,7 @@ pub struct Model {
     /// 公告内容
     #[sea_orm(column_type = "custom(\"longblob\")", nullable)]
     #[serde(serialize_with = "serialize_vec_u8_to_string")]
+    #[schema(value_type = Option<String>)]
     pub notice_content: Option<Vec<u8>>,
     /// 公告状态
     pub status: Option<String>,
@@ -36,4 +43,15 @@ pub struct Model {
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 保存前填充创建者、创建时间、更新者、更新时间，新增时填充租户
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        fill_audit_fields(&mut self, insert);
+        fill_tenant_id(&mut self, insert);
+        Ok(self)
+    }
+}
@@ -1,16 +1,22 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.10
 
 use chrono::{DateTime, Utc};
+use ruoyi_framework::db::audit::fill_audit_fields;
+use ruoyi_framework::tenant::fill_tenant_id;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, ToSchema)]
 #[sea_orm(table_name = "sys_post")]
+#[schema(as = SysPost)]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
     /// 岗位ID
     #[sea_orm(primary_key)]
     pub post_id: i64,
+    /// 租户ID
+    pub tenant_id: i64,
     /// 岗位编码
     pub post_code: String,
     /// 岗位名称
@@ -34,4 +40,15 @@ pub struct Model {
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 保存前填充创建者、创建时间、更新者、更新时间，新增时填充租户
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        fill_audit_fields(&mut self, insert);
+        fill_tenant_id(&mut self, insert);
+        Ok(self)
+    }
+}
@@ -60,6 +60,21 @@ pub use super::notice::{
     Model as NoticeModel,
 };
 
+pub use super::tenant::{
+    ActiveModel as TenantActiveModel, Column as TenantColumn, Entity as TenantEntity,
+    Model as TenantModel,
+};
+
+pub use super::tenant_package::{
+    ActiveModel as TenantPackageActiveModel, Column as TenantPackageColumn,
+    Entity as TenantPackageEntity, Model as TenantPackageModel,
+};
+
+pub use super::tenant_package_menu::{
+    ActiveModel as TenantPackageMenuActiveModel, Column as TenantPackageMenuColumn,
+    Entity as TenantPackageMenuEntity, Model as TenantPackageMenuModel,
+};
+
 pub use ruoyi_framework::logger::entity::oper_log::{
     ActiveModel as OperLogActiveModel, Column as OperLogColumn, Entity as OperLogEntity,
     Model as OperLogModel,
@@ -69,3 +84,8 @@ pub use ruoyi_framework::logger::entity::login_info::{
     ActiveModel as LoginInfoActiveModel, Column as LoginInfoColumn, Entity as LoginInfoEntity,
     Model as LoginInfoModel,
 };
+
+pub use ruoyi_framework::db::history::change_log::{
+    ActiveModel as ChangeLogActiveModel, Column as ChangeLogColumn, Entity as ChangeLogEntity,
+    Model as ChangeLogModel,
+};
@@ -2,17 +2,23 @@
 //! 系统角色实体定义
 
 use chrono::{DateTime, Utc};
+use ruoyi_framework::db::audit::fill_audit_fields;
+use ruoyi_framework::tenant::fill_tenant_id;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 系统角色实体
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize, ToSchema)]
 #[sea_orm(table_name = "sys_role")]
+#[schema(as = SysRole)]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
     /// 角色ID
     #[sea_orm(primary_key)]
     pub role_id: i64,
+    /// 租户ID
+    pub tenant_id: i64,
     /// 角色名称
     pub role_name: String,
     /// 角色权限字符串
@@ -42,6 +48,8 @@ pub struct Model {
     pub update_time: Option<DateTime<Utc>>,
     /// 备注
     pub remark: Option<String>,
+    /// 乐观锁版本号
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -56,4 +64,15 @@ impl Related<crate::entity::role_menu::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 保存前填充创建者、创建时间、更新者、更新时间，新增时填充租户
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        fill_audit_fields(&mut self, insert);
+        fill_tenant_id(&mut self, insert);
+        Ok(self)
+    }
+}
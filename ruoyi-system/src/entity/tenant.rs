@@ -0,0 +1,68 @@
+// ruoyi-system/src/entity/tenant.rs
+//! 租户实体定义
+
+use chrono::{DateTime, Utc};
+use ruoyi_framework::db::audit::fill_audit_fields;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 租户实体
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize, ToSchema)]
+#[sea_orm(table_name = "sys_tenant")]
+#[schema(as = SysTenant)]
+#[serde(rename_all = "camelCase")]
+pub struct Model {
+    /// 租户ID
+    #[sea_orm(primary_key)]
+    pub tenant_id: i64,
+    /// 租户名称
+    pub tenant_name: String,
+    /// 联系人
+    pub contact_user_name: Option<String>,
+    /// 联系电话
+    pub contact_phone: Option<String>,
+    /// 租户套餐ID
+    pub package_id: Option<i64>,
+    /// 过期时间，为空时永不过期
+    pub expire_time: Option<DateTime<Utc>>,
+    /// 状态（0正常 1停用）
+    #[sea_orm(column_type = "Char(Some(1))")]
+    pub status: String,
+    /// 删除标志（0代表存在 2代表删除）
+    #[sea_orm(column_type = "Char(Some(1))")]
+    pub del_flag: Option<String>,
+    /// 创建者
+    pub create_by: Option<String>,
+    /// 创建时间
+    pub create_time: Option<DateTime<Utc>>,
+    /// 更新者
+    pub update_by: Option<String>,
+    /// 更新时间
+    pub update_time: Option<DateTime<Utc>>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+impl Model {
+    /// 租户是否已过期
+    pub fn is_expired(&self) -> bool {
+        self.expire_time
+            .is_some_and(|expire_time| expire_time < Utc::now())
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 保存前填充创建者、创建时间、更新者、更新时间
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        fill_audit_fields(&mut self, insert);
+        Ok(self)
+    }
+}
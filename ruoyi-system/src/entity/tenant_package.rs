@@ -0,0 +1,63 @@
+// ruoyi-system/src/entity/tenant_package.rs
+//! 租户套餐实体定义
+
+use chrono::{DateTime, Utc};
+use ruoyi_framework::db::audit::fill_audit_fields;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 租户套餐实体，套餐决定租户可以使用的菜单
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize, ToSchema)]
+#[sea_orm(table_name = "sys_tenant_package")]
+#[schema(as = SysTenantPackage)]
+#[serde(rename_all = "camelCase")]
+pub struct Model {
+    /// 套餐ID
+    #[sea_orm(primary_key)]
+    pub package_id: i64,
+    /// 套餐名称
+    pub package_name: String,
+    /// 菜单树选择项是否关联显示
+    pub menu_check_strictly: Option<bool>,
+    /// 状态（0正常 1停用）
+    #[sea_orm(column_type = "Char(Some(1))")]
+    pub status: String,
+    /// 删除标志（0代表存在 2代表删除）
+    #[sea_orm(column_type = "Char(Some(1))")]
+    pub del_flag: Option<String>,
+    /// 创建者
+    pub create_by: Option<String>,
+    /// 创建时间
+    pub create_time: Option<DateTime<Utc>>,
+    /// 更新者
+    pub update_by: Option<String>,
+    /// 更新时间
+    pub update_time: Option<DateTime<Utc>>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "crate::entity::tenant_package_menu::Entity")]
+    TenantPackageMenu,
+}
+
+impl Related<crate::entity::tenant_package_menu::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TenantPackageMenu.def()
+    }
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 保存前填充创建者、创建时间、更新者、更新时间
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        fill_audit_fields(&mut self, insert);
+        Ok(self)
+    }
+}
@@ -0,0 +1,42 @@
+// ruoyi-system/src/entity/tenant_package_menu.rs
+//! 租户套餐菜单关联实体定义
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 租户套餐和菜单关联表
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "sys_tenant_package_menu")]
+pub struct Model {
+    /// 套餐ID
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub package_id: i64,
+
+    /// 菜单ID
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub menu_id: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::tenant_package::Entity",
+        from = "Column::PackageId",
+        to = "super::tenant_package::Column::PackageId"
+    )]
+    TenantPackage,
+    #[sea_orm(
+        belongs_to = "super::menu::Entity",
+        from = "Column::MenuId",
+        to = "super::menu::Column::MenuId"
+    )]
+    Menu,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Related<super::tenant_package::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TenantPackage.def()
+    }
+}
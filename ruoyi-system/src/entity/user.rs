@@ -2,16 +2,25 @@
 //! 系统用户实体定义
 
 use chrono::{DateTime, Utc};
+use ruoyi_framework::db::audit::fill_audit_fields;
+use ruoyi_framework::db::encryption::{
+    fill_blind_indexes, Encrypted, EncryptedEntity, EncryptedField,
+};
+use ruoyi_framework::tenant::fill_tenant_id;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// 系统用户实体
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize, ToSchema)]
 #[sea_orm(table_name = "sys_user")]
+#[schema(as = SysUser)]
 pub struct Model {
     /// 用户ID
     #[sea_orm(primary_key)]
     pub user_id: i64,
+    /// 租户ID
+    pub tenant_id: i64,
     /// 部门ID
     pub dept_id: Option<i64>,
     /// 用户账号
@@ -20,10 +29,16 @@ pub struct Model {
     pub nick_name: String,
     /// 用户类型
     pub user_type: Option<String>,
-    /// 邮箱
-    pub email: Option<String>,
-    /// 手机号码
-    pub phonenumber: Option<String>,
+    /// 邮箱（加密存储）
+    pub email: Option<Encrypted>,
+    /// 邮箱盲索引
+    #[serde(skip)]
+    pub email_index: Option<String>,
+    /// 手机号码（加密存储）
+    pub phonenumber: Option<Encrypted>,
+    /// 手机号码盲索引
+    #[serde(skip)]
+    pub phonenumber_index: Option<String>,
     /// 性别
     #[sea_orm(column_type = "Char(Some(1))")]
     pub sex: Option<String>,
@@ -81,4 +96,25 @@ impl Related<super::user_role::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+impl EncryptedEntity for Entity {
+    fn encrypted_fields() -> Vec<EncryptedField<Column>> {
+        vec![
+            EncryptedField::new(Column::Email).with_blind_index(Column::EmailIndex),
+            EncryptedField::new(Column::Phonenumber).with_blind_index(Column::PhonenumberIndex),
+        ]
+    }
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    /// 保存前填充创建者、创建时间、更新者、更新时间和盲索引，新增时填充租户
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        fill_audit_fields(&mut self, insert);
+        fill_tenant_id(&mut self, insert);
+        fill_blind_indexes(&mut self, insert)?;
+        Ok(self)
+    }
+}
@@ -2,16 +2,18 @@ use std::sync::{Arc, RwLock};
 
 use ruoyi_common::utils::tree::TreeNode;
 use serde::Serialize;
+use utoipa::ToSchema;
 
 use crate::entity::prelude::*;
 
-#[derive(Clone, Serialize)]
+#[derive(Clone, Serialize, ToSchema)]
 pub struct DeptSelect {
     pub id: i64,
     #[serde(skip)]
     pub parent_id: i64,
     pub label: String,
     pub disabled: bool,
+    #[schema(value_type = Vec<DeptSelect>, no_recursion)]
     pub children: Arc<RwLock<Vec<Arc<DeptSelect>>>>,
 }
 
@@ -1,7 +1,9 @@
+use utoipa::ToSchema;
+
 use crate::entity::menu::Model as MenuModel;
 
 /// 路由显示信息
-#[derive(serde::Serialize, Debug, Clone)]
+#[derive(serde::Serialize, Debug, Clone, ToSchema)]
 pub struct RouterMeta {
     /// 设置该路由在侧边栏和面包屑中展示的名字
     pub title: String,
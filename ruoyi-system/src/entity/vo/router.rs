@@ -2,13 +2,14 @@ use std::sync::{Arc, RwLock};
 
 use ruoyi_common::utils::string::option_is_empty;
 use ruoyi_common::utils::tree::{children_is_empty, TreeNode};
+use utoipa::ToSchema;
 
 use crate::entity::menu::Model as MenuModel;
 
 use super::meta::RouterMeta;
 
 /// 路由前端对象
-#[derive(serde::Serialize, Debug, Clone)]
+#[derive(serde::Serialize, Debug, Clone, ToSchema)]
 pub struct RouterVo {
     /// 路由id
     #[serde(skip)]
@@ -36,11 +37,13 @@ pub struct RouterVo {
     pub query: Option<String>,
     /// 当你一个路由下面的 children 声明的路由大于1个时，自动会变成嵌套的模式
     #[serde(rename = "alwaysShow", skip_serializing_if = "always_show_is_none")]
+    #[schema(value_type = Option<bool>)]
     pub always_show: Arc<RwLock<Option<bool>>>,
     /// 其他元素
     pub meta: RouterMeta,
     /// 子路由
     #[serde(skip_serializing_if = "children_is_empty")]
+    #[schema(value_type = Vec<RouterVo>, no_recursion)]
     pub children: Arc<RwLock<Vec<Arc<RouterVo>>>>,
 }
 pub fn always_show_is_none(always_show: &Arc<RwLock<Option<bool>>>) -> bool {
@@ -1,11 +1,12 @@
 use chrono::{DateTime, Utc};
 use ruoyi_common::utils::time::{deserialize_optional_datetime, serialize_optional_datetime};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::entity::prelude::*;
 
 /// 用户实体
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserInfo {
     /// 管理员
@@ -97,8 +98,8 @@ impl UserInfo {
             user_name: model.user_name.clone(),
             nick_name: model.nick_name.clone(),
             user_type: model.user_type.clone(),
-            email: model.email.clone(),
-            phonenumber: model.phonenumber.clone(),
+            email: model.email.clone().map(String::from),
+            phonenumber: model.phonenumber.clone().map(String::from),
             sex: model.sex.clone(),
             avatar: model.avatar.clone(),
             password: model.password.clone(),
@@ -150,7 +151,7 @@ pub struct SafeUserInfo {
     pub create_time: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserOnline {
     // 会话编号
@@ -1,6 +1,11 @@
 // ruoyi-system/src/lib.rs
 //! 若依管理系统的系统模块
 use actix_web::web;
+use ruoyi_framework::db::encryption::{backfill_blind_indexes, rotate_encrypted_fields};
+use ruoyi_framework::db::migration::Migration;
+use ruoyi_framework::migration_sql;
+use sea_orm::{ConnectionTrait, DbErr};
+use utoipa::OpenApi;
 
 pub mod controller;
 pub mod entity;
@@ -22,14 +27,81 @@ pub fn register_routes(cfg: &mut web::ServiceConfig) {
             .configure(controller::config_controller::load_config_routes)
             .configure(controller::dict_data_controller::load_dict_data_routes)
             .configure(controller::dict_type_controller::load_dict_type_routes)
-            .configure(controller::notice_controller::load_notice_routes),
+            .configure(controller::notice_controller::load_notice_routes)
+            // 套餐路由需要在租户路由之前注册，避免被 /tenant/{id} 匹配
+            .configure(controller::tenant_package_controller::load_tenant_package_routes)
+            .configure(controller::tenant_controller::load_tenant_routes),
     )
     .service(
         web::scope("/monitor")
             .configure(controller::login_info_controller::load_login_info_routes)
             .configure(controller::oper_log_controller::load_oper_log_routes)
+            .configure(controller::change_log_controller::load_change_log_routes)
             .configure(controller::monitor::cache_controller::load_cache_routes)
             .configure(controller::monitor::server_controller::load_server_routes)
             .configure(controller::monitor::user_online_controller::load_user_online_routes),
     );
 }
+
+/// 系统模块接口文档，路径与 [`register_routes`] 注册的路由一致
+#[derive(OpenApi)]
+#[openapi(nest(
+    (path = "/system/user", api = controller::user_controller::UserApi, tags = ["用户管理"]),
+    (path = "/system/role", api = controller::role_controller::RoleApi, tags = ["角色管理"]),
+    (path = "/system/role/authUser", api = controller::role_controller::RoleAuthUserApi, tags = ["角色管理"]),
+    (path = "/system/menu", api = controller::menu_controller::MenuApi, tags = ["菜单管理"]),
+    (path = "/system/dept", api = controller::dept_controller::DeptApi, tags = ["部门管理"]),
+    (path = "/system/post", api = controller::post_controller::PostApi, tags = ["岗位管理"]),
+    (path = "/system/config", api = controller::config_controller::ConfigApi, tags = ["参数设置"]),
+    (path = "/system/dict/data", api = controller::dict_data_controller::DictDataApi, tags = ["字典数据"]),
+    (path = "/system/dict/type", api = controller::dict_type_controller::DictTypeApi, tags = ["字典类型"]),
+    (path = "/system/notice", api = controller::notice_controller::NoticeApi, tags = ["通知公告"]),
+    (path = "/system/tenant/package", api = controller::tenant_package_controller::TenantPackageApi, tags = ["租户套餐"]),
+    (path = "/system/tenant", api = controller::tenant_controller::TenantApi, tags = ["租户管理"]),
+    (path = "/monitor/logininfor", api = controller::login_info_controller::LoginInfoApi, tags = ["登录日志"]),
+    (path = "/monitor/operlog", api = controller::oper_log_controller::OperLogApi, tags = ["操作日志"]),
+    (path = "/monitor/changelog", api = controller::change_log_controller::ChangeLogApi, tags = ["变更历史"]),
+    (path = "/monitor/cache", api = controller::monitor::cache_controller::CacheApi, tags = ["缓存监控"]),
+    (path = "/monitor/server", api = controller::monitor::server_controller::ServerApi, tags = ["服务监控"]),
+    (path = "/monitor/online", api = controller::monitor::user_online_controller::UserOnlineApi, tags = ["在线用户"]),
+))]
+struct SystemApi;
+
+/// 系统模块的接口文档，包含登录认证接口
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = SystemApi::openapi();
+    openapi.merge(controller::login_controller::LoginApi::openapi());
+    openapi
+}
+
+/// 系统模块的数据库迁移
+pub fn migrations() -> Vec<Migration> {
+    vec![
+        Migration::schema("system", 1, "init", migration_sql!("schema/V1__init")),
+        Migration::seed("system", 1, "init_data", migration_sql!("seed/V1__init_data")),
+        Migration::schema(
+            "system",
+            2,
+            "optimistic_lock",
+            migration_sql!("schema/V2__optimistic_lock"),
+        ),
+        Migration::schema("system", 3, "tenant", migration_sql!("schema/V3__tenant")),
+        Migration::schema(
+            "system",
+            4,
+            "field_encryption",
+            migration_sql!("schema/V4__field_encryption"),
+        ),
+        Migration::schema("system", 5, "change_log", migration_sql!("schema/V5__change_log")),
+    ]
+}
+
+/// 用当前密钥重新加密系统模块的加密字段并重建盲索引，返回更新的记录数
+pub async fn rotate_encryption<C: ConnectionTrait>(db: &C) -> Result<u64, DbErr> {
+    rotate_encrypted_fields::<entity::prelude::UserEntity, _>(db).await
+}
+
+/// 补齐系统模块历史数据缺失的盲索引，返回更新的记录数
+pub async fn backfill_encryption_indexes<C: ConnectionTrait>(db: &C) -> Result<u64, DbErr> {
+    backfill_blind_indexes::<entity::prelude::UserEntity, _>(db).await
+}
@@ -0,0 +1,56 @@
+// ruoyi-system/src/repository/change_log_repository.rs
+//! 实体变更历史仓库实现
+
+use crate::entity::prelude::*;
+use async_trait::async_trait;
+use ruoyi_common::Result;
+use ruoyi_framework::db::repository::BaseRepository;
+use ruoyi_framework::db::transaction::current_connection;
+use sea_orm::{ColumnTrait, DatabaseConnection, QueryFilter, QueryOrder};
+use std::sync::Arc;
+
+/// 实体变更历史仓库特征
+#[async_trait]
+pub trait ChangeLogRepository: Send + Sync {
+    /// 获取记录的变更历史，按变更时间倒序
+    async fn find_by_entity(
+        &self,
+        entity_name: &str,
+        entity_id: &str,
+    ) -> Result<Vec<ChangeLogModel>>;
+}
+
+/// 实体变更历史仓库实现
+pub struct ChangeLogRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+    repository: BaseRepository<ChangeLogEntity, ChangeLogActiveModel>,
+}
+
+impl ChangeLogRepositoryImpl {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db: db.clone(),
+            repository: BaseRepository::new(db.as_ref().clone())
+                .with_tenant(ChangeLogColumn::TenantId),
+        }
+    }
+}
+
+#[async_trait]
+impl ChangeLogRepository for ChangeLogRepositoryImpl {
+    async fn find_by_entity(
+        &self,
+        entity_name: &str,
+        entity_id: &str,
+    ) -> Result<Vec<ChangeLogModel>> {
+        Ok(self
+            .repository
+            .select()
+            .filter(ChangeLogColumn::EntityName.eq(entity_name))
+            .filter(ChangeLogColumn::EntityId.eq(entity_id))
+            .order_by_desc(ChangeLogColumn::ChangeTime)
+            .order_by_desc(ChangeLogColumn::ChangeId)
+            .all(&current_connection(&self.db))
+            .await?)
+    }
+}
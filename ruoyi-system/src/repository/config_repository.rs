@@ -1,15 +1,15 @@
 /// 配置仓库
 use crate::entity::prelude::*;
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::{vo::PageParam, Result};
-use ruoyi_framework::{
-    db::repository::{BaseRepository, Repository},
-    web::tls::get_sync_user_context,
+use ruoyi_framework::db::history::ChangeHistory;
+use ruoyi_framework::db::repository::{
+    unset_zero_primary_key, BaseRepository, OptimisticLock, Repository,
 };
+use ruoyi_framework::db::transaction::current_connection;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, PaginatorTrait, QueryFilter,
-    QueryOrder, Set,
+    QueryOrder,
 };
 use std::sync::Arc;
 
@@ -49,7 +49,9 @@ impl ConfigRepositoryImpl {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
             db: db.clone(),
-            repository: BaseRepository::new(db.as_ref().clone()),
+            repository: BaseRepository::new(db.as_ref().clone())
+                .with_optimistic_lock(OptimisticLock::version(ConfigColumn::Version))
+                .with_change_history(ChangeHistory::new()),
         }
     }
 }
@@ -72,28 +74,21 @@ impl ConfigRepository for ConfigRepositoryImpl {
         Ok((configs, total))
     }
     async fn get_all_configs(&self) -> Result<Vec<ConfigModel>> {
-        Ok(self.repository.select().all(self.db.as_ref()).await?)
+        Ok(self
+            .repository
+            .select()
+            .all(&current_connection(&self.db))
+            .await?)
     }
     async fn get_config_by_id(&self, config_id: i32) -> Result<Option<ConfigModel>> {
         Ok(self.repository.find_by_id(config_id).await?)
     }
     async fn create_config(&self, mut config: ConfigActiveModel) -> Result<ConfigModel> {
-        if let Some(user_content) = get_sync_user_context() {
-            config.create_by = Set(Some(user_content.user_name.clone()));
-            config.update_by = Set(Some(user_content.user_name.clone()));
-        }
-        let now = Utc::now();
-        config.create_time = Set(Some(now));
-        config.update_time = Set(Some(now));
-        Ok(config.insert(self.db.as_ref()).await?)
+        unset_zero_primary_key(&mut config);
+        Ok(config.insert(&current_connection(&self.db)).await?)
     }
-    async fn update_config(&self, mut config: ConfigActiveModel) -> Result<ConfigModel> {
-        if let Some(user_content) = get_sync_user_context() {
-            config.update_by = Set(Some(user_content.user_name.clone()));
-        }
-        let now = Utc::now();
-        config.update_time = Set(Some(now));
-        Ok(config.update(self.db.as_ref()).await?)
+    async fn update_config(&self, config: ConfigActiveModel) -> Result<ConfigModel> {
+        Ok(self.repository.update(config).await?)
     }
     async fn delete_configs(&self, config_ids: Vec<i32>) -> Result<u64> {
         Ok(self.repository.delete_by_ids(config_ids).await?)
@@ -108,7 +103,7 @@ impl ConfigRepository for ConfigRepositoryImpl {
         if let Some(config_id) = config_id {
             query = query.filter(ConfigColumn::ConfigId.ne(config_id));
         }
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
     async fn check_config_key_unique(
@@ -121,7 +116,7 @@ impl ConfigRepository for ConfigRepositoryImpl {
         if let Some(config_id) = config_id {
             query = query.filter(ConfigColumn::ConfigId.ne(config_id));
         }
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
     async fn get_config_by_key(&self, config_key: &str) -> Result<String> {
@@ -129,7 +124,7 @@ impl ConfigRepository for ConfigRepositoryImpl {
             .repository
             .select()
             .filter(ConfigColumn::ConfigKey.eq(config_key))
-            .one(self.db.as_ref())
+            .one(&current_connection(&self.db))
             .await?;
         if let Some(config) = config {
             Ok(config.config_value.unwrap_or_default())
@@ -3,12 +3,12 @@
 
 use crate::entity::prelude::*;
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::Result;
-use ruoyi_framework::{
-    db::repository::{BaseRepository, Repository},
-    web::tls::get_sync_user_context,
+use ruoyi_framework::db::history::ChangeHistory;
+use ruoyi_framework::db::repository::{
+    unset_zero_primary_key, BaseRepository, Repository, SoftDelete,
 };
+use ruoyi_framework::db::transaction::current_connection;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
     QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
@@ -68,7 +68,11 @@ impl DeptRepositoryImpl {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
             db: db.clone(),
-            repository: BaseRepository::new(db.as_ref().clone()),
+            // 删除标志：0代表存在 2代表删除
+            repository: BaseRepository::new(db.as_ref().clone())
+                .with_soft_delete(SoftDelete::new(DeptColumn::DelFlag, "2", "0"))
+                .with_tenant(DeptColumn::TenantId)
+                .with_change_history(ChangeHistory::new()),
         }
     }
 }
@@ -87,7 +91,7 @@ impl DeptRepository for DeptRepositoryImpl {
         query = query
             .order_by_asc(DeptColumn::ParentId)
             .order_by_asc(DeptColumn::OrderNum);
-        let depts = query.all(self.db.as_ref()).await?;
+        let depts = query.all(&current_connection(&self.db)).await?;
         Ok(depts)
     }
 
@@ -95,8 +99,7 @@ impl DeptRepository for DeptRepositoryImpl {
         let depts = self
             .repository
             .select()
-            .filter(DeptColumn::DelFlag.eq("0"))
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
         Ok(depts)
     }
@@ -110,7 +113,6 @@ impl DeptRepository for DeptRepositoryImpl {
         let mut query = self
             .repository
             .select()
-            .filter(DeptColumn::DelFlag.eq("0"))
             .filter(DeptColumn::DeptName.eq(dept_name))
             .filter(DeptColumn::ParentId.eq(parent_id));
 
@@ -118,14 +120,16 @@ impl DeptRepository for DeptRepositoryImpl {
             query = query.filter(DeptColumn::DeptId.ne(exclude_id));
         }
 
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
 
     async fn has_child_by_dept_id(&self, dept_id: i64) -> Result<bool> {
-        let count = DeptEntity::find()
+        let count = self
+            .repository
+            .select()
             .filter(DeptColumn::ParentId.eq(dept_id))
-            .count(self.db.as_ref())
+            .count(&current_connection(&self.db))
             .await?;
         Ok(count > 0)
     }
@@ -134,38 +138,25 @@ impl DeptRepository for DeptRepositoryImpl {
         let count = UserEntity::find()
             .filter(UserColumn::DeptId.eq(dept_id))
             .filter(UserColumn::DelFlag.eq("0"))
-            .count(self.db.as_ref())
+            .count(&current_connection(&self.db))
             .await?;
         Ok(count > 0)
     }
 
     async fn create(&self, mut dept: DeptActiveModel) -> Result<DeptModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            dept.create_by = Set(Some(user_context.user_name.to_string()));
-            dept.update_by = Set(Some(user_context.user_name.to_string()));
-        }
-
-        let now = Utc::now();
-        dept.create_time = Set(Some(now));
-        dept.update_time = Set(Some(now));
         dept.del_flag = Set(Some("0".to_string()));
         // 插入数据库
-        Ok(dept.insert(self.db.as_ref()).await?)
+        unset_zero_primary_key(&mut dept);
+        Ok(dept.insert(&current_connection(&self.db)).await?)
     }
 
-    async fn update(&self, mut dept: DeptActiveModel) -> Result<DeptModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            dept.update_by = Set(Some(user_context.user_name.to_string()));
-        }
-
-        let now = Utc::now();
-        dept.update_time = Set(Some(now));
+    async fn update(&self, dept: DeptActiveModel) -> Result<DeptModel> {
         // 更新数据库
-        Ok(dept.update(self.db.as_ref()).await?)
+        Ok(self.repository.update(dept).await?)
     }
 
-    async fn delete_by_id(&self, mut dept: DeptActiveModel) -> Result<u64> {
-        let tx = self.db.begin().await?;
+    async fn delete_by_id(&self, dept: DeptActiveModel) -> Result<u64> {
+        let tx = current_connection(&self.db).begin().await?;
         let dept_id = dept.dept_id.clone().unwrap();
         // 删除部门对应的角色
         RoleDeptEntity::delete_many()
@@ -173,19 +164,18 @@ impl DeptRepository for DeptRepositoryImpl {
             .exec(&tx)
             .await?;
         // 逻辑删除部门
-        dept.del_flag = Set(Some("2".to_string()));
-        if let Some(user) = get_sync_user_context() {
-            dept.update_by = Set(Some(user.user_name.clone()));
-        }
-        dept.update(&tx).await?;
+        let rows_affected = self
+            .repository
+            .delete_by_ids_with(&tx, vec![dept_id])
+            .await?;
         tx.commit().await?;
-        Ok(1)
+        Ok(rows_affected)
     }
 
     async fn get_dept_ids_by_role_id(&self, role_id: i64) -> Result<Vec<i64>> {
         let role_depts = RoleDeptEntity::find()
             .filter(RoleDeptColumn::RoleId.eq(role_id))
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
         let dept_ids = role_depts
             .iter()
@@ -201,7 +191,7 @@ impl DeptRepository for DeptRepositoryImpl {
             .select_only()
             .column(DeptColumn::ParentId)
             .into_tuple::<i64>()
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
         Ok(parent_ids)
     }
@@ -1,12 +1,9 @@
 /// 配置仓库
 use crate::entity::prelude::*;
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::{vo::PageParam, Result};
-use ruoyi_framework::{
-    db::repository::{BaseRepository, Repository},
-    web::tls::get_sync_user_context,
-};
+use ruoyi_framework::db::repository::{unset_zero_primary_key, BaseRepository, Repository};
+use ruoyi_framework::db::transaction::current_connection;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, PaginatorTrait, QueryFilter,
     QueryOrder, Set,
@@ -70,23 +67,12 @@ impl DictDataRepository for DictDataRepositoryImpl {
         Ok(self.repository.find_by_id(dict_id).await?)
     }
     async fn create_dict_data(&self, mut dict_data: DictDataActiveModel) -> Result<DictDataModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            dict_data.create_by = Set(Some(user_context.user_name.clone()));
-            dict_data.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        let now = Utc::now();
-        dict_data.create_time = Set(Some(now));
-        dict_data.update_time = Set(Some(now));
         dict_data.is_default = Set(Some("N".to_string()));
-        Ok(dict_data.insert(self.db.as_ref()).await?)
+        unset_zero_primary_key(&mut dict_data);
+        Ok(dict_data.insert(&current_connection(&self.db)).await?)
     }
-    async fn update_dict_data(&self, mut dict_data: DictDataActiveModel) -> Result<DictDataModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            dict_data.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        let now = Utc::now();
-        dict_data.update_time = Set(Some(now));
-        Ok(dict_data.update(self.db.as_ref()).await?)
+    async fn update_dict_data(&self, dict_data: DictDataActiveModel) -> Result<DictDataModel> {
+        Ok(dict_data.update(&current_connection(&self.db)).await?)
     }
     async fn delete_dict_datas(&self, dict_ids: Vec<i64>) -> Result<u64> {
         Ok(self.repository.delete_by_ids(dict_ids).await?)
@@ -105,7 +91,7 @@ impl DictDataRepository for DictDataRepositoryImpl {
         if let Some(dict_code) = dict_code {
             query = query.filter(DictDataColumn::DictCode.ne(dict_code));
         }
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
     async fn get_dict_data_by_type(&self, dict_type: &str) -> Result<Vec<DictDataModel>> {
@@ -114,7 +100,7 @@ impl DictDataRepository for DictDataRepositoryImpl {
             .select()
             .filter(DictDataColumn::DictType.eq(dict_type))
             .order_by_asc(DictDataColumn::DictSort)
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
         Ok(dict_data)
     }
@@ -1,16 +1,13 @@
 /// 配置仓库
 use crate::entity::prelude::*;
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::vo::PageParam;
 use ruoyi_common::Result;
-use ruoyi_framework::{
-    db::repository::{BaseRepository, Repository},
-    web::tls::get_sync_user_context,
-};
+use ruoyi_framework::db::repository::{unset_zero_primary_key, BaseRepository, Repository};
+use ruoyi_framework::db::transaction::current_connection;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, PaginatorTrait, QueryFilter,
-    QueryOrder, Set,
+    QueryOrder,
 };
 use std::sync::Arc;
 
@@ -80,23 +77,12 @@ impl DictTypeRepository for DictTypeRepositoryImpl {
     }
 
     async fn create_dict_type(&self, mut dict_type: DictTypeActiveModel) -> Result<DictTypeModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            dict_type.create_by = Set(Some(user_context.user_name.clone()));
-            dict_type.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        let now = Utc::now();
-        dict_type.create_time = Set(Some(now));
-        dict_type.update_time = Set(Some(now));
-        Ok(dict_type.insert(self.db.as_ref()).await?)
+        unset_zero_primary_key(&mut dict_type);
+        Ok(dict_type.insert(&current_connection(&self.db)).await?)
     }
 
-    async fn update_dict_type(&self, mut dict_type: DictTypeActiveModel) -> Result<DictTypeModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            dict_type.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        let now = Utc::now();
-        dict_type.update_time = Set(Some(now));
-        Ok(dict_type.update(self.db.as_ref()).await?)
+    async fn update_dict_type(&self, dict_type: DictTypeActiveModel) -> Result<DictTypeModel> {
+        Ok(dict_type.update(&current_connection(&self.db)).await?)
     }
 
     async fn delete_dict_types(&self, dict_ids: Vec<i64>) -> Result<u64> {
@@ -109,7 +95,7 @@ impl DictTypeRepository for DictTypeRepositoryImpl {
             query = query.filter(DictTypeColumn::DictId.ne(dict_id));
         }
         query = query.filter(DictTypeColumn::DictType.eq(dict_type));
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
 
@@ -123,7 +109,7 @@ impl DictTypeRepository for DictTypeRepositoryImpl {
             query = query.filter(DictTypeColumn::DictId.ne(dict_id));
         }
         query = query.filter(DictTypeColumn::DictName.eq(dict_name));
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
 
@@ -132,7 +118,7 @@ impl DictTypeRepository for DictTypeRepositoryImpl {
             .repository
             .select()
             .order_by_asc(DictTypeColumn::DictId)
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?)
     }
 }
@@ -4,7 +4,9 @@
 use crate::entity::prelude::*;
 use async_trait::async_trait;
 use ruoyi_common::{vo::PageParam, Result};
-use ruoyi_framework::db::repository::{BaseRepository, Repository};
+use ruoyi_framework::db::replica::ReadRouter;
+use ruoyi_framework::db::repository::{unset_zero_primary_key, BaseRepository, Repository};
+use ruoyi_framework::db::transaction::current_connection;
 use sea_orm::{
     ActiveModelTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
     QueryFilter, QueryOrder,
@@ -21,6 +23,13 @@ pub trait LoginInfoRepository: Send + Sync {
         page_param: PageParam,
     ) -> Result<(Vec<LoginInfoModel>, u64)>;
 
+    /// 按游标获取登录日志列表，返回当前页数据和下一页的游标
+    async fn get_login_info_list_by_cursor(
+        &self,
+        condition: Option<Condition>,
+        page_param: PageParam,
+    ) -> Result<(Vec<LoginInfoModel>, Option<i64>)>;
+
     /// 根据ID获取登录日志
     async fn get_login_info_by_id(&self, info_id: i64) -> Result<Option<LoginInfoModel>>;
 
@@ -40,6 +49,7 @@ pub trait LoginInfoRepository: Send + Sync {
 /// 登录日志仓库实现
 pub struct LoginInfoRepositoryImpl {
     db: Arc<DatabaseConnection>,
+    read_router: ReadRouter,
     repository: BaseRepository<LoginInfoEntity, LoginInfoActiveModel>,
 }
 
@@ -47,9 +57,24 @@ impl LoginInfoRepositoryImpl {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
             db: db.clone(),
-            repository: BaseRepository::new(db.as_ref().clone()),
+            read_router: ReadRouter::primary_only(db.clone()),
+            repository: BaseRepository::new(db.as_ref().clone())
+                .with_sortable_columns([
+                    LoginInfoColumn::InfoId,
+                    LoginInfoColumn::UserName,
+                    LoginInfoColumn::Ipaddr,
+                    LoginInfoColumn::LoginTime,
+                    LoginInfoColumn::Status,
+                ])
+                .with_tenant(LoginInfoColumn::TenantId),
         }
     }
+
+    /// 列表查询使用只读副本
+    pub fn with_read_router(mut self, read_router: ReadRouter) -> Self {
+        self.read_router = read_router;
+        self
+    }
 }
 
 #[async_trait]
@@ -63,23 +88,45 @@ impl LoginInfoRepository for LoginInfoRepositoryImpl {
         if let Some(condition) = condition {
             query = query.filter(condition);
         }
+        query = self.repository.apply_order(query, &page_param)?;
         query = query.order_by_desc(LoginInfoColumn::LoginTime);
-        let paginator = query.paginate(self.db.as_ref(), page_param.page_size);
+        let read_db = self.read_router.connection();
+        let paginator = query.paginate(&read_db, page_param.limit());
         let total = paginator.num_items().await?;
-        let login_infos = paginator.fetch_page(page_param.page_num - 1).await?;
+        let login_infos = paginator.fetch_page(page_param.page_index()).await?;
         Ok((login_infos, total))
     }
 
+    async fn get_login_info_list_by_cursor(
+        &self,
+        condition: Option<Condition>,
+        page_param: PageParam,
+    ) -> Result<(Vec<LoginInfoModel>, Option<i64>)> {
+        let mut query = self.repository.select();
+        if let Some(condition) = condition {
+            query = query.filter(condition);
+        }
+        let read_db = self.read_router.connection();
+        Ok(self
+            .repository
+            .paginate_by_cursor_with(&read_db, query, &page_param)
+            .await?)
+    }
+
     async fn get_login_info_by_id(&self, info_id: i64) -> Result<Option<LoginInfoModel>> {
         Ok(self.repository.find_by_id(info_id).await?)
     }
 
-    async fn create_login_info(&self, login_info: LoginInfoActiveModel) -> Result<LoginInfoModel> {
-        Ok(login_info.insert(self.db.as_ref()).await?)
+    async fn create_login_info(
+        &self,
+        mut login_info: LoginInfoActiveModel,
+    ) -> Result<LoginInfoModel> {
+        unset_zero_primary_key(&mut login_info);
+        Ok(login_info.insert(&current_connection(&self.db)).await?)
     }
 
     async fn update_login_info(&self, login_info: LoginInfoActiveModel) -> Result<LoginInfoModel> {
-        Ok(login_info.update(self.db.as_ref()).await?)
+        Ok(login_info.update(&current_connection(&self.db)).await?)
     }
 
     async fn delete_login_infos(&self, info_ids: Vec<i64>) -> Result<u64> {
@@ -88,7 +135,8 @@ impl LoginInfoRepository for LoginInfoRepositoryImpl {
 
     async fn clean_login_info(&self) -> Result<u64> {
         Ok(LoginInfoEntity::delete_many()
-            .exec(self.db.as_ref())
+            .filter(self.repository.tenant_condition())
+            .exec(&current_connection(&self.db))
             .await
             .map(|res| res.rows_affected)?)
     }
@@ -3,15 +3,17 @@
 
 use crate::entity::prelude::*;
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::{vo::PageParam, Result};
-use ruoyi_framework::{
-    db::repository::{BaseRepository, Repository},
-    web::tls::get_sync_user_context,
+use ruoyi_framework::db::history::ChangeHistory;
+use ruoyi_framework::db::repository::{
+    unset_zero_primary_key, BaseRepository, OptimisticLock, Repository,
 };
+use ruoyi_framework::db::transaction::current_connection;
+use ruoyi_framework::tenant::{current_tenant_id, is_platform_tenant};
+use sea_orm::sea_query::{Expr, Query};
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Set,
+    QueryFilter, QueryOrder,
 };
 use std::sync::Arc;
 
@@ -68,10 +70,31 @@ impl MenuRepositoryImpl {
     /// 创建菜单仓库
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
-            repository: BaseRepository::new(db.as_ref().clone()),
+            repository: BaseRepository::new(db.as_ref().clone())
+                .with_optimistic_lock(OptimisticLock::version(MenuColumn::Version))
+                .with_change_history(ChangeHistory::new()),
             db,
         }
     }
+
+    /// 平台共享菜单对租户按套餐限制，租户只能看到所属套餐中的菜单
+    fn package_menu_condition(&self) -> Condition {
+        let tenant_id = match current_tenant_id() {
+            Some(tenant_id) if !is_platform_tenant() => tenant_id,
+            _ => return Condition::all(),
+        };
+        let package_menu_ids = Query::select()
+            .column((TenantPackageMenuEntity, TenantPackageMenuColumn::MenuId))
+            .from(TenantPackageMenuEntity)
+            .inner_join(
+                TenantEntity,
+                Expr::col((TenantEntity, TenantColumn::PackageId))
+                    .equals((TenantPackageMenuEntity, TenantPackageMenuColumn::PackageId)),
+            )
+            .and_where(Expr::col((TenantEntity, TenantColumn::TenantId)).eq(tenant_id))
+            .to_owned();
+        Condition::all().add(MenuColumn::MenuId.in_subquery(package_menu_ids))
+    }
 }
 
 #[async_trait]
@@ -86,18 +109,21 @@ impl MenuRepository for MenuRepositoryImpl {
             .select()
             .filter(MenuColumn::MenuName.eq(menu_name))
             .filter(MenuColumn::Status.eq("0"))
-            .one(self.db.as_ref())
+            .one(&current_connection(&self.db))
             .await?)
     }
 
     async fn find_all(&self, condition: Option<Condition>) -> Result<Vec<MenuModel>> {
-        let mut query = self.repository.select();
+        let mut query = self
+            .repository
+            .select()
+            .filter(self.package_menu_condition());
 
         if let Some(cond) = condition {
             query = query.filter(cond);
         }
 
-        Ok(query.all(self.db.as_ref()).await?)
+        Ok(query.all(&current_connection(&self.db)).await?)
     }
 
     async fn find_list(
@@ -105,7 +131,10 @@ impl MenuRepository for MenuRepositoryImpl {
         condition: Option<Condition>,
         page_param: PageParam,
     ) -> Result<(Vec<MenuModel>, u64)> {
-        let mut query = self.repository.select();
+        let mut query = self
+            .repository
+            .select()
+            .filter(self.package_menu_condition());
 
         if let Some(cond) = condition {
             query = query.filter(cond);
@@ -125,7 +154,7 @@ impl MenuRepository for MenuRepositoryImpl {
     async fn select_menu_ids_by_role_id(&self, role_id: i64) -> Result<Vec<i64>> {
         let menu_ids = RoleMenuEntity::find()
             .filter(RoleMenuColumn::RoleId.eq(role_id))
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?
             .into_iter()
             .map(|rm| rm.menu_id)
@@ -135,59 +164,50 @@ impl MenuRepository for MenuRepositoryImpl {
     }
 
     async fn select_menus_by_user_id(&self, user_id: i64) -> Result<Vec<MenuModel>> {
-        // 用自定义SQL查询用户对应的菜单 (这里简化处理)
-        let sql = format!(
-            "SELECT m.* FROM sys_user_role ur
-        LEFT JOIN sys_role_menu rm ON ur.role_id = rm.role_id
-        LEFT JOIN sys_menu m ON rm.menu_id = m.menu_id
-        WHERE ur.user_id = {}
-        AND m.status = '0'
-        ORDER BY m.parent_id ASC, m.order_num ASC",
-            user_id
-        );
+        // 用户角色关联的菜单
+        let role_menu_ids = Query::select()
+            .distinct()
+            .column((RoleMenuEntity, RoleMenuColumn::MenuId))
+            .from(RoleMenuEntity)
+            .inner_join(
+                UserRoleEntity,
+                Expr::col((UserRoleEntity, UserRoleColumn::RoleId))
+                    .equals((RoleMenuEntity, RoleMenuColumn::RoleId)),
+            )
+            .and_where(Expr::col((UserRoleEntity, UserRoleColumn::UserId)).eq(user_id))
+            .to_owned();
+        // 套餐中移除的菜单即使仍分配给角色也不再可见
         let menus = MenuEntity::find()
-            .from_raw_sql(sea_orm::Statement::from_string(
-                sea_orm::DatabaseBackend::MySql,
-                sql,
-            ))
-            .all(self.db.as_ref())
+            .filter(MenuColumn::MenuId.in_subquery(role_menu_ids))
+            .filter(MenuColumn::Status.eq("0"))
+            .filter(self.package_menu_condition())
+            .order_by_asc(MenuColumn::ParentId)
+            .order_by_asc(MenuColumn::OrderNum)
+            .all(&current_connection(&self.db))
             .await?;
 
         Ok(menus)
     }
 
     async fn create_menu(&self, mut menu: MenuActiveModel) -> Result<MenuModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            menu.create_by = Set(Some(user_context.user_name.to_string()));
-            menu.update_by = Set(Some(user_context.user_name.to_string()));
-        }
-        let now = Utc::now();
-        menu.create_time = Set(Some(now));
-        menu.update_time = Set(Some(now));
-        let result = menu.insert(self.db.as_ref()).await?;
+        unset_zero_primary_key(&mut menu);
+        let result = menu.insert(&current_connection(&self.db)).await?;
         Ok(result)
     }
 
-    async fn update_menu(&self, mut menu: MenuActiveModel) -> Result<MenuModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            menu.update_by = Set(Some(user_context.user_name.to_string()));
-        }
-        let now = Utc::now();
-        menu.update_time = Set(Some(now));
-        let result = menu.update(self.db.as_ref()).await?;
+    async fn update_menu(&self, menu: MenuActiveModel) -> Result<MenuModel> {
+        let result = self.repository.update(menu).await?;
         Ok(result)
     }
 
     async fn delete_by_id(&self, menu_id: i64) -> Result<()> {
         // 删除菜单
-        MenuEntity::delete_by_id(menu_id)
-            .exec(self.db.as_ref())
-            .await?;
+        self.repository.delete_by_id(menu_id).await?;
 
         // 删除菜单角色关联
         RoleMenuEntity::delete_many()
             .filter(RoleMenuColumn::MenuId.eq(menu_id))
-            .exec(self.db.as_ref())
+            .exec(&current_connection(&self.db))
             .await?;
 
         Ok(())
@@ -196,7 +216,7 @@ impl MenuRepository for MenuRepositoryImpl {
     async fn has_child_by_id(&self, menu_id: i64) -> Result<bool> {
         let count = MenuEntity::find()
             .filter(MenuColumn::ParentId.eq(menu_id))
-            .count(self.db.as_ref())
+            .count(&current_connection(&self.db))
             .await?;
 
         Ok(count > 0)
@@ -205,7 +225,7 @@ impl MenuRepository for MenuRepositoryImpl {
     async fn check_menu_assigned(&self, menu_id: i64) -> Result<bool> {
         let count = RoleMenuEntity::find()
             .filter(RoleMenuColumn::MenuId.eq(menu_id))
-            .count(self.db.as_ref())
+            .count(&current_connection(&self.db))
             .await?;
 
         Ok(count > 0)
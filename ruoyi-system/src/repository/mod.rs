@@ -9,7 +9,10 @@ pub mod menu_repository;
 pub mod notice_repository;
 pub mod post_repository;
 pub mod role_repository;
+pub mod tenant_package_repository;
+pub mod tenant_repository;
 pub mod user_repository;
 
 pub mod oper_log_repository;
 pub mod login_info_repository;
+pub mod change_log_repository;
@@ -3,14 +3,11 @@
 
 use crate::entity::prelude::*;
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::{vo::PageParam, Result};
-use ruoyi_framework::{
-    db::repository::{BaseRepository, Repository},
-    web::tls::get_sync_user_context,
-};
+use ruoyi_framework::db::repository::{unset_zero_primary_key, BaseRepository, Repository};
+use ruoyi_framework::db::transaction::current_connection;
 use sea_orm::{
-    ActiveModelTrait, Condition, DatabaseConnection, PaginatorTrait, QueryFilter, QueryOrder, Set,
+    ActiveModelTrait, Condition, DatabaseConnection, PaginatorTrait, QueryFilter, QueryOrder,
 };
 use std::sync::Arc;
 
@@ -47,7 +44,8 @@ impl NoticeRepositoryImpl {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
             db: db.clone(),
-            repository: BaseRepository::new(db.as_ref().clone()),
+            repository: BaseRepository::new(db.as_ref().clone())
+                .with_tenant(NoticeColumn::TenantId),
         }
     }
 }
@@ -75,23 +73,12 @@ impl NoticeRepository for NoticeRepositoryImpl {
     }
 
     async fn create_notice(&self, mut notice: NoticeActiveModel) -> Result<NoticeModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            notice.create_by = Set(Some(user_context.user_name.clone()));
-            notice.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        let now = Utc::now();
-        notice.create_time = Set(Some(now));
-        notice.update_time = Set(Some(now));
-        Ok(notice.insert(self.db.as_ref()).await?)
+        unset_zero_primary_key(&mut notice);
+        Ok(notice.insert(&current_connection(&self.db)).await?)
     }
 
-    async fn update_notice(&self, mut notice: NoticeActiveModel) -> Result<NoticeModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            notice.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        let now = Utc::now();
-        notice.update_time = Set(Some(now));
-        Ok(notice.update(self.db.as_ref()).await?)
+    async fn update_notice(&self, notice: NoticeActiveModel) -> Result<NoticeModel> {
+        Ok(notice.update(&current_connection(&self.db)).await?)
     }
 
     async fn delete_notices(&self, notice_ids: Vec<i32>) -> Result<u64> {
@@ -3,13 +3,15 @@
 
 use crate::entity::prelude::*;
 use async_trait::async_trait;
-use ruoyi_common::{utils, vo::PageParam, Result};
-use ruoyi_framework::db::repository::{BaseRepository, Repository};
+use ruoyi_common::{vo::PageParam, Result};
+use ruoyi_framework::db::replica::ReadRouter;
+use ruoyi_framework::db::repository::{unset_zero_primary_key, BaseRepository, Repository};
+use ruoyi_framework::db::transaction::current_connection;
 use sea_orm::{
     ActiveModelTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
     QueryOrder,
 };
-use std::{str::FromStr, sync::Arc};
+use std::sync::Arc;
 
 /// 操作日志仓库特征
 #[async_trait]
@@ -21,6 +23,13 @@ pub trait OperLogRepository: Send + Sync {
         page_param: PageParam,
     ) -> Result<(Vec<OperLogModel>, u64)>;
 
+    /// 按游标获取操作日志列表，返回当前页数据和下一页的游标
+    async fn get_oper_log_list_by_cursor(
+        &self,
+        condition: Option<Condition>,
+        page_param: PageParam,
+    ) -> Result<(Vec<OperLogModel>, Option<i64>)>;
+
     /// 根据ID获取操作日志
     async fn get_oper_log_by_id(&self, oper_id: i64) -> Result<Option<OperLogModel>>;
 
@@ -40,6 +49,7 @@ pub trait OperLogRepository: Send + Sync {
 /// 操作日志仓库实现
 pub struct OperLogRepositoryImpl {
     db: Arc<DatabaseConnection>,
+    read_router: ReadRouter,
     repository: BaseRepository<OperLogEntity, OperLogActiveModel>,
 }
 
@@ -47,9 +57,26 @@ impl OperLogRepositoryImpl {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
             db: db.clone(),
-            repository: BaseRepository::new(db.as_ref().clone()),
+            read_router: ReadRouter::primary_only(db.clone()),
+            repository: BaseRepository::new(db.as_ref().clone())
+                .with_sortable_columns([
+                    OperLogColumn::OperId,
+                    OperLogColumn::Title,
+                    OperLogColumn::BusinessType,
+                    OperLogColumn::OperName,
+                    OperLogColumn::OperTime,
+                    OperLogColumn::CostTime,
+                    OperLogColumn::Status,
+                ])
+                .with_tenant(OperLogColumn::TenantId),
         }
     }
+
+    /// 列表查询使用只读副本
+    pub fn with_read_router(mut self, read_router: ReadRouter) -> Self {
+        self.read_router = read_router;
+        self
+    }
 }
 
 #[async_trait]
@@ -63,31 +90,42 @@ impl OperLogRepository for OperLogRepositoryImpl {
         if let Some(condition) = condition {
             query = query.filter(condition);
         }
-        if let Some(order_by_column) = page_param.order_by_column {
-            let order_by_column = utils::string::to_snake_case(&order_by_column);
-            if page_param.is_asc == Some("ascending".to_string()) {
-                query = query.order_by_asc(OperLogColumn::from_str(&order_by_column).unwrap());
-            } else {
-                query = query.order_by_desc(OperLogColumn::from_str(&order_by_column).unwrap());
-            }
-        }
+        query = self.repository.apply_order(query, &page_param)?;
         query = query.order_by_desc(OperLogColumn::OperTime);
-        let paginator = query.paginate(self.db.as_ref(), page_param.page_size);
+        let read_db = self.read_router.connection();
+        let paginator = query.paginate(&read_db, page_param.limit());
         let total = paginator.num_items().await?;
-        let oper_logs = paginator.fetch_page(page_param.page_num - 1).await?;
+        let oper_logs = paginator.fetch_page(page_param.page_index()).await?;
         Ok((oper_logs, total))
     }
 
+    async fn get_oper_log_list_by_cursor(
+        &self,
+        condition: Option<Condition>,
+        page_param: PageParam,
+    ) -> Result<(Vec<OperLogModel>, Option<i64>)> {
+        let mut query = self.repository.select();
+        if let Some(condition) = condition {
+            query = query.filter(condition);
+        }
+        let read_db = self.read_router.connection();
+        Ok(self
+            .repository
+            .paginate_by_cursor_with(&read_db, query, &page_param)
+            .await?)
+    }
+
     async fn get_oper_log_by_id(&self, oper_id: i64) -> Result<Option<OperLogModel>> {
         Ok(self.repository.find_by_id(oper_id).await?)
     }
 
-    async fn create_oper_log(&self, oper_log: OperLogActiveModel) -> Result<OperLogModel> {
-        Ok(oper_log.insert(self.db.as_ref()).await?)
+    async fn create_oper_log(&self, mut oper_log: OperLogActiveModel) -> Result<OperLogModel> {
+        unset_zero_primary_key(&mut oper_log);
+        Ok(oper_log.insert(&current_connection(&self.db)).await?)
     }
 
     async fn update_oper_log(&self, oper_log: OperLogActiveModel) -> Result<OperLogModel> {
-        Ok(oper_log.update(self.db.as_ref()).await?)
+        Ok(oper_log.update(&current_connection(&self.db)).await?)
     }
 
     async fn delete_oper_logs(&self, oper_ids: Vec<i64>) -> Result<u64> {
@@ -96,7 +134,8 @@ impl OperLogRepository for OperLogRepositoryImpl {
 
     async fn clean_oper_log(&self) -> Result<u64> {
         Ok(OperLogEntity::delete_many()
-            .exec(self.db.as_ref())
+            .filter(self.repository.tenant_condition())
+            .exec(&current_connection(&self.db))
             .await
             .map(|res| res.rows_affected)?)
     }
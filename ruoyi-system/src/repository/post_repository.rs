@@ -1,15 +1,12 @@
 /// 配置仓库
 use crate::entity::prelude::*;
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::{vo::PageParam, Result};
-use ruoyi_framework::{
-    db::repository::{BaseRepository, Repository},
-    web::tls::get_sync_user_context,
-};
+use ruoyi_framework::db::repository::{unset_zero_primary_key, BaseRepository, Repository};
+use ruoyi_framework::db::transaction::current_connection;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Set,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
 };
 use std::sync::Arc;
 
@@ -38,6 +35,8 @@ pub trait PostRepository: Send + Sync {
     async fn get_posts_by_user_id(&self, user_id: i64) -> Result<Vec<PostModel>>;
     async fn get_posts_all(&self) -> Result<Vec<PostModel>>;
     async fn get_post_ids_by_user_id(&self, user_id: i64) -> Result<Vec<i64>>;
+    /// 重新设置用户的岗位关联
+    async fn set_user_posts(&self, user_id: i64, post_ids: Vec<i64>) -> Result<()>;
 }
 
 /// 岗位仓库实现
@@ -50,7 +49,7 @@ impl PostRepositoryImpl {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
             db: db.clone(),
-            repository: BaseRepository::new(db.as_ref().clone()),
+            repository: BaseRepository::new(db.as_ref().clone()).with_tenant(PostColumn::TenantId),
         }
     }
 }
@@ -80,24 +79,13 @@ impl PostRepository for PostRepositoryImpl {
     }
 
     async fn create_post(&self, mut post: PostActiveModel) -> Result<PostModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            post.create_by = Set(Some(user_context.user_name.clone()));
-            post.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        let now = Utc::now();
-        post.create_time = Set(Some(now));
-        post.update_time = Set(Some(now));
         post.status = Set("0".to_owned());
-        Ok(post.insert(self.db.as_ref()).await?)
+        unset_zero_primary_key(&mut post);
+        Ok(post.insert(&current_connection(&self.db)).await?)
     }
 
-    async fn update_post(&self, mut post: PostActiveModel) -> Result<PostModel> {
-        if let Some(user_context) = get_sync_user_context() {
-            post.update_by = Set(Some(user_context.user_name.clone()));
-        }
-        let now = Utc::now();
-        post.update_time = Set(Some(now));
-        Ok(post.update(self.db.as_ref()).await?)
+    async fn update_post(&self, post: PostActiveModel) -> Result<PostModel> {
+        Ok(post.update(&current_connection(&self.db)).await?)
     }
 
     async fn delete_posts(&self, ids: Vec<i64>) -> Result<u64> {
@@ -116,7 +104,7 @@ impl PostRepository for PostRepositoryImpl {
         if let Some(exclude_id) = exclude_id {
             query = query.filter(PostColumn::PostId.ne(exclude_id));
         }
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
 
@@ -132,14 +120,14 @@ impl PostRepository for PostRepositoryImpl {
         if let Some(exclude_id) = exclude_id {
             query = query.filter(PostColumn::PostId.ne(exclude_id));
         }
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
 
     async fn get_post_ids_by_user_id(&self, user_id: i64) -> Result<Vec<i64>> {
         let post_ids = UserPostEntity::find()
             .filter(UserPostColumn::UserId.eq(user_id))
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
         let post_ids = post_ids.iter().map(|post| post.post_id).collect();
         Ok(post_ids)
@@ -156,10 +144,10 @@ impl PostRepository for PostRepositoryImpl {
             .repository
             .select()
             .from_raw_sql(sea_orm::Statement::from_string(
-                sea_orm::DatabaseBackend::MySql,
+                self.db.get_database_backend(),
                 sql,
             ))
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
         Ok(posts)
     }
@@ -170,7 +158,26 @@ impl PostRepository for PostRepositoryImpl {
             .select()
             .filter(PostColumn::Status.eq("0"))
             .order_by_asc(PostColumn::PostSort)
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?)
     }
+
+    async fn set_user_posts(&self, user_id: i64, post_ids: Vec<i64>) -> Result<()> {
+        let txn = current_connection(&self.db).begin().await?;
+        // 删除旧的岗位关联
+        UserPostEntity::delete_many()
+            .filter(UserPostColumn::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+        // 添加新的岗位关联
+        for post_id in post_ids {
+            let user_post = UserPostActiveModel {
+                user_id: Set(user_id),
+                post_id: Set(post_id),
+            };
+            user_post.insert(&txn).await?;
+        }
+        txn.commit().await?;
+        Ok(())
+    }
 }
@@ -2,14 +2,17 @@
 //! 角色仓库实现
 
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::vo::PageParam;
 use ruoyi_common::Result;
-use ruoyi_framework::db::repository::{BaseRepository, Repository};
-use ruoyi_framework::web::tls::get_sync_user_context;
+use ruoyi_framework::db::history::ChangeHistory;
+use ruoyi_framework::db::repository::{
+    unset_zero_primary_key, BaseRepository, OptimisticLock, Repository, SoftDelete,
+};
+use ruoyi_framework::db::transaction::current_connection;
+use ruoyi_framework::tenant::tenant_condition;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
 };
 use sea_orm::{Condition, QuerySelect};
 use std::sync::Arc;
@@ -62,6 +65,12 @@ pub trait RoleRepository: Send + Sync {
     /// 删除角色
     async fn delete_role_by_ids(&self, role_ids: Vec<i64>) -> Result<u64>;
 
+    /// 恢复已删除的角色
+    async fn restore_role_by_ids(&self, role_ids: Vec<i64>) -> Result<u64>;
+
+    /// 彻底删除已删除的角色
+    async fn purge_role_by_ids(&self, role_ids: Vec<i64>) -> Result<u64>;
+
     /// 获取角色分配的用户列表
     async fn get_role_allocated_users(
         &self,
@@ -98,6 +107,9 @@ pub trait RoleRepository: Send + Sync {
 
     /// 获取所有角色
     async fn get_roles_all(&self) -> Result<Vec<RoleModel>>;
+
+    /// 重新设置用户的角色关联
+    async fn set_user_roles(&self, user_id: i64, role_ids: Vec<i64>) -> Result<()>;
 }
 
 /// 角色仓库实现
@@ -112,7 +124,12 @@ impl RoleRepositoryImpl {
     /// 创建角色仓库
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
-            repository: BaseRepository::new(db.as_ref().clone()),
+            // 删除标志：0代表存在 2代表删除
+            repository: BaseRepository::new(db.as_ref().clone())
+                .with_soft_delete(SoftDelete::new(RoleColumn::DelFlag, "2", "0"))
+                .with_optimistic_lock(OptimisticLock::version(RoleColumn::Version))
+                .with_tenant(RoleColumn::TenantId)
+                .with_change_history(ChangeHistory::new()),
             db,
         }
     }
@@ -133,9 +150,6 @@ impl RoleRepository for RoleRepositoryImpl {
 
         if let Some(cond) = condition {
             query = query.filter(cond);
-        } else {
-            // 默认只查询未删除的角色
-            query = query.filter(RoleColumn::DelFlag.eq("0"));
         }
 
         query = query.order_by(RoleColumn::RoleSort, sea_orm::Order::Asc);
@@ -159,10 +173,10 @@ impl RoleRepository for RoleRepositoryImpl {
 
         let roles = RoleEntity::find()
             .from_raw_sql(sea_orm::Statement::from_string(
-                sea_orm::DatabaseBackend::MySql,
+                self.db.get_database_backend(),
                 query,
             ))
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
 
         Ok(roles)
@@ -172,12 +186,11 @@ impl RoleRepository for RoleRepositoryImpl {
         let mut query = self
             .repository
             .select()
-            .filter(RoleColumn::RoleName.eq(role_name))
-            .filter(RoleColumn::DelFlag.eq("0"));
+            .filter(RoleColumn::RoleName.eq(role_name));
         if let Some(role_id) = role_id {
             query = query.filter(RoleColumn::RoleId.ne(role_id));
         }
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
 
         Ok(count == 0)
     }
@@ -186,12 +199,11 @@ impl RoleRepository for RoleRepositoryImpl {
         let mut query = self
             .repository
             .select()
-            .filter(RoleColumn::RoleKey.eq(role_key))
-            .filter(RoleColumn::DelFlag.eq("0"));
+            .filter(RoleColumn::RoleKey.eq(role_key));
         if let Some(role_id) = role_id {
             query = query.filter(RoleColumn::RoleId.ne(role_id));
         }
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
 
         Ok(count == 0)
     }
@@ -202,21 +214,13 @@ impl RoleRepository for RoleRepositoryImpl {
         menu_ids: Option<Vec<i64>>,
     ) -> Result<RoleModel> {
         // 获取创建人信息
-        if let Some(user) = get_sync_user_context() {
-            role.create_by = Set(Some(user.user_name.clone()));
-            role.update_by = Set(Some(user.user_name.clone()));
-        }
-        let txn = self.db.begin().await?;
-
-        // 设置创建时间
-        let now = Utc::now();
-        role.create_time = Set(Some(now));
-        role.update_time = Set(Some(now));
+        let txn = current_connection(&self.db).begin().await?;
 
         // 默认设置删除标志为0（未删除）
         role.del_flag = Set(Some("0".to_string()));
 
         // 插入角色
+        unset_zero_primary_key(&mut role);
         let role = role.insert(&txn).await?;
 
         // 添加角色菜单关联
@@ -236,20 +240,14 @@ impl RoleRepository for RoleRepositoryImpl {
 
     async fn update_role(
         &self,
-        mut role: RoleActiveModel,
+        role: RoleActiveModel,
         menu_ids: Option<Vec<i64>>,
         dept_ids: Option<Vec<i64>>,
     ) -> Result<RoleModel> {
-        if let Some(user) = get_sync_user_context() {
-            role.update_by = Set(Some(user.user_name.clone()));
-        }
-
-        let txn = self.db.begin().await?;
-        // 设置更新时间
-        role.update_time = Set(Some(Utc::now()));
+        let txn = current_connection(&self.db).begin().await?;
 
         // 更新角色
-        let role = role.update(&txn).await?;
+        let role = self.repository.update_with(&txn, role).await?;
         if let Some(menu_ids) = menu_ids {
             // 删除旧的菜单关联
             RoleMenuEntity::delete_many()
@@ -289,21 +287,12 @@ impl RoleRepository for RoleRepositoryImpl {
     }
 
     async fn delete_role_by_ids(&self, role_ids: Vec<i64>) -> Result<u64> {
-        let txn = self.db.begin().await?;
-
-        // 软删除角色 (设置 del_flag = "2")
-        let mut update = RoleActiveModel {
-            del_flag: Set(Some("2".to_string())),
-            update_time: Set(Some(Utc::now())),
-            ..Default::default()
-        };
-        if let Some(user) = get_sync_user_context() {
-            update.update_by = Set(Some(user.user_name.clone()));
-        }
-        let res = RoleEntity::update_many()
-            .filter(RoleColumn::RoleId.is_in(role_ids.clone()))
-            .set(update)
-            .exec(&txn)
+        let txn = current_connection(&self.db).begin().await?;
+
+        // 逻辑删除角色
+        let rows_affected = self
+            .repository
+            .delete_by_ids_with(&txn, role_ids.clone())
             .await?;
 
         // 删除角色菜单关联
@@ -319,7 +308,36 @@ impl RoleRepository for RoleRepositoryImpl {
             .await?;
 
         txn.commit().await?;
-        Ok(res.rows_affected)
+        Ok(rows_affected)
+    }
+
+    async fn restore_role_by_ids(&self, role_ids: Vec<i64>) -> Result<u64> {
+        Ok(self.repository.restore_by_ids(role_ids).await?)
+    }
+
+    async fn purge_role_by_ids(&self, role_ids: Vec<i64>) -> Result<u64> {
+        // 只清除已删除的角色及其部门关联，菜单和用户关联在删除时已清除
+        let role_ids: Vec<i64> = self
+            .repository
+            .select_deleted()
+            .filter(RoleColumn::RoleId.is_in(role_ids))
+            .all(&current_connection(&self.db))
+            .await?
+            .into_iter()
+            .map(|role| role.role_id)
+            .collect();
+        if role_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let txn = current_connection(&self.db).begin().await?;
+        RoleDeptEntity::delete_many()
+            .filter(RoleDeptColumn::RoleId.is_in(role_ids.clone()))
+            .exec(&txn)
+            .await?;
+        let rows_affected = self.repository.purge_by_ids_with(&txn, role_ids).await?;
+        txn.commit().await?;
+        Ok(rows_affected)
     }
 
     async fn auth_data_scope(&self, role_id: i64, _dept_ids: Vec<i64>) -> Result<RoleModel> {
@@ -348,13 +366,14 @@ impl RoleRepository for RoleRepositoryImpl {
             .select_only()
             .column(UserRoleColumn::UserId)
             .into_tuple::<i64>()
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
         if user_ids.is_empty() {
             return Ok((vec![], 0));
         }
         // 构建查询
         let mut query = UserEntity::find()
+            .filter(tenant_condition(UserColumn::TenantId))
             .filter(UserColumn::UserId.is_in(user_ids))
             .filter(UserColumn::DelFlag.eq("0"));
 
@@ -386,9 +405,11 @@ impl RoleRepository for RoleRepositoryImpl {
             .select_only()
             .column(UserRoleColumn::UserId)
             .into_tuple::<i64>()
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
-        let mut query = UserEntity::find().filter(UserColumn::DelFlag.eq("0"));
+        let mut query = UserEntity::find()
+            .filter(tenant_condition(UserColumn::TenantId))
+            .filter(UserColumn::DelFlag.eq("0"));
         if !user_ids.is_empty() {
             query = query.filter(UserColumn::UserId.is_not_in(user_ids));
         }
@@ -413,7 +434,7 @@ impl RoleRepository for RoleRepositoryImpl {
         UserRoleEntity::delete_many()
             .filter(UserRoleColumn::UserId.eq(user_id))
             .filter(UserRoleColumn::RoleId.eq(role_id))
-            .exec(self.db.as_ref())
+            .exec(&current_connection(&self.db))
             .await?;
 
         Ok(())
@@ -424,7 +445,7 @@ impl RoleRepository for RoleRepositoryImpl {
         UserRoleEntity::delete_many()
             .filter(UserRoleColumn::UserId.is_in(user_ids))
             .filter(UserRoleColumn::RoleId.eq(role_id))
-            .exec(self.db.as_ref())
+            .exec(&current_connection(&self.db))
             .await?;
 
         Ok(())
@@ -432,7 +453,7 @@ impl RoleRepository for RoleRepositoryImpl {
 
     async fn auth_role_users(&self, role_id: i64, user_ids: Vec<i64>) -> Result<()> {
         // 开始事务
-        let txn = self.db.begin().await?;
+        let txn = current_connection(&self.db).begin().await?;
 
         // 批量插入
         for user_id in user_ids {
@@ -452,7 +473,7 @@ impl RoleRepository for RoleRepositoryImpl {
         // 直接使用表连接查询用户ID
         let user_ids = UserRoleEntity::find()
             .filter(UserRoleColumn::RoleId.eq(role_id))
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?
             .into_iter()
             .map(|ur| ur.user_id)
@@ -472,10 +493,10 @@ impl RoleRepository for RoleRepositoryImpl {
 
         let users = UserEntity::find()
             .from_raw_sql(sea_orm::Statement::from_string(
-                sea_orm::DatabaseBackend::MySql,
+                self.db.get_database_backend(),
                 query,
             ))
-            .all(self.db.as_ref())
+            .all(&current_connection(&self.db))
             .await?;
 
         let mut user_with_roles = Vec::new();
@@ -492,4 +513,23 @@ impl RoleRepository for RoleRepositoryImpl {
     async fn get_roles_all(&self) -> Result<Vec<RoleModel>> {
         Ok(self.repository.find_all().await?)
     }
+
+    async fn set_user_roles(&self, user_id: i64, role_ids: Vec<i64>) -> Result<()> {
+        let txn = current_connection(&self.db).begin().await?;
+        // 删除旧的角色关联
+        UserRoleEntity::delete_many()
+            .filter(UserRoleColumn::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+        // 添加新的角色关联
+        for role_id in role_ids {
+            let user_role = UserRoleActiveModel {
+                user_id: Set(user_id),
+                role_id: Set(role_id),
+            };
+            user_role.insert(&txn).await?;
+        }
+        txn.commit().await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,199 @@
+// ruoyi-system/src/repository/tenant_package_repository.rs
+//! 租户套餐仓库
+
+use crate::entity::prelude::*;
+use async_trait::async_trait;
+use ruoyi_common::{vo::PageParam, Result};
+use ruoyi_framework::db::repository::{
+    unset_zero_primary_key, BaseRepository, Repository, SoftDelete,
+};
+use ruoyi_framework::db::transaction::current_connection;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use std::sync::Arc;
+
+/// 租户套餐仓库特征
+#[async_trait]
+pub trait TenantPackageRepository: Send + Sync {
+    /// 根据ID查询套餐
+    async fn find_by_id(&self, package_id: i64) -> Result<Option<TenantPackageModel>>;
+
+    /// 分页查询套餐列表
+    async fn find_list(
+        &self,
+        condition: Option<Condition>,
+        page_param: &PageParam,
+    ) -> Result<(Vec<TenantPackageModel>, u64)>;
+
+    /// 查询所有正常状态的套餐
+    async fn find_all_normal(&self) -> Result<Vec<TenantPackageModel>>;
+
+    /// 创建套餐并关联菜单
+    async fn create(
+        &self,
+        package: TenantPackageActiveModel,
+        menu_ids: Vec<i64>,
+    ) -> Result<TenantPackageModel>;
+
+    /// 更新套餐，菜单列表不为空时重新关联菜单
+    async fn update(
+        &self,
+        package: TenantPackageActiveModel,
+        menu_ids: Option<Vec<i64>>,
+    ) -> Result<TenantPackageModel>;
+
+    /// 批量删除套餐
+    async fn delete_by_ids(&self, package_ids: Vec<i64>) -> Result<u64>;
+
+    /// 查询套餐关联的菜单ID
+    async fn find_menu_ids(&self, package_id: i64) -> Result<Vec<i64>>;
+
+    /// 检查套餐名称是否唯一
+    async fn check_package_name_unique(
+        &self,
+        package_name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool>;
+}
+
+/// 租户套餐仓库实现
+pub struct TenantPackageRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+    repository: BaseRepository<TenantPackageEntity, TenantPackageActiveModel>,
+}
+
+impl TenantPackageRepositoryImpl {
+    /// 创建租户套餐仓库
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            // 删除标志：0代表存在 2代表删除
+            repository: BaseRepository::new(db.as_ref().clone()).with_soft_delete(SoftDelete::new(
+                TenantPackageColumn::DelFlag,
+                "2",
+                "0",
+            )),
+            db,
+        }
+    }
+}
+
+#[async_trait]
+impl TenantPackageRepository for TenantPackageRepositoryImpl {
+    async fn find_by_id(&self, package_id: i64) -> Result<Option<TenantPackageModel>> {
+        Ok(self.repository.find_by_id(package_id).await?)
+    }
+
+    async fn find_list(
+        &self,
+        condition: Option<Condition>,
+        page_param: &PageParam,
+    ) -> Result<(Vec<TenantPackageModel>, u64)> {
+        let mut query = self.repository.select();
+        if let Some(condition) = condition {
+            query = query.filter(condition);
+        }
+        query = query.order_by_asc(TenantPackageColumn::PackageId);
+
+        let paginator = query.paginate(self.db.as_ref(), page_param.page_size);
+        let total = paginator.num_items().await?;
+        let packages = paginator.fetch_page(page_param.page_num - 1).await?;
+        Ok((packages, total))
+    }
+
+    async fn find_all_normal(&self) -> Result<Vec<TenantPackageModel>> {
+        Ok(self
+            .repository
+            .select()
+            .filter(TenantPackageColumn::Status.eq("0"))
+            .order_by_asc(TenantPackageColumn::PackageId)
+            .all(&current_connection(&self.db))
+            .await?)
+    }
+
+    async fn create(
+        &self,
+        mut package: TenantPackageActiveModel,
+        menu_ids: Vec<i64>,
+    ) -> Result<TenantPackageModel> {
+        let txn = current_connection(&self.db).begin().await?;
+        unset_zero_primary_key(&mut package);
+        let package = package.insert(&txn).await?;
+        for menu_id in menu_ids {
+            let package_menu = TenantPackageMenuActiveModel {
+                package_id: Set(package.package_id),
+                menu_id: Set(menu_id),
+            };
+            package_menu.insert(&txn).await?;
+        }
+        txn.commit().await?;
+        Ok(package)
+    }
+
+    async fn update(
+        &self,
+        package: TenantPackageActiveModel,
+        menu_ids: Option<Vec<i64>>,
+    ) -> Result<TenantPackageModel> {
+        let txn = current_connection(&self.db).begin().await?;
+        let package = self.repository.update_with(&txn, package).await?;
+        if let Some(menu_ids) = menu_ids {
+            // 删除旧的菜单关联
+            TenantPackageMenuEntity::delete_many()
+                .filter(TenantPackageMenuColumn::PackageId.eq(package.package_id))
+                .exec(&txn)
+                .await?;
+            // 添加新的菜单关联
+            for menu_id in menu_ids {
+                let package_menu = TenantPackageMenuActiveModel {
+                    package_id: Set(package.package_id),
+                    menu_id: Set(menu_id),
+                };
+                package_menu.insert(&txn).await?;
+            }
+        }
+        txn.commit().await?;
+        Ok(package)
+    }
+
+    async fn delete_by_ids(&self, package_ids: Vec<i64>) -> Result<u64> {
+        let txn = current_connection(&self.db).begin().await?;
+        let rows_affected = self
+            .repository
+            .delete_by_ids_with(&txn, package_ids.clone())
+            .await?;
+        TenantPackageMenuEntity::delete_many()
+            .filter(TenantPackageMenuColumn::PackageId.is_in(package_ids))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+        Ok(rows_affected)
+    }
+
+    async fn find_menu_ids(&self, package_id: i64) -> Result<Vec<i64>> {
+        Ok(TenantPackageMenuEntity::find()
+            .filter(TenantPackageMenuColumn::PackageId.eq(package_id))
+            .select_only()
+            .column(TenantPackageMenuColumn::MenuId)
+            .into_tuple::<i64>()
+            .all(&current_connection(&self.db))
+            .await?)
+    }
+
+    async fn check_package_name_unique(
+        &self,
+        package_name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool> {
+        let mut query = self
+            .repository
+            .select()
+            .filter(TenantPackageColumn::PackageName.eq(package_name));
+        if let Some(exclude_id) = exclude_id {
+            query = query.filter(TenantPackageColumn::PackageId.ne(exclude_id));
+        }
+        let count = query.count(&current_connection(&self.db)).await?;
+        Ok(count == 0)
+    }
+}
@@ -0,0 +1,128 @@
+// ruoyi-system/src/repository/tenant_repository.rs
+//! 租户仓库
+
+use crate::entity::prelude::*;
+use async_trait::async_trait;
+use ruoyi_common::{vo::PageParam, Result};
+use ruoyi_framework::db::repository::{BaseRepository, Repository, SoftDelete};
+use ruoyi_framework::db::transaction::current_connection;
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, PaginatorTrait, QueryFilter, QueryOrder,
+};
+use std::sync::Arc;
+
+/// 租户仓库特征
+#[async_trait]
+pub trait TenantRepository: Send + Sync {
+    /// 根据ID查询租户
+    async fn find_by_id(&self, tenant_id: i64) -> Result<Option<TenantModel>>;
+
+    /// 分页查询租户列表
+    async fn find_list(
+        &self,
+        condition: Option<Condition>,
+        page_param: &PageParam,
+    ) -> Result<(Vec<TenantModel>, u64)>;
+
+    /// 创建租户
+    async fn create(&self, tenant: TenantActiveModel) -> Result<TenantModel>;
+
+    /// 更新租户
+    async fn update(&self, tenant: TenantActiveModel) -> Result<TenantModel>;
+
+    /// 批量删除租户
+    async fn delete_by_ids(&self, tenant_ids: Vec<i64>) -> Result<u64>;
+
+    /// 检查租户名称是否唯一
+    async fn check_tenant_name_unique(
+        &self,
+        tenant_name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool>;
+
+    /// 套餐是否被租户使用
+    async fn has_tenant_by_package_id(&self, package_id: i64) -> Result<bool>;
+}
+
+/// 租户仓库实现
+pub struct TenantRepositoryImpl {
+    db: Arc<DatabaseConnection>,
+    repository: BaseRepository<TenantEntity, TenantActiveModel>,
+}
+
+impl TenantRepositoryImpl {
+    /// 创建租户仓库
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            // 删除标志：0代表存在 2代表删除
+            repository: BaseRepository::new(db.as_ref().clone()).with_soft_delete(SoftDelete::new(
+                TenantColumn::DelFlag,
+                "2",
+                "0",
+            )),
+            db,
+        }
+    }
+}
+
+#[async_trait]
+impl TenantRepository for TenantRepositoryImpl {
+    async fn find_by_id(&self, tenant_id: i64) -> Result<Option<TenantModel>> {
+        Ok(self.repository.find_by_id(tenant_id).await?)
+    }
+
+    async fn find_list(
+        &self,
+        condition: Option<Condition>,
+        page_param: &PageParam,
+    ) -> Result<(Vec<TenantModel>, u64)> {
+        let mut query = self.repository.select();
+        if let Some(condition) = condition {
+            query = query.filter(condition);
+        }
+        query = query.order_by_asc(TenantColumn::TenantId);
+
+        let paginator = query.paginate(self.db.as_ref(), page_param.page_size);
+        let total = paginator.num_items().await?;
+        let tenants = paginator.fetch_page(page_param.page_num - 1).await?;
+        Ok((tenants, total))
+    }
+
+    async fn create(&self, tenant: TenantActiveModel) -> Result<TenantModel> {
+        Ok(self.repository.insert(tenant).await?)
+    }
+
+    async fn update(&self, tenant: TenantActiveModel) -> Result<TenantModel> {
+        Ok(self.repository.update(tenant).await?)
+    }
+
+    async fn delete_by_ids(&self, tenant_ids: Vec<i64>) -> Result<u64> {
+        Ok(self.repository.delete_by_ids(tenant_ids).await?)
+    }
+
+    async fn check_tenant_name_unique(
+        &self,
+        tenant_name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool> {
+        let mut query = self
+            .repository
+            .select()
+            .filter(TenantColumn::TenantName.eq(tenant_name));
+        if let Some(exclude_id) = exclude_id {
+            query = query.filter(TenantColumn::TenantId.ne(exclude_id));
+        }
+        let count = query.count(&current_connection(&self.db)).await?;
+        Ok(count == 0)
+    }
+
+    async fn has_tenant_by_package_id(&self, package_id: i64) -> Result<bool> {
+        let count = self
+            .repository
+            .select()
+            .filter(TenantColumn::PackageId.eq(package_id))
+            .count(&current_connection(&self.db))
+            .await?;
+        Ok(count > 0)
+    }
+}
@@ -2,15 +2,18 @@
 //! 用户仓库实现
 
 use async_trait::async_trait;
-use chrono::Utc;
 use ruoyi_common::Result;
 use ruoyi_common::{error::Error, vo::PageParam};
+use ruoyi_framework::db::encryption::blind_index;
+use ruoyi_framework::db::history::ChangeHistory;
+use ruoyi_framework::db::transaction::current_connection;
 use ruoyi_framework::{
-    db::repository::{BaseRepository, Repository},
-    web::tls::get_sync_user_context,
+    db::query::QueryCondition,
+    db::replica::ReadRouter,
+    db::repository::{unset_zero_primary_key, BaseRepository, Repository, SoftDelete},
 };
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, IntoActiveModel,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel,
     PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
 };
 use std::sync::Arc;
@@ -26,25 +29,21 @@ pub trait UserRepository: Send + Sync {
     /// 根据用户名查找用户
     async fn find_by_username(&self, username: &str) -> Result<Option<UserModel>>;
 
-    /// 创建用户和关联角色
-    async fn create_user(
-        &self,
-        user_active_model: UserActiveModel,
-        role_ids: Option<Vec<i64>>,
-        post_ids: Option<Vec<i64>>,
-    ) -> Result<UserModel>;
+    /// 创建用户，角色和岗位关联由服务层在同一事务中设置
+    async fn create_user(&self, user_active_model: UserActiveModel) -> Result<UserModel>;
 
-    /// 更新用户和关联角色
-    async fn update_user(
-        &self,
-        user_active_model: UserActiveModel,
-        role_ids: Option<Vec<i64>>,
-        post_ids: Option<Vec<i64>>,
-    ) -> Result<UserModel>;
+    /// 更新用户
+    async fn update_user(&self, user_active_model: UserActiveModel) -> Result<UserModel>;
 
     /// 批量删除用户
     async fn delete_users_by_ids(&self, user_ids: Vec<i64>) -> Result<u64>;
 
+    /// 批量恢复已删除的用户
+    async fn restore_users_by_ids(&self, user_ids: Vec<i64>) -> Result<u64>;
+
+    /// 批量彻底删除已删除的用户
+    async fn purge_users_by_ids(&self, user_ids: Vec<i64>) -> Result<u64>;
+
     /// 重置用户密码
     async fn reset_password(&self, user_id: i64, password: &str) -> Result<UserModel>;
 
@@ -58,10 +57,10 @@ pub trait UserRepository: Send + Sync {
         exclude_user_id: Option<i64>,
     ) -> Result<bool>;
 
-    /// 检查手机号是否唯一
+    /// 检查手机号是否唯一，手机号加密存储，按盲索引比较
     async fn check_phone_unique(&self, phone: &str, exclude_user_id: Option<i64>) -> Result<bool>;
 
-    /// 检查邮箱是否唯一
+    /// 检查邮箱是否唯一，邮箱加密存储，按盲索引比较
     async fn check_email_unique(&self, email: &str, exclude_user_id: Option<i64>) -> Result<bool>;
 
     /// 查询用户列表
@@ -78,77 +77,40 @@ pub struct UserRepositoryImpl {
     repository: BaseRepository<UserEntity, UserActiveModel>,
     /// 数据库连接
     db: Arc<DatabaseConnection>,
+    /// 只读查询路由
+    read_router: ReadRouter,
 }
 
 impl UserRepositoryImpl {
     /// 创建用户仓库
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
-            repository: BaseRepository::new(db.as_ref().clone()),
+            // 删除标志：0代表存在 2代表删除
+            repository: BaseRepository::new(db.as_ref().clone())
+                .with_soft_delete(SoftDelete::new(UserColumn::DelFlag, "2", "0"))
+                .with_tenant(UserColumn::TenantId)
+                // 密码和加密存储的字段只记录是否修改
+                .with_change_history(ChangeHistory::new().with_masked_columns([
+                    UserColumn::Password,
+                    UserColumn::Email,
+                    UserColumn::Phonenumber,
+                ])),
+            read_router: ReadRouter::primary_only(db.clone()),
             db,
         }
     }
 
-    /// 构建查询条件
-    fn build_query_condition(&self, query: &UserQuery) -> Condition {
-        let mut condition = Condition::all();
-
-        if let Some(user_id) = query.user_id {
-            condition = condition.add(UserColumn::UserId.eq(user_id));
-        }
-
-        if let Some(dept_id) = query.dept_id {
-            condition = condition.add(UserColumn::DeptId.eq(dept_id));
-        }
-
-        if let Some(ref user_name) = query.user_name {
-            if !user_name.is_empty() {
-                condition = condition.add(UserColumn::UserName.contains(user_name));
-            }
-        }
-
-        if let Some(ref nick_name) = query.nick_name {
-            if !nick_name.is_empty() {
-                condition = condition.add(UserColumn::NickName.contains(nick_name));
-            }
-        }
-
-        if let Some(ref phonenumber) = query.phonenumber {
-            if !phonenumber.is_empty() {
-                condition = condition.add(UserColumn::Phonenumber.contains(phonenumber));
-            }
-        }
-
-        if let Some(ref status) = query.status {
-            if !status.is_empty() {
-                condition = condition.add(UserColumn::Status.eq(status));
-            }
-        }
-
-        if let Some(ref begin_time) = query.begin_time {
-            condition = condition.add(UserColumn::CreateTime.gt(begin_time.clone()));
-        }
-
-        if let Some(ref end_time) = query.end_time {
-            condition = condition.add(UserColumn::CreateTime.lt(end_time.clone()));
-        }
-
-        // 默认只查询未删除的用户
-        condition = condition.add(UserColumn::DelFlag.eq("0"));
-
-        condition
+    /// 用户列表查询使用只读副本
+    pub fn with_read_router(mut self, read_router: ReadRouter) -> Self {
+        self.read_router = read_router;
+        self
     }
 }
 
 #[async_trait]
 impl UserRepository for UserRepositoryImpl {
     async fn find_by_id(&self, user_id: i64) -> Result<Option<UserModel>> {
-        let user_opt = self.repository.find_by_id(user_id).await?;
-        if user_opt.is_some() && user_opt.as_ref().unwrap().del_flag == Some("0".to_string()) {
-            Ok(user_opt)
-        } else {
-            Ok(None)
-        }
+        Ok(self.repository.find_by_id(user_id).await?)
     }
 
     async fn find_by_username(&self, username: &str) -> Result<Option<UserModel>> {
@@ -156,134 +118,70 @@ impl UserRepository for UserRepositoryImpl {
             .repository
             .select()
             .filter(UserColumn::UserName.eq(username))
-            .filter(UserColumn::DelFlag.eq("0"))
-            .one(self.db.as_ref())
+            .one(&current_connection(&self.db))
             .await?)
     }
 
-    async fn create_user(
-        &self,
-        mut user_active_model: UserActiveModel,
-        role_ids: Option<Vec<i64>>,
-        post_ids: Option<Vec<i64>>,
-    ) -> Result<UserModel> {
-        if let Some(user) = get_sync_user_context() {
-            user_active_model.create_by = Set(Some(user.user_name.clone()));
-            user_active_model.update_by = Set(Some(user.user_name.clone()));
-        }
-        let txn = self.db.begin().await?;
-        // 设置创建信息
-        let now = Utc::now();
-        user_active_model.create_time = Set(Some(now));
-        user_active_model.update_time = Set(Some(now));
-
+    async fn create_user(&self, mut user_active_model: UserActiveModel) -> Result<UserModel> {
         // 标记为未删除
         user_active_model.del_flag = Set(Some("0".to_string()));
 
         // 插入用户
-        let user = user_active_model.insert(&txn).await?;
-
-        // 添加用户角色关联
-        if let Some(role_ids) = role_ids {
-            for role_id in role_ids {
-                let user_role = UserRoleActiveModel {
-                    user_id: Set(user.user_id),
-                    role_id: Set(role_id),
-                };
-                user_role.insert(&txn).await?;
-            }
-        }
-
-        // 添加用户岗位关联
-        if let Some(post_ids) = post_ids {
-            for post_id in post_ids {
-                let user_post = UserPostActiveModel {
-                    user_id: Set(user.user_id),
-                    post_id: Set(post_id),
-                };
-                user_post.insert(&txn).await?;
-            }
-        }
+        unset_zero_primary_key(&mut user_active_model);
+        Ok(user_active_model
+            .insert(&current_connection(&self.db))
+            .await?)
+    }
 
-        txn.commit().await?;
-        Ok(user)
+    async fn update_user(&self, user_active_model: UserActiveModel) -> Result<UserModel> {
+        Ok(self.repository.update(user_active_model).await?)
     }
 
-    async fn update_user(
-        &self,
-        mut user_active_model: UserActiveModel,
-        role_ids: Option<Vec<i64>>,
-        post_ids: Option<Vec<i64>>,
-    ) -> Result<UserModel> {
-        if let Some(user) = get_sync_user_context() {
-            user_active_model.update_by = Set(Some(user.user_name.clone()));
-        }
-        let txn = self.db.begin().await?;
-        let user_id = user_active_model.user_id.clone().unwrap();
-        // 设置更新时间
-        user_active_model.update_time = Set(Some(Utc::now()));
-        // 删除旧的角色关联
-        UserRoleEntity::delete_many()
-            .filter(UserRoleColumn::UserId.eq(user_id))
-            .exec(&txn)
+    async fn delete_users_by_ids(&self, user_ids: Vec<i64>) -> Result<u64> {
+        let txn = current_connection(&self.db).begin().await?;
+        // 逻辑删除用户
+        let rows_affected = self
+            .repository
+            .delete_by_ids_with(&txn, user_ids.clone())
             .await?;
-        // 添加新的角色关联
-        if let Some(role_ids) = role_ids {
-            for role_id in role_ids {
-                let user_role = UserRoleActiveModel {
-                    user_id: Set(user_id),
-                    role_id: Set(role_id),
-                };
-                user_role.insert(&txn).await?;
-            }
-        }
-        // 删除旧的岗位关联
-        UserPostEntity::delete_many()
-            .filter(UserPostColumn::UserId.eq(user_id))
+
+        // 删除用户角色关联
+        UserRoleEntity::delete_many()
+            .filter(UserRoleColumn::UserId.is_in(user_ids))
             .exec(&txn)
             .await?;
-        // 添加新的岗位关联
-        if let Some(post_ids) = post_ids {
-            for post_id in post_ids {
-                let user_post = UserPostActiveModel {
-                    user_id: Set(user_id),
-                    post_id: Set(post_id),
-                };
-                user_post.insert(&txn).await?;
-            }
-        }
-        // 更新用户
-        let user = user_active_model.update(&txn).await?;
 
         txn.commit().await?;
-        Ok(user)
+        Ok(rows_affected)
     }
 
-    async fn delete_users_by_ids(&self, user_ids: Vec<i64>) -> Result<u64> {
-        let txn = self.db.begin().await?;
-        // 软删除用户 (设置 del_flag = "2")
-        let mut update = UserActiveModel {
-            del_flag: Set(Some("2".to_string())),
-            update_time: Set(Some(Utc::now())),
-            ..Default::default()
-        };
-        if let Some(user) = get_sync_user_context() {
-            update.update_by = Set(Some(user.user_name.clone()));
+    async fn restore_users_by_ids(&self, user_ids: Vec<i64>) -> Result<u64> {
+        Ok(self.repository.restore_by_ids(user_ids).await?)
+    }
+
+    async fn purge_users_by_ids(&self, user_ids: Vec<i64>) -> Result<u64> {
+        // 只清除已删除的用户及其岗位关联，角色关联在删除时已清除
+        let user_ids: Vec<i64> = self
+            .repository
+            .select_deleted()
+            .filter(UserColumn::UserId.is_in(user_ids))
+            .all(&current_connection(&self.db))
+            .await?
+            .into_iter()
+            .map(|user| user.user_id)
+            .collect();
+        if user_ids.is_empty() {
+            return Ok(0);
         }
-        let res = UserEntity::update_many()
-            .filter(UserColumn::UserId.is_in(user_ids.clone()))
-            .set(update)
-            .exec(&txn)
-            .await?;
 
-        // 删除用户角色关联
-        UserRoleEntity::delete_many()
-            .filter(UserRoleColumn::UserId.is_in(user_ids))
+        let txn = current_connection(&self.db).begin().await?;
+        UserPostEntity::delete_many()
+            .filter(UserPostColumn::UserId.is_in(user_ids.clone()))
             .exec(&txn)
             .await?;
-
+        let rows_affected = self.repository.purge_by_ids_with(&txn, user_ids).await?;
         txn.commit().await?;
-        Ok(res.rows_affected)
+        Ok(rows_affected)
     }
 
     async fn reset_password(&self, user_id: i64, password: &str) -> Result<UserModel> {
@@ -297,12 +195,8 @@ impl UserRepository for UserRepositoryImpl {
         // 更新密码
         let mut active_model: UserActiveModel = user.clone().into_active_model();
         active_model.password = Set(Some(password.to_string()));
-        active_model.update_time = Set(Some(Utc::now()));
-        if let Some(user) = get_sync_user_context() {
-            active_model.update_by = Set(Some(user.user_name.clone()));
-        }
 
-        let updated_user = active_model.update(self.db.as_ref()).await?;
+        let updated_user = self.repository.update(active_model).await?;
         Ok(updated_user)
     }
 
@@ -317,11 +211,7 @@ impl UserRepository for UserRepositoryImpl {
         // 更新状态
         let mut active_model: UserActiveModel = user.clone().into_active_model();
         active_model.status = Set(Some(status.to_string()));
-        active_model.update_time = Set(Some(Utc::now()));
-        if let Some(user) = get_sync_user_context() {
-            active_model.update_by = Set(Some(user.user_name.clone()));
-        }
-        let updated_user = active_model.update(self.db.as_ref()).await?;
+        let updated_user = self.repository.update(active_model).await?;
         Ok(updated_user)
     }
 
@@ -330,41 +220,44 @@ impl UserRepository for UserRepositoryImpl {
         username: &str,
         exclude_user_id: Option<i64>,
     ) -> Result<bool> {
-        let mut query = UserEntity::find()
-            .filter(UserColumn::UserName.eq(username))
-            .filter(UserColumn::DelFlag.eq("0"));
+        let mut query = self
+            .repository
+            .select()
+            .filter(UserColumn::UserName.eq(username));
 
         if let Some(user_id) = exclude_user_id {
             query = query.filter(UserColumn::UserId.ne(user_id));
         }
 
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
 
     async fn check_phone_unique(&self, phone: &str, exclude_user_id: Option<i64>) -> Result<bool> {
-        let mut query = UserEntity::find()
-            .filter(UserColumn::Phonenumber.eq(phone))
-            .filter(UserColumn::DelFlag.eq("0"));
+        let mut query = self
+            .repository
+            .select()
+            .filter(UserColumn::PhonenumberIndex.eq(blind_index(phone)));
 
         if let Some(user_id) = exclude_user_id {
             query = query.filter(UserColumn::UserId.ne(user_id));
         }
 
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
 
     async fn check_email_unique(&self, email: &str, exclude_user_id: Option<i64>) -> Result<bool> {
-        let mut query = UserEntity::find()
-            .filter(UserColumn::Email.eq(email))
-            .filter(UserColumn::DelFlag.eq("0"));
+        let mut query = self
+            .repository
+            .select()
+            .filter(UserColumn::EmailIndex.eq(blind_index(email)));
 
         if let Some(user_id) = exclude_user_id {
             query = query.filter(UserColumn::UserId.ne(user_id));
         }
 
-        let count = query.count(self.db.as_ref()).await?;
+        let count = query.count(&current_connection(&self.db)).await?;
         Ok(count == 0)
     }
 
@@ -373,15 +266,18 @@ impl UserRepository for UserRepositoryImpl {
         query: &UserQuery,
         page_param: &PageParam,
     ) -> Result<(Vec<(UserModel, Option<DeptModel>)>, u64)> {
-        let condition = self.build_query_condition(query);
+        let condition = query.to_condition();
 
         // 创建查询构建器
-        let query_builder = UserEntity::find()
+        let query_builder = self
+            .repository
+            .select()
             .filter(condition)
             .order_by(UserColumn::UserId, sea_orm::Order::Asc);
 
-        // 使用分页
-        let paginator = query_builder.paginate(self.db.as_ref(), page_param.page_size as u64);
+        // 使用分页，列表查询走只读副本
+        let read_db = self.read_router.connection();
+        let paginator = query_builder.paginate(&read_db, page_param.page_size as u64);
 
         // 获取总数
         let total = paginator.num_items().await?;
@@ -401,7 +297,7 @@ impl UserRepository for UserRepositoryImpl {
         let users_with_depts = UserEntity::find()
             .filter(UserColumn::UserId.is_in(user_ids.clone()))
             .find_with_related(DeptEntity)
-            .all(self.db.as_ref())
+            .all(&read_db)
             .await?;
 
         // 创建用户ID到(用户,部门)的映射
@@ -0,0 +1,112 @@
+// ruoyi-system/src/service/change_log_service.rs
+//! 实体变更历史服务实现
+
+use crate::entity::prelude::*;
+use crate::repository::change_log_repository::ChangeLogRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ruoyi_common::Result;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// 记录变更历史的实体，键为接口中使用的名称，值为表名
+pub const TRACKED_ENTITIES: [(&str, &str); 5] = [
+    ("user", "sys_user"),
+    ("role", "sys_role"),
+    ("menu", "sys_menu"),
+    ("dept", "sys_dept"),
+    ("config", "sys_config"),
+];
+
+/// 变更历史响应数据
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLogResponse {
+    /// 变更主键
+    pub change_id: i64,
+    /// 实体名称（表名）
+    pub entity_name: String,
+    /// 实体主键
+    pub entity_id: String,
+    /// 变更类型（update修改 delete删除 restore恢复 purge彻底删除）
+    pub change_type: String,
+    /// 字段差异，格式为 `{"字段": {"before": 旧值, "after": 新值}}`
+    #[schema(value_type = Object)]
+    pub changes: JsonValue,
+    /// 操作人员
+    pub oper_name: Option<String>,
+    /// 变更时间
+    pub change_time: Option<DateTime<Utc>>,
+}
+
+impl From<ChangeLogModel> for ChangeLogResponse {
+    fn from(model: ChangeLogModel) -> Self {
+        let changes = model
+            .changes
+            .as_deref()
+            .and_then(|changes| serde_json::from_str(changes).ok())
+            .unwrap_or(JsonValue::Null);
+        Self {
+            change_id: model.change_id,
+            entity_name: model.entity_name,
+            entity_id: model.entity_id,
+            change_type: model.change_type,
+            changes,
+            oper_name: model.oper_name,
+            change_time: model.change_time,
+        }
+    }
+}
+
+/// 实体变更历史服务特征
+#[async_trait]
+pub trait ChangeLogService: Send + Sync {
+    /// 获取记录的变更历史时间线，实体不在 [`TRACKED_ENTITIES`] 中时返回None
+    async fn get_history(
+        &self,
+        entity: &str,
+        entity_id: &str,
+    ) -> Result<Option<Vec<ChangeLogResponse>>>;
+}
+
+/// 实体变更历史服务实现
+pub struct ChangeLogServiceImpl {
+    change_log_repository: Arc<dyn ChangeLogRepository>,
+}
+
+impl ChangeLogServiceImpl {
+    pub fn new(change_log_repository: Arc<dyn ChangeLogRepository>) -> Self {
+        Self {
+            change_log_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl ChangeLogService for ChangeLogServiceImpl {
+    async fn get_history(
+        &self,
+        entity: &str,
+        entity_id: &str,
+    ) -> Result<Option<Vec<ChangeLogResponse>>> {
+        // 支持接口名称和表名
+        let Some((_, table_name)) = TRACKED_ENTITIES
+            .iter()
+            .find(|(name, table_name)| *name == entity || *table_name == entity)
+        else {
+            return Ok(None);
+        };
+        let change_logs = self
+            .change_log_repository
+            .find_by_entity(table_name, entity_id)
+            .await?;
+        Ok(Some(
+            change_logs
+                .into_iter()
+                .map(ChangeLogResponse::from)
+                .collect(),
+        ))
+    }
+}
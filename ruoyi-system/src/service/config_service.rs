@@ -1,8 +1,13 @@
 use crate::{controller::config_controller::{ConfigQuery, CreateOrUpdateConfigRequest}, repository::config_repository::ConfigRepository};
-use ruoyi_common::{error::Error, Result};
+use ruoyi_common::{constants, error::Error, Result};
+use ruoyi_framework::cache::{get_global_cache, DistributedLock};
+use ruoyi_framework::db::query::QueryCondition;
+use ruoyi_framework::tenant::check_platform_tenant;
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, Condition, IntoActiveModel, Set};
+use log::{info, warn};
+use sea_orm::{IntoActiveModel, Set};
 use std::sync::Arc;
+use std::time::Instant;
 use ruoyi_common::vo::PageParam;
 use crate::entity::prelude::*;
 
@@ -17,6 +22,36 @@ pub trait ConfigService: Send + Sync {
     async fn check_config_name_unique(&self, config_name: &str, config_id: Option<i32>) -> Result<bool>;
     async fn check_config_key_unique(&self, config_key: &str, config_id: Option<i32>) -> Result<bool>;
     async fn get_config_by_key(&self, config_key: &str) -> Result<String>;
+    /// 将所有参数加载到缓存，返回加载的数量
+    async fn load_config_cache(&self) -> Result<usize>;
+    /// 清空参数缓存
+    async fn clear_config_cache(&self) -> Result<()>;
+    /// 重置参数缓存
+    async fn reset_config_cache(&self) -> Result<usize>;
+}
+
+/// 参数缓存的键
+fn config_cache_key(config_key: &str) -> String {
+    format!("{}{}", constants::cache::SYS_CONFIG_PREFIX, config_key)
+}
+
+/// 写入参数缓存，缓存不可用时只记录日志，不影响数据库操作
+async fn set_config_cache(config: &ConfigModel) {
+    if let (Ok(cache), Some(config_key)) = (get_global_cache(), &config.config_key) {
+        let value = config.config_value.clone().unwrap_or_default();
+        if let Err(e) = cache.set_string(&config_cache_key(config_key), &value).await {
+            warn!("写入参数缓存失败: {}, {}", config_key, e);
+        }
+    }
+}
+
+/// 删除参数缓存
+async fn del_config_cache(config_key: &str) {
+    if let Ok(cache) = get_global_cache() {
+        if let Err(e) = cache.del(&config_cache_key(config_key)).await {
+            warn!("删除参数缓存失败: {}, {}", config_key, e);
+        }
+    }
 }
 
 pub struct ConfigServiceImpl {
@@ -27,31 +62,12 @@ impl ConfigServiceImpl {
     pub fn new(config_repository: Arc<dyn ConfigRepository>) -> Self {
         Self { config_repository }
     }
-    pub fn build_query_condition(&self, query: &ConfigQuery) -> Option<Condition> {
-        let mut condition = Condition::all();
-        if let Some(config_key) = &query.config_key {
-            condition = condition.add(ConfigColumn::ConfigKey.contains(config_key));
-        }
-        if let Some(config_name) = &query.config_name {
-            condition = condition.add(ConfigColumn::ConfigName.contains(config_name));
-        }
-        if let Some(config_type) = &query.config_type {
-            condition = condition.add(ConfigColumn::ConfigType.eq(config_type));
-        }
-        if let Some(begin_time) = query.begin_time {
-            condition = condition.add(ConfigColumn::CreateTime.gte(begin_time));
-        }
-        if let Some(end_time) = query.end_time {
-            condition = condition.add(ConfigColumn::CreateTime.lte(end_time));
-        }
-        Some(condition)
-    }
 }
 
 #[async_trait]
 impl ConfigService for ConfigServiceImpl {
     async fn get_config_list(&self, query: ConfigQuery, page_param: PageParam) -> Result<(Vec<ConfigModel>, u64)> {
-        Ok(self.config_repository.get_config_list(self.build_query_condition(&query), page_param).await?)
+        Ok(self.config_repository.get_config_list(Some(query.to_condition()), page_param).await?)
     }
     async fn get_all_configs(&self) -> Result<Vec<ConfigModel>> {
         Ok(self.config_repository.get_all_configs().await?)
@@ -60,6 +76,7 @@ impl ConfigService for ConfigServiceImpl {
         Ok(self.config_repository.get_config_by_id(config_id).await?)
     }
     async fn create_config(&self, req: CreateOrUpdateConfigRequest) -> Result<ConfigModel> {
+        check_platform_tenant("参数配置")?;
         let model = ConfigModel {
             config_id: 0,
             config_key: req.config_key,
@@ -71,16 +88,25 @@ impl ConfigService for ConfigServiceImpl {
             create_time: None,
             update_by: None,
             update_time: None,
+            version: 0,
         };
-        Ok(self.config_repository.create_config(model.into_active_model()).await?)
+        let config = self.config_repository.create_config(model.into_active_model()).await?;
+        set_config_cache(&config).await;
+        Ok(config)
     }
     async fn update_config(&self, req: CreateOrUpdateConfigRequest) -> Result<ConfigModel> {
+        check_platform_tenant("参数配置")?;
         let config_id = req.config_id.unwrap();
         let model = self.config_repository.get_config_by_id(config_id).await?;
         if model.is_none() {
             return Err(Error::BusinessError(format!("配置不存在: {}", config_id)));
         }
+        let old_config_key = model.as_ref().and_then(|m| m.config_key.clone());
         let mut active_model = model.unwrap().into_active_model();
+        // 以页面加载时的版本号为准，数据已被其他用户修改时更新失败
+        if let Some(version) = req.version {
+            active_model.version = Set(version);
+        }
         if let Some(config_key) = req.config_key {
             active_model.config_key = Set(Some(config_key));
         }
@@ -96,10 +122,29 @@ impl ConfigService for ConfigServiceImpl {
         if let Some(remark) = req.remark {
             active_model.remark = Set(Some(remark));
         }
-        Ok(self.config_repository.update_config(active_model).await?)
+        let config = self.config_repository.update_config(active_model).await?;
+        // 参数键名修改后删除旧键名的缓存
+        if let Some(old_config_key) = old_config_key {
+            if config.config_key.as_ref() != Some(&old_config_key) {
+                del_config_cache(&old_config_key).await;
+            }
+        }
+        set_config_cache(&config).await;
+        Ok(config)
     }
     async fn delete_configs(&self, config_ids: Vec<i32>) -> Result<u64> {
-        Ok(self.config_repository.delete_configs(config_ids).await?)
+        check_platform_tenant("参数配置")?;
+        let mut config_keys = Vec::with_capacity(config_ids.len());
+        for config_id in &config_ids {
+            if let Some(config) = self.config_repository.get_config_by_id(*config_id).await? {
+                config_keys.extend(config.config_key);
+            }
+        }
+        let num = self.config_repository.delete_configs(config_ids).await?;
+        for config_key in config_keys {
+            del_config_cache(&config_key).await;
+        }
+        Ok(num)
     }
     async fn check_config_name_unique(&self, config_name: &str, config_id: Option<i32>) -> Result<bool> {
         Ok(self.config_repository.check_config_name_unique(config_name, config_id).await?)
@@ -108,6 +153,57 @@ impl ConfigService for ConfigServiceImpl {
         Ok(self.config_repository.check_config_key_unique(config_key, config_id).await?)
     }
     async fn get_config_by_key(&self, config_key: &str) -> Result<String> {
-        Ok(self.config_repository.get_config_by_key(config_key).await?)
+        // 优先从缓存读取，未命中时查询数据库并回填缓存
+        let cache_key = config_cache_key(config_key);
+        let cache = get_global_cache().ok();
+        if let Some(cache) = &cache {
+            match cache.get_string(&cache_key).await {
+                Ok(Some(value)) => return Ok(value),
+                Ok(None) => {}
+                Err(e) => warn!("读取参数缓存失败: {}, {}", config_key, e),
+            }
+        }
+        let start = Instant::now();
+        let value = self.config_repository.get_config_by_key(config_key).await?;
+        if let Some(cache) = &cache {
+            cache.record_load(&cache_key, start.elapsed());
+            // 参数不存在时不写入缓存，避免新增参数后读到空值
+            if !value.is_empty() {
+                if let Err(e) = cache.set_string(&cache_key, &value).await {
+                    warn!("写入参数缓存失败: {}, {}", config_key, e);
+                }
+            }
+        }
+        Ok(value)
+    }
+    async fn load_config_cache(&self) -> Result<usize> {
+        let configs = self.config_repository.get_all_configs().await?;
+        for config in &configs {
+            set_config_cache(config).await;
+        }
+        info!("参数缓存加载完成, 数量: {}", configs.len());
+        Ok(configs.len())
+    }
+    async fn clear_config_cache(&self) -> Result<()> {
+        check_platform_tenant("参数配置")?;
+        let cache = get_global_cache().map_err(|e| Error::BusinessError(e.to_string()))?;
+        let pattern = format!("{}*", constants::cache::SYS_CONFIG_PREFIX);
+        let keys = cache.scan_keys(&pattern, constants::cache::MAX_SCAN_KEYS).await.map_err(|e| Error::BusinessError(e.to_string()))?;
+        for key in keys {
+            cache.del(&key).await.map_err(|e| Error::BusinessError(e.to_string()))?;
+        }
+        Ok(())
+    }
+    async fn reset_config_cache(&self) -> Result<usize> {
+        check_platform_tenant("参数配置")?;
+        // 多个实例同时刷新时只允许一个实例重建缓存
+        let lock = DistributedLock::global("refresh_config_cache").map_err(|e| Error::BusinessError(e.to_string()))?;
+        let guard = lock.acquire().await.map_err(|e| Error::BusinessError(e.to_string()))?.ok_or_else(|| Error::BusinessError("其他实例正在刷新参数缓存，请稍后再试".to_string()))?;
+        let result = match self.clear_config_cache().await {
+            Ok(_) => self.load_config_cache().await,
+            Err(e) => Err(e),
+        };
+        let _ = guard.release().await;
+        result
     }
 }
@@ -5,6 +5,7 @@ use async_trait::async_trait;
 use ruoyi_common::error::Error;
 use ruoyi_common::utils::tree::build_tree;
 use ruoyi_common::Result;
+use ruoyi_framework::db::query::QueryCondition;
 use sea_orm::{ColumnTrait, Condition, IntoActiveModel, Set};
 use std::sync::Arc;
 
@@ -62,14 +63,7 @@ impl DeptServiceImpl {
     }
 
     pub fn build_query(&self, query: DeptQuery, exclude_id: Option<i64>) -> Option<Condition> {
-        let mut condition = Condition::all();
-        condition = condition.add(DeptColumn::DelFlag.eq("0"));
-        if let Some(dept_name) = query.dept_name {
-            condition = condition.add(DeptColumn::DeptName.like(dept_name));
-        }
-        if let Some(status) = query.status {
-            condition = condition.add(DeptColumn::Status.eq(status));
-        }
+        let mut condition = query.to_condition();
         if let Some(exclude_id) = exclude_id {
             condition = condition.add(DeptColumn::DeptId.ne(exclude_id));
         }
@@ -126,6 +120,7 @@ impl DeptService for DeptServiceImpl {
             update_by: None,
             update_time: None,
             ancestors,
+            tenant_id: 0,
         };
         let dept_active_model = dept_model.into_active_model();
         Ok(self.dept_repository.create(dept_active_model).await?)
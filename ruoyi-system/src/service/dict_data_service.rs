@@ -1,11 +1,14 @@
 use std::sync::Arc;
+use std::time::Instant;
 
 use async_trait::async_trait;
-use log::info;
+use log::{info, warn};
 use ruoyi_common::{constants, Result};
 use ruoyi_common::{error::Error, vo::PageParam};
 use ruoyi_framework::cache::get_global_cache;
-use sea_orm::{ColumnTrait, Condition, IntoActiveModel, Set};
+use ruoyi_framework::db::query::QueryCondition;
+use ruoyi_framework::tenant::check_platform_tenant;
+use sea_orm::{IntoActiveModel, Set};
 
 use crate::{
     controller::dict_data_controller::{CreateOrUpdateDictDataRequest, DictDataQuery},
@@ -31,6 +34,40 @@ pub trait DictDataService {
         dict_code: Option<i64>,
     ) -> Result<bool>;
     async fn get_dict_data_by_type(&self, dict_type: &str) -> Result<Vec<DictDataModel>>;
+    /// 将指定字典类型的字典数据加载到缓存，返回加载的类型数量
+    async fn load_dict_cache(&self, dict_types: &[String]) -> Result<usize>;
+    /// 清空字典缓存
+    async fn clear_dict_cache(&self) -> Result<()>;
+}
+
+/// 字典缓存的键
+pub fn dict_cache_key(dict_type: &str) -> String {
+    format!("{}{}", constants::cache::SYS_DICT_PREFIX, dict_type)
+}
+
+/// 写入字典缓存，缓存不可用时只记录日志
+async fn set_dict_cache(dict_type: &str, dict_data: &[DictDataModel]) {
+    if let Ok(cache) = get_global_cache() {
+        let value = match serde_json::to_string(dict_data) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("序列化字典数据失败: {}, {}", dict_type, e);
+                return;
+            }
+        };
+        if let Err(e) = cache.set_string(&dict_cache_key(dict_type), &value).await {
+            warn!("写入字典缓存失败: {}, {}", dict_type, e);
+        }
+    }
+}
+
+/// 删除字典缓存，下次读取时从数据库重新加载
+pub async fn remove_dict_cache(dict_type: &str) {
+    if let Ok(cache) = get_global_cache() {
+        if let Err(e) = cache.del(&dict_cache_key(dict_type)).await {
+            warn!("删除字典缓存失败: {}, {}", dict_type, e);
+        }
+    }
 }
 
 pub struct DictDataServiceImpl {
@@ -43,19 +80,6 @@ impl DictDataServiceImpl {
             dict_data_repository,
         }
     }
-    pub fn build_query_condition(&self, query: &DictDataQuery) -> Option<Condition> {
-        let mut condition = Condition::all();
-        if let Some(dict_type) = &query.dict_type {
-            condition = condition.add(DictDataColumn::DictType.eq(dict_type));
-        }
-        if let Some(dict_label) = &query.dict_label {
-            condition = condition.add(DictDataColumn::DictLabel.contains(dict_label));
-        }
-        if let Some(status) = &query.status {
-            condition = condition.add(DictDataColumn::Status.eq(status));
-        }
-        Some(condition)
-    }
 }
 
 #[async_trait]
@@ -67,7 +91,7 @@ impl DictDataService for DictDataServiceImpl {
     ) -> Result<(Vec<DictDataModel>, u64)> {
         Ok(self
             .dict_data_repository
-            .get_dict_data_list(self.build_query_condition(&query), page_param)
+            .get_dict_data_list(Some(query.to_condition()), page_param)
             .await?)
     }
     async fn get_dict_data_by_id(&self, dict_id: i64) -> Result<Option<DictDataModel>> {
@@ -77,6 +101,7 @@ impl DictDataService for DictDataServiceImpl {
             .await?)
     }
     async fn create_dict_data(&self, req: CreateOrUpdateDictDataRequest) -> Result<DictDataModel> {
+        check_platform_tenant("字典")?;
         let dict_data = DictDataModel {
             dict_code: 0,
             dict_label: req.dict_label,
@@ -93,12 +118,17 @@ impl DictDataService for DictDataServiceImpl {
             remark: req.remark,
             dict_sort: req.dict_sort,
         };
-        Ok(self
+        let dict_data = self
             .dict_data_repository
             .create_dict_data(dict_data.into_active_model())
-            .await?)
+            .await?;
+        if let Some(dict_type) = &dict_data.dict_type {
+            remove_dict_cache(dict_type).await;
+        }
+        Ok(dict_data)
     }
     async fn update_dict_data(&self, req: CreateOrUpdateDictDataRequest) -> Result<DictDataModel> {
+        check_platform_tenant("字典")?;
         let dict_data_id = req.dict_code.unwrap();
         let dict_data_model = self
             .dict_data_repository
@@ -135,16 +165,40 @@ impl DictDataService for DictDataServiceImpl {
         if let Some(dict_sort) = req.dict_sort {
             dict_data_active_model.dict_sort = Set(Some(dict_sort));
         }
-        Ok(self
+        let dict_data = self
             .dict_data_repository
             .update_dict_data(dict_data_active_model)
-            .await?)
+            .await?;
+        if let Some(dict_type) = &dict_data.dict_type {
+            remove_dict_cache(dict_type).await;
+        }
+        Ok(dict_data)
     }
     async fn delete_dict_datas(&self, dict_ids: Vec<i64>) -> Result<u64> {
-        Ok(self
+        check_platform_tenant("字典")?;
+        // 删除前记录受影响的字典类型，删除后清除对应缓存
+        let mut dict_types = Vec::new();
+        for dict_id in &dict_ids {
+            if let Some(dict_data) = self
+                .dict_data_repository
+                .get_dict_data_by_id(*dict_id)
+                .await?
+            {
+                if let Some(dict_type) = dict_data.dict_type {
+                    if !dict_types.contains(&dict_type) {
+                        dict_types.push(dict_type);
+                    }
+                }
+            }
+        }
+        let num = self
             .dict_data_repository
             .delete_dict_datas(dict_ids)
-            .await?)
+            .await?;
+        for dict_type in &dict_types {
+            remove_dict_cache(dict_type).await;
+        }
+        Ok(num)
     }
     async fn check_dict_data_label_unique(
         &self,
@@ -159,15 +213,10 @@ impl DictDataService for DictDataServiceImpl {
     }
     async fn get_dict_data_by_type(&self, dict_type: &str) -> Result<Vec<DictDataModel>> {
         // 从缓存中获取字典数据
-        if let Ok(cache) = get_global_cache() {
-            if let Ok(dict_data) = cache
-                .get_string(&format!(
-                    "{}{}",
-                    constants::cache::SYS_DICT_PREFIX,
-                    &dict_type
-                ))
-                .await
-            {
+        let cache_key = dict_cache_key(dict_type);
+        let cache = get_global_cache().ok();
+        if let Some(cache) = &cache {
+            if let Ok(dict_data) = cache.get_string(&cache_key).await {
                 if let Some(dict_data) = dict_data {
                     if let Ok(dict_data) = serde_json::from_str::<Vec<DictDataModel>>(&dict_data) {
                         info!("缓存命中获取字典数据: {:?}", dict_data);
@@ -177,10 +226,42 @@ impl DictDataService for DictDataServiceImpl {
             }
         }
         info!("缓存未命中获取字典数据: {}", dict_type);
+        let start = Instant::now();
         let dict_data = self
             .dict_data_repository
             .get_dict_data_by_type(dict_type)
             .await?;
+        if let Some(cache) = &cache {
+            cache.record_load(&cache_key, start.elapsed());
+            set_dict_cache(dict_type, &dict_data).await;
+        }
         Ok(dict_data)
     }
+    async fn load_dict_cache(&self, dict_types: &[String]) -> Result<usize> {
+        for dict_type in dict_types {
+            let dict_data = self
+                .dict_data_repository
+                .get_dict_data_by_type(dict_type)
+                .await?;
+            set_dict_cache(dict_type, &dict_data).await;
+        }
+        info!("字典缓存加载完成, 类型数量: {}", dict_types.len());
+        Ok(dict_types.len())
+    }
+    async fn clear_dict_cache(&self) -> Result<()> {
+        check_platform_tenant("字典")?;
+        let cache = get_global_cache().map_err(|e| Error::BusinessError(e.to_string()))?;
+        let pattern = format!("{}*", constants::cache::SYS_DICT_PREFIX);
+        let keys = cache
+            .scan_keys(&pattern, constants::cache::MAX_SCAN_KEYS)
+            .await
+            .map_err(|e| Error::BusinessError(e.to_string()))?;
+        for key in keys {
+            cache
+                .del(&key)
+                .await
+                .map_err(|e| Error::BusinessError(e.to_string()))?;
+        }
+        Ok(())
+    }
 }
@@ -3,11 +3,14 @@ use std::sync::Arc;
 use crate::controller::dict_type_controller::{CreateOrUpdateDictTypeRequest, DictTypeQuery};
 use crate::entity::prelude::*;
 use crate::repository::dict_type_repository::DictTypeRepository;
+use crate::service::dict_data_service::remove_dict_cache;
 use async_trait::async_trait;
 use ruoyi_common::error::Error;
 use ruoyi_common::vo::PageParam;
 use ruoyi_common::Result;
-use sea_orm::{ColumnTrait, Condition, IntoActiveModel, Set};
+use ruoyi_framework::db::query::QueryCondition;
+use ruoyi_framework::tenant::check_platform_tenant;
+use sea_orm::{IntoActiveModel, Set};
 
 #[async_trait]
 pub trait DictTypeService {
@@ -52,25 +55,6 @@ impl DictTypeServiceImpl {
             dict_type_repository,
         }
     }
-    pub fn build_query_condition(&self, query: &DictTypeQuery) -> Option<Condition> {
-        let mut condition = Condition::all();
-        if let Some(dict_name) = &query.dict_name {
-            condition = condition.add(DictTypeColumn::DictName.contains(dict_name));
-        }
-        if let Some(dict_type) = &query.dict_type {
-            condition = condition.add(DictTypeColumn::DictType.contains(dict_type));
-        }
-        if let Some(status) = &query.status {
-            condition = condition.add(DictTypeColumn::Status.eq(status));
-        }
-        if let Some(begin_time) = query.begin_time {
-            condition = condition.add(DictTypeColumn::CreateTime.gt(begin_time));
-        }
-        if let Some(end_time) = query.end_time {
-            condition = condition.add(DictTypeColumn::CreateTime.lt(end_time));
-        }
-        Some(condition)
-    }
 }
 
 #[async_trait]
@@ -82,7 +66,7 @@ impl DictTypeService for DictTypeServiceImpl {
     ) -> Result<(Vec<DictTypeModel>, u64)> {
         Ok(self
             .dict_type_repository
-            .get_dict_type_list(self.build_query_condition(&query), page_param)
+            .get_dict_type_list(Some(query.to_condition()), page_param)
             .await?)
     }
     async fn get_dict_type(&self, dict_id: i64) -> Result<Option<DictTypeModel>> {
@@ -95,6 +79,7 @@ impl DictTypeService for DictTypeServiceImpl {
         &self,
         dict_type: CreateOrUpdateDictTypeRequest,
     ) -> Result<DictTypeModel> {
+        check_platform_tenant("字典")?;
         let dict_type = DictTypeModel {
             dict_id: 0,
             dict_name: dict_type.dict_name,
@@ -113,6 +98,7 @@ impl DictTypeService for DictTypeServiceImpl {
             .await?)
     }
     async fn update_dict_type(&self, req: CreateOrUpdateDictTypeRequest) -> Result<DictTypeModel> {
+        check_platform_tenant("字典")?;
         let dict_type_id = req.dict_id.unwrap();
         let dict_type_model = self
             .dict_type_repository
@@ -124,6 +110,7 @@ impl DictTypeService for DictTypeServiceImpl {
                 dict_type_id
             )));
         }
+        let old_dict_type = dict_type_model.as_ref().and_then(|m| m.dict_type.clone());
         let mut dict_type_active_model = dict_type_model.unwrap().into_active_model();
         if let Some(dict_name) = req.dict_name {
             dict_type_active_model.dict_name = Set(Some(dict_name));
@@ -137,16 +124,34 @@ impl DictTypeService for DictTypeServiceImpl {
         if let Some(remark) = req.remark {
             dict_type_active_model.remark = Set(Some(remark));
         }
-        Ok(self
+        let dict_type = self
             .dict_type_repository
             .update_dict_type(dict_type_active_model)
-            .await?)
+            .await?;
+        if let Some(old_dict_type) = &old_dict_type {
+            remove_dict_cache(old_dict_type).await;
+        }
+        if let Some(new_dict_type) = &dict_type.dict_type {
+            remove_dict_cache(new_dict_type).await;
+        }
+        Ok(dict_type)
     }
     async fn delete_dict_types(&self, dict_ids: Vec<i64>) -> Result<u64> {
-        Ok(self
+        check_platform_tenant("字典")?;
+        let mut dict_types = Vec::new();
+        for dict_id in &dict_ids {
+            if let Some(model) = self.dict_type_repository.get_dict_type_by_id(*dict_id).await? {
+                dict_types.extend(model.dict_type);
+            }
+        }
+        let num = self
             .dict_type_repository
             .delete_dict_types(dict_ids)
-            .await?)
+            .await?;
+        for dict_type in &dict_types {
+            remove_dict_cache(dict_type).await;
+        }
+        Ok(num)
     }
     async fn check_dict_type_unique(&self, dict_type: &str, dict_id: Option<i64>) -> Result<bool> {
         Ok(self
@@ -7,24 +7,32 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use ruoyi_common::utils::time::deserialize_optional_datetime;
 use ruoyi_common::{vo::PageParam, Result};
-use sea_orm::{ColumnTrait, Condition, Set};
+use ruoyi_framework::db::query::QueryCondition;
+use ruoyi_framework::tenant::current_tenant_id_or_default;
+use sea_orm::Set;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
 /// 登录日志查询参数
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::LoginInfoColumn")]
 pub struct LoginInfoQuery {
     /// 用户名
+    #[query(like)]
     pub user_name: Option<String>,
     /// IP地址
+    #[query(like)]
     pub ipaddr: Option<String>,
     /// 登录状态（0成功 1失败）
+    #[query(eq)]
     pub status: Option<String>,
     /// 登录开始时间
     #[serde(rename = "params[beginTime]", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_optional_datetime")]
+    #[query(between = "begin_time,end_time", column = "login_time")]
     pub begin_time: Option<DateTime<Utc>>,
     /// 登录结束时间
     #[serde(rename = "params[endTime]", default)]
@@ -34,7 +42,7 @@ pub struct LoginInfoQuery {
 }
 
 /// 创建登录日志请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateLoginInfoRequest {
     /// 用户名
@@ -72,6 +80,13 @@ pub trait LoginInfoService: Send + Sync {
         page_param: PageParam,
     ) -> Result<(Vec<LoginInfoModel>, u64)>;
 
+    /// 按游标获取登录日志列表，返回当前页数据和下一页的游标
+    async fn get_login_info_list_by_cursor(
+        &self,
+        query: LoginInfoQuery,
+        page_param: PageParam,
+    ) -> Result<(Vec<LoginInfoModel>, Option<i64>)>;
+
     /// 根据ID获取登录日志
     async fn get_login_info_by_id(&self, info_id: i64) -> Result<Option<LoginInfoModel>>;
 
@@ -96,33 +111,6 @@ impl LoginInfoServiceImpl {
             login_info_repository,
         }
     }
-
-    pub fn build_query_condition(&self, query: &LoginInfoQuery) -> Option<Condition> {
-        let mut condition = Condition::all();
-
-        if let Some(user_name) = &query.user_name {
-            condition = condition.add(LoginInfoColumn::UserName.contains(user_name));
-        }
-
-        if let Some(ipaddr) = &query.ipaddr {
-            condition = condition.add(LoginInfoColumn::Ipaddr.contains(ipaddr));
-        }
-
-        if let Some(status) = &query.status {
-            condition = condition.add(LoginInfoColumn::Status.eq(status));
-        }
-
-        // 如果提供了开始时间和结束时间，添加时间范围条件
-        if let Some(begin_time) = &query.begin_time {
-            condition = condition.add(LoginInfoColumn::LoginTime.gte(*begin_time));
-        }
-
-        if let Some(end_time) = &query.end_time {
-            condition = condition.add(LoginInfoColumn::LoginTime.lte(*end_time));
-        }
-
-        Some(condition)
-    }
 }
 
 #[async_trait]
@@ -133,7 +121,17 @@ impl LoginInfoService for LoginInfoServiceImpl {
         page_param: PageParam,
     ) -> Result<(Vec<LoginInfoModel>, u64)> {
         self.login_info_repository
-            .get_login_info_list(self.build_query_condition(&query), page_param)
+            .get_login_info_list(Some(query.to_condition()), page_param)
+            .await
+    }
+
+    async fn get_login_info_list_by_cursor(
+        &self,
+        query: LoginInfoQuery,
+        page_param: PageParam,
+    ) -> Result<(Vec<LoginInfoModel>, Option<i64>)> {
+        self.login_info_repository
+            .get_login_info_list_by_cursor(Some(query.to_condition()), page_param)
             .await
     }
 
@@ -155,6 +153,7 @@ impl LoginInfoService for LoginInfoServiceImpl {
             status: Set(req.status),
             msg: Set(req.msg),
             login_time: Set(Some(Utc::now())),
+            tenant_id: Set(current_tenant_id_or_default()),
         };
 
         self.login_info_repository
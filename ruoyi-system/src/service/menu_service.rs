@@ -6,13 +6,15 @@ use ruoyi_common::error::Error::BusinessError;
 use ruoyi_common::utils::tree::build_tree;
 use ruoyi_common::vo::PageParam;
 use ruoyi_common::Result;
+use ruoyi_framework::db::query::QueryCondition;
+use ruoyi_framework::tenant::check_platform_tenant;
 use sea_orm::{ColumnTrait, Condition, IntoActiveModel, Set};
 use std::sync::Arc;
 
 use crate::controller::menu_controller::{CreateOrUpdateMenuRequest, MenuQuery};
 use crate::entity::vo::menu::MenuSelect;
 use crate::entity::vo::user::UserInfo;
-use crate::entity::{menu::Column, prelude::*, vo::router::RouterVo};
+use crate::entity::{prelude::*, vo::router::RouterVo};
 use crate::repository::menu_repository::MenuRepository;
 
 /// 菜单服务特征
@@ -64,21 +66,6 @@ impl MenuServiceImpl {
     pub fn new(menu_repository: Arc<dyn MenuRepository>) -> Self {
         Self { menu_repository }
     }
-
-    pub fn build_query_condition(&self, req: &MenuQuery) -> Option<Condition> {
-        let mut cond = Condition::all();
-        if let Some(name) = &req.menu_name {
-            if !name.is_empty() {
-                cond = cond.add(MenuColumn::MenuName.contains(name));
-            }
-        }
-        if let Some(status) = &req.status {
-            if !status.is_empty() {
-                cond = cond.add(Column::Status.eq(status.as_str()));
-            }
-        }
-        Some(cond)
-    }
 }
 
 #[async_trait]
@@ -88,7 +75,7 @@ impl MenuService for MenuServiceImpl {
         req: MenuQuery,
         page_param: PageParam,
     ) -> Result<(Vec<MenuModel>, u64)> {
-        let condition = self.build_query_condition(&req);
+        let condition = Some(req.to_condition());
         let (menus, total) = self
             .menu_repository
             .find_list(condition, page_param)
@@ -97,7 +84,7 @@ impl MenuService for MenuServiceImpl {
     }
 
     async fn get_menus_all(&self, req: MenuQuery) -> Result<Vec<MenuModel>> {
-        let condition = self.build_query_condition(&req);
+        let condition = Some(req.to_condition());
         Ok(self.menu_repository.find_all(condition).await?)
     }
 
@@ -110,6 +97,7 @@ impl MenuService for MenuServiceImpl {
     }
 
     async fn create_menu(&self, req: CreateOrUpdateMenuRequest) -> Result<MenuModel> {
+        check_platform_tenant("菜单")?;
         let menu_model = MenuModel {
             menu_id: 0,
             menu_name: req.menu_name.unwrap(),
@@ -131,18 +119,24 @@ impl MenuService for MenuServiceImpl {
             update_by: None,
             update_time: None,
             route_name: None,
+            version: 0,
         };
         let menu_active_model = menu_model.into_active_model();
         Ok(self.menu_repository.create_menu(menu_active_model).await?)
     }
 
     async fn update_menu(&self, req: CreateOrUpdateMenuRequest) -> Result<MenuModel> {
+        check_platform_tenant("菜单")?;
         let menu_id = req.menu_id.unwrap();
         let menu_model = self.menu_repository.find_by_id(menu_id).await?;
         if menu_model.is_none() {
             return Err(BusinessError(format!("菜单不存在")));
         }
         let mut menu_active_model = menu_model.unwrap().into_active_model();
+        // 以页面加载时的版本号为准，数据已被其他用户修改时更新失败
+        if let Some(version) = req.version {
+            menu_active_model.version = Set(version);
+        }
         // 更新菜单父级
         if let Some(parent_id) = req.parent_id {
             menu_active_model.parent_id = Set(Some(parent_id));
@@ -200,6 +194,7 @@ impl MenuService for MenuServiceImpl {
     }
 
     async fn delete_menu(&self, menu_id: i64) -> Result<()> {
+        check_platform_tenant("菜单")?;
         // 检查是否存在子菜单
         let has_child = self
             .menu_repository
@@ -9,7 +9,10 @@ pub mod menu_service;
 pub mod notice_service;
 pub mod post_service;
 pub mod role_service;
+pub mod tenant_package_service;
+pub mod tenant_service;
 pub mod user_service;
 
 pub mod login_info_service;
 pub mod oper_log_service;
+pub mod change_log_service;
@@ -7,26 +7,33 @@ use crate::{
 };
 use async_trait::async_trait;
 use ruoyi_common::{error::Error, utils::string::string_to_vec_u8, vo::PageParam, Result};
-use sea_orm::{ColumnTrait, Condition, Set};
+use ruoyi_framework::db::query::QueryCondition;
+use sea_orm::Set;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
 /// 通知公告查询参数
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::NoticeColumn")]
 pub struct NoticeQuery {
     /// 公告标题
+    #[query(like)]
     pub notice_title: Option<String>,
     /// 公告类型（1通知 2公告）
+    #[query(eq)]
     pub notice_type: Option<String>,
     /// 创建者
+    #[query(eq)]
     pub create_by: Option<String>,
     /// 公告状态（0正常 1关闭）
+    #[query(eq)]
     pub status: Option<String>,
 }
 
 /// 创建或更新通知公告请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrUpdateNoticeRequest {
     /// 公告ID
@@ -84,29 +91,6 @@ impl NoticeServiceImpl {
     pub fn new(notice_repository: Arc<dyn NoticeRepository>) -> Self {
         Self { notice_repository }
     }
-
-    pub fn build_query_condition(&self, query: &NoticeQuery) -> Option<Condition> {
-        let mut condition = Condition::all();
-
-        if let Some(notice_title) = &query.notice_title {
-            condition =
-                condition.add(crate::entity::notice::Column::NoticeTitle.contains(notice_title));
-        }
-
-        if let Some(notice_type) = &query.notice_type {
-            condition = condition.add(crate::entity::notice::Column::NoticeType.eq(notice_type));
-        }
-
-        if let Some(create_by) = &query.create_by {
-            condition = condition.add(crate::entity::notice::Column::CreateBy.eq(create_by));
-        }
-
-        if let Some(status) = &query.status {
-            condition = condition.add(crate::entity::notice::Column::Status.eq(status));
-        }
-
-        Some(condition)
-    }
 }
 
 #[async_trait]
@@ -117,7 +101,7 @@ impl NoticeService for NoticeServiceImpl {
         page_param: PageParam,
     ) -> Result<(Vec<NoticeModel>, u64)> {
         self.notice_repository
-            .get_notice_list(self.build_query_condition(&query), page_param)
+            .get_notice_list(Some(query.to_condition()), page_param)
             .await
     }
 
@@ -7,28 +7,38 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use ruoyi_common::utils::time::deserialize_optional_datetime;
 use ruoyi_common::{vo::PageParam, Result};
-use sea_orm::{ColumnTrait, Condition, Set};
+use ruoyi_framework::db::query::QueryCondition;
+use ruoyi_framework::tenant::current_tenant_id_or_default;
+use sea_orm::Set;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
 
 /// 操作日志查询参数
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, QueryCondition, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[query(columns = "crate::entity::prelude::OperLogColumn")]
 pub struct OperLogQuery {
     /// 模块标题
+    #[query(like)]
     pub title: Option<String>,
     /// 操作地址
+    #[query(like)]
     pub oper_ip: Option<String>,
     /// 操作人员
+    #[query(like)]
     pub oper_name: Option<String>,
     /// 业务类型（0=其它,1=新增,2=修改,3=删除,4=授权,5=导出,6=导入,7=强退,8=生成代码,9=清空数据）
+    #[query(eq)]
     pub business_type: Option<i32>,
     /// 操作状态（0正常 1异常）
+    #[query(eq)]
     pub status: Option<i32>,
     /// 操作开始时间
     #[serde(rename = "params[beginTime]", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_optional_datetime")]
+    #[query(between = "begin_time,end_time", column = "oper_time")]
     pub begin_time: Option<DateTime<Utc>>,
     /// 操作结束时间
     #[serde(rename = "params[endTime]", default)]
@@ -38,7 +48,7 @@ pub struct OperLogQuery {
 }
 
 /// 创建操作日志请求
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOperLogRequest {
     /// 模块标题
@@ -92,6 +102,13 @@ pub trait OperLogService: Send + Sync {
         page_param: PageParam,
     ) -> Result<(Vec<OperLogModel>, u64)>;
 
+    /// 按游标获取操作日志列表，返回当前页数据和下一页的游标
+    async fn get_oper_log_list_by_cursor(
+        &self,
+        query: OperLogQuery,
+        page_param: PageParam,
+    ) -> Result<(Vec<OperLogModel>, Option<i64>)>;
+
     /// 根据ID获取操作日志
     async fn get_oper_log_by_id(&self, oper_id: i64) -> Result<Option<OperLogModel>>;
 
@@ -116,40 +133,6 @@ impl OperLogServiceImpl {
             oper_log_repository,
         }
     }
-
-    pub fn build_query_condition(&self, query: &OperLogQuery) -> Option<Condition> {
-        let mut condition = Condition::all();
-        if let Some(title) = &query.title {
-            condition = condition.add(OperLogColumn::Title.contains(title));
-        }
-
-        if let Some(oper_ip) = &query.oper_ip {
-            condition = condition.add(OperLogColumn::OperIp.contains(oper_ip));
-        }
-
-        if let Some(oper_name) = &query.oper_name {
-            condition = condition.add(OperLogColumn::OperName.contains(oper_name));
-        }
-
-        if let Some(business_type) = &query.business_type {
-            condition = condition.add(OperLogColumn::BusinessType.eq(*business_type));
-        }
-
-        if let Some(status) = &query.status {
-            condition = condition.add(OperLogColumn::Status.eq(*status));
-        }
-
-        // 如果提供了开始时间和结束时间，添加时间范围条件
-        if let Some(begin_time) = &query.begin_time {
-            condition = condition.add(OperLogColumn::OperTime.gte(*begin_time));
-        }
-
-        if let Some(end_time) = &query.end_time {
-            condition = condition.add(OperLogColumn::OperTime.lte(*end_time));
-        }
-
-        Some(condition)
-    }
 }
 
 #[async_trait]
@@ -160,7 +143,17 @@ impl OperLogService for OperLogServiceImpl {
         page_param: PageParam,
     ) -> Result<(Vec<OperLogModel>, u64)> {
         self.oper_log_repository
-            .get_oper_log_list(self.build_query_condition(&query), page_param)
+            .get_oper_log_list(Some(query.to_condition()), page_param)
+            .await
+    }
+
+    async fn get_oper_log_list_by_cursor(
+        &self,
+        query: OperLogQuery,
+        page_param: PageParam,
+    ) -> Result<(Vec<OperLogModel>, Option<i64>)> {
+        self.oper_log_repository
+            .get_oper_log_list_by_cursor(Some(query.to_condition()), page_param)
             .await
     }
 
@@ -209,6 +202,7 @@ impl OperLogService for OperLogServiceImpl {
             error_msg: Set(req.error_msg),
             oper_time: Set(Some(Utc::now())),
             cost_time: Set(req.cost_time),
+            tenant_id: Set(current_tenant_id_or_default()),
         };
 
         self.oper_log_repository.create_oper_log(oper_log).await
@@ -6,7 +6,8 @@ use log::error;
 use ruoyi_common::error::Error;
 use ruoyi_common::vo::PageParam;
 use ruoyi_common::Result;
-use sea_orm::{ColumnTrait, Condition, IntoActiveModel, Set};
+use ruoyi_framework::db::query::QueryCondition;
+use sea_orm::{IntoActiveModel, Set};
 use std::sync::Arc;
 
 #[async_trait]
@@ -43,20 +44,6 @@ impl PostServiceImpl {
     pub fn new(post_repository: Arc<dyn PostRepository>) -> Self {
         Self { post_repository }
     }
-
-    pub fn build_query_condition(&self, req: &PostQuery) -> Option<Condition> {
-        let mut condition = Condition::all();
-        if let Some(post_name) = &req.post_name {
-            condition = condition.add(PostColumn::PostName.contains(post_name));
-        }
-        if let Some(post_code) = &req.post_code {
-            condition = condition.add(PostColumn::PostCode.contains(post_code));
-        }
-        if let Some(status) = &req.status {
-            condition = condition.add(PostColumn::Status.eq(status));
-        }
-        Some(condition)
-    }
 }
 
 #[async_trait]
@@ -68,7 +55,7 @@ impl PostService for PostServiceImpl {
     ) -> Result<(Vec<PostModel>, u64)> {
         Ok(self
             .post_repository
-            .get_post_list(self.build_query_condition(&req), page_param)
+            .get_post_list(Some(req.to_condition()), page_param)
             .await?)
     }
 
@@ -88,6 +75,7 @@ impl PostService for PostServiceImpl {
             update_by: None,
             update_time: None,
             remark: post.remark,
+            tenant_id: 0,
         };
 
         Ok(self
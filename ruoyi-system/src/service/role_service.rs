@@ -6,7 +6,8 @@ use log::error;
 use ruoyi_common::error::Error::BusinessError;
 use ruoyi_common::vo::PageParam;
 use ruoyi_common::Result;
-use sea_orm::{ColumnTrait, Condition, IntoActiveModel, Set};
+use ruoyi_framework::db::query::QueryCondition;
+use sea_orm::{IntoActiveModel, Set};
 use std::sync::Arc;
 
 use crate::controller::role_controller::{CreateOrUpdateRoleRequest, RoleAuthUserQuery, RoleQuery};
@@ -39,6 +40,12 @@ pub trait RoleService: Send + Sync {
     /// 删除角色
     async fn delete_roles(&self, role_ids: Vec<i64>) -> Result<()>;
 
+    /// 恢复已删除的角色
+    async fn restore_roles(&self, role_ids: Vec<i64>) -> Result<u64>;
+
+    /// 彻底删除已删除的角色
+    async fn purge_roles(&self, role_ids: Vec<i64>) -> Result<u64>;
+
     /// 检查角色名称是否唯一
     async fn check_role_name_unique(&self, role_name: &str, role_id: Option<i64>) -> Result<bool>;
 
@@ -83,60 +90,6 @@ impl RoleServiceImpl {
     pub fn new(role_repository: Arc<dyn RoleRepository>) -> Self {
         Self { role_repository }
     }
-
-    /// 构建查询条件
-    fn build_query_condition(&self, query: &RoleQuery) -> Option<Condition> {
-        let mut condition = Condition::all();
-        let mut has_condition = false;
-
-        if let Some(name) = &query.role_name {
-            if !name.is_empty() {
-                condition = condition.add(RoleColumn::RoleName.contains(name));
-                has_condition = true;
-            }
-        }
-
-        if let Some(key) = &query.role_key {
-            if !key.is_empty() {
-                condition = condition.add(RoleColumn::RoleKey.contains(key));
-                has_condition = true;
-            }
-        }
-
-        if let Some(status) = &query.status {
-            if !status.is_empty() {
-                condition = condition.add(RoleColumn::Status.eq(status));
-                has_condition = true;
-            }
-        }
-
-        if let Some(begin_time) = query.begin_time {
-            condition = condition.add(RoleColumn::CreateTime.gt(begin_time));
-            has_condition = true;
-        }
-
-        if let Some(end_time) = query.end_time {
-            condition = condition.add(RoleColumn::CreateTime.lt(end_time));
-            has_condition = true;
-        }
-
-        if has_condition {
-            Some(condition)
-        } else {
-            None
-        }
-    }
-
-    fn build_allocated_users_condition(&self, req: &RoleAuthUserQuery) -> Option<Condition> {
-        let mut condition = Condition::all();
-        if let Some(user_name) = &req.user_name {
-            condition = condition.add(UserColumn::UserName.contains(user_name));
-        }
-        if let Some(phonenumber) = &req.phonenumber {
-            condition = condition.add(UserColumn::Phonenumber.contains(phonenumber));
-        }
-        Some(condition)
-    }
 }
 
 #[async_trait]
@@ -146,7 +99,7 @@ impl RoleService for RoleServiceImpl {
         query: RoleQuery,
         page_param: PageParam,
     ) -> Result<(Vec<RoleModel>, u64)> {
-        let condition = self.build_query_condition(&query);
+        let condition = Some(query.to_condition());
         let (roles, total) = self
             .role_repository
             .find_list(condition, &page_param)
@@ -179,6 +132,8 @@ impl RoleService for RoleServiceImpl {
             update_by: None,
             update_time: None,
             remark: req.remark,
+            version: 0,
+            tenant_id: 0,
         };
         let role_active_model = role_model.into_active_model();
         let role = self
@@ -197,6 +152,10 @@ impl RoleService for RoleServiceImpl {
         let menu_ids = req.menu_ids;
         let dept_ids = req.dept_ids;
         let mut role_active_model = role_model.unwrap().into_active_model();
+        // 以页面加载时的版本号为准，数据已被其他用户修改时更新失败
+        if let Some(version) = req.version {
+            role_active_model.version = Set(version);
+        }
         if let Some(role_name) = req.role_name {
             role_active_model.role_name = Set(role_name);
         }
@@ -239,6 +198,14 @@ impl RoleService for RoleServiceImpl {
         Ok(())
     }
 
+    async fn restore_roles(&self, role_ids: Vec<i64>) -> Result<u64> {
+        self.role_repository.restore_role_by_ids(role_ids).await
+    }
+
+    async fn purge_roles(&self, role_ids: Vec<i64>) -> Result<u64> {
+        self.role_repository.purge_role_by_ids(role_ids).await
+    }
+
     async fn check_role_name_unique(&self, role_name: &str, role_id: Option<i64>) -> Result<bool> {
         self.role_repository
             .check_role_name_unique(role_name, role_id)
@@ -270,12 +237,11 @@ impl RoleService for RoleServiceImpl {
         &self,
         req: RoleAuthUserQuery,
     ) -> Result<(Vec<UserInfo>, u64)> {
-        let condition = self.build_allocated_users_condition(&req);
+        let condition = Some(req.to_condition());
         let page_param = PageParam {
             page_size: req.page_size,
             page_num: req.page_num,
-            order_by_column: None,
-            is_asc: None,
+            ..Default::default()
         };
         let (users, total) = self
             .role_repository
@@ -292,12 +258,11 @@ impl RoleService for RoleServiceImpl {
         &self,
         req: RoleAuthUserQuery,
     ) -> Result<(Vec<UserInfo>, u64)> {
-        let condition = self.build_allocated_users_condition(&req);
+        let condition = Some(req.to_condition());
         let page_param = PageParam {
             page_size: req.page_size,
             page_num: req.page_num,
-            order_by_column: None,
-            is_asc: None,
+            ..Default::default()
         };
         let (users, total) = self
             .role_repository
@@ -0,0 +1,185 @@
+// ruoyi-system/src/service/tenant_package_service.rs
+//! 租户套餐服务
+
+use async_trait::async_trait;
+use ruoyi_common::error::Error;
+use ruoyi_common::vo::PageParam;
+use ruoyi_common::Result;
+use ruoyi_framework::db::query::QueryCondition;
+use sea_orm::{IntoActiveModel, Set};
+use std::sync::Arc;
+
+use crate::controller::tenant_package_controller::{
+    CreateOrUpdateTenantPackageRequest, TenantPackageQuery,
+};
+use crate::entity::prelude::*;
+use crate::repository::tenant_package_repository::TenantPackageRepository;
+use crate::repository::tenant_repository::TenantRepository;
+
+/// 租户套餐服务接口
+#[async_trait]
+pub trait TenantPackageService: Send + Sync {
+    /// 获取套餐列表
+    async fn get_package_list(
+        &self,
+        query: TenantPackageQuery,
+        page_param: PageParam,
+    ) -> Result<(Vec<TenantPackageModel>, u64)>;
+
+    /// 获取套餐详情
+    async fn get_package(&self, package_id: i64) -> Result<Option<TenantPackageModel>>;
+
+    /// 获取套餐关联的菜单ID
+    async fn get_package_menu_ids(&self, package_id: i64) -> Result<Vec<i64>>;
+
+    /// 获取可选的套餐
+    async fn get_package_options(&self) -> Result<Vec<TenantPackageModel>>;
+
+    /// 创建套餐
+    async fn create_package(
+        &self,
+        req: CreateOrUpdateTenantPackageRequest,
+    ) -> Result<TenantPackageModel>;
+
+    /// 更新套餐
+    async fn update_package(
+        &self,
+        req: CreateOrUpdateTenantPackageRequest,
+    ) -> Result<TenantPackageModel>;
+
+    /// 删除套餐
+    async fn delete_packages(&self, package_ids: Vec<i64>) -> Result<u64>;
+
+    /// 检查套餐名称是否唯一
+    async fn check_package_name_unique(
+        &self,
+        package_name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool>;
+}
+
+/// 租户套餐服务实现
+pub struct TenantPackageServiceImpl {
+    tenant_package_repository: Arc<dyn TenantPackageRepository>,
+    tenant_repository: Arc<dyn TenantRepository>,
+}
+
+impl TenantPackageServiceImpl {
+    /// 创建租户套餐服务
+    pub fn new(
+        tenant_package_repository: Arc<dyn TenantPackageRepository>,
+        tenant_repository: Arc<dyn TenantRepository>,
+    ) -> Self {
+        Self {
+            tenant_package_repository,
+            tenant_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl TenantPackageService for TenantPackageServiceImpl {
+    async fn get_package_list(
+        &self,
+        query: TenantPackageQuery,
+        page_param: PageParam,
+    ) -> Result<(Vec<TenantPackageModel>, u64)> {
+        self.tenant_package_repository
+            .find_list(Some(query.to_condition()), &page_param)
+            .await
+    }
+
+    async fn get_package(&self, package_id: i64) -> Result<Option<TenantPackageModel>> {
+        self.tenant_package_repository.find_by_id(package_id).await
+    }
+
+    async fn get_package_menu_ids(&self, package_id: i64) -> Result<Vec<i64>> {
+        self.tenant_package_repository
+            .find_menu_ids(package_id)
+            .await
+    }
+
+    async fn get_package_options(&self) -> Result<Vec<TenantPackageModel>> {
+        self.tenant_package_repository.find_all_normal().await
+    }
+
+    async fn create_package(
+        &self,
+        req: CreateOrUpdateTenantPackageRequest,
+    ) -> Result<TenantPackageModel> {
+        let package = TenantPackageModel {
+            package_id: 0,
+            package_name: req.package_name.unwrap_or_default(),
+            menu_check_strictly: req.menu_check_strictly,
+            status: req.status.unwrap_or_else(|| "0".to_string()),
+            del_flag: Some("0".to_string()),
+            create_by: None,
+            create_time: None,
+            update_by: None,
+            update_time: None,
+            remark: req.remark,
+        };
+        self.tenant_package_repository
+            .create(
+                package.into_active_model(),
+                req.menu_ids.unwrap_or_default(),
+            )
+            .await
+    }
+
+    async fn update_package(
+        &self,
+        req: CreateOrUpdateTenantPackageRequest,
+    ) -> Result<TenantPackageModel> {
+        let package_id = req.package_id.unwrap_or(0);
+        let package = self
+            .tenant_package_repository
+            .find_by_id(package_id)
+            .await?
+            .ok_or_else(|| Error::BusinessError(format!("套餐不存在: {}", package_id)))?;
+        let mut active_model = package.into_active_model();
+        if let Some(package_name) = req.package_name {
+            active_model.package_name = Set(package_name);
+        }
+        if let Some(menu_check_strictly) = req.menu_check_strictly {
+            active_model.menu_check_strictly = Set(Some(menu_check_strictly));
+        }
+        if let Some(status) = req.status {
+            active_model.status = Set(status);
+        }
+        if let Some(remark) = req.remark {
+            active_model.remark = Set(Some(remark));
+        }
+        self.tenant_package_repository
+            .update(active_model, req.menu_ids)
+            .await
+    }
+
+    async fn delete_packages(&self, package_ids: Vec<i64>) -> Result<u64> {
+        for package_id in &package_ids {
+            if self
+                .tenant_repository
+                .has_tenant_by_package_id(*package_id)
+                .await?
+            {
+                return Err(Error::BusinessError(format!(
+                    "套餐{}已被租户使用，不能删除",
+                    package_id
+                )));
+            }
+        }
+        self.tenant_package_repository
+            .delete_by_ids(package_ids)
+            .await
+    }
+
+    async fn check_package_name_unique(
+        &self,
+        package_name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool> {
+        self.tenant_package_repository
+            .check_package_name_unique(package_name, exclude_id)
+            .await
+    }
+}
@@ -0,0 +1,274 @@
+// ruoyi-system/src/service/tenant_service.rs
+//! 租户服务
+
+use async_trait::async_trait;
+use ruoyi_common::error::Error;
+use ruoyi_common::utils::password::encrypt_password;
+use ruoyi_common::vo::PageParam;
+use ruoyi_common::Result;
+use ruoyi_framework::db::query::QueryCondition;
+use ruoyi_framework::db::transaction::transactional;
+use ruoyi_framework::tenant::DEFAULT_TENANT_ID;
+use sea_orm::{DatabaseConnection, IntoActiveModel, Set};
+use std::sync::Arc;
+
+use crate::controller::tenant_controller::{CreateOrUpdateTenantRequest, TenantQuery};
+use crate::entity::prelude::*;
+use crate::repository::dept_repository::DeptRepository;
+use crate::repository::role_repository::RoleRepository;
+use crate::repository::tenant_package_repository::TenantPackageRepository;
+use crate::repository::tenant_repository::TenantRepository;
+use crate::repository::user_repository::UserRepository;
+
+/// 租户服务接口
+#[async_trait]
+pub trait TenantService: Send + Sync {
+    /// 获取租户列表
+    async fn get_tenant_list(
+        &self,
+        query: TenantQuery,
+        page_param: PageParam,
+    ) -> Result<(Vec<TenantModel>, u64)>;
+
+    /// 获取租户详情
+    async fn get_tenant(&self, tenant_id: i64) -> Result<Option<TenantModel>>;
+
+    /// 创建租户，同时初始化租户的部门、管理员角色和管理员账号
+    async fn create_tenant(&self, req: CreateOrUpdateTenantRequest) -> Result<TenantModel>;
+
+    /// 更新租户
+    async fn update_tenant(&self, req: CreateOrUpdateTenantRequest) -> Result<TenantModel>;
+
+    /// 删除租户
+    async fn delete_tenants(&self, tenant_ids: Vec<i64>) -> Result<u64>;
+
+    /// 修改租户状态
+    async fn change_status(&self, tenant_id: i64, status: &str) -> Result<TenantModel>;
+
+    /// 检查租户名称是否唯一
+    async fn check_tenant_name_unique(
+        &self,
+        tenant_name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool>;
+
+    /// 检查租户是否可以登录：存在、未停用且未过期
+    async fn check_tenant(&self, tenant_id: i64) -> Result<()>;
+}
+
+/// 租户服务实现
+pub struct TenantServiceImpl {
+    /// 数据库连接，用于开启事务
+    db: Arc<DatabaseConnection>,
+    tenant_repository: Arc<dyn TenantRepository>,
+    tenant_package_repository: Arc<dyn TenantPackageRepository>,
+    dept_repository: Arc<dyn DeptRepository>,
+    role_repository: Arc<dyn RoleRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl TenantServiceImpl {
+    /// 创建租户服务
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        tenant_repository: Arc<dyn TenantRepository>,
+        tenant_package_repository: Arc<dyn TenantPackageRepository>,
+        dept_repository: Arc<dyn DeptRepository>,
+        role_repository: Arc<dyn RoleRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            db,
+            tenant_repository,
+            tenant_package_repository,
+            dept_repository,
+            role_repository,
+            user_repository,
+        }
+    }
+
+    /// 查询租户，不存在时返回业务错误
+    async fn find_tenant(&self, tenant_id: i64) -> Result<TenantModel> {
+        self.tenant_repository
+            .find_by_id(tenant_id)
+            .await?
+            .ok_or_else(|| Error::BusinessError(format!("租户不存在: {}", tenant_id)))
+    }
+}
+
+#[async_trait]
+impl TenantService for TenantServiceImpl {
+    async fn get_tenant_list(
+        &self,
+        query: TenantQuery,
+        page_param: PageParam,
+    ) -> Result<(Vec<TenantModel>, u64)> {
+        self.tenant_repository
+            .find_list(Some(query.to_condition()), &page_param)
+            .await
+    }
+
+    async fn get_tenant(&self, tenant_id: i64) -> Result<Option<TenantModel>> {
+        self.tenant_repository.find_by_id(tenant_id).await
+    }
+
+    #[transactional]
+    async fn create_tenant(&self, req: CreateOrUpdateTenantRequest) -> Result<TenantModel> {
+        // 套餐决定租户管理员可以使用的菜单
+        let menu_ids = match req.package_id {
+            Some(package_id) => {
+                self.tenant_package_repository
+                    .find_by_id(package_id)
+                    .await?
+                    .ok_or_else(|| Error::BusinessError(format!("套餐不存在: {}", package_id)))?;
+                self.tenant_package_repository
+                    .find_menu_ids(package_id)
+                    .await?
+            }
+            None => vec![],
+        };
+
+        let tenant_name = req.tenant_name.unwrap_or_default();
+        let tenant = TenantModel {
+            tenant_id: 0,
+            tenant_name: tenant_name.clone(),
+            contact_user_name: req.contact_user_name.clone(),
+            contact_phone: req.contact_phone.clone(),
+            package_id: req.package_id,
+            expire_time: req.expire_time,
+            status: req.status.unwrap_or_else(|| "0".to_string()),
+            del_flag: Some("0".to_string()),
+            create_by: None,
+            create_time: None,
+            update_by: None,
+            update_time: None,
+            remark: req.remark,
+        };
+        let tenant = self
+            .tenant_repository
+            .create(tenant.into_active_model())
+            .await?;
+        let tenant_id = tenant.tenant_id;
+
+        // 租户的根部门
+        let dept = DeptActiveModel {
+            tenant_id: Set(tenant_id),
+            parent_id: Set(Some(0)),
+            ancestors: Set(Some("0".to_string())),
+            dept_name: Set(Some(tenant_name)),
+            order_num: Set(Some(0)),
+            leader: Set(req.contact_user_name),
+            phone: Set(req.contact_phone),
+            status: Set(Some("0".to_string())),
+            ..Default::default()
+        };
+        let dept = self.dept_repository.create(dept).await?;
+
+        // 租户管理员角色，拥有套餐内的全部菜单
+        let role = RoleActiveModel {
+            tenant_id: Set(tenant_id),
+            role_name: Set("租户管理员".to_string()),
+            role_key: Set("admin".to_string()),
+            role_sort: Set(1),
+            data_scope: Set(Some("1".to_string())),
+            menu_check_strictly: Set(Some(true)),
+            dept_check_strictly: Set(Some(true)),
+            status: Set("0".to_string()),
+            version: Set(0),
+            ..Default::default()
+        };
+        let role = self
+            .role_repository
+            .create_role(role, Some(menu_ids))
+            .await?;
+
+        // 租户管理员账号
+        let user_name = req.user_name.unwrap_or_default();
+        let user = UserActiveModel {
+            tenant_id: Set(tenant_id),
+            dept_id: Set(Some(dept.dept_id)),
+            user_name: Set(user_name.clone()),
+            nick_name: Set(user_name),
+            password: Set(Some(encrypt_password(
+                req.password.as_deref().unwrap_or_default(),
+            )?)),
+            status: Set(Some("0".to_string())),
+            ..Default::default()
+        };
+        let user = self.user_repository.create_user(user).await?;
+        self.role_repository
+            .set_user_roles(user.user_id, vec![role.role_id])
+            .await?;
+
+        Ok(tenant)
+    }
+
+    async fn update_tenant(&self, req: CreateOrUpdateTenantRequest) -> Result<TenantModel> {
+        let tenant = self.find_tenant(req.tenant_id.unwrap_or(0)).await?;
+        let mut active_model = tenant.into_active_model();
+        if let Some(tenant_name) = req.tenant_name {
+            active_model.tenant_name = Set(tenant_name);
+        }
+        if let Some(contact_user_name) = req.contact_user_name {
+            active_model.contact_user_name = Set(Some(contact_user_name));
+        }
+        if let Some(contact_phone) = req.contact_phone {
+            active_model.contact_phone = Set(Some(contact_phone));
+        }
+        if let Some(package_id) = req.package_id {
+            active_model.package_id = Set(Some(package_id));
+        }
+        if req.expire_time.is_some() {
+            active_model.expire_time = Set(req.expire_time);
+        }
+        if let Some(status) = req.status {
+            active_model.status = Set(status);
+        }
+        if let Some(remark) = req.remark {
+            active_model.remark = Set(Some(remark));
+        }
+        self.tenant_repository.update(active_model).await
+    }
+
+    async fn delete_tenants(&self, tenant_ids: Vec<i64>) -> Result<u64> {
+        if tenant_ids.contains(&DEFAULT_TENANT_ID) {
+            return Err(Error::BusinessError("默认租户不能删除".to_string()));
+        }
+        self.tenant_repository.delete_by_ids(tenant_ids).await
+    }
+
+    async fn change_status(&self, tenant_id: i64, status: &str) -> Result<TenantModel> {
+        if tenant_id == DEFAULT_TENANT_ID && status != "0" {
+            return Err(Error::BusinessError("默认租户不能停用".to_string()));
+        }
+        let tenant = self.find_tenant(tenant_id).await?;
+        let mut active_model = tenant.into_active_model();
+        active_model.status = Set(status.to_string());
+        self.tenant_repository.update(active_model).await
+    }
+
+    async fn check_tenant_name_unique(
+        &self,
+        tenant_name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<bool> {
+        self.tenant_repository
+            .check_tenant_name_unique(tenant_name, exclude_id)
+            .await
+    }
+
+    async fn check_tenant(&self, tenant_id: i64) -> Result<()> {
+        let tenant = self
+            .tenant_repository
+            .find_by_id(tenant_id)
+            .await?
+            .ok_or_else(|| Error::BusinessError("租户不存在".to_string()))?;
+        if tenant.status != "0" {
+            return Err(Error::BusinessError("租户已停用".to_string()));
+        }
+        if tenant.is_expired() {
+            return Err(Error::BusinessError("租户已过期".to_string()));
+        }
+        Ok(())
+    }
+}
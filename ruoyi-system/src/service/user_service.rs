@@ -7,7 +7,9 @@ use ruoyi_common::error::Error;
 use ruoyi_common::utils::password::encrypt_password;
 use ruoyi_common::vo::PageParam;
 use ruoyi_common::Result;
-use sea_orm::{IntoActiveModel, Set};
+use ruoyi_framework::db::encryption::Encrypted;
+use ruoyi_framework::db::transaction::transactional;
+use sea_orm::{DatabaseConnection, IntoActiveModel, Set};
 use std::sync::Arc;
 
 use crate::controller::user_controller::{CreateOrUpdateUserRequest, UserQuery};
@@ -15,6 +17,7 @@ use crate::entity::prelude::*;
 use crate::entity::vo::user::UserInfo;
 use crate::repository::dept_repository::DeptRepository;
 use crate::repository::menu_repository::MenuRepository;
+use crate::repository::post_repository::PostRepository;
 use crate::repository::role_repository::RoleRepository;
 use crate::repository::user_repository::UserRepository;
 
@@ -43,6 +46,12 @@ pub trait UserService: Send + Sync {
     /// 删除用户
     async fn delete_user_by_ids(&self, user_ids: Vec<i64>) -> Result<()>;
 
+    /// 恢复已删除的用户
+    async fn restore_user_by_ids(&self, user_ids: Vec<i64>) -> Result<u64>;
+
+    /// 彻底删除已删除的用户
+    async fn purge_user_by_ids(&self, user_ids: Vec<i64>) -> Result<u64>;
+
     /// 重置密码
     async fn reset_password(&self, user_id: i64, new_password: &str) -> Result<()>;
 
@@ -52,6 +61,12 @@ pub trait UserService: Send + Sync {
     /// 检查用户是否存在
     async fn check_user_name_unique(&self, username: &str, user_id: Option<i64>) -> Result<bool>;
 
+    /// 检查手机号码是否唯一
+    async fn check_phone_unique(&self, phone: &str, user_id: Option<i64>) -> Result<bool>;
+
+    /// 检查邮箱是否唯一
+    async fn check_email_unique(&self, email: &str, user_id: Option<i64>) -> Result<bool>;
+
     /// 获取用户角色列表
     async fn get_user_roles(&self, user_id: i64) -> Result<Vec<RoleModel>>;
 
@@ -61,8 +76,11 @@ pub trait UserService: Send + Sync {
 
 /// 用户服务实现
 pub struct UserServiceImpl {
+    /// 数据库连接，用于开启事务
+    db: Arc<DatabaseConnection>,
     user_repository: Arc<dyn UserRepository>,
     role_repository: Arc<dyn RoleRepository>,
+    post_repository: Arc<dyn PostRepository>,
     menu_repository: Arc<dyn MenuRepository>,
     dept_repository: Arc<dyn DeptRepository>,
 }
@@ -70,14 +88,18 @@ pub struct UserServiceImpl {
 impl UserServiceImpl {
     /// 创建用户服务
     pub fn new(
+        db: Arc<DatabaseConnection>,
         user_repository: Arc<dyn UserRepository>,
         role_repository: Arc<dyn RoleRepository>,
+        post_repository: Arc<dyn PostRepository>,
         menu_repository: Arc<dyn MenuRepository>,
         dept_repository: Arc<dyn DeptRepository>,
     ) -> Self {
         Self {
+            db,
             user_repository,
             role_repository,
+            post_repository,
             menu_repository,
             dept_repository,
         }
@@ -146,6 +168,7 @@ impl UserService for UserServiceImpl {
         Ok(Some(user))
     }
 
+    #[transactional]
     async fn create_user(&self, req: CreateOrUpdateUserRequest) -> Result<UserModel> {
         // 用户密码进行编码
         let password = if let Some(password) = req.password {
@@ -161,8 +184,10 @@ impl UserService for UserServiceImpl {
             user_name: req.user_name.unwrap(),
             nick_name: req.nick_name.unwrap(),
             user_type: None,
-            email: req.email,
-            phonenumber: req.phonenumber,
+            email: req.email.map(Encrypted::from),
+            email_index: None,
+            phonenumber: req.phonenumber.map(Encrypted::from),
+            phonenumber_index: None,
             sex: req.sex,
             avatar: None,
             password: password,
@@ -175,15 +200,28 @@ impl UserService for UserServiceImpl {
             update_by: None,
             update_time: None,
             remark: req.remark,
+            tenant_id: 0,
         };
 
+        // 用户、角色关联和岗位关联在同一事务中写入
         let user = self
             .user_repository
-            .create_user(user_model.into_active_model(), req.role_ids, req.post_ids)
+            .create_user(user_model.into_active_model())
             .await?;
+        if let Some(role_ids) = req.role_ids {
+            self.role_repository
+                .set_user_roles(user.user_id, role_ids)
+                .await?;
+        }
+        if let Some(post_ids) = req.post_ids {
+            self.post_repository
+                .set_user_posts(user.user_id, post_ids)
+                .await?;
+        }
         Ok(user)
     }
 
+    #[transactional]
     async fn update_user(&self, req: CreateOrUpdateUserRequest) -> Result<UserInfo> {
         // 先检查用户是否存在
         let user = self
@@ -202,11 +240,11 @@ impl UserService for UserServiceImpl {
                 update_flag = true;
             }
             if let Some(email) = req.email {
-                user_active_model.email = Set(Some(email));
+                user_active_model.email = Set(Some(email.into()));
                 update_flag = true;
             }
             if let Some(phonenumber) = req.phonenumber {
-                user_active_model.phonenumber = Set(Some(phonenumber));
+                user_active_model.phonenumber = Set(Some(phonenumber.into()));
                 update_flag = true;
             }
             if let Some(sex) = req.sex {
@@ -226,10 +264,13 @@ impl UserService for UserServiceImpl {
                 update_flag = true;
             }
             if update_flag {
-                let user_model = self
-                    .user_repository
-                    .update_user(user_active_model, req.role_ids, req.post_ids)
+                self.role_repository
+                    .set_user_roles(user.user_id, req.role_ids.unwrap_or_default())
                     .await?;
+                self.post_repository
+                    .set_user_posts(user.user_id, req.post_ids.unwrap_or_default())
+                    .await?;
+                let user_model = self.user_repository.update_user(user_active_model).await?;
                 Ok(UserInfo::from_model(&user_model))
             } else {
                 Ok(UserInfo::from_model(&user))
@@ -248,6 +289,14 @@ impl UserService for UserServiceImpl {
         Ok(())
     }
 
+    async fn restore_user_by_ids(&self, user_ids: Vec<i64>) -> Result<u64> {
+        self.user_repository.restore_users_by_ids(user_ids).await
+    }
+
+    async fn purge_user_by_ids(&self, user_ids: Vec<i64>) -> Result<u64> {
+        self.user_repository.purge_users_by_ids(user_ids).await
+    }
+
     async fn reset_password(&self, user_id: i64, new_password: &str) -> Result<()> {
         // 加密密码
         match encrypt_password(new_password) {
@@ -275,6 +324,14 @@ impl UserService for UserServiceImpl {
             .await
     }
 
+    async fn check_phone_unique(&self, phone: &str, user_id: Option<i64>) -> Result<bool> {
+        self.user_repository.check_phone_unique(phone, user_id).await
+    }
+
+    async fn check_email_unique(&self, email: &str, user_id: Option<i64>) -> Result<bool> {
+        self.user_repository.check_email_unique(email, user_id).await
+    }
+
     async fn get_user_roles(&self, user_id: i64) -> Result<Vec<RoleModel>> {
         // 调用仓库方法获取用户角色
         match self